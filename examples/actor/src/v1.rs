@@ -0,0 +1,140 @@
+use tokio::sync::mpsc;
+use tokio::sync::oneshot;
+
+#[cfg(test)]
+mod contract;
+
+/// How many commands may wait in the mailbox before senders have to wait.
+pub const MAILBOX_CAPACITY: usize = 32;
+
+/// `Stopped` is returned to a command sent to a counter that has stopped.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+#[error("counter has stopped")]
+pub struct Stopped;
+
+/// `Command` is a message in the mailbox of the counter. A command that
+/// expects an answer carries the channel to send it on.
+enum Command {
+    Increase,
+    Value { reply: oneshot::Sender<usize> },
+    Stop { reply: oneshot::Sender<usize> },
+}
+
+/// `CounterActor` owns the count. Commands are handled one at a time in the
+/// order they arrived, so the count needs no lock.
+struct CounterActor {
+    value: usize,
+    mailbox: mpsc::Receiver<Command>,
+}
+
+impl CounterActor {
+    /// Handles commands until told to stop or until every handle is gone.
+    async fn run(mut self) {
+        while let Some(command) = self.mailbox.recv().await {
+            match command {
+                Command::Increase => self.value += 1,
+                Command::Value { reply } => {
+                    let _ = reply.send(self.value);
+                }
+                Command::Stop { reply } => {
+                    let _ = reply.send(self.value);
+                    break;
+                }
+            }
+        }
+    }
+}
+
+/// `Counter` is a handle to a counter actor running in a task of its own.
+///
+/// Cloning the handle shares the same counter, as an `Arc` of the lock-based
+/// counter in the sync example would.
+#[derive(Clone, Debug)]
+pub struct Counter {
+    sender: mpsc::Sender<Command>,
+}
+
+impl Counter {
+    /// Spawns the actor on the current tokio runtime.
+    pub fn spawn() -> Self {
+        let (sender, mailbox) = mpsc::channel(MAILBOX_CAPACITY);
+        tokio::spawn(CounterActor { value: 0, mailbox }.run());
+        Counter { sender }
+    }
+
+    pub async fn increase(&self) -> Result<(), Stopped> {
+        self.send(Command::Increase).await
+    }
+
+    pub async fn value(&self) -> Result<usize, Stopped> {
+        let (reply, answer) = oneshot::channel();
+        self.send(Command::Value { reply }).await?;
+        answer.await.map_err(|_| Stopped)
+    }
+
+    /// Stops the counter once the commands already in the mailbox are
+    /// handled, returning the final count. Commands sent afterwards, through
+    /// any handle, return `Stopped`.
+    pub async fn stop(&self) -> Result<usize, Stopped> {
+        let (reply, answer) = oneshot::channel();
+        self.send(Command::Stop { reply }).await?;
+        answer.await.map_err(|_| Stopped)
+    }
+
+    async fn send(&self, command: Command) -> Result<(), Stopped> {
+        self.sender.send(command).await.map_err(|_| Stopped)
+    }
+}
+
+#[cfg(test)]
+mod specs_for_counter {
+    use super::Counter;
+    use super::Stopped;
+    use super::contract::Count;
+    use super::contract::counter_contract;
+
+    impl Count for Counter {
+        async fn increase(&self) {
+            Counter::increase(self).await.unwrap();
+        }
+
+        async fn value(&self) -> usize {
+            Counter::value(self).await.unwrap()
+        }
+    }
+
+    counter_contract!(Counter::spawn());
+
+    #[tokio::test]
+    async fn sut_handles_queued_commands_before_stopping() {
+        // Arrange
+        let counter = Counter::spawn();
+        for _ in 0..3 {
+            counter.increase().await.unwrap();
+        }
+
+        // Act
+        let actual = counter.stop().await;
+
+        // Assert
+        assert_eq!(Ok(3), actual);
+    }
+
+    #[tokio::test]
+    async fn sut_refuses_commands_from_every_handle_once_stopped() {
+        // Arrange
+        let counter = Counter::spawn();
+        let other = counter.clone();
+        counter.stop().await.unwrap();
+
+        // Act
+        let actual = (
+            other.increase().await,
+            other.value().await,
+            other.stop().await,
+        );
+
+        // Assert
+        assert_eq!((Err(Stopped), Err(Stopped), Err(Stopped)), actual);
+    }
+}
@@ -0,0 +1,81 @@
+//! Specs every counter has to meet, however it keeps the count.
+//!
+//! `counter_contract!` expands to a test module running the specs against the
+//! counter the expression creates. The actor and the lock-based counter of the
+//! sync example both run them, though one passes messages and the other locks.
+
+/// `Count` is what the specs need of a counter.
+pub(crate) trait Count {
+    fn increase(&self) -> impl Future<Output = ()> + Send;
+    fn value(&self) -> impl Future<Output = usize> + Send;
+}
+
+impl Count for sync::v2::Counter {
+    async fn increase(&self) {
+        sync::v2::Counter::increase(self);
+    }
+
+    async fn value(&self) -> usize {
+        sync::v2::Counter::value(self)
+    }
+}
+
+macro_rules! counter_contract {
+    ($counter:expr) => {
+        mod specs_for_counter_contract {
+            use std::sync::Arc;
+
+            use futures::future::join_all;
+
+            #[allow(unused_imports)]
+            use super::*;
+            use $crate::v1::contract::Count;
+
+            #[tokio::test]
+            async fn sut_is_increased_3_times_and_leaves_it_at_3() {
+                // Arrange
+                let count = 3;
+                let counter = $counter;
+
+                // Act
+                for _ in 0..count {
+                    Count::increase(&counter).await;
+                }
+
+                // Assert
+                let actual = Count::value(&counter).await;
+                assert_eq!(count, actual);
+            }
+
+            #[tokio::test(flavor = "multi_thread")]
+            async fn sut_runs_concurrently_safe() {
+                // Arrange
+                let count = 1000;
+                let counter = Arc::new($counter);
+
+                // Act
+                let handles = (0..count)
+                    .map(|_| {
+                        let counter = Arc::clone(&counter);
+                        tokio::spawn(async move {
+                            Count::increase(&*counter).await;
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                join_all(handles).await;
+
+                // Assert
+                let actual = Count::value(&*counter).await;
+                assert_eq!(count, actual);
+            }
+        }
+    };
+}
+
+pub(crate) use counter_contract;
+
+mod specs_for_lock_counter {
+    use sync::v2::Counter;
+
+    counter_contract!(Counter::new());
+}
@@ -1,3 +1,10 @@
+//! The sums only need `alloc`, so without the default `std` feature the
+//! crate is `no_std`.
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
 pub mod v1;
 pub mod v2;
 pub mod v3;
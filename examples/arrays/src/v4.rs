@@ -1,3 +1,5 @@
+use alloc::vec::Vec;
+
 pub fn sum(numbers: &[i32]) -> i32 {
     let mut total = 0;
     for number in numbers {
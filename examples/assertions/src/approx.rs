@@ -0,0 +1,167 @@
+use std::fmt::Debug;
+
+/// `Tolerance` tells how far apart two floats may be and still count as
+/// equal.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Tolerance {
+    /// The floats may differ by up to this much.
+    Absolute(f64),
+    /// The floats may differ by up to this share of the larger of them.
+    /// Near zero, where any share is tiny, they may differ by up to the
+    /// share itself.
+    Relative(f64),
+}
+
+impl Tolerance {
+    /// Tolerates the rounding errors of a few floating-point operations.
+    pub const DEFAULT: Tolerance = Tolerance::Relative(1e-9);
+
+    pub fn allows(self, a: f64, b: f64) -> bool {
+        if a == b {
+            return true;
+        }
+        let difference = (a - b).abs();
+        match self {
+            Tolerance::Absolute(tolerance) => difference <= tolerance,
+            Tolerance::Relative(tolerance) => {
+                difference <= tolerance * a.abs().max(b.abs()).max(1.0)
+            }
+        }
+    }
+}
+
+/// `ApproxEq` is for values made of floats, which are equal when every
+/// float of one is within the tolerance of the float of the other.
+pub trait ApproxEq: Debug {
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance) -> bool;
+}
+
+impl ApproxEq for f64 {
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance) -> bool {
+        tolerance.allows(*self, *other)
+    }
+}
+
+impl ApproxEq for f32 {
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance) -> bool {
+        tolerance.allows(f64::from(*self), f64::from(*other))
+    }
+}
+
+impl<A: ApproxEq, B: ApproxEq> ApproxEq for (A, B) {
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance) -> bool {
+        self.0.approx_eq(&other.0, tolerance) && self.1.approx_eq(&other.1, tolerance)
+    }
+}
+
+impl<T: ApproxEq> ApproxEq for [T] {
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other)
+                .all(|(a, b)| a.approx_eq(b, tolerance))
+    }
+}
+
+impl<T: ApproxEq, const N: usize> ApproxEq for [T; N] {
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance) -> bool {
+        self[..].approx_eq(&other[..], tolerance)
+    }
+}
+
+impl<T: ApproxEq> ApproxEq for Vec<T> {
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance) -> bool {
+        self[..].approx_eq(&other[..], tolerance)
+    }
+}
+
+/// Asserts that two values made of floats are equal within a tolerance,
+/// `Tolerance::DEFAULT` unless told otherwise:
+///
+/// ```
+/// use std::f64::consts::PI;
+///
+/// use assertions::assert_approx_eq;
+///
+/// assert_approx_eq!(0.3, 0.1 + 0.2);
+/// assert_approx_eq!(22.0 / 7.0, PI, abs = 0.01);
+/// assert_approx_eq!((0.0, -1.0), (PI.sin(), PI.cos()), rel = 1e-12);
+/// ```
+#[macro_export]
+macro_rules! assert_approx_eq {
+    ($expected:expr, $actual:expr $(,)?) => {
+        $crate::assert_approx_eq!($expected, $actual, $crate::Tolerance::DEFAULT)
+    };
+    ($expected:expr, $actual:expr, abs = $tolerance:expr $(,)?) => {
+        $crate::assert_approx_eq!($expected, $actual, $crate::Tolerance::Absolute($tolerance))
+    };
+    ($expected:expr, $actual:expr, rel = $tolerance:expr $(,)?) => {
+        $crate::assert_approx_eq!($expected, $actual, $crate::Tolerance::Relative($tolerance))
+    };
+    ($expected:expr, $actual:expr, $tolerance:expr $(,)?) => {
+        match (&$expected, &$actual, $tolerance) {
+            (expected, actual, tolerance) => {
+                if !$crate::ApproxEq::approx_eq(expected, actual, tolerance) {
+                    panic!(
+                        "assertion `expected ≈ actual` failed ({:?})\n  expected: {:?}\n    actual: {:?}",
+                        tolerance, expected, actual,
+                    );
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod specs_for_assert_approx_eq {
+    use std::f64::consts::PI;
+
+    use super::Tolerance;
+
+    #[rstest::rstest]
+    #[case::rounding_error(0.3, 0.1 + 0.2)]
+    #[case::near_zero(0.0, PI.sin())]
+    #[case::large(1e12, 1e12 + 1e-3)]
+    #[case::infinite(f64::INFINITY, f64::INFINITY)]
+    fn sut_accepts_floats_within_default_tolerance(#[case] expected: f64, #[case] actual: f64) {
+        assert_approx_eq!(expected, actual);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion `expected ≈ actual` failed")]
+    fn sut_rejects_floats_beyond_tolerance() {
+        assert_approx_eq!(1.0, 1.001);
+    }
+
+    #[rstest::rstest]
+    #[case::absolute_within(Tolerance::Absolute(0.01), 22.0 / 7.0, true)]
+    #[case::absolute_beyond(Tolerance::Absolute(0.001), 22.0 / 7.0, false)]
+    #[case::relative_within(Tolerance::Relative(0.001), 22.0 / 7.0, true)]
+    #[case::relative_beyond(Tolerance::Relative(0.0001), 22.0 / 7.0, false)]
+    fn sut_allows_difference_as_tolerance_tells(
+        #[case] tolerance: Tolerance,
+        #[case] expected: f64,
+        #[case] allowed: bool,
+    ) {
+        assert_eq!(allowed, tolerance.allows(expected, PI));
+    }
+
+    #[test]
+    fn sut_compares_tolerance_given_by_name() {
+        assert_approx_eq!(22.0 / 7.0, PI, abs = 0.01);
+        assert_approx_eq!(22.0 / 7.0, PI, rel = 0.001);
+    }
+
+    #[test]
+    fn sut_compares_every_float_of_tuples_and_slices() {
+        assert_approx_eq!((0.0, -1.0), (PI.sin(), PI.cos()));
+        assert_approx_eq!(vec![0.3, 0.6], vec![0.1 + 0.2, 0.2 + 0.4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion `expected ≈ actual` failed")]
+    fn sut_rejects_slices_of_different_lengths() {
+        assert_approx_eq!(vec![1.0], vec![1.0, 2.0]);
+    }
+}
@@ -0,0 +1,68 @@
+/// Returns the index of the first expected call that is missing from
+/// `calls` after the ones before it, or `None` if they all appear in order.
+pub fn first_out_of_order<T: PartialEq>(calls: &[T], expected: &[T]) -> Option<usize> {
+    let mut calls = calls.iter();
+    expected
+        .iter()
+        .position(|call| !calls.any(|made| made == call))
+}
+
+/// Asserts that the expected calls were made in this order. Other calls
+/// may come before, between and after them:
+///
+/// ```
+/// use assertions::assert_ordered_calls;
+///
+/// let calls = ["open", "write", "flush", "write", "close"];
+///
+/// assert_ordered_calls!(calls, ["open", "write", "close"]);
+/// ```
+#[macro_export]
+macro_rules! assert_ordered_calls {
+    ($calls:expr, [$($expected:expr),* $(,)?] $(,)?) => {
+        match (&$calls, [$($expected),*]) {
+            (calls, expected) => {
+                if let Some(i) = $crate::__private::first_out_of_order(&calls[..], &expected[..]) {
+                    panic!(
+                        "assertion failed: call {:?} at {} is not made in order\n  expected: {:?}\n     calls: {:?}",
+                        expected[i], i, expected, calls,
+                    );
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod specs_for_assert_ordered_calls {
+    use super::first_out_of_order;
+
+    #[rstest::rstest]
+    #[case::all_calls(&["write", "sleep", "write"], None)]
+    #[case::some_calls(&["sleep", "write"], None)]
+    #[case::no_calls(&[], None)]
+    #[case::swapped(&["sleep", "open"], Some(1))]
+    #[case::missing(&["close"], Some(0))]
+    #[case::too_often(&["sleep", "sleep"], Some(1))]
+    fn sut_finds_first_call_out_of_order(
+        #[case] expected: &[&str],
+        #[case] out_of_order: Option<usize>,
+    ) {
+        assert_eq!(
+            out_of_order,
+            first_out_of_order(&["open", "write", "sleep", "write"], expected)
+        );
+    }
+
+    #[test]
+    fn sut_accepts_calls_in_order() {
+        let calls = vec!["write".to_string(), "sleep".to_string()];
+        assert_ordered_calls!(calls, ["write".to_string(), "sleep".to_string()]);
+    }
+
+    #[test]
+    #[should_panic(expected = "call \"write\" at 1 is not made in order")]
+    fn sut_rejects_calls_out_of_order() {
+        assert_ordered_calls!(["write", "sleep"], ["sleep", "write"]);
+    }
+}
@@ -0,0 +1,72 @@
+/// Asserts that a result is an error matching a pattern, with an optional
+/// guard as in `matches!`:
+///
+/// ```
+/// use assertions::assert_err_matches;
+///
+/// let actual = "x".parse::<u8>();
+///
+/// assert_err_matches!(actual, error if error.to_string().contains("invalid digit"));
+/// ```
+///
+/// On failure it shows the error, or the value if the result is `Ok`.
+#[macro_export]
+macro_rules! assert_err_matches {
+    ($result:expr, $pattern:pat $(if $guard:expr)? $(,)?) => {
+        match $result {
+            Err($pattern) $(if $guard)? => {}
+            Err(error) => panic!(
+                "assertion failed: error does not match `{}`\n  error: {:?}",
+                stringify!($pattern $(if $guard)?),
+                error,
+            ),
+            Ok(value) => panic!(
+                "assertion failed: expected an error matching `{}`\n     ok: {:?}",
+                stringify!($pattern $(if $guard)?),
+                value,
+            ),
+        }
+    };
+}
+
+#[cfg(test)]
+mod specs_for_assert_err_matches {
+    #[derive(Debug)]
+    enum Error {
+        NotFound(u32),
+        Empty,
+    }
+
+    fn find(id: u32) -> Result<&'static str, Error> {
+        match id {
+            0 => Err(Error::Empty),
+            1 => Ok("one"),
+            _ => Err(Error::NotFound(id)),
+        }
+    }
+
+    #[test]
+    fn sut_accepts_matching_error() {
+        assert_err_matches!(find(0), Error::Empty);
+        assert_err_matches!(find(7), Error::NotFound(_));
+        assert_err_matches!(find(7), Error::NotFound(id) if id == 7);
+    }
+
+    #[test]
+    #[should_panic(expected = "error does not match `Error::NotFound(id) if id == 8`")]
+    fn sut_rejects_error_failing_guard() {
+        assert_err_matches!(find(7), Error::NotFound(id) if id == 8);
+    }
+
+    #[test]
+    #[should_panic(expected = "error does not match `Error::Empty`")]
+    fn sut_rejects_other_error() {
+        assert_err_matches!(find(7), Error::Empty);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected an error matching `Error::Empty`")]
+    fn sut_rejects_ok() {
+        assert_err_matches!(find(1), Error::Empty);
+    }
+}
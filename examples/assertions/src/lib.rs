@@ -0,0 +1,22 @@
+//! Assertion macros shared by the examples' specs.
+//!
+//! - `assert_approx_eq!` compares floats, and tuples or slices of them,
+//!   within a tolerance.
+//! - `assert_err_matches!` checks that a result is an error matching a
+//!   pattern.
+//! - `assert_ordered_calls!` checks that calls were made in an order.
+//!
+//! Like `assert_eq!`, each macro takes the expected value before the
+//! actual one, where it takes both.
+
+mod approx;
+mod calls;
+mod err;
+
+pub use approx::ApproxEq;
+pub use approx::Tolerance;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use super::calls::first_out_of_order;
+}
@@ -0,0 +1,187 @@
+//! Times the implementations the book compares and reports how long each
+//! took, so that its performance claims can be checked with one command:
+//!
+//! ```text
+//! cargo run --release --manifest-path examples/bench-report/Cargo.toml -- --format csv
+//! ```
+//!
+//! Every variant is timed by the same `time` harness, so the numbers of one
+//! report can be compared with each other, if not with another machine's.
+
+use std::fmt::Write as _;
+use std::hint::black_box;
+use std::io;
+use std::io::Write;
+use std::time::Duration;
+use std::time::Instant;
+
+pub mod suites;
+
+/// `Measurement` is how long a variant took, on average, on an input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Measurement {
+    /// What is compared, as `"dictionary search"`.
+    pub group: String,
+    /// The implementation timed, as `"HashMap"`.
+    pub variant: String,
+    /// The size of the input, or the number of threads.
+    pub input: usize,
+    pub mean: Duration,
+}
+
+/// `Format` is how a report is written.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    /// A markdown table per group, for the book.
+    Markdown,
+    /// A row per measurement, with the mean in nanoseconds, for spreadsheets.
+    Csv,
+}
+
+/// Runs `f` once to warm up, then `iterations` times, returning how long a
+/// run took on average. What `f` returns is kept from the optimizer.
+pub fn time<T>(iterations: u32, mut f: impl FnMut() -> T) -> Duration {
+    black_box(f());
+    let start = Instant::now();
+    for _ in 0..iterations {
+        black_box(f());
+    }
+    start.elapsed() / iterations.max(1)
+}
+
+/// Writes the measurements in the format, keeping their order.
+pub fn write_report(
+    out: &mut dyn Write,
+    measurements: &[Measurement],
+    format: Format,
+) -> io::Result<()> {
+    let report = match format {
+        Format::Markdown => markdown(measurements),
+        Format::Csv => csv(measurements),
+    };
+    out.write_all(report.as_bytes())
+}
+
+fn markdown(measurements: &[Measurement]) -> String {
+    let mut report = String::new();
+    let mut group = None;
+    for measurement in measurements {
+        if group != Some(&measurement.group) {
+            if group.is_some() {
+                report.push('\n');
+            }
+            group = Some(&measurement.group);
+            let _ = writeln!(report, "## {}\n", measurement.group);
+            report.push_str("| variant | input | mean |\n");
+            report.push_str("| --- | ---: | ---: |\n");
+        }
+        let _ = writeln!(
+            report,
+            "| {} | {} | {:?} |",
+            measurement.variant, measurement.input, measurement.mean
+        );
+    }
+    report
+}
+
+fn csv(measurements: &[Measurement]) -> String {
+    let mut report = String::from("group,variant,input,mean_ns\n");
+    for measurement in measurements {
+        let _ = writeln!(
+            report,
+            "{},{},{},{}",
+            measurement.group,
+            measurement.variant,
+            measurement.input,
+            measurement.mean.as_nanos()
+        );
+    }
+    report
+}
+
+#[cfg(test)]
+mod specs_for_time {
+    use super::time;
+
+    #[test]
+    fn sut_runs_function_once_more_than_iterations_to_warm_up() {
+        // Arrange
+        let mut calls = 0;
+
+        // Act
+        time(10, || calls += 1);
+
+        // Assert
+        assert_eq!(11, calls);
+    }
+}
+
+#[cfg(test)]
+mod specs_for_write_report {
+    use std::time::Duration;
+
+    use super::Format;
+    use super::Measurement;
+    use super::write_report;
+
+    fn measurements() -> Vec<Measurement> {
+        [
+            ("dictionary search", "HashMap", 10, 20),
+            ("dictionary search", "BTreeMap", 10, 30),
+            ("sum", "slice", 5, 1_500),
+        ]
+        .map(|(group, variant, input, nanos)| Measurement {
+            group: group.to_string(),
+            variant: variant.to_string(),
+            input,
+            mean: Duration::from_nanos(nanos),
+        })
+        .to_vec()
+    }
+
+    #[test]
+    fn sut_writes_markdown_table_per_group() {
+        // Arrange
+        let mut out = Vec::new();
+
+        // Act
+        write_report(&mut out, &measurements(), Format::Markdown).unwrap();
+
+        // Assert
+        let actual = String::from_utf8(out).unwrap();
+        let expected = "\
+## dictionary search
+
+| variant | input | mean |
+| --- | ---: | ---: |
+| HashMap | 10 | 20ns |
+| BTreeMap | 10 | 30ns |
+
+## sum
+
+| variant | input | mean |
+| --- | ---: | ---: |
+| slice | 5 | 1.5µs |
+";
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn sut_writes_csv_row_per_measurement() {
+        // Arrange
+        let mut out = Vec::new();
+
+        // Act
+        write_report(&mut out, &measurements(), Format::Csv).unwrap();
+
+        // Assert
+        let actual = String::from_utf8(out).unwrap();
+        let expected = "\
+group,variant,input,mean_ns
+dictionary search,HashMap,10,20
+dictionary search,BTreeMap,10,30
+sum,slice,5,1500
+";
+        assert_eq!(expected, actual);
+    }
+}
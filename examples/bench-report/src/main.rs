@@ -0,0 +1,40 @@
+use std::env;
+use std::io::stdout;
+use std::process::ExitCode;
+
+use bench_report::Format;
+use bench_report::suites;
+use bench_report::write_report;
+
+const ITERATIONS: u32 = 100;
+
+fn main() -> ExitCode {
+    // As in `bench-report --format csv --iterations 1000`.
+    let mut format = Format::Markdown;
+    let mut iterations = ITERATIONS;
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match (arg.as_str(), args.next().as_deref()) {
+            ("--format", Some("markdown")) => format = Format::Markdown,
+            ("--format", Some("csv")) => format = Format::Csv,
+            ("--iterations", Some(n)) if n.parse::<u32>().is_ok() => {
+                iterations = n.parse().unwrap();
+            }
+            _ => {
+                eprintln!("usage: bench-report [--format markdown|csv] [--iterations N]");
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    let mut measurements = suites::counters(iterations);
+    measurements.extend(suites::dictionaries(iterations));
+    measurements.extend(suites::sums(iterations));
+    match write_report(&mut stdout(), &measurements, format) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("failed to write report: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
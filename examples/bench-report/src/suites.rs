@@ -0,0 +1,116 @@
+//! The comparisons of the book, each timing its variants on the same inputs.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::thread;
+
+use maps::v7::Dictionary;
+use maps::v7::Entries;
+use sync::v3::AtomicCounter;
+use sync::v3::Counter;
+use sync::v3::ShardedCounter;
+
+use super::Measurement;
+use super::time;
+
+type NewCounter = fn() -> Arc<dyn Counter>;
+
+const THREADS: [usize; 3] = [1, 4, 16];
+const INCREASES: usize = 10_000;
+const SIZES: [usize; 3] = [10, 1000, 100_000];
+
+fn measurement(group: &str, variant: &str, input: usize, mean: std::time::Duration) -> Measurement {
+    Measurement {
+        group: group.to_string(),
+        variant: variant.to_string(),
+        input,
+        mean,
+    }
+}
+
+/// Times counting up from a growing number of threads behind a lock, in an
+/// atomic and in sharded atomics.
+pub fn counters(iterations: u32) -> Vec<Measurement> {
+    let counters: [(&str, NewCounter); 3] = [
+        ("RwLock", || Arc::new(sync::v2::Counter::new())),
+        ("atomic", || Arc::new(AtomicCounter::new())),
+        ("sharded", || Arc::new(ShardedCounter::new())),
+    ];
+    let mut measurements = vec![];
+    for threads in THREADS {
+        for (name, counter) in counters {
+            let mean = time(iterations, || count_up(counter(), threads));
+            measurements.push(measurement("counter increase", name, threads, mean));
+        }
+    }
+    measurements
+}
+
+fn count_up(counter: Arc<dyn Counter>, threads: usize) -> usize {
+    let handles = (0..threads)
+        .map(|_| {
+            let counter = Arc::clone(&counter);
+            thread::spawn(move || {
+                for _ in 0..INCREASES {
+                    counter.increase();
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    counter.value()
+}
+
+/// Times searching a dictionary kept in a `HashMap` and in a `BTreeMap`.
+pub fn dictionaries(iterations: u32) -> Vec<Measurement> {
+    let mut measurements = vec![];
+    for size in SIZES {
+        let key = format!("key{}", size / 2);
+        let hash_map = filled::<HashMap<String, String>>(size);
+        let mean = time(iterations, || hash_map.search(&key).unwrap().len());
+        measurements.push(measurement("dictionary search", "HashMap", size, mean));
+        let btree_map = filled::<BTreeMap<String, String>>(size);
+        let mean = time(iterations, || btree_map.search(&key).unwrap().len());
+        measurements.push(measurement("dictionary search", "BTreeMap", size, mean));
+    }
+    measurements
+}
+
+fn filled<E: Entries>(size: usize) -> Dictionary<E> {
+    let mut dictionary = Dictionary::new();
+    for i in 0..size {
+        dictionary
+            .add(format!("key{i}"), format!("value{i}"))
+            .unwrap();
+    }
+    dictionary
+}
+
+/// Times summing five numbers by index, with a `for` loop over a slice and
+/// with `Iterator::sum`.
+pub fn sums(iterations: u32) -> Vec<Measurement> {
+    let numbers = [1, 2, 3, 4, 5];
+    vec![
+        measurement(
+            "sum",
+            "indexed",
+            5,
+            time(iterations, || arrays::v1::sum(&numbers)),
+        ),
+        measurement(
+            "sum",
+            "for loop",
+            5,
+            time(iterations, || arrays::v6::sum(&numbers)),
+        ),
+        measurement(
+            "sum",
+            "Iterator::sum",
+            5,
+            time(iterations, || numbers.iter().sum::<i32>()),
+        ),
+    ]
+}
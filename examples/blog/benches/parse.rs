@@ -0,0 +1,80 @@
+use criterion::BenchmarkId;
+use criterion::Criterion;
+use criterion::black_box;
+use criterion::criterion_group;
+use criterion::criterion_main;
+
+use blog::v6::Post;
+use blog::v6::front_matter;
+use blog::v6::markdown;
+
+const PARAGRAPHS: [usize; 3] = [1, 10, 100];
+
+const PARAGRAPH: &str = "Write the test first, watch it fail, and then write just enough \
+code to make it pass. *Refactor* once it is green, with the tests as a **safety net**.\n\n";
+
+const CODE_BLOCK: &str = "```rust\nfn add(x: i32, y: i32) -> i32 {\n    x + y\n}\n```\n\n";
+
+fn front_matters() -> [(&'static str, &'static str); 3] {
+    [
+        (
+            "legacy",
+            "Title: Hello\nDescription: World\nTags: rust, tdd\nDate: 2024-01-31\n---\n",
+        ),
+        (
+            "yaml",
+            "---\ntitle: Hello\ndescription: World\ntags: [rust, tdd]\ndate: 2024-01-31\n---\n",
+        ),
+        (
+            "toml",
+            "+++\ntitle = \"Hello\"\ndescription = \"World\"\ntags = [\"rust\", \"tdd\"]\ndate = 2024-01-31\n+++\n",
+        ),
+    ]
+}
+
+/// Compares parsing the front matter formats of the same post.
+pub fn bench_front_matter(c: &mut Criterion) {
+    let mut group = c.benchmark_group("front matter parse");
+    for (format, front_matter) in front_matters() {
+        let content = format!("{front_matter}{PARAGRAPH}");
+        group.bench_with_input(
+            BenchmarkId::from_parameter(format),
+            &content,
+            |b, content| b.iter(|| front_matter::parse(black_box(content)).unwrap()),
+        );
+    }
+    group.finish();
+}
+
+/// Compares rendering prose with rendering code blocks, which are
+/// highlighted, for growing posts.
+pub fn bench_render(c: &mut Criterion) {
+    let mut group = c.benchmark_group("markdown render");
+    for paragraphs in PARAGRAPHS {
+        for (name, paragraph) in [("prose", PARAGRAPH), ("code", CODE_BLOCK)] {
+            let body = paragraph.repeat(paragraphs);
+            group.bench_with_input(BenchmarkId::new(name, paragraphs), &body, |b, body| {
+                b.iter(|| markdown::render(black_box(body)))
+            });
+        }
+    }
+    group.finish();
+}
+
+/// Measures parsing a whole post, front matter and body, for growing posts.
+pub fn bench_post(c: &mut Criterion) {
+    let (_, front_matter) = front_matters()[1];
+    let mut group = c.benchmark_group("post parse");
+    for paragraphs in PARAGRAPHS {
+        let content = format!("{front_matter}{}", PARAGRAPH.repeat(paragraphs));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(paragraphs),
+            &content,
+            |b, content| b.iter(|| Post::try_from(black_box(content.clone())).unwrap()),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_front_matter, bench_render, bench_post);
+criterion_main!(benches);
@@ -1,5 +1,8 @@
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 pub mod v1;
 pub mod v2;
 pub mod v3;
 pub mod v4;
 pub mod v5;
+pub mod v6;
@@ -1,3 +1,96 @@
-fn main() {
-    println!("Hello, world!");
+use std::env::args;
+use std::process::ExitCode;
+
+use chrono::Local;
+
+use blog::v6::Post;
+use blog::v6::check::CheckOptions;
+use blog::v6::check::Severity;
+use blog::v6::check::check;
+use blog::v6::server::ServeOptions;
+use blog::v6::server::router;
+use blog::v6::server::serve;
+use blog::v6::site::Builder;
+use blog::v6::site::Options;
+use blog::v6::site::Report;
+use blog::v6::site::SiteError;
+use blog::v6::source::DirectorySource;
+use blog::v6::template::HandlebarsRenderer;
+use blog::v6::watch::DEBOUNCE_WINDOW;
+use blog::v6::watch::NotifySource;
+use blog::v6::watch::watch;
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let args = args().skip(1).collect::<Vec<_>>();
+    let result = match args.first().map(String::as_str) {
+        Some("serve") => run_server(args.into_iter().skip(1)).await,
+        Some("check") => check_content(args.into_iter().skip(1)),
+        _ => generate_site(args),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("{}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn generate_site(args: Vec<String>) -> Result<(), SiteError> {
+    let options = Options::from_args(args)?;
+    let renderer = HandlebarsRenderer::new()?;
+    let mut builder = Builder::new(&options, &renderer);
+    print_report(&builder.build()?);
+    if options.watch {
+        println!("watching {} for changes", options.content.display());
+        let mut source = NotifySource::new(&options.content)?;
+        watch(&mut builder, &mut source, DEBOUNCE_WINDOW, print_report)?;
+    }
+    Ok(())
+}
+
+fn print_report(report: &Report) {
+    for error in &report.errors {
+        eprintln!("skipped {}", error);
+    }
+    println!(
+        "generated {} pages, removed {}",
+        report.pages.len(),
+        report.removed.len()
+    );
+}
+
+fn check_content(args: impl Iterator<Item = String>) -> Result<(), SiteError> {
+    let options = CheckOptions::from_args(args)?;
+    let source = DirectorySource::new(&options.posts_directory());
+    let diagnostics = check(&source, &options.permalink, Local::now().date_naive())?;
+    for diagnostic in &diagnostics {
+        println!("{}", diagnostic);
+    }
+    let errors = diagnostics
+        .iter()
+        .filter(|diagnostic| diagnostic.severity == Severity::Error)
+        .count();
+    let warnings = diagnostics.len() - errors;
+    if errors > 0 {
+        return Err(SiteError::Check { errors, warnings });
+    }
+    println!("0 errors, {} warnings", warnings);
+    Ok(())
+}
+
+async fn run_server(args: impl Iterator<Item = String>) -> Result<(), SiteError> {
+    let options = ServeOptions::from_args(args)?;
+    let (blog, errors) = Post::from_directory(&options.posts_directory())?;
+    for error in &errors {
+        eprintln!("skipped {}", error);
+    }
+    println!(
+        "serving {} posts on http://{}",
+        blog.published().len(),
+        options.address
+    );
+    serve(&options.address, router(&blog, HandlebarsRenderer::new()?)).await?;
+    Ok(())
 }
@@ -0,0 +1,36 @@
+//! Generators of posts for property tests, shared with the crates depending
+//! on this one through the `testing` feature.
+
+use chrono::Days;
+use chrono::NaiveDate;
+use proptest::prelude::*;
+
+use crate::v6::Post;
+
+/// Generates dates from 2000 on, about 27 years' worth.
+pub fn date() -> impl Strategy<Value = NaiveDate> {
+    (0..10_000u64).prop_map(|days| NaiveDate::from_ymd_opt(2000, 1, 1).unwrap() + Days::new(days))
+}
+
+/// Generates posts with a title, a description, up to five tags, a date or
+/// none, and a body of a few lines. The posts are in no particular language.
+pub fn post() -> impl Strategy<Value = Post> {
+    (
+        "[A-Z][A-Za-z ]{0,30}",
+        "[A-Za-z ,.]{0,60}",
+        prop::collection::vec("[a-z]{1,10}", 0..=5),
+        prop::option::of(date()),
+        any::<bool>(),
+        "([A-Za-z ,.]{0,60}\n){0,5}",
+    )
+        .prop_map(|(title, description, tags, date, draft, body)| Post {
+            title,
+            description,
+            tags,
+            date,
+            draft,
+            body,
+            lang: None,
+            translation_key: None,
+        })
+}
@@ -0,0 +1,298 @@
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::num::NonZeroUsize;
+use std::ops::RangeBounds;
+use std::path::Path;
+use std::path::PathBuf;
+
+use chrono::NaiveDate;
+
+pub mod cache;
+pub mod check;
+pub mod front_matter;
+pub mod markdown;
+pub mod permalink;
+pub mod server;
+pub mod site;
+pub mod sitemap;
+pub mod source;
+pub mod template;
+pub mod watch;
+
+use permalink::Permalink;
+use source::DirectorySource;
+use source::PostSource;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Post {
+    pub title: String,
+    pub description: String,
+    pub tags: Vec<String>,
+    pub date: Option<NaiveDate>,
+    pub draft: bool,
+    pub body: String,
+    /// The language the post is written in, from the front matter or else the
+    /// file name, as in `hello.ko.md`.
+    pub lang: Option<String>,
+    /// Posts with the same key are translations of each other. It is the file
+    /// name without the language and extension, so `hello.en.md` and
+    /// `hello.ko.md` share the key `hello`. Posts without a language have none.
+    pub translation_key: Option<String>,
+}
+
+impl Post {
+    /// Loads every post in the directory.
+    pub fn from_directory(path: &Path) -> Result<(Blog, Vec<PostParseError>), std::io::Error> {
+        Self::from_source(&DirectorySource::new(path))
+    }
+
+    /// Loads every post the source lists.
+    ///
+    /// A malformed post does not stop the others from loading; its error is
+    /// returned alongside the posts that were parsed successfully. A post that
+    /// cannot be read aborts the load.
+    pub fn from_source(
+        source: &dyn PostSource,
+    ) -> Result<(Blog, Vec<PostParseError>), std::io::Error> {
+        let mut posts = vec![];
+        let mut errors = vec![];
+        for entry in source.list()? {
+            match Self::try_from(source.read(&entry)?) {
+                Ok(post) => posts.push(post.in_file(&entry.path)),
+                Err(error) => errors.push(error.in_file(entry.path)),
+            }
+        }
+        Ok((Blog::new(posts), errors))
+    }
+
+    /// Fills in what the file name tells about the post.
+    fn in_file(self, path: &Path) -> Self {
+        let (key, lang) = translation_key(path);
+        let lang = self.lang.or(lang);
+        Post {
+            // A post in no particular language has no translations.
+            translation_key: lang.is_some().then_some(key),
+            lang,
+            ..self
+        }
+    }
+
+    /// Counts the words of the body, leaving out fenced code blocks.
+    pub fn word_count(&self) -> usize {
+        let mut in_code_block = false;
+        self.body
+            .lines()
+            .filter(|line| {
+                let line = line.trim_start();
+                let fence = line.starts_with("```") || line.starts_with("~~~");
+                if fence {
+                    in_code_block = !in_code_block;
+                }
+                !fence && !in_code_block
+            })
+            .map(|line| line.split_whitespace().count())
+            .sum()
+    }
+
+    /// Returns the minutes it takes to read the body, rounded up.
+    pub fn reading_time(&self, words_per_minute: NonZeroUsize) -> usize {
+        self.word_count().div_ceil(words_per_minute.get())
+    }
+
+    /// Returns the slug derived from the title. Use `Blog::slugs` to get slugs
+    /// that are unique across the blog.
+    pub fn slug(&self) -> String {
+        permalink::slugify(&self.title)
+    }
+}
+
+/// `Blog` is the collection of loaded posts, with the queries readers of the
+/// blog need most.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Blog {
+    posts: Vec<Post>,
+}
+
+impl Blog {
+    pub fn new(posts: Vec<Post>) -> Self {
+        Blog { posts }
+    }
+
+    /// Returns every post, drafts included.
+    pub fn all(&self) -> &[Post] {
+        &self.posts
+    }
+
+    /// Returns the posts that are not drafts.
+    pub fn published(&self) -> Vec<&Post> {
+        self.filter(|post| !post.draft)
+    }
+
+    /// Returns the posts tagged with `tag`, or with any tag of the same
+    /// slug, so that case and punctuation are ignored.
+    pub fn with_tag(&self, tag: &str) -> Vec<&Post> {
+        let slug = permalink::slugify(tag);
+        self.filter(|post| post.tags.iter().any(|t| permalink::slugify(t) == slug))
+    }
+
+    /// Returns the slug of every tag together with the number of posts
+    /// using it. Tags sharing a slug share a page, so they count as one.
+    pub fn tags(&self) -> BTreeMap<String, usize> {
+        let mut tags = BTreeMap::new();
+        for post in &self.posts {
+            let unique = post
+                .tags
+                .iter()
+                .map(|tag| permalink::slugify(tag))
+                .collect::<BTreeSet<_>>();
+            for tag in unique {
+                *tags.entry(tag).or_insert(0) += 1;
+            }
+        }
+        tags
+    }
+
+    /// Returns the slug of every post in the order of `all`, with numeric
+    /// suffixes added to titles that would otherwise collide.
+    ///
+    /// Published posts take their slugs before drafts do, so that a draft
+    /// never moves a published post to another permalink.
+    pub fn slugs(&self) -> Vec<String> {
+        let (published, drafts): (Vec<_>, Vec<_>) =
+            (0..self.posts.len()).partition(|&n| !self.posts[n].draft);
+        let order = published.into_iter().chain(drafts).collect::<Vec<_>>();
+        let deduped = permalink::dedupe(order.iter().map(|&n| self.posts[n].slug()).collect());
+        let mut slugs = vec![String::new(); self.posts.len()];
+        for (n, slug) in order.into_iter().zip(deduped) {
+            slugs[n] = slug;
+        }
+        slugs
+    }
+
+    /// Returns the permalink of every post in the order of `all`.
+    pub fn permalinks(&self, permalink: &Permalink) -> Vec<String> {
+        self.posts
+            .iter()
+            .zip(self.slugs())
+            .map(|(post, slug)| permalink.render(&slug, post.date))
+            .collect()
+    }
+
+    /// Returns the other translations of the post, ordered by language.
+    pub fn translations(&self, post: &Post) -> Vec<&Post> {
+        let Some(key) = &post.translation_key else {
+            return vec![];
+        };
+        let mut translations = self
+            .filter(|other| other.translation_key.as_ref() == Some(key) && other.lang != post.lang);
+        translations.sort_by_key(|other| &other.lang);
+        translations
+    }
+
+    pub fn filter(&self, predicate: impl Fn(&Post) -> bool) -> Vec<&Post> {
+        self.posts.iter().filter(|post| predicate(post)).collect()
+    }
+}
+
+impl From<Vec<Post>> for Blog {
+    fn from(posts: Vec<Post>) -> Self {
+        Blog::new(posts)
+    }
+}
+
+/// Returns the posts newest first. Posts without a date come last.
+pub fn posts_sorted_by_date(posts: &[Post]) -> Vec<&Post> {
+    let mut sorted = posts.iter().collect::<Vec<_>>();
+    // `None` orders before any date, so reversing puts undated posts last.
+    sorted.sort_by_key(|post| Reverse(post.date));
+    sorted
+}
+
+/// Returns the posts published within the range, newest first.
+pub fn posts_between(posts: &[Post], range: impl RangeBounds<NaiveDate>) -> Vec<&Post> {
+    posts_sorted_by_date(posts)
+        .into_iter()
+        .filter(|post| post.date.is_some_and(|date| range.contains(&date)))
+        .collect()
+}
+
+/// Splits the file name into the translation key and the language, if the
+/// name carries one before its extension, as in `hello.ko.md`.
+pub fn translation_key(path: &Path) -> (String, Option<String>) {
+    let stem = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    match stem.rsplit_once('.') {
+        Some((key, lang)) if is_language_tag(lang) => (key.to_string(), Some(lang.to_string())),
+        _ => (stem, None),
+    }
+}
+
+/// Accepts a language with an optional region or script, such as `en`,
+/// `pt-br` or `zh-hant`.
+fn is_language_tag(tag: &str) -> bool {
+    let (language, rest) = tag.split_once('-').unwrap_or((tag, ""));
+    let language =
+        (2..=3).contains(&language.len()) && language.chars().all(|c| c.is_ascii_lowercase());
+    let rest = rest.is_empty()
+        || ((2..=4).contains(&rest.len()) && rest.chars().all(|c| c.is_ascii_alphanumeric()));
+    language && rest
+}
+
+impl TryFrom<String> for Post {
+    type Error = PostParseError;
+
+    fn try_from(content: String) -> Result<Self, Self::Error> {
+        let (front_matter, body) = front_matter::parse(&content)?;
+        Ok(Post {
+            title: front_matter.title,
+            description: front_matter.description,
+            tags: front_matter.tags,
+            date: front_matter.date,
+            draft: front_matter.draft,
+            body,
+            lang: front_matter.lang,
+            translation_key: None,
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, thiserror::Error)]
+pub struct PostParseError {
+    pub file: Option<PathBuf>,
+    pub line: usize,
+    pub expected: String,
+}
+
+impl PostParseError {
+    fn in_file(self, file: PathBuf) -> Self {
+        PostParseError {
+            file: Some(file),
+            ..self
+        }
+    }
+}
+
+impl Display for PostParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.file {
+            Some(file) => write!(f, "{}:{}: ", file.display(), self.line)?,
+            None => write!(f, "line {}: ", self.line)?,
+        }
+        write!(f, "expected {}", self.expected)
+    }
+}
+
+impl From<front_matter::FrontMatterError> for PostParseError {
+    fn from(error: front_matter::FrontMatterError) -> Self {
+        PostParseError {
+            file: None,
+            line: error.line,
+            expected: error.expected,
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::fs;
+use std::hash::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::io::Error;
+use std::path::Path;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// `BuildCache` records what the last build read and wrote, so that the next
+/// build can skip the posts that did not change since.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct BuildCache {
+    /// The hash of the options the site was built with. A build with other
+    /// options cannot reuse anything.
+    pub options: String,
+    /// The source files by file name.
+    pub sources: BTreeMap<String, Source>,
+    /// The listing pages by permalink.
+    pub listings: BTreeMap<String, Listing>,
+}
+
+/// `Source` is a post file as the last build saw it.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Source {
+    pub hash: String,
+    /// The permalink of the page written for the post, if one was written.
+    pub permalink: Option<String>,
+}
+
+/// `Listing` is an index or tag page as the last build wrote it.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Listing {
+    /// The hash of everything the page shows besides the posts' content.
+    pub hash: String,
+    /// The file names of the posts the page lists.
+    pub sources: BTreeSet<String>,
+}
+
+impl BuildCache {
+    /// Reads the cache written to `path`. A missing or unreadable cache is
+    /// empty, which makes the next build write every page.
+    pub fn load(path: &Path) -> Self {
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|content| toml::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        let content = toml::to_string(self).map_err(Error::other)?;
+        fs::write(path, content)
+    }
+}
+
+/// Returns the hash of the value as hex digits.
+///
+/// The hash is stable between runs of the same binary, which is all a cache
+/// needs: a different binary at worst rebuilds everything once.
+pub fn hash(value: &impl Hash) -> String {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
@@ -0,0 +1,291 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::path::Component;
+use std::path::Path;
+use std::path::PathBuf;
+
+use chrono::NaiveDate;
+use pulldown_cmark::Event;
+use pulldown_cmark::Parser;
+use pulldown_cmark::Tag;
+
+use super::Blog;
+use super::Post;
+use super::front_matter;
+use super::permalink::Permalink;
+use super::permalink::tag_path;
+use super::site::Options;
+use super::site::POSTS_DIRECTORY;
+use super::site::SiteError;
+use super::source::PostSource;
+
+const ASSETS_PATH: &str = "/assets/";
+
+/// `CheckOptions` configures `blog check`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CheckOptions {
+    pub content: PathBuf,
+    /// The permalink template relative links are resolved against.
+    pub permalink: Permalink,
+}
+
+impl CheckOptions {
+    /// Parses the arguments following `check`.
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Result<Self, SiteError> {
+        let mut args = args.into_iter();
+        let mut content = None;
+        let mut permalink = Permalink::default();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--permalink" => permalink = Permalink::new(&Options::value_of(&mut args, &arg)?),
+                flag if flag.starts_with("--") => {
+                    return Err(SiteError::Usage(format!("unknown flag '{}'", flag)));
+                }
+                _ if content.is_none() => content = Some(PathBuf::from(arg)),
+                _ => return Err(SiteError::Usage(format!("unexpected argument '{}'", arg))),
+            }
+        }
+        let content =
+            content.ok_or_else(|| SiteError::Usage(String::from("expected content path")))?;
+        Ok(CheckOptions { content, permalink })
+    }
+
+    pub fn posts_directory(&self) -> PathBuf {
+        self.content.join(POSTS_DIRECTORY)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Warning => write!(f, "warning"),
+            Severity::Error => write!(f, "error"),
+        }
+    }
+}
+
+/// `Diagnostic` is a problem found in a post, pointing at the line to fix.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub file: PathBuf,
+    pub line: usize,
+    pub message: String,
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}:{}: {}: {}",
+            self.file.display(),
+            self.line,
+            self.severity,
+            self.message
+        )
+    }
+}
+
+/// A post that parsed, with what is needed to point at its lines.
+struct Checked<'a> {
+    file: &'a Path,
+    keys: Vec<(usize, String)>,
+    body_line: usize,
+    post: Post,
+}
+
+impl Checked<'_> {
+    /// Returns the line of the front matter key, or the first line if the
+    /// key is missing.
+    fn line_of(&self, key: &str) -> usize {
+        self.keys
+            .iter()
+            .find(|(_, k)| k == key)
+            .map_or(1, |(line, _)| *line)
+    }
+}
+
+/// Checks every post the source lists without generating anything, returning
+/// the problems ordered by file and line.
+///
+/// Posts that fail to parse, reuse the slug of another post or link to a page
+/// the site does not have are errors. Missing descriptions, unknown front
+/// matter keys and published posts dated after `today` are warnings. Links to
+/// assets are not checked.
+pub fn check(
+    source: &dyn PostSource,
+    permalink: &Permalink,
+    today: NaiveDate,
+) -> Result<Vec<Diagnostic>, std::io::Error> {
+    let entries = source.list()?;
+    let mut diagnostics = vec![];
+    let mut posts = vec![];
+    for entry in &entries {
+        let content = source.read(entry)?;
+        let diagnostic = |severity, line, message| Diagnostic {
+            severity,
+            file: entry.path.clone(),
+            line,
+            message,
+        };
+        let keys = front_matter::keys(&content);
+        for (line, key) in &keys {
+            if !front_matter::KEYS.contains(&key.as_str()) {
+                let message = format!("unknown front matter key '{}'", key);
+                diagnostics.push(diagnostic(Severity::Warning, *line, message));
+            }
+        }
+        match Post::try_from(content.clone()) {
+            Ok(post) => posts.push(Checked {
+                file: &entry.path,
+                keys,
+                body_line: body_line(&content, &post.body),
+                post,
+            }),
+            Err(error) => {
+                let message = format!("expected {}", error.expected);
+                diagnostics.push(diagnostic(Severity::Error, error.line, message));
+            }
+        }
+    }
+
+    let blog = Blog::new(posts.iter().map(|checked| checked.post.clone()).collect());
+    let permalinks = blog.permalinks(permalink);
+    let mut pages = permalinks
+        .iter()
+        .map(|permalink| normalize(permalink))
+        .collect::<BTreeSet<_>>();
+    pages.insert(normalize("/"));
+    pages.extend(blog.tags().keys().map(|tag| normalize(&tag_path(tag))));
+    let files = entries
+        .iter()
+        .map(|entry| normalize_path(&entry.path))
+        .collect::<BTreeSet<_>>();
+
+    let mut slugs = BTreeMap::<String, &Path>::new();
+    for (checked, permalink) in posts.iter().zip(&permalinks) {
+        let post = &checked.post;
+        let diagnostic = |severity, line, message| Diagnostic {
+            severity,
+            file: checked.file.to_path_buf(),
+            line,
+            message,
+        };
+        let slug = post.slug();
+        match slugs.get(&slug) {
+            Some(other) => {
+                let message = format!("slug '{}' is already used by {}", slug, other.display());
+                let line = checked.line_of("title");
+                diagnostics.push(diagnostic(Severity::Error, line, message));
+            }
+            None => {
+                slugs.insert(slug, checked.file);
+            }
+        }
+        if post.description.trim().is_empty() {
+            let message = String::from("missing description");
+            let line = checked.line_of("description");
+            diagnostics.push(diagnostic(Severity::Warning, line, message));
+        }
+        if let Some(date) = post.date
+            && date > today
+            && !post.draft
+        {
+            let message = format!("date {} is in the future", date);
+            let line = checked.line_of("date");
+            diagnostics.push(diagnostic(Severity::Warning, line, message));
+        }
+        for (offset, link) in links(&post.body) {
+            let exists = if link.ends_with(".md") {
+                let directory = checked.file.parent().unwrap_or(Path::new(""));
+                files.contains(&normalize_path(&directory.join(&link)))
+            } else {
+                let target = resolve(permalink, &link);
+                target.starts_with(ASSETS_PATH) || pages.contains(&normalize(&target))
+            };
+            if !exists {
+                let line = checked.body_line + post.body[..offset].matches('\n').count();
+                let message = format!("dead link '{}'", link);
+                diagnostics.push(diagnostic(Severity::Error, line, message));
+            }
+        }
+    }
+
+    diagnostics.sort_by(|a, b| a.file.cmp(&b.file).then(a.line.cmp(&b.line)));
+    Ok(diagnostics)
+}
+
+/// Returns the line of the file the body starts on.
+fn body_line(content: &str, body: &str) -> usize {
+    let lines = content.lines().count();
+    let body_lines = body.split('\n').count();
+    lines.saturating_sub(body_lines) + 1
+}
+
+/// Returns the relative links of the Markdown with their offset, without
+/// query or fragment.
+fn links(markdown: &str) -> Vec<(usize, String)> {
+    Parser::new(markdown)
+        .into_offset_iter()
+        .filter_map(|(event, range)| match event {
+            Event::Start(Tag::Link { dest_url, .. }) => Some((range.start, dest_url)),
+            _ => None,
+        })
+        .filter_map(|(offset, link)| {
+            let path = link.split(['#', '?']).next().unwrap_or_default();
+            let has_scheme = link
+                .split_once(':')
+                .is_some_and(|(scheme, _)| !scheme.contains('/'));
+            let relative = !path.is_empty() && !has_scheme && !link.starts_with("//");
+            relative.then(|| (offset, path.to_string()))
+        })
+        .collect()
+}
+
+/// Resolves the link the way a browser would on the page at `base`.
+fn resolve(base: &str, link: &str) -> String {
+    let mut segments = vec![];
+    if !link.starts_with('/') {
+        segments = base.split('/').filter(|s| !s.is_empty()).collect();
+        if !base.ends_with('/') {
+            segments.pop();
+        }
+    }
+    for segment in link.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                segments.pop();
+            }
+            segment => segments.push(segment),
+        }
+    }
+    format!("/{}", segments.join("/"))
+}
+
+/// Drops the trailing slash, so that `/a/` and `/a` are the same page.
+fn normalize(path: &str) -> String {
+    path.trim_end_matches('/').to_string()
+}
+
+fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
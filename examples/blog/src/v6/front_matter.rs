@@ -0,0 +1,311 @@
+use std::fmt::Formatter;
+use std::iter::Enumerate;
+use std::iter::Peekable;
+use std::str::Lines;
+
+use chrono::NaiveDate;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::de;
+use serde::de::MapAccess;
+use serde::de::Unexpected;
+use serde::de::Visitor;
+use serde::de::value::MapAccessDeserializer;
+
+const TITLE_PREFIX: &str = "Title: ";
+const DESCRIPTION_PREFIX: &str = "Description: ";
+const TAGS_PREFIX: &str = "Tags: ";
+const DATE_PREFIX: &str = "Date: ";
+const DRAFT_PREFIX: &str = "Draft: ";
+const LANG_PREFIX: &str = "Lang: ";
+const DATE_FORMAT: &str = "%Y-%m-%d";
+const LEGACY_SEPARATOR: &str = "---";
+const YAML_DELIMITER: &str = "---";
+const TOML_DELIMITER: &str = "+++";
+
+/// `FrontMatter` is the metadata every post carries, whichever format it was
+/// written in.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+pub struct FrontMatter {
+    pub title: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default, deserialize_with = "deserialize_date")]
+    pub date: Option<NaiveDate>,
+    #[serde(default)]
+    pub draft: bool,
+    /// The language the post is written in, such as `en` or `ko`.
+    #[serde(default)]
+    pub lang: Option<String>,
+}
+
+/// The keys `FrontMatter` reads. Other keys are ignored.
+pub const KEYS: [&str; 6] = ["title", "description", "tags", "date", "draft", "lang"];
+
+/// `FrontMatterFormat` is the adapter a front matter syntax has to implement.
+///
+/// `parse` returns the metadata together with the remaining body, so supporting
+/// another syntax only requires another implementation listed in `FORMATS`.
+pub trait FrontMatterFormat {
+    fn matches(&self, content: &str) -> bool;
+    fn parse(&self, content: &str) -> Result<(FrontMatter, String), FrontMatterError>;
+    /// Returns the top level keys with the line they are on, in the order they
+    /// are written.
+    fn keys(&self, content: &str) -> Vec<(usize, String)>;
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+#[error("line {line}: expected {expected}")]
+pub struct FrontMatterError {
+    pub line: usize,
+    pub expected: String,
+}
+
+/// `Legacy` is the original line based format. The `Date:`, `Draft:` and
+/// `Lang:` lines are optional.
+///
+/// ```text
+/// Title: Hello, World
+/// Description: My first post
+/// Tags: rust, tdd
+/// Date: 2024-01-31
+/// Draft: true
+/// Lang: en
+/// ---
+/// Body
+/// ```
+pub struct Legacy;
+
+/// `Yaml` is front matter fenced by `---` lines, as used by Jekyll.
+pub struct Yaml;
+
+/// `Toml` is front matter fenced by `+++` lines, as used by Hugo.
+pub struct Toml;
+
+const FORMATS: [&dyn FrontMatterFormat; 3] = [&Toml, &Yaml, &Legacy];
+
+pub fn parse(content: &str) -> Result<(FrontMatter, String), FrontMatterError> {
+    format_of(content).parse(content)
+}
+
+/// Returns the top level keys of the front matter with the line they are on.
+pub fn keys(content: &str) -> Vec<(usize, String)> {
+    format_of(content).keys(content)
+}
+
+fn format_of(content: &str) -> &'static dyn FrontMatterFormat {
+    FORMATS
+        .iter()
+        .copied()
+        .find(|format| format.matches(content))
+        .unwrap_or(&Legacy)
+}
+
+impl FrontMatterFormat for Legacy {
+    fn matches(&self, content: &str) -> bool {
+        content.starts_with(TITLE_PREFIX)
+    }
+
+    fn parse(&self, content: &str) -> Result<(FrontMatter, String), FrontMatterError> {
+        let end = content.lines().count();
+        let mut lines = content.lines().enumerate().peekable();
+        let extract = |lines: &mut Peekable<Enumerate<Lines>>, prefix: &str| {
+            let (n, line) = lines.next().unwrap_or((end, ""));
+            line.strip_prefix(prefix)
+                .map(|value| value.to_string())
+                .ok_or_else(|| FrontMatterError {
+                    line: n + 1,
+                    expected: format!("'{}'", prefix.trim_end()),
+                })
+        };
+
+        let title = extract(&mut lines, TITLE_PREFIX)?;
+        let description = extract(&mut lines, DESCRIPTION_PREFIX)?;
+        let tags = extract(&mut lines, TAGS_PREFIX)?
+            .split(", ")
+            .filter(|tag| !tag.is_empty())
+            .map(|tag| tag.to_string())
+            .collect();
+        let optional = |lines: &mut Peekable<Enumerate<Lines>>, prefix: &str| {
+            lines
+                .next_if(|(_, line)| line.starts_with(prefix))
+                .map(|(n, line)| (n + 1, line[prefix.len()..].to_string()))
+        };
+
+        let mut date = None;
+        let mut draft = false;
+        let mut lang = None;
+        loop {
+            if let Some((n, value)) = optional(&mut lines, DATE_PREFIX) {
+                date = Some(parse_date(&value).ok_or_else(|| FrontMatterError {
+                    line: n,
+                    expected: expected_date(),
+                })?);
+            } else if let Some((n, value)) = optional(&mut lines, DRAFT_PREFIX) {
+                draft = value.trim().parse().map_err(|_| FrontMatterError {
+                    line: n,
+                    expected: String::from("'true' or 'false'"),
+                })?;
+            } else if let Some((_, value)) = optional(&mut lines, LANG_PREFIX) {
+                lang = Some(value.trim().to_string());
+            } else {
+                break;
+            }
+        }
+        let _separator = extract(&mut lines, LEGACY_SEPARATOR)?;
+        let body = lines.map(|(_, line)| line).collect::<Vec<_>>().join("\n");
+
+        let front_matter = FrontMatter {
+            title,
+            description,
+            tags,
+            date,
+            draft,
+            lang,
+        };
+        Ok((front_matter, body))
+    }
+
+    fn keys(&self, content: &str) -> Vec<(usize, String)> {
+        content
+            .lines()
+            .enumerate()
+            .take_while(|(_, line)| *line != LEGACY_SEPARATOR)
+            .filter_map(|(n, line)| {
+                let (key, _) = line.split_once(": ")?;
+                Some((n + 1, key.to_lowercase()))
+            })
+            .collect()
+    }
+}
+
+impl FrontMatterFormat for Yaml {
+    fn matches(&self, content: &str) -> bool {
+        content.lines().next() == Some(YAML_DELIMITER)
+    }
+
+    fn parse(&self, content: &str) -> Result<(FrontMatter, String), FrontMatterError> {
+        let (header, body) = split_fenced(content, YAML_DELIMITER)?;
+        let front_matter = serde_yaml::from_str(&header).map_err(|error| FrontMatterError {
+            // The opening delimiter occupies the first line of the file.
+            line: error.location().map_or(1, |location| location.line() + 1),
+            expected: format!("valid YAML front matter ({})", error),
+        })?;
+        Ok((front_matter, body))
+    }
+
+    fn keys(&self, content: &str) -> Vec<(usize, String)> {
+        fenced_lines(content, YAML_DELIMITER)
+            .filter(|(_, line)| !line.starts_with([' ', '\t', '#', '-']))
+            .filter_map(|(n, line)| {
+                let (key, _) = line.split_once(':')?;
+                Some((n, unquote(key)))
+            })
+            .collect()
+    }
+}
+
+impl FrontMatterFormat for Toml {
+    fn matches(&self, content: &str) -> bool {
+        content.lines().next() == Some(TOML_DELIMITER)
+    }
+
+    fn parse(&self, content: &str) -> Result<(FrontMatter, String), FrontMatterError> {
+        let (header, body) = split_fenced(content, TOML_DELIMITER)?;
+        let front_matter = toml::from_str(&header).map_err(|error| FrontMatterError {
+            line: error
+                .span()
+                .map_or(1, |span| header[..span.start].matches('\n').count() + 2),
+            expected: format!("valid TOML front matter ({})", error.message()),
+        })?;
+        Ok((front_matter, body))
+    }
+
+    fn keys(&self, content: &str) -> Vec<(usize, String)> {
+        let mut in_table = false;
+        let mut keys = vec![];
+        for (n, line) in fenced_lines(content, TOML_DELIMITER) {
+            if let Some(table) = line.strip_prefix('[') {
+                // Keys below a table header belong to the table.
+                in_table = true;
+                let name = table.trim_start_matches('[').trim_end_matches(']');
+                let name = name.split('.').next().unwrap_or_default();
+                keys.push((n, unquote(name)));
+            } else if !in_table && !line.starts_with([' ', '\t', '#']) {
+                keys.extend(line.split_once('=').map(|(key, _)| (n, unquote(key))));
+            }
+        }
+        keys
+    }
+}
+
+fn parse_date(value: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(value.trim(), DATE_FORMAT).ok()
+}
+
+fn expected_date() -> String {
+    String::from("a date in YYYY-MM-DD format")
+}
+
+/// Accepts a quoted date in every format, and TOML's bare date literal too.
+fn deserialize_date<'de, D>(deserializer: D) -> Result<Option<NaiveDate>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_any(DateVisitor).map(Some)
+}
+
+struct DateVisitor;
+
+impl<'de> Visitor<'de> for DateVisitor {
+    type Value = NaiveDate;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str(&expected_date())
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        parse_date(value).ok_or_else(|| E::invalid_value(Unexpected::Str(value), &self))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        let value = toml::value::Datetime::deserialize(MapAccessDeserializer::new(map))?;
+        self.visit_str(&value.to_string())
+    }
+}
+
+/// Returns the lines between the fences with their line numbers.
+fn fenced_lines<'a>(
+    content: &'a str,
+    delimiter: &'a str,
+) -> impl Iterator<Item = (usize, &'a str)> {
+    content
+        .lines()
+        .enumerate()
+        .skip(1)
+        .take_while(move |(_, line)| *line != delimiter)
+        .map(|(n, line)| (n + 1, line))
+}
+
+fn unquote(key: &str) -> String {
+    key.trim().trim_matches(['"', '\'']).to_string()
+}
+
+fn split_fenced(content: &str, delimiter: &str) -> Result<(String, String), FrontMatterError> {
+    let lines = content.lines().collect::<Vec<_>>();
+    let closing = lines
+        .iter()
+        .skip(1)
+        .position(|line| *line == delimiter)
+        .map(|n| n + 1)
+        .ok_or_else(|| FrontMatterError {
+            line: lines.len() + 1,
+            expected: format!("closing '{}'", delimiter),
+        })?;
+    Ok((
+        lines[1..closing].join("\n"),
+        lines[closing + 1..].join("\n"),
+    ))
+}
@@ -0,0 +1,72 @@
+use std::sync::LazyLock;
+
+use pulldown_cmark::CodeBlockKind;
+use pulldown_cmark::CowStr;
+use pulldown_cmark::Event;
+use pulldown_cmark::Parser;
+use pulldown_cmark::Tag;
+use pulldown_cmark::TagEnd;
+use pulldown_cmark::html::push_html;
+use syntect::highlighting::Theme;
+use syntect::highlighting::ThemeSet;
+use syntect::html::highlighted_html_for_string;
+use syntect::parsing::SyntaxSet;
+
+const THEME: &str = "InspiredGitHub";
+
+// Loading the syntax definitions takes a while, so it happens once.
+static SYNTAXES: LazyLock<SyntaxSet> = LazyLock::new(SyntaxSet::load_defaults_newlines);
+static THEMES: LazyLock<ThemeSet> = LazyLock::new(ThemeSet::load_defaults);
+
+/// Renders the Markdown body of a post as HTML.
+///
+/// Fenced code blocks are highlighted for the language named in their info
+/// string, such as `rust` or `rs`. Blocks in a language that is not known
+/// are rendered as plain text.
+pub fn render(markdown: &str) -> String {
+    let mut events = vec![];
+    let mut code_block: Option<(CowStr, String)> = None;
+    for event in Parser::new(markdown) {
+        match (event, &mut code_block) {
+            (Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(info))), None) => {
+                code_block = Some((info, String::new()));
+            }
+            (Event::Text(text), Some((_, code))) => code.push_str(&text),
+            (Event::End(TagEnd::CodeBlock), Some(_)) => {
+                if let Some((info, code)) = code_block.take() {
+                    events.extend(highlight(info, code));
+                }
+            }
+            (event, _) => events.push(event),
+        }
+    }
+
+    let mut html = String::new();
+    push_html(&mut html, events.into_iter());
+    html
+}
+
+/// Returns the events rendering the code block, highlighted as HTML if the
+/// language is known and as they were otherwise.
+fn highlight<'a>(info: CowStr<'a>, code: String) -> Vec<Event<'a>> {
+    // Info strings may carry attributes after the language, as in `rust,ignore`.
+    let language = info
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .next()
+        .unwrap_or_default();
+    let syntax = SYNTAXES.find_syntax_by_token(language);
+    let html = syntax
+        .and_then(|syntax| highlighted_html_for_string(&code, &SYNTAXES, syntax, theme()).ok());
+    match html {
+        Some(html) => vec![Event::Html(html.into())],
+        None => vec![
+            Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(info))),
+            Event::Text(code.into()),
+            Event::End(TagEnd::CodeBlock),
+        ],
+    }
+}
+
+fn theme() -> &'static Theme {
+    &THEMES.themes[THEME]
+}
@@ -0,0 +1,94 @@
+use std::collections::HashSet;
+
+use chrono::Datelike;
+use chrono::NaiveDate;
+use deunicode::deunicode;
+
+const FALLBACK_SLUG: &str = "untitled";
+
+/// Turns a title into a URL friendly slug: transliterated to ASCII, lower
+/// case, with every run of other characters replaced by a single hyphen.
+pub fn slugify(title: &str) -> String {
+    let slug = deunicode(title)
+        .to_lowercase()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+    if slug.is_empty() {
+        String::from(FALLBACK_SLUG)
+    } else {
+        slug
+    }
+}
+
+/// Returns the path of the page listing the posts tagged with `tag`.
+pub fn tag_path(tag: &str) -> String {
+    format!("/tags/{}/", slugify(tag))
+}
+
+/// Appends `-2`, `-3`, ... to slugs that were already taken, in order.
+pub fn dedupe(slugs: Vec<String>) -> Vec<String> {
+    let mut taken = HashSet::new();
+    slugs
+        .into_iter()
+        .map(|slug| {
+            let mut candidate = slug.clone();
+            let mut n = 1;
+            while !taken.insert(candidate.clone()) {
+                n += 1;
+                candidate = format!("{}-{}", slug, n);
+            }
+            candidate
+        })
+        .collect()
+}
+
+/// `Permalink` is the URL template of a post, such as `/:year/:slug/`.
+///
+/// `:year`, `:month`, `:day` and `:slug` are replaced by the post's values.
+/// Date segments of an undated post are left out.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Permalink {
+    template: String,
+}
+
+impl Permalink {
+    pub fn new(template: &str) -> Self {
+        Permalink {
+            template: template.to_string(),
+        }
+    }
+
+    pub fn render(&self, slug: &str, date: Option<NaiveDate>) -> String {
+        let (year, month, day) = date
+            .map(|date| {
+                (
+                    date.year().to_string(),
+                    format!("{:02}", date.month()),
+                    format!("{:02}", date.day()),
+                )
+            })
+            .unwrap_or_default();
+        let path = self
+            .template
+            .replace(":year", &year)
+            .replace(":month", &month)
+            .replace(":day", &day)
+            .replace(":slug", slug);
+
+        let mut collapsed = String::with_capacity(path.len());
+        for c in path.chars() {
+            if !(c == '/' && collapsed.ends_with('/')) {
+                collapsed.push(c);
+            }
+        }
+        collapsed
+    }
+}
+
+impl Default for Permalink {
+    fn default() -> Self {
+        Permalink::new("/:year/:slug/")
+    }
+}
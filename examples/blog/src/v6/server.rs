@@ -0,0 +1,184 @@
+use std::cmp::Reverse;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use axum::Router;
+use axum::extract::Path;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::Html;
+use axum::response::IntoResponse;
+use axum::response::Response;
+use axum::routing::get;
+
+use super::Blog;
+use super::Post;
+use super::markdown;
+use super::permalink::Permalink;
+use super::permalink::slugify;
+use super::site::Options;
+use super::site::POSTS_DIRECTORY;
+use super::site::SiteError;
+use super::template::ErrorView;
+use super::template::IndexView;
+use super::template::PostLink;
+use super::template::PostView;
+use super::template::TemplateError;
+use super::template::TemplateRenderer;
+use super::template::TranslationLink;
+
+const POST_PERMALINK: &str = "/posts/:slug";
+const DEFAULT_ADDRESS: &str = "127.0.0.1:3000";
+const INDEX_TITLE: &str = "Blog";
+
+/// `ServeOptions` configures the server started by `blog serve`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ServeOptions {
+    pub content: PathBuf,
+    pub address: String,
+}
+
+impl ServeOptions {
+    /// Parses the arguments following `serve`.
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Result<Self, SiteError> {
+        let mut args = args.into_iter();
+        let mut content = None;
+        let mut address = String::from(DEFAULT_ADDRESS);
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--address" => address = Options::value_of(&mut args, &arg)?,
+                flag if flag.starts_with("--") => {
+                    return Err(SiteError::Usage(format!("unknown flag '{}'", flag)));
+                }
+                _ if content.is_none() => content = Some(PathBuf::from(arg)),
+                _ => return Err(SiteError::Usage(format!("unexpected argument '{}'", arg))),
+            }
+        }
+        let content =
+            content.ok_or_else(|| SiteError::Usage(String::from("expected content path")))?;
+        Ok(ServeOptions { content, address })
+    }
+
+    pub fn posts_directory(&self) -> PathBuf {
+        self.content.join(POSTS_DIRECTORY)
+    }
+}
+
+struct AppState {
+    posts: Vec<(Post, String)>,
+    renderer: Box<dyn TemplateRenderer + Send + Sync>,
+}
+
+/// Builds the routes serving the published posts of the blog:
+///
+/// - `GET /` lists every post, newest first
+/// - `GET /posts/{slug}` shows a single post
+/// - `GET /tags/{tag}` lists the posts tagged with `tag`
+///
+/// Unknown posts and tags get a rendered 404 page.
+pub fn router(blog: &Blog, renderer: impl TemplateRenderer + Send + Sync + 'static) -> Router {
+    let permalinks = blog.permalinks(&Permalink::new(POST_PERMALINK));
+    let mut posts = blog
+        .all()
+        .iter()
+        .cloned()
+        .zip(permalinks)
+        .filter(|(post, _)| !post.draft)
+        .collect::<Vec<_>>();
+    posts.sort_by_key(|(post, _)| Reverse(post.date));
+
+    let state = AppState {
+        posts,
+        renderer: Box::new(renderer),
+    };
+    Router::new()
+        .route("/", get(index))
+        .route("/posts/{slug}", get(post))
+        .route("/tags/{tag}", get(tag))
+        .route("/tags/{tag}/", get(tag))
+        .with_state(Arc::new(state))
+}
+
+/// Serves the blog until the process is stopped.
+pub async fn serve(address: &str, router: Router) -> std::io::Result<()> {
+    let listener = tokio::net::TcpListener::bind(address).await?;
+    axum::serve(listener, router).await
+}
+
+async fn index(State(state): State<Arc<AppState>>) -> Response {
+    let view = IndexView {
+        title: String::from(INDEX_TITLE),
+        posts: links(state.posts.iter()),
+        previous: None,
+        next: None,
+    };
+    html(StatusCode::OK, state.renderer.render_index(&view))
+}
+
+async fn post(State(state): State<Arc<AppState>>, Path(slug): Path<String>) -> Response {
+    let permalink = Permalink::new(POST_PERMALINK).render(&slug, None);
+    match state.posts.iter().find(|(_, link)| *link == permalink) {
+        Some((post, _)) => {
+            let view = PostView::new(post, markdown::render(&post.body))
+                .with_translations(translations(&state, post));
+            html(StatusCode::OK, state.renderer.render_post(&view))
+        }
+        None => not_found(&state, format!("There is no post '{}'.", slug)),
+    }
+}
+
+async fn tag(State(state): State<Arc<AppState>>, Path(tag): Path<String>) -> Response {
+    let slug = slugify(&tag);
+    let tagged = state
+        .posts
+        .iter()
+        .filter(|(post, _)| post.tags.iter().any(|t| slugify(t) == slug))
+        .collect::<Vec<_>>();
+    if tagged.is_empty() {
+        return not_found(&state, format!("There are no posts tagged '{}'.", tag));
+    }
+    let view = IndexView {
+        title: format!("Posts tagged {}", tag),
+        posts: links(tagged.into_iter()),
+        previous: None,
+        next: None,
+    };
+    html(StatusCode::OK, state.renderer.render_index(&view))
+}
+
+/// Links the other translations of the post, ordered by language.
+fn translations(state: &AppState, post: &Post) -> Vec<TranslationLink> {
+    let mut translations = state
+        .posts
+        .iter()
+        .filter(|(other, _)| {
+            post.translation_key.is_some()
+                && other.translation_key == post.translation_key
+                && other.lang != post.lang
+        })
+        .filter_map(|(other, permalink)| TranslationLink::new(other, permalink))
+        .collect::<Vec<_>>();
+    translations.sort_by(|a, b| a.lang.cmp(&b.lang));
+    translations
+}
+
+fn links<'a>(posts: impl Iterator<Item = &'a (Post, String)>) -> Vec<PostLink> {
+    posts
+        .map(|(post, permalink)| PostLink::new(post, permalink))
+        .collect()
+}
+
+fn not_found(state: &AppState, message: String) -> Response {
+    let view = ErrorView {
+        status: StatusCode::NOT_FOUND.as_u16(),
+        message,
+    };
+    html(StatusCode::NOT_FOUND, state.renderer.render_error(&view))
+}
+
+fn html(status: StatusCode, page: Result<String, TemplateError>) -> Response {
+    match page {
+        Ok(page) => (status, Html(page)).into_response(),
+        Err(error) => (StatusCode::INTERNAL_SERVER_ERROR, error.to_string()).into_response(),
+    }
+}
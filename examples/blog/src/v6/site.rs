@@ -0,0 +1,543 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use super::Blog;
+use super::Post;
+use super::PostParseError;
+use super::cache;
+use super::cache::BuildCache;
+use super::cache::Listing;
+use super::cache::Source;
+use super::markdown;
+use super::permalink::Permalink;
+use super::permalink::slugify;
+use super::permalink::tag_path;
+use super::sitemap::Sitemap;
+use super::source::DirectorySource;
+use super::source::PostSource;
+use super::template::HandlebarsRenderer;
+use super::template::IndexView;
+use super::template::PostLink;
+use super::template::PostView;
+use super::template::TemplateError;
+use super::template::TemplateRenderer;
+use super::template::TranslationLink;
+use super::translation_key;
+
+pub(crate) const POSTS_DIRECTORY: &str = "posts";
+const ASSETS_DIRECTORY: &str = "assets";
+const PAGE_FILE: &str = "index.html";
+const SITEMAP_FILE: &str = "sitemap.xml";
+const CACHE_FILE: &str = ".build-cache.toml";
+const INDEX_TITLE: &str = "Blog";
+
+const DEFAULT_PER_PAGE: usize = 10;
+
+const USAGE: &str = "usage: blog <content> <output> [--clean] [--drafts] [--base-url <url>] [--permalink <template>] [--per-page <n>] [--watch]
+       blog serve <content> [--address <address>]
+       blog check <content> [--permalink <template>]";
+
+/// `Options` configures a site generation run.
+///
+/// The content directory holds the posts under `posts/` and static files
+/// under `assets/`, which are copied to the output as they are.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Options {
+    pub content: PathBuf,
+    pub output: PathBuf,
+    pub clean: bool,
+    pub drafts: bool,
+    pub base_url: Option<String>,
+    pub permalink: Permalink,
+    pub per_page: usize,
+    pub watch: bool,
+}
+
+impl Options {
+    pub fn new(content: &Path, output: &Path) -> Self {
+        Options {
+            content: content.to_path_buf(),
+            output: output.to_path_buf(),
+            clean: false,
+            drafts: false,
+            base_url: None,
+            permalink: Permalink::default(),
+            per_page: DEFAULT_PER_PAGE,
+            watch: false,
+        }
+    }
+
+    /// Parses the command line arguments, without the program name.
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Result<Self, SiteError> {
+        let mut args = args.into_iter();
+        let mut paths = vec![];
+        let mut clean = false;
+        let mut drafts = false;
+        let mut base_url = None;
+        let mut permalink = Permalink::default();
+        let mut per_page = DEFAULT_PER_PAGE;
+        let mut watch = false;
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--clean" => clean = true,
+                "--drafts" => drafts = true,
+                "--watch" => watch = true,
+                "--base-url" => base_url = Some(Self::value_of(&mut args, &arg)?),
+                "--permalink" => permalink = Permalink::new(&Self::value_of(&mut args, &arg)?),
+                "--per-page" => {
+                    per_page = Self::value_of(&mut args, &arg)?
+                        .parse()
+                        .ok()
+                        .filter(|n| *n > 0)
+                        .ok_or_else(|| {
+                            SiteError::Usage(String::from(
+                                "expected a positive number of posts per page",
+                            ))
+                        })?
+                }
+                flag if flag.starts_with("--") => {
+                    return Err(SiteError::Usage(format!("unknown flag '{}'", flag)));
+                }
+                _ => paths.push(PathBuf::from(arg)),
+            }
+        }
+
+        let [content, output] = <[PathBuf; 2]>::try_from(paths)
+            .map_err(|_| SiteError::Usage(String::from("expected content and output paths")))?;
+        Ok(Options {
+            clean,
+            drafts,
+            base_url,
+            permalink,
+            per_page,
+            watch,
+            ..Options::new(&content, &output)
+        })
+    }
+
+    pub(crate) fn value_of(
+        args: &mut impl Iterator<Item = String>,
+        flag: &str,
+    ) -> Result<String, SiteError> {
+        args.next()
+            .ok_or_else(|| SiteError::Usage(format!("missing value for '{}'", flag)))
+    }
+}
+
+/// `Report` summarises a generation run.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Report {
+    /// The pages written by the run.
+    pub pages: Vec<PathBuf>,
+    /// The pages removed because their post or listing is gone.
+    pub removed: Vec<PathBuf>,
+    pub errors: Vec<PostParseError>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum SiteError {
+    #[error("{0}\n{USAGE}")]
+    Usage(String),
+
+    #[error("failed to generate site: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error(transparent)]
+    Template(#[from] TemplateError),
+
+    #[error("failed to watch content: {0}")]
+    Watch(#[from] notify::Error),
+
+    #[error("{errors} errors, {warnings} warnings")]
+    Check { errors: usize, warnings: usize },
+}
+
+/// Writes the HTML site for the content directory into the output directory.
+///
+/// Posts that fail to parse are skipped and reported; drafts are skipped
+/// unless `Options::drafts` is set.
+pub fn generate(options: &Options) -> Result<Report, SiteError> {
+    generate_with(options, &HandlebarsRenderer::new()?)
+}
+
+/// Same as `generate`, rendering the pages with the given renderer.
+pub fn generate_with(
+    options: &Options,
+    renderer: &dyn TemplateRenderer,
+) -> Result<Report, SiteError> {
+    Builder::new(options, renderer).build()
+}
+
+/// `Builder` remembers what it generated, so that a rebuild after some files
+/// changed only rewrites the pages affected by them.
+///
+/// What a build wrote is kept in a cache in the output directory, so that the
+/// next run skips the posts whose content did not change. The cache does not
+/// cover the templates; build with `clean` after changing them.
+pub struct Builder<'a> {
+    options: &'a Options,
+    renderer: &'a dyn TemplateRenderer,
+    posts: BTreeMap<String, Post>,
+    errors: BTreeMap<String, PostParseError>,
+    hashes: BTreeMap<String, String>,
+    cache: BuildCache,
+}
+
+impl<'a> Builder<'a> {
+    pub fn new(options: &'a Options, renderer: &'a dyn TemplateRenderer) -> Self {
+        Builder {
+            options,
+            renderer,
+            posts: BTreeMap::new(),
+            errors: BTreeMap::new(),
+            hashes: BTreeMap::new(),
+            cache: BuildCache::default(),
+        }
+    }
+
+    /// Generates the site, skipping the posts the cache of the last build
+    /// shows to be unchanged.
+    pub fn build(&mut self) -> Result<Report, SiteError> {
+        let output = &self.options.output;
+        if self.options.clean && output.exists() {
+            fs::remove_dir_all(output)?;
+        }
+        fs::create_dir_all(output)?;
+
+        self.cache = BuildCache::load(&output.join(CACHE_FILE));
+        if self.cache.options != self.fingerprint() {
+            self.cache = BuildCache::default();
+        }
+        self.posts.clear();
+        self.errors.clear();
+        self.hashes.clear();
+        let mut changed = BTreeSet::new();
+        for entry in DirectorySource::new(&self.posts_directory()).list()? {
+            if let Some(name) = entry.path.file_name() {
+                let name = name.to_string_lossy();
+                if self.load(&name)? {
+                    changed.insert(name.into_owned());
+                }
+            }
+        }
+        for name in self.cache.sources.keys() {
+            if !self.hashes.contains_key(name) {
+                changed.insert(name.clone());
+            }
+        }
+
+        let assets = self.options.content.join(ASSETS_DIRECTORY);
+        if assets.is_dir() {
+            copy_directory(&assets, &output.join(ASSETS_DIRECTORY))?;
+        }
+        self.write(&changed)
+    }
+
+    /// Regenerates the output affected by the changed paths of the content
+    /// directory. Paths that no longer exist are treated as removed.
+    pub fn rebuild(&mut self, changed: &BTreeSet<PathBuf>) -> Result<Report, SiteError> {
+        let posts_directory = fs::canonicalize(self.posts_directory())?;
+        let assets_directory = fs::canonicalize(&self.options.content)?.join(ASSETS_DIRECTORY);
+        let mut changed_posts = BTreeSet::new();
+        for path in changed {
+            let (Some(parent), Some(name)) = (path.parent(), path.file_name()) else {
+                continue;
+            };
+            let Ok(parent) = fs::canonicalize(parent) else {
+                continue;
+            };
+            if parent == posts_directory {
+                let name = name.to_string_lossy();
+                if self.load(&name)? {
+                    changed_posts.insert(name.into_owned());
+                }
+            } else if let Ok(relative) = parent.join(name).strip_prefix(&assets_directory) {
+                let target = self.options.output.join(ASSETS_DIRECTORY).join(relative);
+                if path.is_file() {
+                    fs::create_dir_all(target.parent().unwrap_or(&self.options.output))?;
+                    fs::copy(path, target)?;
+                } else if target.is_file() {
+                    fs::remove_file(target)?;
+                }
+            }
+        }
+
+        if changed_posts.is_empty() {
+            return Ok(Report {
+                errors: self.errors.values().cloned().collect(),
+                ..Report::default()
+            });
+        }
+        self.write(&changed_posts)
+    }
+
+    fn posts_directory(&self) -> PathBuf {
+        self.options.content.join(POSTS_DIRECTORY)
+    }
+
+    /// Returns the hash of the options that decide what the pages look like.
+    fn fingerprint(&self) -> String {
+        let options = self.options;
+        let fingerprint = format!(
+            "{} {} {:?} {} {:?}",
+            env!("CARGO_PKG_VERSION"),
+            options.drafts,
+            options.permalink,
+            options.per_page,
+            options.base_url,
+        );
+        cache::hash(&fingerprint)
+    }
+
+    /// Reads the post again, returning whether its content differs from what
+    /// the last build saw.
+    fn load(&mut self, name: &str) -> std::io::Result<bool> {
+        self.posts.remove(name);
+        self.errors.remove(name);
+        self.hashes.remove(name);
+        let path = self.posts_directory().join(name);
+        if path.is_file() {
+            let content = fs::read_to_string(&path)?;
+            self.hashes.insert(name.to_string(), cache::hash(&content));
+            match Post::try_from(content) {
+                Ok(post) => {
+                    self.posts.insert(name.to_string(), post.in_file(&path));
+                }
+                Err(error) => {
+                    self.errors.insert(name.to_string(), error.in_file(path));
+                }
+            }
+        }
+        let previous = self.cache.sources.get(name).map(|source| &source.hash);
+        Ok(self.hashes.get(name) != previous)
+    }
+
+    fn write(&mut self, changed: &BTreeSet<String>) -> Result<Report, SiteError> {
+        let output = &self.options.output;
+        let mut report = Report::default();
+
+        let blog = Blog::new(self.posts.values().cloned().collect());
+        let permalinks = self
+            .posts
+            .iter()
+            .zip(blog.permalinks(&self.options.permalink))
+            .filter(|((_, post), _)| self.options.drafts || !post.draft)
+            .map(|((name, _), permalink)| (name.clone(), permalink))
+            .collect::<BTreeMap<_, _>>();
+
+        let current = permalinks.values().collect::<BTreeSet<_>>();
+        for source in self.cache.sources.values() {
+            if let Some(permalink) = &source.permalink
+                && !current.contains(permalink)
+            {
+                report.removed.extend(remove_page(output, permalink)?);
+            }
+        }
+        // A post page links its translations, so it changes with them.
+        let changed_keys = changed
+            .iter()
+            .map(|name| translation_key(Path::new(name)).0)
+            .collect::<BTreeSet<_>>();
+        let translations = permalinks
+            .iter()
+            .map(|(name, permalink)| {
+                let post = &self.posts[name];
+                ((&post.translation_key, &post.lang), permalink.as_str())
+            })
+            .collect::<BTreeMap<_, _>>();
+        for (name, permalink) in &permalinks {
+            let previous = self
+                .cache
+                .sources
+                .get(name)
+                .and_then(|source| source.permalink.as_ref());
+            if changed.contains(name)
+                || changed_keys.contains(&translation_key(Path::new(name)).0)
+                || previous != Some(permalink)
+                || !page_path(output, permalink).is_file()
+            {
+                let post = &self.posts[name];
+                let links = blog
+                    .translations(post)
+                    .into_iter()
+                    .filter_map(|other| {
+                        let permalink = translations.get(&(&other.translation_key, &other.lang))?;
+                        TranslationLink::new(other, permalink)
+                    })
+                    .collect();
+                let view =
+                    PostView::new(post, markdown::render(&post.body)).with_translations(links);
+                let html = self.renderer.render_post(&view)?;
+                report.pages.push(write_page(output, permalink, &html)?);
+            }
+        }
+
+        let mut posts = permalinks
+            .iter()
+            .map(|(name, permalink)| (name.as_str(), &self.posts[name], permalink.clone()))
+            .collect::<Vec<_>>();
+        // Newest first, with the permalink breaking ties so reruns write the same pages.
+        posts.sort_by(|(_, a, a_permalink), (_, b, b_permalink)| {
+            b.date
+                .cmp(&a.date)
+                .then_with(|| a_permalink.cmp(b_permalink))
+        });
+        let listings = self.write_listings(&posts, changed, &mut report)?;
+        for permalink in self.cache.listings.keys() {
+            if !listings.contains_key(permalink) {
+                report.removed.extend(remove_page(output, permalink)?);
+            }
+        }
+
+        if let Some(base_url) = &self.options.base_url {
+            let sitemap = Sitemap::for_blog(base_url, &blog, &self.options.permalink);
+            let mut file = fs::File::create(output.join(SITEMAP_FILE))?;
+            sitemap.write(&mut file)?;
+        }
+
+        let sources = self
+            .hashes
+            .iter()
+            .map(|(name, hash)| {
+                let source = Source {
+                    hash: hash.clone(),
+                    permalink: permalinks.get(name).cloned(),
+                };
+                (name.clone(), source)
+            })
+            .collect();
+        self.cache = BuildCache {
+            options: self.fingerprint(),
+            sources,
+            listings,
+        };
+        self.cache.save(&output.join(CACHE_FILE))?;
+        report.errors = self.errors.values().cloned().collect();
+        Ok(report)
+    }
+
+    /// Writes the index pages and tag pages that list a changed post or whose
+    /// layout changed, returning every listing.
+    fn write_listings(
+        &self,
+        posts: &[(&str, &Post, String)],
+        changed: &BTreeSet<String>,
+        report: &mut Report,
+    ) -> Result<BTreeMap<String, Listing>, SiteError> {
+        let mut views = vec![];
+
+        let chunks = posts.chunks(self.options.per_page).collect::<Vec<_>>();
+        let last = chunks.len().saturating_sub(1);
+        for n in 0..=last {
+            let posts = chunks.get(n).copied().unwrap_or_default();
+            let index = IndexView {
+                title: String::from(INDEX_TITLE),
+                posts: links(posts),
+                previous: (n > 0).then(|| index_path(n - 1)),
+                next: (n < last).then(|| index_path(n + 1)),
+            };
+            views.push((index_path(n), index, names(posts)));
+        }
+
+        // Tags are grouped by slug, as tags sharing one share a page.
+        let mut tags = BTreeMap::<String, Vec<(&str, &Post, String)>>::new();
+        for (name, post, permalink) in posts {
+            let unique = post
+                .tags
+                .iter()
+                .map(|tag| slugify(tag))
+                .collect::<BTreeSet<_>>();
+            for tag in unique {
+                tags.entry(tag)
+                    .or_default()
+                    .push((name, post, permalink.clone()));
+            }
+        }
+        for (tag, posts) in &tags {
+            let page = IndexView {
+                title: format!("Posts tagged {}", tag),
+                posts: links(posts),
+                previous: None,
+                next: None,
+            };
+            views.push((tag_path(tag), page, names(posts)));
+        }
+
+        let output = &self.options.output;
+        let mut listings = BTreeMap::new();
+        for (permalink, view, sources) in views {
+            let listing = Listing {
+                hash: cache::hash(&format!("{:?}", view)),
+                sources,
+            };
+            if self.cache.listings.get(&permalink) != Some(&listing)
+                || !listing.sources.is_disjoint(changed)
+                || !page_path(output, &permalink).is_file()
+            {
+                let html = self.renderer.render_index(&view)?;
+                report.pages.push(write_page(output, &permalink, &html)?);
+            }
+            listings.insert(permalink, listing);
+        }
+        Ok(listings)
+    }
+}
+
+/// Returns the path of the `n`th index page, counting from zero.
+fn index_path(n: usize) -> String {
+    match n {
+        0 => String::from("/"),
+        n => format!("/page/{}/", n + 1),
+    }
+}
+
+fn links(posts: &[(&str, &Post, String)]) -> Vec<PostLink> {
+    posts
+        .iter()
+        .map(|(_, post, permalink)| PostLink::new(post, permalink))
+        .collect()
+}
+
+fn names(posts: &[(&str, &Post, String)]) -> BTreeSet<String> {
+    posts.iter().map(|(name, _, _)| name.to_string()).collect()
+}
+
+fn page_path(output: &Path, permalink: &str) -> PathBuf {
+    output
+        .join(permalink.trim_start_matches('/'))
+        .join(PAGE_FILE)
+}
+
+fn write_page(output: &Path, permalink: &str, html: &str) -> std::io::Result<PathBuf> {
+    let path = page_path(output, permalink);
+    fs::create_dir_all(path.parent().unwrap_or(output))?;
+    fs::write(&path, html)?;
+    Ok(path)
+}
+
+/// Removes the page written for the permalink, if there is one.
+fn remove_page(output: &Path, permalink: &str) -> std::io::Result<Option<PathBuf>> {
+    let path = page_path(output, permalink);
+    if !path.is_file() {
+        return Ok(None);
+    }
+    fs::remove_file(&path)?;
+    Ok(Some(path))
+}
+
+fn copy_directory(from: &Path, to: &Path) -> std::io::Result<()> {
+    fs::create_dir_all(to)?;
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_directory(&entry.path(), &target)?;
+        } else {
+            fs::copy(entry.path(), target)?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,114 @@
+use std::collections::BTreeMap;
+use std::io::Write;
+
+use chrono::NaiveDate;
+
+use super::Blog;
+use super::permalink::Permalink;
+use super::permalink::tag_path;
+
+const INDEX_PATH: &str = "/";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SitemapEntry {
+    pub path: String,
+    pub lastmod: Option<NaiveDate>,
+}
+
+/// `Sitemap` lists the pages of the site for search engines, following the
+/// sitemaps.org protocol.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Sitemap {
+    base_url: String,
+    entries: Vec<SitemapEntry>,
+}
+
+impl Sitemap {
+    pub fn new(base_url: &str) -> Self {
+        Sitemap {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            entries: vec![],
+        }
+    }
+
+    /// Lists the index, every published post and every tag page of the blog.
+    ///
+    /// The index and tag pages are as fresh as the newest post they list.
+    pub fn for_blog(base_url: &str, blog: &Blog, permalink: &Permalink) -> Self {
+        let mut sitemap = Sitemap::new(base_url);
+        let published = blog
+            .all()
+            .iter()
+            .zip(blog.permalinks(permalink))
+            .filter(|(post, _)| !post.draft)
+            .collect::<Vec<_>>();
+
+        let newest = published.iter().filter_map(|(post, _)| post.date).max();
+        sitemap.add(INDEX_PATH, newest);
+
+        let mut tags = BTreeMap::<String, Option<NaiveDate>>::new();
+        for (post, path) in &published {
+            sitemap.add(path, post.date);
+            for tag in &post.tags {
+                let lastmod = tags.entry(tag_path(tag)).or_default();
+                *lastmod = (*lastmod).max(post.date);
+            }
+        }
+        for (path, lastmod) in tags {
+            sitemap.add(&path, lastmod);
+        }
+        sitemap
+    }
+
+    pub fn add(&mut self, path: &str, lastmod: Option<NaiveDate>) {
+        self.entries.push(SitemapEntry {
+            path: path.to_string(),
+            lastmod,
+        });
+    }
+
+    pub fn entries(&self) -> &[SitemapEntry] {
+        &self.entries
+    }
+
+    pub fn write(&self, w: &mut dyn Write) -> std::io::Result<()> {
+        writeln!(w, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(
+            w,
+            r#"<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">"#
+        )?;
+        for entry in &self.entries {
+            writeln!(w, "  <url>")?;
+            writeln!(
+                w,
+                "    <loc>{}</loc>",
+                escape_xml(&format!("{}{}", self.base_url, encode_path(&entry.path)))
+            )?;
+            if let Some(lastmod) = entry.lastmod {
+                writeln!(w, "    <lastmod>{}</lastmod>", lastmod.format("%Y-%m-%d"))?;
+            }
+            writeln!(w, "  </url>")?;
+        }
+        writeln!(w, "</urlset>")
+    }
+}
+
+/// Percent-encodes everything in the path but unreserved characters and `/`.
+fn encode_path(path: &str) -> String {
+    path.bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
@@ -0,0 +1,87 @@
+use std::collections::BTreeMap;
+use std::fs::read_dir;
+use std::fs::read_to_string;
+use std::io::Error;
+use std::io::ErrorKind;
+use std::path::Path;
+use std::path::PathBuf;
+
+/// `Entry` identifies a single post within a `PostSource`.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Entry {
+    pub path: PathBuf,
+}
+
+/// `PostSource` is where raw posts come from, so that loading can be tested
+/// without touching the filesystem.
+pub trait PostSource {
+    fn list(&self) -> Result<Vec<Entry>, Error>;
+    fn read(&self, entry: &Entry) -> Result<String, Error>;
+}
+
+/// `DirectorySource` reads every file of a directory on disk.
+pub struct DirectorySource {
+    path: PathBuf,
+}
+
+impl DirectorySource {
+    pub fn new(path: &Path) -> Self {
+        DirectorySource {
+            path: path.to_path_buf(),
+        }
+    }
+}
+
+impl PostSource for DirectorySource {
+    fn list(&self) -> Result<Vec<Entry>, Error> {
+        Ok(read_dir(&self.path)?
+            .filter_map(Result::ok)
+            .map(|entry| Entry { path: entry.path() })
+            .collect())
+    }
+
+    fn read(&self, entry: &Entry) -> Result<String, Error> {
+        read_to_string(&entry.path)
+    }
+}
+
+/// `InMemorySource` keeps posts in a map, listed in path order. A path added
+/// with `with_unreadable` is listed but fails to read.
+#[derive(Default)]
+pub struct InMemorySource {
+    files: BTreeMap<PathBuf, Option<String>>,
+}
+
+impl InMemorySource {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_file(mut self, path: impl Into<PathBuf>, content: impl Into<String>) -> Self {
+        self.files.insert(path.into(), Some(content.into()));
+        self
+    }
+
+    pub fn with_unreadable(mut self, path: impl Into<PathBuf>) -> Self {
+        self.files.insert(path.into(), None);
+        self
+    }
+}
+
+impl PostSource for InMemorySource {
+    fn list(&self) -> Result<Vec<Entry>, Error> {
+        Ok(self
+            .files
+            .keys()
+            .map(|path| Entry { path: path.clone() })
+            .collect())
+    }
+
+    fn read(&self, entry: &Entry) -> Result<String, Error> {
+        match self.files.get(&entry.path) {
+            Some(Some(content)) => Ok(content.clone()),
+            Some(None) => Err(Error::new(ErrorKind::PermissionDenied, "unreadable file")),
+            None => Err(Error::new(ErrorKind::NotFound, "no such file")),
+        }
+    }
+}
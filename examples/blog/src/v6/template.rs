@@ -0,0 +1,194 @@
+use std::num::NonZeroUsize;
+
+use handlebars::Handlebars;
+use serde::Serialize;
+
+use super::Post;
+use super::permalink::tag_path;
+
+const WORDS_PER_MINUTE: NonZeroUsize = NonZeroUsize::new(200).unwrap();
+
+/// `PostView` is what a post page template gets to see.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct PostView {
+    pub title: String,
+    pub description: String,
+    pub date: Option<String>,
+    pub tags: Vec<TagLink>,
+    pub reading_time: usize,
+    /// The body already rendered as HTML.
+    pub body: String,
+    pub lang: Option<String>,
+    pub translations: Vec<TranslationLink>,
+}
+
+impl PostView {
+    pub fn new(post: &Post, body: String) -> Self {
+        PostView {
+            title: post.title.clone(),
+            description: post.description.clone(),
+            date: post.date.map(|date| date.to_string()),
+            tags: post.tags.iter().map(|tag| TagLink::new(tag)).collect(),
+            reading_time: post.reading_time(WORDS_PER_MINUTE),
+            body,
+            lang: post.lang.clone(),
+            translations: vec![],
+        }
+    }
+
+    pub fn with_translations(self, translations: Vec<TranslationLink>) -> Self {
+        PostView {
+            translations,
+            ..self
+        }
+    }
+}
+
+/// `TranslationLink` points at the same post in another language.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct TranslationLink {
+    pub lang: String,
+    pub permalink: String,
+}
+
+impl TranslationLink {
+    /// Links to the post at the permalink, if its language is known.
+    pub fn new(post: &Post, permalink: &str) -> Option<Self> {
+        Some(TranslationLink {
+            lang: post.lang.clone()?,
+            permalink: permalink.to_string(),
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct TagLink {
+    pub name: String,
+    pub permalink: String,
+}
+
+impl TagLink {
+    pub fn new(tag: &str) -> Self {
+        TagLink {
+            name: tag.to_string(),
+            permalink: tag_path(tag),
+        }
+    }
+}
+
+/// `IndexView` is what a page listing posts gets to see, either a page of the
+/// home index or a tag page.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct IndexView {
+    pub title: String,
+    pub posts: Vec<PostLink>,
+    pub previous: Option<String>,
+    pub next: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct PostLink {
+    pub title: String,
+    pub permalink: String,
+    pub date: Option<String>,
+}
+
+impl PostLink {
+    pub fn new(post: &Post, permalink: &str) -> Self {
+        PostLink {
+            title: post.title.clone(),
+            permalink: permalink.to_string(),
+            date: post.date.map(|date| date.to_string()),
+        }
+    }
+}
+
+/// `ErrorView` is what the page shown for a failed request gets to see.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ErrorView {
+    pub status: u16,
+    pub message: String,
+}
+
+/// `TemplateRenderer` turns the views of a site into HTML pages.
+pub trait TemplateRenderer {
+    fn render_post(&self, view: &PostView) -> Result<String, TemplateError>;
+    fn render_index(&self, view: &IndexView) -> Result<String, TemplateError>;
+    fn render_error(&self, view: &ErrorView) -> Result<String, TemplateError>;
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum TemplateError {
+    #[error("failed to compile template '{name}': {source}")]
+    Compile {
+        name: String,
+        #[source]
+        source: Box<handlebars::TemplateError>,
+    },
+
+    #[error("failed to render template '{name}': {source}")]
+    Render {
+        name: String,
+        #[source]
+        source: handlebars::RenderError,
+    },
+}
+
+const BUNDLED_TEMPLATES: [(&str, &str); 4] = [
+    ("layout", include_str!("../../templates/layout.hbs")),
+    ("post", include_str!("../../templates/post.hbs")),
+    ("index", include_str!("../../templates/index.hbs")),
+    ("error", include_str!("../../templates/error.hbs")),
+];
+
+/// `HandlebarsRenderer` renders the templates bundled in `templates/`.
+pub struct HandlebarsRenderer {
+    registry: Handlebars<'static>,
+}
+
+impl HandlebarsRenderer {
+    pub fn new() -> Result<Self, TemplateError> {
+        Self::from_templates(BUNDLED_TEMPLATES)
+    }
+
+    /// Same as `new`, rendering the given `(name, template)` pairs instead
+    /// of the bundled ones.
+    pub fn from_templates<'a>(
+        templates: impl IntoIterator<Item = (&'a str, &'a str)>,
+    ) -> Result<Self, TemplateError> {
+        let mut registry = Handlebars::new();
+        registry.set_strict_mode(true);
+        for (name, template) in templates {
+            registry
+                .register_template_string(name, template)
+                .map_err(|error| TemplateError::Compile {
+                    name: name.to_string(),
+                    source: Box::new(error),
+                })?;
+        }
+        Ok(HandlebarsRenderer { registry })
+    }
+
+    fn render(&self, name: &str, view: &impl Serialize) -> Result<String, TemplateError> {
+        self.registry
+            .render(name, view)
+            .map_err(|error| TemplateError::Render {
+                name: name.to_string(),
+                source: error,
+            })
+    }
+}
+
+impl TemplateRenderer for HandlebarsRenderer {
+    fn render_post(&self, view: &PostView) -> Result<String, TemplateError> {
+        self.render("post", view)
+    }
+
+    fn render_index(&self, view: &IndexView) -> Result<String, TemplateError> {
+        self.render("index", view)
+    }
+
+    fn render_error(&self, view: &ErrorView) -> Result<String, TemplateError> {
+        self.render("error", view)
+    }
+}
@@ -0,0 +1,110 @@
+use std::collections::BTreeSet;
+use std::collections::VecDeque;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::mpsc::Receiver;
+use std::sync::mpsc::RecvTimeoutError;
+use std::sync::mpsc::channel;
+use std::time::Duration;
+
+use notify::Event;
+use notify::RecommendedWatcher;
+use notify::RecursiveMode;
+use notify::Watcher;
+
+use super::site::Builder;
+use super::site::Report;
+use super::site::SiteError;
+
+/// How long the content has to stay quiet before a burst of changes is
+/// rebuilt, so that an editor saving several files triggers one rebuild.
+pub const DEBOUNCE_WINDOW: Duration = Duration::from_millis(200);
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Next {
+    Change(PathBuf),
+    Timeout,
+    Closed,
+}
+
+/// `ChangeSource` reports the paths that changed under the content directory.
+pub trait ChangeSource {
+    /// Waits for the next changed path, at most `timeout` if one is given.
+    fn next(&mut self, timeout: Option<Duration>) -> Next;
+}
+
+/// `NotifySource` reports changes made on disk, using the file watcher of the
+/// operating system.
+pub struct NotifySource {
+    _watcher: RecommendedWatcher,
+    events: Receiver<notify::Result<Event>>,
+    pending: VecDeque<PathBuf>,
+}
+
+impl NotifySource {
+    pub fn new(path: &Path) -> notify::Result<Self> {
+        let (sender, events) = channel();
+        let mut watcher = notify::recommended_watcher(sender)?;
+        watcher.watch(path, RecursiveMode::Recursive)?;
+        Ok(NotifySource {
+            _watcher: watcher,
+            events,
+            pending: VecDeque::new(),
+        })
+    }
+}
+
+impl ChangeSource for NotifySource {
+    fn next(&mut self, timeout: Option<Duration>) -> Next {
+        loop {
+            if let Some(path) = self.pending.pop_front() {
+                return Next::Change(path);
+            }
+            let event = match timeout {
+                Some(timeout) => match self.events.recv_timeout(timeout) {
+                    Ok(event) => event,
+                    Err(RecvTimeoutError::Timeout) => return Next::Timeout,
+                    Err(RecvTimeoutError::Disconnected) => return Next::Closed,
+                },
+                None => match self.events.recv() {
+                    Ok(event) => event,
+                    Err(_) => return Next::Closed,
+                },
+            };
+            if let Ok(event) = event
+                && !event.kind.is_access()
+            {
+                self.pending.extend(event.paths);
+            }
+        }
+    }
+}
+
+/// Waits for a change, then keeps collecting changes until none arrived for
+/// `window`. Returns `None` once the source is closed.
+pub fn debounce(source: &mut dyn ChangeSource, window: Duration) -> Option<BTreeSet<PathBuf>> {
+    let mut changes = BTreeSet::new();
+    match source.next(None) {
+        Next::Change(path) => changes.insert(path),
+        Next::Timeout | Next::Closed => return None,
+    };
+    while let Next::Change(path) = source.next(Some(window)) {
+        changes.insert(path);
+    }
+    Some(changes)
+}
+
+/// Rebuilds the site for every debounced burst of changes until the source is
+/// closed.
+pub fn watch(
+    builder: &mut Builder,
+    source: &mut dyn ChangeSource,
+    window: Duration,
+    mut on_rebuild: impl FnMut(&Report),
+) -> Result<(), SiteError> {
+    while let Some(changes) = debounce(source, window) {
+        let report = builder.rebuild(&changes)?;
+        on_rebuild(&report);
+    }
+    Ok(())
+}
@@ -0,0 +1,192 @@
+use std::collections::BTreeMap;
+
+use chrono::NaiveDate;
+use fixtures::PostBuilder;
+
+use blog::v6::Blog;
+use blog::v6::Post;
+use blog::v6::permalink::Permalink;
+
+#[rstest::fixture]
+fn sut() -> Blog {
+    Blog::new(vec![
+        post("Hello", &["Rust", "tdd"]),
+        post("Testing", &["TDD", "rust", "Rust"]),
+        post("Cooking", &["food"]),
+    ])
+}
+
+fn post(title: &str, tags: &[&str]) -> Post {
+    PostBuilder::new()
+        .title(title)
+        .tags(tags)
+        .date(None)
+        .draft(false)
+        .build()
+}
+
+fn titles(posts: Vec<&Post>) -> Vec<&str> {
+    posts.iter().map(|post| post.title.as_str()).collect()
+}
+
+#[rstest::rstest]
+#[case("rust", vec!["Hello", "Testing"])]
+#[case("RUST", vec!["Hello", "Testing"])]
+#[case("Food", vec!["Cooking"])]
+#[case("go", vec![])]
+fn sut_returns_posts_with_tag_ignoring_case(
+    sut: Blog,
+    #[case] tag: &str,
+    #[case] expected: Vec<&str>,
+) {
+    // Act
+    let actual = sut.with_tag(tag);
+
+    // Assert
+    assert_eq!(expected, titles(actual));
+}
+
+#[rstest::rstest]
+fn sut_counts_each_tag_once_per_post(sut: Blog) {
+    // Act
+    let actual = sut.tags();
+
+    // Assert
+    let expected = BTreeMap::from([
+        ("food".to_string(), 1),
+        ("rust".to_string(), 2),
+        ("tdd".to_string(), 2),
+    ]);
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn sut_counts_tags_sharing_slug_as_one() {
+    // Arrange
+    let sut = Blog::new(vec![post("Plus", &["C++"]), post("Sharp", &["C#"])]);
+
+    // Act
+    let actual = sut.tags();
+
+    // Assert
+    assert_eq!(BTreeMap::from([("c".to_string(), 2)]), actual);
+    assert_eq!(vec!["Plus", "Sharp"], titles(sut.with_tag("c++")));
+}
+
+#[rstest::rstest]
+fn sut_filters_posts_by_predicate(sut: Blog) {
+    // Act
+    let actual = sut.filter(|post| post.title.contains("ing"));
+
+    // Assert
+    assert_eq!(vec!["Testing", "Cooking"], titles(actual));
+}
+
+#[test]
+fn sut_has_no_tags_if_empty() {
+    // Arrange
+    let sut = Blog::from(vec![]);
+
+    // Act
+    let actual = sut.tags();
+
+    // Assert
+    assert!(actual.is_empty());
+    assert!(sut.all().is_empty());
+}
+
+#[test]
+fn sut_returns_unique_slugs_for_duplicate_titles() {
+    // Arrange
+    let sut = Blog::new(vec![
+        post("Hello, World", &[]),
+        post("Héllo world!", &[]),
+        post("Other", &[]),
+    ]);
+
+    // Act
+    let actual = sut.slugs();
+
+    // Assert
+    let expected = vec!["hello-world", "hello-world-2", "other"];
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn sut_leaves_slug_of_published_post_to_it_over_draft_of_same_title() {
+    // Arrange
+    let draft = Post {
+        draft: true,
+        ..post("Hello", &[])
+    };
+    let sut = Blog::new(vec![draft, post("Hello", &[])]);
+
+    // Act
+    let actual = sut.slugs();
+
+    // Assert
+    let expected = vec!["hello-2", "hello"];
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn sut_renders_permalink_of_every_post() {
+    // Arrange
+    let dated = Post {
+        date: NaiveDate::from_ymd_opt(2024, 3, 1),
+        ..post("Hello", &[])
+    };
+    let sut = Blog::new(vec![dated, post("Hello", &[])]);
+
+    // Act
+    let actual = sut.permalinks(&Permalink::default());
+
+    // Assert
+    let expected = vec!["/2024/hello/", "/hello-2/"];
+    assert_eq!(expected, actual);
+}
+
+fn translated(title: &str, key: &str, lang: &str) -> Post {
+    PostBuilder::new()
+        .title(title)
+        .date(None)
+        .draft(false)
+        .translation(key, lang)
+        .build()
+}
+
+#[test]
+fn sut_returns_other_translations_ordered_by_language() {
+    // Arrange
+    let sut = Blog::new(vec![
+        translated("Hello", "hello", "en"),
+        translated("안녕하세요", "hello", "ko"),
+        translated("Bonjour", "hello", "fr"),
+        translated("Partial", "partial", "en"),
+        post("Untranslated", &[]),
+    ]);
+
+    // Act
+    let actual = sut.translations(&sut.all()[0]);
+
+    // Assert
+    assert_eq!(vec!["Bonjour", "안녕하세요"], titles(actual));
+}
+
+#[rstest::rstest]
+#[case(translated("Partial", "partial", "en"))]
+#[case(post("Untranslated", &[]))]
+fn sut_returns_no_translations_if_post_is_not_translated(#[case] post: Post) {
+    // Arrange
+    let sut = Blog::new(vec![
+        translated("Hello", "hello", "en"),
+        translated("안녕하세요", "hello", "ko"),
+        post.clone(),
+    ]);
+
+    // Act
+    let actual = sut.translations(&post);
+
+    // Assert
+    assert!(actual.is_empty());
+}
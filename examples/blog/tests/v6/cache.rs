@@ -0,0 +1,74 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::fs;
+
+use tempfile::tempdir;
+
+use blog::v6::cache::BuildCache;
+use blog::v6::cache::Listing;
+use blog::v6::cache::Source;
+use blog::v6::cache::hash;
+
+#[test]
+fn sut_loads_what_it_saved() {
+    // Arrange
+    let directory = tempdir().unwrap();
+    let path = directory.path().join("cache.toml");
+    let sut = BuildCache {
+        options: hash(&"options"),
+        sources: BTreeMap::from([
+            (
+                "hello.md".to_string(),
+                Source {
+                    hash: hash(&"Hello"),
+                    permalink: Some("/2024/hello/".to_string()),
+                },
+            ),
+            ("broken.md".to_string(), Source::default()),
+        ]),
+        listings: BTreeMap::from([(
+            "/".to_string(),
+            Listing {
+                hash: hash(&"index"),
+                sources: BTreeSet::from(["hello.md".to_string()]),
+            },
+        )]),
+    };
+
+    // Act
+    sut.save(&path).unwrap();
+    let actual = BuildCache::load(&path);
+
+    // Assert
+    assert_eq!(sut, actual);
+}
+
+#[rstest::rstest]
+#[case(None)]
+#[case(Some("not = [a cache"))]
+#[case(Some("options = 3"))]
+fn sut_is_empty_if_file_is_missing_or_invalid(#[case] content: Option<&str>) {
+    // Arrange
+    let directory = tempdir().unwrap();
+    let path = directory.path().join("cache.toml");
+    if let Some(content) = content {
+        fs::write(&path, content).unwrap();
+    }
+
+    // Act
+    let actual = BuildCache::load(&path);
+
+    // Assert
+    assert_eq!(BuildCache::default(), actual);
+}
+
+#[test]
+fn sut_hashes_same_content_to_same_value() {
+    // Act
+    let actual = (hash(&"Hello"), hash(&"Hello"), hash(&"Hello!"));
+
+    // Assert
+    assert_eq!(actual.0, actual.1);
+    assert_ne!(actual.0, actual.2);
+    assert_eq!(16, actual.0.len());
+}
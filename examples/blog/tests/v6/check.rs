@@ -0,0 +1,278 @@
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process::Command;
+
+use assertions::assert_err_matches;
+use chrono::NaiveDate;
+use tempfile::tempdir;
+
+use blog::v6::check::CheckOptions;
+use blog::v6::check::Diagnostic;
+use blog::v6::check::Severity;
+use blog::v6::check::check;
+use blog::v6::permalink::Permalink;
+use blog::v6::site::SiteError;
+use blog::v6::source::InMemorySource;
+
+const HELLO: &str = "Title: Hello\nDescription: First\nTags: rust\nDate: 2024-01-01\n---\nHello";
+
+fn today() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2024, 6, 1).unwrap()
+}
+
+fn diagnostic(severity: Severity, file: &str, line: usize, message: &str) -> Diagnostic {
+    Diagnostic {
+        severity,
+        file: PathBuf::from(file),
+        line,
+        message: message.to_string(),
+    }
+}
+
+fn check_posts(source: &InMemorySource) -> Vec<Diagnostic> {
+    check(source, &Permalink::default(), today()).unwrap()
+}
+
+#[test]
+fn sut_finds_nothing_in_valid_posts() {
+    // Arrange
+    let source = InMemorySource::new()
+        .with_file("posts/hello.md", HELLO)
+        .with_file(
+            "posts/links.md",
+            "---\ntitle: Links\ndescription: Links\ntags: [rust]\ndate: 2024-02-01\n---\n\
+             [post](hello.md), [page](../hello/), [tag](/tags/rust/), [home](/),\n\
+             [asset](/assets/logo.png), [web](https://example.com), [top](#top)",
+        );
+
+    // Act
+    let actual = check_posts(&source);
+
+    // Assert
+    assert_eq!(Vec::<Diagnostic>::new(), actual);
+}
+
+#[test]
+fn sut_reports_post_that_fails_to_parse_as_error() {
+    // Arrange
+    let source = InMemorySource::new().with_file("posts/broken.md", "Title: Broken\n---\n");
+
+    // Act
+    let actual = check_posts(&source);
+
+    // Assert
+    let expected = vec![diagnostic(
+        Severity::Error,
+        "posts/broken.md",
+        2,
+        "expected 'Description:'",
+    )];
+    assert_eq!(expected, actual);
+}
+
+#[rstest::rstest]
+#[case("Title: Hello\nDescription: \nTags: \n---\n", 2)]
+#[case("---\ntitle: Hello\n---\n", 1)]
+fn sut_warns_about_missing_description(#[case] content: &str, #[case] line: usize) {
+    // Arrange
+    let source = InMemorySource::new().with_file("posts/hello.md", content);
+
+    // Act
+    let actual = check_posts(&source);
+
+    // Assert
+    let expected = vec![diagnostic(
+        Severity::Warning,
+        "posts/hello.md",
+        line,
+        "missing description",
+    )];
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn sut_reports_every_later_post_reusing_slug_as_error() {
+    // Arrange
+    let source = InMemorySource::new()
+        .with_file("posts/a.md", HELLO)
+        .with_file(
+            "posts/b.md",
+            "---\ntitle: hello!\ndescription: Again\n---\n",
+        )
+        .with_file("posts/c.md", HELLO);
+
+    // Act
+    let actual = check_posts(&source);
+
+    // Assert
+    let expected = vec![
+        diagnostic(
+            Severity::Error,
+            "posts/b.md",
+            2,
+            "slug 'hello' is already used by posts/a.md",
+        ),
+        diagnostic(
+            Severity::Error,
+            "posts/c.md",
+            1,
+            "slug 'hello' is already used by posts/a.md",
+        ),
+    ];
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn sut_reports_dead_relative_links_as_errors_on_their_line() {
+    // Arrange
+    let source = InMemorySource::new().with_file(
+        "posts/hello.md",
+        "Title: Hello\nDescription: First\nTags: \nDate: 2024-01-01\n---\n\
+         Intro\n\nSee [missing](missing.md#part)\nand [gone](../gone/?q=1).\n\n[tag](/tags/none/)",
+    );
+
+    // Act
+    let actual = check_posts(&source);
+
+    // Assert
+    let expected = vec![
+        diagnostic(
+            Severity::Error,
+            "posts/hello.md",
+            8,
+            "dead link 'missing.md'",
+        ),
+        diagnostic(Severity::Error, "posts/hello.md", 9, "dead link '../gone/'"),
+        diagnostic(
+            Severity::Error,
+            "posts/hello.md",
+            11,
+            "dead link '/tags/none/'",
+        ),
+    ];
+    assert_eq!(expected, actual);
+}
+
+#[rstest::rstest]
+#[case(false, 1)]
+#[case(true, 0)]
+fn sut_warns_about_future_date_unless_post_is_draft(#[case] draft: bool, #[case] expected: usize) {
+    // Arrange
+    let content = format!(
+        "+++\ntitle = \"Soon\"\ndescription = \"Soon\"\ndate = 2024-06-02\ndraft = {}\n+++\n",
+        draft
+    );
+    let source = InMemorySource::new().with_file("posts/soon.md", &content);
+
+    // Act
+    let actual = check_posts(&source);
+
+    // Assert
+    assert_eq!(expected, actual.len());
+    if let Some(actual) = actual.first() {
+        let expected = diagnostic(
+            Severity::Warning,
+            "posts/soon.md",
+            4,
+            "date 2024-06-02 is in the future",
+        );
+        assert_eq!(&expected, actual);
+    }
+}
+
+#[rstest::rstest]
+#[case("---\ntitle: Hello\ndescription: First\nauthor: me\nextra:\n  nested: 1\n---\n")]
+#[case(
+    "+++\ntitle = \"Hello\"\ndescription = \"First\"\nauthor = \"me\"\n[extra]\nnested = 1\n+++\n"
+)]
+fn sut_warns_about_unknown_front_matter_keys(#[case] content: &str) {
+    // Arrange
+    let source = InMemorySource::new().with_file("posts/hello.md", content);
+
+    // Act
+    let actual = check_posts(&source);
+
+    // Assert
+    let expected = vec![
+        diagnostic(
+            Severity::Warning,
+            "posts/hello.md",
+            4,
+            "unknown front matter key 'author'",
+        ),
+        diagnostic(
+            Severity::Warning,
+            "posts/hello.md",
+            5,
+            "unknown front matter key 'extra'",
+        ),
+    ];
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn sut_formats_diagnostic_as_file_line_severity_and_message() {
+    // Arrange
+    let sut = diagnostic(Severity::Error, "posts/a.md", 3, "dead link 'b.md'");
+
+    // Act
+    let actual = sut.to_string();
+
+    // Assert
+    assert_eq!("posts/a.md:3: error: dead link 'b.md'", actual);
+}
+
+#[rstest::rstest]
+#[case(&["content"], Ok(CheckOptions { content: PathBuf::from("content"), permalink: Permalink::default() }))]
+#[case(&["--permalink", "/:slug/", "content"], Ok(CheckOptions { content: PathBuf::from("content"), permalink: Permalink::new("/:slug/") }))]
+#[case(&[], Err(()))]
+#[case(&["content", "other"], Err(()))]
+#[case(&["content", "--unknown"], Err(()))]
+fn sut_parses_check_arguments(#[case] args: &[&str], #[case] expected: Result<CheckOptions, ()>) {
+    // Act
+    let actual = CheckOptions::from_args(args.iter().map(|arg| arg.to_string()));
+
+    // Assert
+    match expected {
+        Ok(expected) => assert_eq!(expected, actual.unwrap()),
+        Err(()) => assert_err_matches!(actual, SiteError::Usage(_)),
+    }
+}
+
+fn run_check(content: &Path) -> std::process::Output {
+    Command::new(env!("CARGO_BIN_EXE_blog"))
+        .arg("check")
+        .arg(content)
+        .output()
+        .unwrap()
+}
+
+#[rstest::rstest]
+#[case(
+    "Title: Hello\nDescription: \nTags: \n---\n",
+    true,
+    "0 errors, 1 warnings"
+)]
+#[case("Title: Hello\n---\n", false, "1 errors, 0 warnings")]
+fn sut_fails_only_if_errors_are_found(
+    #[case] content: &str,
+    #[case] success: bool,
+    #[case] summary: &str,
+) {
+    // Arrange
+    let directory = tempdir().unwrap();
+    let posts = directory.path().join("posts");
+    fs::create_dir_all(&posts).unwrap();
+    fs::write(posts.join("hello.md"), content).unwrap();
+
+    // Act
+    let actual = run_check(directory.path());
+
+    // Assert
+    assert_eq!(success, actual.status.success());
+    let output =
+        String::from_utf8(actual.stdout).unwrap() + &String::from_utf8(actual.stderr).unwrap();
+    assert!(output.contains(summary), "{}", output);
+    assert!(output.contains("hello.md:2: "), "{}", output);
+}
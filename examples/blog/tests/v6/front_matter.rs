@@ -0,0 +1,142 @@
+use chrono::NaiveDate;
+
+use blog::v6::front_matter::FrontMatter;
+use blog::v6::front_matter::FrontMatterError;
+use blog::v6::front_matter::FrontMatterFormat;
+use blog::v6::front_matter::Legacy;
+use blog::v6::front_matter::Toml;
+use blog::v6::front_matter::Yaml;
+use blog::v6::front_matter::keys;
+use blog::v6::front_matter::parse;
+
+#[rstest::rstest]
+#[case(
+    "Title: Hello\nDescription: World\nTags: a, b\nDate: 2024-01-31\nDraft: true\nLang: ko\n---\nBody"
+)]
+#[case(
+    "---\ntitle: Hello\ndescription: World\ntags: [a, b]\ndate: 2024-01-31\ndraft: true\nlang: ko\n---\nBody"
+)]
+#[case(
+    "+++\ntitle = \"Hello\"\ndescription = \"World\"\ntags = [\"a\", \"b\"]\ndate = 2024-01-31\ndraft = true\nlang = \"ko\"\n+++\nBody"
+)]
+#[case(
+    "+++\ntitle = \"Hello\"\ndescription = \"World\"\ntags = [\"a\", \"b\"]\ndate = \"2024-01-31\"\ndraft = true\nlang = \"ko\"\n+++\nBody"
+)]
+fn sut_parses_same_front_matter_from_every_format(#[case] content: &str) {
+    // Act
+    let (actual, body) = parse(content).unwrap();
+
+    // Assert
+    let expected = FrontMatter {
+        title: "Hello".to_string(),
+        description: "World".to_string(),
+        tags: vec!["a".to_string(), "b".to_string()],
+        date: NaiveDate::from_ymd_opt(2024, 1, 31),
+        draft: true,
+        lang: Some("ko".to_string()),
+    };
+    assert_eq!(expected, actual);
+    assert_eq!("Body", body);
+}
+
+#[rstest::rstest]
+#[case("Title: Hello\n", (true, false, false))]
+#[case("---\ntitle: Hello\n---\n", (false, true, false))]
+#[case("+++\ntitle = \"Hello\"\n+++\n", (false, false, true))]
+fn sut_detects_format_by_its_first_line(
+    #[case] content: &str,
+    #[case] expected: (bool, bool, bool),
+) {
+    // Act
+    let actual = (
+        Legacy.matches(content),
+        Yaml.matches(content),
+        Toml.matches(content),
+    );
+
+    // Assert
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn sut_defaults_optional_fields_of_fenced_formats() {
+    // Arrange
+    let content = "+++\ntitle = \"Hello\"\n+++\nBody";
+
+    // Act
+    let (actual, _) = parse(content).unwrap();
+
+    // Assert
+    assert_eq!("", actual.description);
+    assert!(actual.tags.is_empty());
+    assert_eq!(None, actual.date);
+    assert!(!actual.draft);
+    assert_eq!(None, actual.lang);
+}
+
+#[rstest::rstest]
+#[case("Title: Hello\nTags: a\n---\nBody", 2, "'Description:'")]
+#[case("Title: Hello\nDescription: World\nTags: a\nBody", 4, "'---'")]
+#[case(
+    "Title: Hello\nDescription: World\nTags: a\nDate: 2024-13-01\n---\nBody",
+    4,
+    "a date in YYYY-MM-DD format"
+)]
+#[case(
+    "Title: Hello\nDescription: World\nTags: a\nDate: 2024-01-31\nDraft: yes\n---\nBody",
+    5,
+    "'true' or 'false'"
+)]
+#[case("+++\ntitle = \"Hello\"\nBody", 4, "closing '+++'")]
+#[case("---\ntitle: Hello\nBody", 4, "closing '---'")]
+fn sut_reports_line_and_expectation_if_front_matter_is_malformed(
+    #[case] content: &str,
+    #[case] line: usize,
+    #[case] expected: &str,
+) {
+    // Act
+    let actual = parse(content).unwrap_err();
+
+    // Assert
+    let expected = FrontMatterError {
+        line,
+        expected: expected.to_string(),
+    };
+    assert_eq!(expected, actual);
+}
+
+#[rstest::rstest]
+#[case("+++\ntitle = \"Hello\"\ntags = 3\n+++\nBody", 3)]
+#[case("---\ntitle: Hello\ntags: 3\n---\nBody", 3)]
+#[case("+++\ntitle = \"Hello\"\ndate = \"yesterday\"\n+++\nBody", 3)]
+#[case("---\ntitle: Hello\ndate: 2024-02-30\n---\nBody", 3)]
+fn sut_reports_line_of_invalid_value_in_fenced_formats(#[case] content: &str, #[case] line: usize) {
+    // Act
+    let actual = parse(content).unwrap_err();
+
+    // Assert
+    assert_eq!(line, actual.line);
+}
+
+#[rstest::rstest]
+#[case("Title: Hello\nDescription: World\nTags: a\n---\nKey: body", 1)]
+#[case(
+    "---\ntitle: Hello\ndescription: World\ntags:\n  - a\n---\nkey: body",
+    2
+)]
+#[case(
+    "+++\ntitle = \"Hello\"\n\"description\" = \"World\"\ntags = [\"a\"]\n+++\nkey = body",
+    2
+)]
+fn sut_lists_top_level_keys_with_their_line(#[case] content: &str, #[case] first_line: usize) {
+    // Act
+    let actual = keys(content);
+
+    // Assert
+    let expected = ["title", "description", "tags"]
+        .into_iter()
+        .enumerate()
+        .map(|(n, key)| (first_line + n, key.to_string()))
+        .collect::<Vec<_>>();
+    assert_eq!(expected, actual);
+}
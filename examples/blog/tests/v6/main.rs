@@ -0,0 +1,13 @@
+mod blog;
+mod cache;
+mod check;
+mod front_matter;
+mod markdown;
+mod permalink;
+mod post;
+mod server;
+mod site;
+mod sitemap;
+mod source;
+mod template;
+mod watch;
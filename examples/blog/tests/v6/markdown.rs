@@ -0,0 +1,48 @@
+use blog::v6::markdown::render;
+use golden::assert_matches_golden;
+
+#[test]
+fn sut_highlights_rust_code_block() {
+    // Arrange
+    let markdown = "```rust\nfn add(a: i32, b: i32) -> i32 {\n    // Sum\n    a + b\n}\n```\n";
+
+    // Act
+    let actual = render(markdown);
+
+    // Assert
+    assert_matches_golden!("v6/rust.html", actual);
+}
+
+#[rstest::rstest]
+#[case("rust")]
+#[case("rs")]
+#[case("rust,ignore")]
+#[case("rust title=\"main.rs\"")]
+fn sut_detects_language_from_info_string(#[case] info: &str) {
+    // Arrange
+    let markdown = format!("```{}\nlet x = 1;\n```\n", info);
+
+    // Act
+    let actual = render(&markdown);
+
+    // Assert
+    assert_eq!(render("```rust\nlet x = 1;\n```\n"), actual);
+}
+
+#[rstest::rstest]
+#[case(
+    "```klingon\nQapla' <3\n```\n",
+    "<pre><code class=\"language-klingon\">Qapla' &lt;3\n</code></pre>\n"
+)]
+#[case("```\nplain\n```\n", "<pre><code>plain\n</code></pre>\n")]
+#[case("    indented\n", "<pre><code>indented\n</code></pre>\n")]
+fn sut_renders_code_as_plain_text_if_language_is_unknown(
+    #[case] markdown: &str,
+    #[case] expected: &str,
+) {
+    // Act
+    let actual = render(markdown);
+
+    // Assert
+    assert_eq!(expected, actual);
+}
@@ -0,0 +1,61 @@
+use chrono::NaiveDate;
+
+use blog::v6::permalink::Permalink;
+use blog::v6::permalink::dedupe;
+use blog::v6::permalink::slugify;
+
+#[rstest::rstest]
+#[case("Hello, World!", "hello-world")]
+#[case("  Learn Rust -- with   Tests ", "learn-rust-with-tests")]
+#[case("Café au lait", "cafe-au-lait")]
+#[case("Привет, мир", "privet-mir")]
+#[case("Straße 2024", "strasse-2024")]
+#[case("東京", "dong-jing")]
+#[case("!!!", "untitled")]
+fn sut_slugifies_title_correctly(#[case] title: &str, #[case] expected: &str) {
+    // Act
+    let actual = slugify(title);
+
+    // Assert
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn sut_adds_numeric_suffixes_to_duplicate_slugs() {
+    // Arrange
+    let slugs = ["hello", "world", "hello", "hello-2", "hello"].map(String::from);
+
+    // Act
+    let actual = dedupe(slugs.to_vec());
+
+    // Assert
+    let expected = ["hello", "world", "hello-2", "hello-2-2", "hello-3"].map(String::from);
+    assert_eq!(expected.to_vec(), actual);
+}
+
+#[rstest::rstest]
+#[case("/:year/:slug/", "/2024/hello/")]
+#[case("/:year/:month/:day/:slug.html", "/2024/01/05/hello.html")]
+#[case("/posts/:slug", "/posts/hello")]
+fn sut_renders_permalink_template(#[case] template: &str, #[case] expected: &str) {
+    // Arrange
+    let sut = Permalink::new(template);
+
+    // Act
+    let actual = sut.render("hello", NaiveDate::from_ymd_opt(2024, 1, 5));
+
+    // Assert
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn sut_leaves_out_date_segments_of_undated_post() {
+    // Arrange
+    let sut = Permalink::default();
+
+    // Act
+    let actual = sut.render("hello", None);
+
+    // Assert
+    assert_eq!("/hello/", actual);
+}
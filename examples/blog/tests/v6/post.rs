@@ -0,0 +1,439 @@
+use std::fs::File;
+use std::io::ErrorKind;
+use std::io::Write;
+use std::num::NonZeroUsize;
+use std::path::Path;
+use std::path::PathBuf;
+
+use chrono::NaiveDate;
+use fixtures::PostBuilder;
+use tempfile::tempdir;
+
+use blog::v6::Post;
+use blog::v6::PostParseError;
+use blog::v6::posts_between;
+use blog::v6::posts_sorted_by_date;
+use blog::v6::source::InMemorySource;
+use blog::v6::translation_key;
+
+const WORDS_PER_MINUTE: NonZeroUsize = NonZeroUsize::new(200).unwrap();
+
+#[rstest::rstest]
+#[case(legacy_format)]
+#[case(yaml_format)]
+#[case(toml_format)]
+fn sut_loads_posts_from_files_correctly(posts: Vec<Post>, #[case] format: fn(&Post) -> String) {
+    // Arrange
+    let directory = tempdir().unwrap();
+    arrange_post_files(&posts, directory.path(), format);
+
+    // Act
+    let (actuals, errors) = Post::from_directory(directory.path()).unwrap();
+
+    // Assert
+    assert!(errors.is_empty());
+    assert_eq!(actuals.all().len(), posts.len());
+    for actual in actuals.all() {
+        assert!(posts.contains(actual));
+    }
+}
+
+#[rstest::rstest]
+fn sut_loads_posts_written_in_mixed_formats(posts: Vec<Post>) {
+    // Arrange
+    let directory = tempdir().unwrap();
+    let formats = [legacy_format, yaml_format, toml_format];
+    for (n, post) in posts.iter().enumerate() {
+        let content = formats[n % formats.len()](post);
+        let mut file = File::create(directory.path().join(format!("post_{}.md", n))).unwrap();
+        file.write_all(content.as_bytes()).unwrap();
+    }
+
+    // Act
+    let (actuals, errors) = Post::from_directory(directory.path()).unwrap();
+
+    // Assert
+    assert!(errors.is_empty());
+    assert_eq!(actuals.all().len(), posts.len());
+    for actual in actuals.all() {
+        assert!(posts.contains(actual));
+    }
+}
+
+fn arrange_post_files(posts: &[Post], directory: &Path, format: fn(&Post) -> String) {
+    for (n, post) in posts.iter().enumerate() {
+        let mut file = File::create(directory.join(format!("post_{}.md", n))).unwrap();
+        file.write_all(format(post).as_bytes()).unwrap();
+    }
+}
+
+fn legacy_format(post: &Post) -> String {
+    let date = post
+        .date
+        .map(|date| format!("Date: {}\n", date))
+        .unwrap_or_default();
+    let draft = if post.draft { "Draft: true\n" } else { "" };
+    format!(
+        "Title: {}\nDescription: {}\nTags: {}\n{}{}---\n{}",
+        post.title,
+        post.description,
+        post.tags.join(", "),
+        date,
+        draft,
+        post.body
+    )
+}
+
+fn yaml_format(post: &Post) -> String {
+    let tags = post
+        .tags
+        .iter()
+        .map(|tag| format!("  - \"{}\"", tag))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let date = post
+        .date
+        .map(|date| format!("date: {}\n", date))
+        .unwrap_or_default();
+    let draft = if post.draft { "draft: true\n" } else { "" };
+    format!(
+        "---\ntitle: \"{}\"\ndescription: \"{}\"\ntags:\n{}\n{}{}---\n{}",
+        post.title, post.description, tags, date, draft, post.body
+    )
+}
+
+fn toml_format(post: &Post) -> String {
+    let tags = post
+        .tags
+        .iter()
+        .map(|tag| format!("\"{}\"", tag))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let date = post
+        .date
+        .map(|date| format!("date = {}\n", date))
+        .unwrap_or_default();
+    let draft = if post.draft { "draft = true\n" } else { "" };
+    format!(
+        "+++\ntitle = \"{}\"\ndescription = \"{}\"\ntags = [{}]\n{}{}+++\n{}",
+        post.title, post.description, tags, date, draft, post.body
+    )
+}
+
+#[rstest::fixture]
+fn post() -> Post {
+    PostBuilder::new().build()
+}
+
+#[rstest::fixture]
+fn posts(#[default(5)] n: usize) -> Vec<Post> {
+    PostBuilder::many(n)
+}
+
+#[rstest::rstest]
+fn sut_collects_parse_errors_per_file_and_keeps_loading_others(posts: Vec<Post>) {
+    // Arrange
+    let source = posts
+        .iter()
+        .enumerate()
+        .fold(InMemorySource::new(), |source, (n, post)| {
+            source.with_file(format!("post_{}.md", n), legacy_format(post))
+        })
+        .with_file("broken.md", "Title: Broken\nTags: a\n---\nBody");
+
+    // Act
+    let (actuals, errors) = Post::from_source(&source).unwrap();
+
+    // Assert
+    assert_eq!(posts, actuals.all());
+    let expected = vec![PostParseError {
+        file: Some(PathBuf::from("broken.md")),
+        line: 2,
+        expected: "'Description:'".to_string(),
+    }];
+    assert_eq!(expected, errors);
+}
+
+#[test]
+fn sut_aborts_loading_if_post_cannot_be_read() {
+    // Arrange
+    let source = InMemorySource::new()
+        .with_file(
+            "hello.md",
+            "Title: Hello\nDescription: World\nTags: a\n---\nBody",
+        )
+        .with_unreadable("secret.md");
+
+    // Act
+    let actual = Post::from_source(&source).unwrap_err();
+
+    // Assert
+    assert_eq!(ErrorKind::PermissionDenied, actual.kind());
+}
+
+#[test]
+fn sut_returns_parse_error_without_file_if_parsed_from_string() {
+    // Arrange
+    let content = "Title: Hello\nDescription: World\n".to_string();
+
+    // Act
+    let actual = Post::try_from(content).unwrap_err();
+
+    // Assert
+    assert_eq!(None, actual.file);
+    assert_eq!(3, actual.line);
+    assert_eq!("line 3: expected 'Tags:'", actual.to_string());
+}
+
+#[test]
+fn sut_mentions_file_and_line_in_error_message() {
+    // Arrange
+    let error = PostParseError {
+        file: Some(PathBuf::from("posts/hello.md")),
+        line: 2,
+        expected: "'Description:'".to_string(),
+    };
+
+    // Act
+    let actual = error.to_string();
+
+    // Assert
+    assert_eq!("posts/hello.md:2: expected 'Description:'", actual);
+}
+
+#[test]
+fn sut_reports_file_of_post_with_unparseable_date() {
+    // Arrange
+    let source = InMemorySource::new().with_file(
+        "dated.md",
+        "Title: Hello\nDescription: World\nTags: a\nDate: 31/01/2024\n---\nBody",
+    );
+
+    // Act
+    let (_, errors) = Post::from_source(&source).unwrap();
+
+    // Assert
+    let expected = vec![PostParseError {
+        file: Some(PathBuf::from("dated.md")),
+        line: 4,
+        expected: "a date in YYYY-MM-DD format".to_string(),
+    }];
+    assert_eq!(expected, errors);
+}
+
+#[rstest::rstest]
+fn sut_sorts_posts_newest_first_with_undated_posts_last(#[with(4)] posts: Vec<Post>) {
+    // Arrange
+    let dates = [date(2024, 1, 1), date(2024, 3, 1)];
+    let posts = posts
+        .into_iter()
+        .enumerate()
+        .map(|(n, post)| Post {
+            date: dates.get(n / 2).copied().filter(|_| n % 2 == 0),
+            ..post
+        })
+        .collect::<Vec<_>>();
+
+    // Act
+    let actual = posts_sorted_by_date(&posts)
+        .into_iter()
+        .map(|post| post.date)
+        .collect::<Vec<_>>();
+
+    // Assert
+    let expected = vec![Some(date(2024, 3, 1)), Some(date(2024, 1, 1)), None, None];
+    assert_eq!(expected, actual);
+}
+
+#[rstest::rstest]
+#[case(date(2024, 2, 1)..date(2024, 4, 1), vec![date(2024, 3, 1), date(2024, 2, 1)])]
+#[case(date(2024, 2, 1)..=date(2024, 4, 1), vec![date(2024, 4, 1), date(2024, 3, 1), date(2024, 2, 1)])]
+fn sut_returns_posts_published_within_range(
+    #[with(5)] posts: Vec<Post>,
+    #[case] range: impl std::ops::RangeBounds<NaiveDate>,
+    #[case] expected: Vec<NaiveDate>,
+) {
+    // Arrange
+    let posts = posts
+        .into_iter()
+        .zip(1..)
+        .map(|(post, month)| Post {
+            date: Some(date(2024, month, 1)),
+            ..post
+        })
+        .collect::<Vec<_>>();
+
+    // Act
+    let actual = posts_between(&posts, range)
+        .into_iter()
+        .filter_map(|post| post.date)
+        .collect::<Vec<_>>();
+
+    // Assert
+    assert_eq!(expected, actual);
+}
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+#[rstest::rstest]
+fn sut_excludes_drafts_from_published_posts_by_default(#[with(6)] posts: Vec<Post>) {
+    // Arrange
+    let source = posts
+        .into_iter()
+        .enumerate()
+        .fold(InMemorySource::new(), |source, (n, post)| {
+            let post = Post {
+                draft: n % 3 == 0,
+                ..post
+            };
+            source.with_file(format!("post_{}.md", n), legacy_format(&post))
+        });
+
+    // Act
+    let (actual, _) = Post::from_source(&source).unwrap();
+
+    // Assert
+    assert_eq!(6, actual.all().len());
+    assert_eq!(4, actual.published().len());
+    assert!(actual.published().iter().all(|post| !post.draft));
+}
+
+#[rstest::rstest]
+#[case("", 0)]
+#[case("One two\nthree   four\n\nfive", 5)]
+#[case("Before\n```rust\nfn main() {}\n```\nAfter", 2)]
+#[case("Before\n~~~\nlet x = 1;\n~~~\nAfter the fence", 4)]
+#[case("```\nonly code here\n```", 0)]
+fn sut_counts_words_outside_code_blocks(post: Post, #[case] body: &str, #[case] expected: usize) {
+    // Arrange
+    let sut = Post {
+        body: body.to_string(),
+        ..post
+    };
+
+    // Act
+    let actual = sut.word_count();
+
+    // Assert
+    assert_eq!(expected, actual);
+}
+
+#[rstest::rstest]
+#[case(0, 0)]
+#[case(1, 1)]
+#[case(200, 1)]
+#[case(201, 2)]
+#[case(1000, 5)]
+fn sut_rounds_reading_time_up_to_whole_minutes(
+    post: Post,
+    #[case] words: usize,
+    #[case] expected: usize,
+) {
+    // Arrange
+    let sut = Post {
+        body: vec!["word"; words].join(" "),
+        ..post
+    };
+
+    // Act
+    let actual = sut.reading_time(WORDS_PER_MINUTE);
+
+    // Assert
+    assert_eq!(expected, actual);
+}
+
+#[rstest::rstest]
+fn sut_takes_a_minute_per_word_at_slowest_reading_speed(post: Post) {
+    // Arrange
+    let sut = Post {
+        body: "three short words".to_string(),
+        ..post
+    };
+
+    // Act
+    let actual = sut.reading_time(NonZeroUsize::MIN);
+
+    // Assert
+    assert_eq!(3, actual);
+}
+
+#[rstest::rstest]
+fn sut_ignores_code_when_estimating_reading_time(post: Post) {
+    // Arrange
+    let code = vec!["let x = 1;"; 500].join("\n");
+    let sut = Post {
+        body: format!("A short intro.\n```\n{}\n```\nThe end.", code),
+        ..post
+    };
+
+    // Act
+    let actual = sut.reading_time(WORDS_PER_MINUTE);
+
+    // Assert
+    assert_eq!(1, actual);
+}
+
+#[rstest::rstest]
+#[case("hello.ko.md", "hello", Some("ko"))]
+#[case("posts/hello.pt-br.md", "hello", Some("pt-br"))]
+#[case("hello.md", "hello", None)]
+#[case("v1.2.md", "v1.2", None)]
+#[case("notes.draft.md", "notes.draft", None)]
+fn sut_splits_language_from_file_name(
+    #[case] path: &str,
+    #[case] key: &str,
+    #[case] lang: Option<&str>,
+) {
+    // Act
+    let actual = translation_key(Path::new(path));
+
+    // Assert
+    let expected = (key.to_string(), lang.map(String::from));
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn sut_loads_language_from_front_matter_or_else_file_name() {
+    // Arrange
+    let legacy = "Title: T\nDescription: D\nTags: \n";
+    let source = InMemorySource::new()
+        .with_file("hello.en.md", format!("{}---\n", legacy))
+        .with_file("hello.md", format!("{}Lang: ko\n---\n", legacy))
+        .with_file("notes.md", format!("{}---\n", legacy));
+
+    // Act
+    let (actual, _) = Post::from_source(&source).unwrap();
+
+    // Assert
+    let actual = actual
+        .all()
+        .iter()
+        .map(|post| (post.lang.as_deref(), post.translation_key.as_deref()))
+        .collect::<Vec<_>>();
+    let expected = vec![
+        (Some("en"), Some("hello")),
+        (Some("ko"), Some("hello")),
+        (None, None),
+    ];
+    assert_eq!(expected, actual);
+}
+
+proptest::proptest! {
+    #[test]
+    fn sut_sorts_posts_newest_first_and_undated_last(
+        posts in proptest::collection::vec(blog::testing::post(), 0..20),
+    ) {
+        let sorted = posts_sorted_by_date(&posts);
+
+        proptest::prop_assert_eq!(posts.len(), sorted.len());
+        for pair in sorted.windows(2) {
+            match (pair[0].date, pair[1].date) {
+                (Some(newer), Some(older)) => proptest::prop_assert!(newer >= older),
+                (None, Some(_)) => proptest::prop_assert!(false, "undated post before dated one"),
+                _ => {}
+            }
+        }
+    }
+}
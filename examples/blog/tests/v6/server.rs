@@ -0,0 +1,144 @@
+use axum::Router;
+use axum::body::Body;
+use axum::http::Request;
+use axum::http::StatusCode;
+use chrono::NaiveDate;
+use http_body_util::BodyExt;
+use tower::ServiceExt;
+
+use blog::v6::Blog;
+use blog::v6::Post;
+use blog::v6::server::ServeOptions;
+use blog::v6::server::router;
+use blog::v6::template::HandlebarsRenderer;
+
+fn post(title: &str, tags: &[&str], day: u32, draft: bool) -> Post {
+    Post {
+        title: title.to_string(),
+        description: format!("About {}", title),
+        tags: tags.iter().map(|tag| tag.to_string()).collect(),
+        date: NaiveDate::from_ymd_opt(2024, 1, day),
+        draft,
+        body: format!("# {}\n\nSome *text*.", title),
+        lang: None,
+        translation_key: None,
+    }
+}
+
+#[rstest::fixture]
+fn sut() -> Router {
+    let blog = Blog::new(vec![
+        post("Hello, World", &["Rust"], 1, false),
+        post("Testing", &["rust", "TDD"], 2, false),
+        post("Secret", &["rust"], 3, true),
+    ]);
+    router(&blog, HandlebarsRenderer::new().unwrap())
+}
+
+async fn get(sut: Router, uri: &str) -> (StatusCode, String) {
+    let request = Request::builder().uri(uri).body(Body::empty()).unwrap();
+    let response = sut.oneshot(request).await.unwrap();
+    let status = response.status();
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    (status, String::from_utf8(body.to_vec()).unwrap())
+}
+
+#[rstest::rstest]
+#[tokio::test]
+async fn sut_lists_published_posts_newest_first_on_index(sut: Router) {
+    // Act
+    let (status, body) = get(sut, "/").await;
+
+    // Assert
+    assert_eq!(StatusCode::OK, status);
+    let testing = body
+        .find(r#"<a href="/posts/testing">Testing</a>"#)
+        .unwrap();
+    let hello = body
+        .find(r#"<a href="/posts/hello-world">Hello, World</a>"#)
+        .unwrap();
+    assert!(testing < hello);
+    assert!(!body.contains("Secret"));
+}
+
+#[rstest::rstest]
+#[tokio::test]
+async fn sut_renders_post_by_slug(sut: Router) {
+    // Act
+    let (status, body) = get(sut, "/posts/hello-world").await;
+
+    // Assert
+    assert_eq!(StatusCode::OK, status);
+    assert!(body.contains("<title>Hello, World</title>"));
+    assert!(body.contains("<em>text</em>"));
+    assert!(body.contains(r#"<a href="/tags/rust/">Rust</a>"#));
+}
+
+#[rstest::rstest]
+#[case("/tags/rust")]
+#[case("/tags/RUST/")]
+#[tokio::test]
+async fn sut_lists_posts_by_tag(sut: Router, #[case] uri: &str) {
+    // Act
+    let (status, body) = get(sut, uri).await;
+
+    // Assert
+    assert_eq!(StatusCode::OK, status);
+    assert!(body.contains("Hello, World"));
+    assert!(body.contains("Testing"));
+    assert!(!body.contains("Secret"));
+}
+
+#[rstest::rstest]
+#[case("/posts/missing", "There is no post &#x27;missing&#x27;.")]
+#[case("/posts/secret", "There is no post &#x27;secret&#x27;.")]
+#[case("/tags/go", "There are no posts tagged &#x27;go&#x27;.")]
+#[tokio::test]
+async fn sut_renders_not_found_page_for_unknown_slug_or_tag(
+    sut: Router,
+    #[case] uri: &str,
+    #[case] expected: &str,
+) {
+    // Act
+    let (status, body) = get(sut, uri).await;
+
+    // Assert
+    assert_eq!(StatusCode::NOT_FOUND, status);
+    assert!(body.contains("<h1>404</h1>"));
+    assert!(body.contains(expected));
+}
+
+#[rstest::rstest]
+#[case(&["content"], "127.0.0.1:3000")]
+#[case(&["--address", "0.0.0.0:8080", "content"], "0.0.0.0:8080")]
+fn sut_parses_serve_arguments(#[case] args: &[&str], #[case] expected: &str) {
+    // Act
+    let actual = ServeOptions::from_args(args.iter().map(|arg| arg.to_string())).unwrap();
+
+    // Assert
+    assert_eq!(expected, actual.address);
+    assert_eq!("content/posts", actual.posts_directory().to_str().unwrap());
+}
+
+#[tokio::test]
+async fn sut_links_published_translations_of_post() {
+    // Arrange
+    let translated = |title, lang: &str, draft| Post {
+        lang: Some(lang.to_string()),
+        translation_key: Some("hello".to_string()),
+        ..post(title, &[], 1, draft)
+    };
+    let blog = Blog::new(vec![
+        translated("Hello", "en", false),
+        translated("Annyeong", "ko", false),
+        translated("Bonjour", "fr", true),
+    ]);
+    let sut = router(&blog, HandlebarsRenderer::new().unwrap());
+
+    // Act
+    let (_, body) = get(sut, "/posts/hello").await;
+
+    // Assert
+    assert!(body.contains(r#"<a rel="alternate" hreflang="ko" lang="ko" href="/posts/annyeong">"#));
+    assert!(!body.contains(r#"hreflang="fr""#));
+}
@@ -0,0 +1,554 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process::Command;
+use std::process::Output;
+
+use assertions::assert_err_matches;
+use tempfile::TempDir;
+use tempfile::tempdir;
+
+use blog::v6::permalink::Permalink;
+use blog::v6::site::Options;
+use blog::v6::site::SiteError;
+use blog::v6::site::generate;
+use blog::v6::site::generate_with;
+use blog::v6::template::ErrorView;
+use blog::v6::template::IndexView;
+use blog::v6::template::PostView;
+use blog::v6::template::TemplateError;
+use blog::v6::template::TemplateRenderer;
+
+struct Fixture {
+    content: TempDir,
+    output: TempDir,
+}
+
+#[rstest::fixture]
+fn fixture() -> Fixture {
+    let content = tempdir().unwrap();
+    let posts = content.path().join("posts");
+    fs::create_dir_all(&posts).unwrap();
+    fs::write(
+        posts.join("hello.md"),
+        "Title: Hello, World\nDescription: First post\nTags: rust\nDate: 2024-01-05\n---\n# Hi\n\nSome *text*.",
+    )
+    .unwrap();
+    fs::write(
+        posts.join("testing.md"),
+        "---\ntitle: Testing\ndescription: Second post\ndate: 2024-02-01\n---\nMore text.",
+    )
+    .unwrap();
+    fs::write(
+        posts.join("secret.md"),
+        "+++\ntitle = \"Secret\"\ndate = 2024-03-01\ndraft = true\n+++\nNot yet.",
+    )
+    .unwrap();
+    let assets = content.path().join("assets").join("css");
+    fs::create_dir_all(&assets).unwrap();
+    fs::write(assets.join("style.css"), "body {}").unwrap();
+
+    Fixture {
+        content,
+        output: tempdir().unwrap(),
+    }
+}
+
+fn run(fixture: &Fixture, flags: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_blog"))
+        .arg(fixture.content.path())
+        .arg(fixture.output.path())
+        .args(flags)
+        .output()
+        .unwrap()
+}
+
+fn read(output: &Path, path: &str) -> String {
+    fs::read_to_string(output.join(path)).unwrap()
+}
+
+#[rstest::rstest]
+fn sut_writes_post_pages_index_and_assets(fixture: Fixture) {
+    // Act
+    let actual = run(&fixture, &[]);
+
+    // Assert
+    assert!(actual.status.success());
+    let output = fixture.output.path();
+    let hello = read(output, "2024/hello-world/index.html");
+    assert!(hello.contains("<title>Hello, World</title>"));
+    assert!(hello.contains("<h1>Hi</h1>"));
+    assert!(hello.contains("<em>text</em>"));
+    assert!(read(output, "2024/testing/index.html").contains("<p>More text.</p>"));
+    assert_eq!("body {}", read(output, "assets/css/style.css"));
+    let index = read(output, "index.html");
+    let testing = index
+        .find(r#"<a href="/2024/testing/">Testing</a>"#)
+        .unwrap();
+    let hello = index
+        .find(r#"<a href="/2024/hello-world/">Hello, World</a>"#)
+        .unwrap();
+    assert!(testing < hello);
+}
+
+#[rstest::rstest]
+fn sut_links_index_to_generated_pages(fixture: Fixture) {
+    // Act
+    run(&fixture, &[]);
+
+    // Assert
+    let output = fixture.output.path();
+    let index = read(output, "index.html");
+    let links = index
+        .split(r#"<a href=""#)
+        .skip(1)
+        .map(|rest| &rest[..rest.find('"').unwrap()])
+        .collect::<Vec<_>>();
+    assert_eq!(2, links.len());
+    for link in links {
+        assert!(output.join(&link[1..]).join("index.html").is_file());
+    }
+}
+
+#[rstest::rstest]
+fn sut_skips_drafts_by_default(fixture: Fixture) {
+    // Act
+    run(&fixture, &[]);
+
+    // Assert
+    let output = fixture.output.path();
+    assert!(!output.join("2024/secret").exists());
+    assert!(!read(output, "index.html").contains("Secret"));
+}
+
+#[rstest::rstest]
+fn sut_includes_drafts_if_requested(fixture: Fixture) {
+    // Act
+    run(&fixture, &["--drafts"]);
+
+    // Assert
+    let output = fixture.output.path();
+    assert!(output.join("2024/secret/index.html").is_file());
+    assert!(read(output, "index.html").contains("Secret"));
+}
+
+#[rstest::rstest]
+#[case(&[], true)]
+#[case(&["--clean"], false)]
+fn sut_removes_stale_files_only_if_cleaning(
+    fixture: Fixture,
+    #[case] flags: &[&str],
+    #[case] expected: bool,
+) {
+    // Arrange
+    let stale = fixture.output.path().join("stale.html");
+    fs::write(&stale, "old").unwrap();
+
+    // Act
+    let actual = run(&fixture, flags);
+
+    // Assert
+    assert!(actual.status.success());
+    assert_eq!(expected, stale.exists());
+    assert!(fixture.output.path().join("index.html").is_file());
+}
+
+#[rstest::rstest]
+fn sut_writes_sitemap_if_base_url_is_given(fixture: Fixture) {
+    // Act
+    run(&fixture, &["--base-url", "https://example.com"]);
+
+    // Assert
+    let sitemap = read(fixture.output.path(), "sitemap.xml");
+    assert!(sitemap.contains("<loc>https://example.com/2024/hello-world/</loc>"));
+}
+
+#[rstest::rstest]
+fn sut_reports_malformed_post_and_generates_others(fixture: Fixture) {
+    // Arrange
+    let broken = fixture.content.path().join("posts").join("broken.md");
+    fs::write(&broken, "Title: Broken\n---\n").unwrap();
+
+    // Act
+    let actual = run(&fixture, &[]);
+
+    // Assert
+    assert!(actual.status.success());
+    let stderr = String::from_utf8(actual.stderr).unwrap();
+    assert!(stderr.contains(&format!("{}:2: expected 'Description:'", broken.display())));
+    assert!(
+        fixture
+            .output
+            .path()
+            .join("2024/testing/index.html")
+            .is_file()
+    );
+}
+
+#[rstest::rstest]
+fn sut_paginates_home_index(fixture: Fixture) {
+    // Arrange
+    let posts = fixture.content.path().join("posts");
+    for n in 1..=3 {
+        let content = format!(
+            "Title: Old {}\nDescription: Old\nTags: \nDate: 2023-0{}-01\n---\n",
+            n, n
+        );
+        fs::write(posts.join(format!("old_{}.md", n)), content).unwrap();
+    }
+
+    // Act
+    run(&fixture, &["--per-page", "2"]);
+
+    // Assert
+    let output = fixture.output.path();
+    let pages =
+        ["index.html", "page/2/index.html", "page/3/index.html"].map(|page| read(output, page));
+    assert!(pages[0].contains("Testing") && pages[0].contains("Hello, World"));
+    assert!(pages[1].contains("Old 3") && pages[1].contains("Old 2"));
+    assert!(pages[2].contains("Old 1"));
+    assert!(pages[0].contains(r#"<a rel="next" href="/page/2/">"#));
+    assert!(!pages[0].contains(r#"rel="prev""#));
+    assert!(pages[1].contains(r#"<a rel="prev" href="/">"#));
+    assert!(pages[1].contains(r#"<a rel="next" href="/page/3/">"#));
+    assert!(!pages[2].contains(r#"rel="next""#));
+    assert!(!output.join("page/4").exists());
+}
+
+#[rstest::rstest]
+fn sut_writes_page_per_tag_listing_matching_posts(fixture: Fixture) {
+    // Arrange
+    let posts = fixture.content.path().join("posts");
+    fs::write(
+        posts.join("more.md"),
+        "Title: More\nDescription: More\nTags: Rust, TDD\nDate: 2024-01-10\n---\n",
+    )
+    .unwrap();
+
+    // Act
+    run(&fixture, &[]);
+
+    // Assert
+    let output = fixture.output.path();
+    let rust = read(output, "tags/rust/index.html");
+    assert!(rust.contains("Posts tagged rust"));
+    let more = rust.find(r#"<a href="/2024/more/">More</a>"#).unwrap();
+    let hello = rust
+        .find(r#"<a href="/2024/hello-world/">Hello, World</a>"#)
+        .unwrap();
+    assert!(more < hello);
+    let tdd = read(output, "tags/tdd/index.html");
+    assert!(tdd.contains("More") && !tdd.contains("Hello, World"));
+}
+
+#[rstest::rstest]
+fn sut_lists_posts_of_tags_sharing_slug_on_one_page(fixture: Fixture) {
+    // Arrange
+    let posts = fixture.content.path().join("posts");
+    fs::write(
+        posts.join("plus.md"),
+        "Title: Plus\nDescription: Plus\nTags: C++\nDate: 2024-01-10\n---\n",
+    )
+    .unwrap();
+    fs::write(
+        posts.join("sharp.md"),
+        "Title: Sharp\nDescription: Sharp\nTags: C#\nDate: 2024-01-20\n---\n",
+    )
+    .unwrap();
+
+    // Act
+    run(&fixture, &[]);
+
+    // Assert
+    let c = read(fixture.output.path(), "tags/c/index.html");
+    assert!(c.contains(r#"<a href="/2024/plus/">Plus</a>"#));
+    assert!(c.contains(r#"<a href="/2024/sharp/">Sharp</a>"#));
+}
+
+#[rstest::rstest]
+fn sut_writes_same_site_on_every_run(fixture: Fixture) {
+    // Arrange
+    run(&fixture, &[]);
+    let expected = read_site(fixture.output.path());
+
+    // Act
+    run(&fixture, &["--clean"]);
+
+    // Assert
+    let actual = read_site(fixture.output.path());
+    assert_eq!(expected, actual);
+}
+
+#[rstest::rstest]
+fn sut_links_only_to_generated_pages(fixture: Fixture) {
+    // Arrange
+    let posts = fixture.content.path().join("posts");
+    fs::write(
+        posts.join("more.md"),
+        "Title: More\nDescription: More\nTags: Rust, TDD\nDate: 2024-01-10\n---\n",
+    )
+    .unwrap();
+
+    // Act
+    run(&fixture, &["--per-page", "1"]);
+
+    // Assert
+    let output = fixture.output.path();
+    let site = read_site(output);
+    let links = site
+        .values()
+        .flat_map(|html| html.split(r#"href=""#).skip(1))
+        .map(|rest| &rest[..rest.find('"').unwrap()])
+        .collect::<BTreeSet<_>>();
+    assert!(links.contains("/tags/tdd/"));
+    assert!(links.contains("/page/3/"));
+    for link in links {
+        let target = output.join(&link[1..]);
+        assert!(
+            target.is_file() || target.join("index.html").is_file(),
+            "{} is broken",
+            link
+        );
+    }
+}
+
+fn read_site(output: &Path) -> BTreeMap<PathBuf, String> {
+    let mut pages = BTreeMap::new();
+    let mut directories = vec![output.to_path_buf()];
+    while let Some(directory) = directories.pop() {
+        for entry in fs::read_dir(directory).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                directories.push(path);
+            } else if path
+                .extension()
+                .is_some_and(|extension| extension == "html")
+            {
+                pages.insert(path.clone(), fs::read_to_string(path).unwrap());
+            }
+        }
+    }
+    pages
+}
+
+#[test]
+fn sut_fails_with_usage_if_paths_are_missing() {
+    // Act
+    let actual = Command::new(env!("CARGO_BIN_EXE_blog"))
+        .arg("--clean")
+        .output()
+        .unwrap();
+
+    // Assert
+    assert!(!actual.status.success());
+    let stderr = String::from_utf8(actual.stderr).unwrap();
+    assert!(stderr.contains("usage: blog <content> <output>"));
+}
+
+#[rstest::rstest]
+#[case(&["in", "out", "--per-page", "0"])]
+#[case(&["in", "out", "--per-page", "many"])]
+#[case(&["in", "out", "--per-page"])]
+#[case(&["in", "out", "--unknown"])]
+#[case(&["in"])]
+fn sut_rejects_invalid_arguments(#[case] args: &[&str]) {
+    // Act
+    let actual = Options::from_args(args.iter().map(|arg| arg.to_string()));
+
+    // Assert
+    assert_err_matches!(actual, SiteError::Usage(_));
+}
+
+#[test]
+fn sut_parses_flags_in_any_position() {
+    // Arrange
+    let args = [
+        "--drafts",
+        "content",
+        "--permalink",
+        "/:slug/",
+        "out",
+        "--clean",
+        "--per-page",
+        "5",
+        "--watch",
+    ];
+
+    // Act
+    let actual = Options::from_args(args.map(String::from)).unwrap();
+
+    // Assert
+    let expected = Options {
+        clean: true,
+        drafts: true,
+        permalink: Permalink::new("/:slug/"),
+        per_page: 5,
+        watch: true,
+        ..Options::new(Path::new("content"), Path::new("out"))
+    };
+    assert_eq!(expected, actual);
+}
+
+struct TitleRenderer;
+
+impl TemplateRenderer for TitleRenderer {
+    fn render_post(&self, view: &PostView) -> Result<String, TemplateError> {
+        Ok(format!("post: {}", view.title))
+    }
+
+    fn render_index(&self, view: &IndexView) -> Result<String, TemplateError> {
+        Ok(format!("index: {}", view.posts.len()))
+    }
+
+    fn render_error(&self, view: &ErrorView) -> Result<String, TemplateError> {
+        Ok(format!("error: {}", view.status))
+    }
+}
+
+#[rstest::rstest]
+fn sut_renders_pages_with_given_renderer(fixture: Fixture) {
+    // Arrange
+    let options = Options::new(fixture.content.path(), fixture.output.path());
+
+    // Act
+    let actual = generate_with(&options, &TitleRenderer).unwrap();
+
+    // Assert
+    let output = fixture.output.path();
+    assert_eq!(4, actual.pages.len());
+    assert_eq!("post: Testing", read(output, "2024/testing/index.html"));
+    assert_eq!("index: 2", read(output, "index.html"));
+}
+
+#[rstest::rstest]
+fn sut_rewrites_only_changed_post_and_listings_of_it(fixture: Fixture) {
+    // Arrange
+    let options = Options::new(fixture.content.path(), fixture.output.path());
+    generate(&options).unwrap();
+    fs::write(
+        fixture.content.path().join("posts").join("hello.md"),
+        "Title: Hello, World\nDescription: First post\nTags: rust\nDate: 2024-01-05\n---\nEdited.",
+    )
+    .unwrap();
+
+    // Act
+    let actual = generate(&options).unwrap();
+
+    // Assert
+    let output = fixture.output.path();
+    let expected = [
+        "2024/hello-world/index.html",
+        "index.html",
+        "tags/rust/index.html",
+    ]
+    .map(|page| output.join(page))
+    .to_vec();
+    assert_eq!(expected, actual.pages);
+    assert!(read(output, "2024/hello-world/index.html").contains("Edited."));
+}
+
+#[rstest::rstest]
+fn sut_rewrites_nothing_if_content_is_unchanged(fixture: Fixture) {
+    // Arrange
+    let options = Options::new(fixture.content.path(), fixture.output.path());
+    generate(&options).unwrap();
+
+    // Act
+    let actual = generate(&options).unwrap();
+
+    // Assert
+    assert!(actual.pages.is_empty());
+    assert!(actual.removed.is_empty());
+}
+
+#[rstest::rstest]
+fn sut_rewrites_every_page_if_options_change(fixture: Fixture) {
+    // Arrange
+    generate(&Options::new(fixture.content.path(), fixture.output.path())).unwrap();
+    let options = Options {
+        drafts: true,
+        ..Options::new(fixture.content.path(), fixture.output.path())
+    };
+
+    // Act
+    let actual = generate(&options).unwrap();
+
+    // Assert
+    assert_eq!(5, actual.pages.len());
+}
+
+#[rstest::rstest]
+fn sut_rewrites_page_missing_from_output(fixture: Fixture) {
+    // Arrange
+    let options = Options::new(fixture.content.path(), fixture.output.path());
+    generate(&options).unwrap();
+    let page = fixture.output.path().join("2024/testing/index.html");
+    fs::remove_file(&page).unwrap();
+
+    // Act
+    let actual = generate(&options).unwrap();
+
+    // Assert
+    assert_eq!(vec![page.clone()], actual.pages);
+    assert!(page.is_file());
+}
+
+#[rstest::rstest]
+fn sut_links_translations_of_post_with_hreflang(fixture: Fixture) {
+    // Arrange
+    let posts = fixture.content.path().join("posts");
+    let translations = [
+        ("greeting.en.md", "Greeting", "Tags: \nDate: 2024-04-01\n"),
+        ("greeting.ko.md", "Insa", "Tags: \nDate: 2024-04-01\n"),
+        ("partial.en.md", "Partial", "Tags: \nDate: 2024-04-02\n"),
+    ];
+    for (file, title, rest) in translations {
+        let content = format!("Title: {}\nDescription: D\n{}---\n", title, rest);
+        fs::write(posts.join(file), content).unwrap();
+    }
+    let options = Options::new(fixture.content.path(), fixture.output.path());
+
+    // Act
+    generate(&options).unwrap();
+
+    // Assert
+    let output = fixture.output.path();
+    let english = read(output, "2024/greeting/index.html");
+    assert!(english.contains(r#"<article lang="en">"#));
+    assert!(english.contains(r#"<a rel="alternate" hreflang="ko" lang="ko" href="/2024/insa/">"#));
+    let korean = read(output, "2024/insa/index.html");
+    assert!(korean.contains(r#"hreflang="en" lang="en" href="/2024/greeting/""#));
+    assert!(!read(output, "2024/partial/index.html").contains("hreflang"));
+}
+
+#[rstest::rstest]
+fn sut_rewrites_translations_of_added_translation(fixture: Fixture) {
+    // Arrange
+    let posts = fixture.content.path().join("posts");
+    fs::write(
+        posts.join("partial.en.md"),
+        "Title: Partial\nDescription: D\nTags: \nDate: 2024-04-02\n---\n",
+    )
+    .unwrap();
+    let options = Options::new(fixture.content.path(), fixture.output.path());
+    generate(&options).unwrap();
+    fs::write(
+        posts.join("partial.ko.md"),
+        "Title: Bubun\nDescription: D\nTags: \nDate: 2024-04-02\n---\n",
+    )
+    .unwrap();
+
+    // Act
+    let actual = generate(&options).unwrap();
+
+    // Assert
+    let output = fixture.output.path();
+    assert!(
+        actual
+            .pages
+            .contains(&output.join("2024/partial/index.html"))
+    );
+    assert!(read(output, "2024/partial/index.html").contains(r#"hreflang="ko""#));
+}
@@ -0,0 +1,102 @@
+use chrono::NaiveDate;
+
+use blog::v6::Blog;
+use blog::v6::Post;
+use blog::v6::permalink::Permalink;
+use blog::v6::sitemap::Sitemap;
+use blog::v6::sitemap::SitemapEntry;
+
+fn post(title: &str, tags: &[&str], date: Option<NaiveDate>, draft: bool) -> Post {
+    Post {
+        title: title.to_string(),
+        description: String::new(),
+        tags: tags.iter().map(|tag| tag.to_string()).collect(),
+        date,
+        draft,
+        body: String::new(),
+        lang: None,
+        translation_key: None,
+    }
+}
+
+fn date(year: i32, month: u32, day: u32) -> Option<NaiveDate> {
+    NaiveDate::from_ymd_opt(year, month, day)
+}
+
+fn entry(path: &str, lastmod: Option<NaiveDate>) -> SitemapEntry {
+    SitemapEntry {
+        path: path.to_string(),
+        lastmod,
+    }
+}
+
+#[test]
+fn sut_lists_index_published_posts_and_tag_pages() {
+    // Arrange
+    let blog = Blog::new(vec![
+        post("Hello", &["Rust"], date(2024, 1, 1), false),
+        post("Testing", &["rust", "TDD"], date(2024, 2, 1), false),
+        post("Secret", &["draft-only"], date(2024, 3, 1), true),
+        post("Undated", &[], None, false),
+    ]);
+
+    // Act
+    let sut = Sitemap::for_blog("https://example.com/", &blog, &Permalink::default());
+
+    // Assert
+    let expected = vec![
+        entry("/", date(2024, 2, 1)),
+        entry("/2024/hello/", date(2024, 1, 1)),
+        entry("/2024/testing/", date(2024, 2, 1)),
+        entry("/undated/", None),
+        entry("/tags/rust/", date(2024, 2, 1)),
+        entry("/tags/tdd/", date(2024, 2, 1)),
+    ];
+    assert_eq!(expected, sut.entries());
+}
+
+#[test]
+fn sut_writes_one_url_per_entry_with_lastmod() {
+    // Arrange
+    let mut sut = Sitemap::new("https://example.com");
+    sut.add("/", date(2024, 2, 1));
+    sut.add("/about/", None);
+    let mut buffer = Vec::new();
+
+    // Act
+    sut.write(&mut buffer).unwrap();
+
+    // Assert
+    let actual = String::from_utf8(buffer).unwrap();
+    let expected = r#"<?xml version="1.0" encoding="UTF-8"?>
+<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+  <url>
+    <loc>https://example.com/</loc>
+    <lastmod>2024-02-01</lastmod>
+  </url>
+  <url>
+    <loc>https://example.com/about/</loc>
+  </url>
+</urlset>
+"#;
+    assert_eq!(expected, actual);
+}
+
+#[rstest::rstest]
+#[case("https://example.com", "/a b/", "https://example.com/a%20b/")]
+#[case("https://example.com", "/café/", "https://example.com/caf%C3%A9/")]
+#[case("https://example.com", "/q&a/", "https://example.com/q%26a/")]
+#[case("https://example.com/r&d", "/", "https://example.com/r&amp;d/")]
+fn sut_escapes_urls(#[case] base_url: &str, #[case] path: &str, #[case] expected: &str) {
+    // Arrange
+    let mut sut = Sitemap::new(base_url);
+    sut.add(path, None);
+    let mut buffer = Vec::new();
+
+    // Act
+    sut.write(&mut buffer).unwrap();
+
+    // Assert
+    let actual = String::from_utf8(buffer).unwrap();
+    assert!(actual.contains(&format!("<loc>{}</loc>", expected)));
+}
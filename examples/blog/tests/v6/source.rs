@@ -0,0 +1,89 @@
+use std::fs::File;
+use std::io::ErrorKind;
+use std::io::Write;
+use std::path::PathBuf;
+
+use tempfile::tempdir;
+
+use blog::v6::source::DirectorySource;
+use blog::v6::source::Entry;
+use blog::v6::source::InMemorySource;
+use blog::v6::source::PostSource;
+
+fn entry(path: &str) -> Entry {
+    Entry {
+        path: PathBuf::from(path),
+    }
+}
+
+#[test]
+fn sut_lists_in_memory_files_in_path_order() {
+    // Arrange
+    let sut = InMemorySource::new()
+        .with_file("b.md", "B")
+        .with_file("a.md", "A")
+        .with_unreadable("c.md");
+
+    // Act
+    let actual = sut.list().unwrap();
+
+    // Assert
+    let expected = vec![entry("a.md"), entry("b.md"), entry("c.md")];
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn sut_reads_in_memory_file_content() {
+    // Arrange
+    let sut = InMemorySource::new().with_file("a.md", "Content");
+
+    // Act
+    let actual = sut.read(&entry("a.md")).unwrap();
+
+    // Assert
+    assert_eq!("Content", actual);
+}
+
+#[rstest::rstest]
+#[case("secret.md", ErrorKind::PermissionDenied)]
+#[case("missing.md", ErrorKind::NotFound)]
+fn sut_fails_to_read_unreadable_or_missing_file(#[case] path: &str, #[case] expected: ErrorKind) {
+    // Arrange
+    let sut = InMemorySource::new().with_unreadable("secret.md");
+
+    // Act
+    let actual = sut.read(&entry(path)).unwrap_err();
+
+    // Assert
+    assert_eq!(expected, actual.kind());
+}
+
+#[test]
+fn sut_lists_and_reads_files_of_directory() {
+    // Arrange
+    let directory = tempdir().unwrap();
+    let path = directory.path().join("hello.md");
+    File::create(&path).unwrap().write_all(b"Hello").unwrap();
+    let sut = DirectorySource::new(directory.path());
+
+    // Act
+    let entries = sut.list().unwrap();
+    let actual = sut.read(&entries[0]).unwrap();
+
+    // Assert
+    assert_eq!(vec![Entry { path }], entries);
+    assert_eq!("Hello", actual);
+}
+
+#[test]
+fn sut_fails_to_list_missing_directory() {
+    // Arrange
+    let directory = tempdir().unwrap();
+    let sut = DirectorySource::new(&directory.path().join("missing"));
+
+    // Act
+    let actual = sut.list().unwrap_err();
+
+    // Assert
+    assert_eq!(ErrorKind::NotFound, actual.kind());
+}
@@ -0,0 +1,176 @@
+use std::error::Error;
+
+use assertions::assert_err_matches;
+use blog::v6::template::ErrorView;
+use blog::v6::template::HandlebarsRenderer;
+use blog::v6::template::IndexView;
+use blog::v6::template::PostLink;
+use blog::v6::template::PostView;
+use blog::v6::template::TagLink;
+use blog::v6::template::TemplateError;
+use blog::v6::template::TemplateRenderer;
+use blog::v6::template::TranslationLink;
+use golden::assert_matches_golden;
+
+fn post_view() -> PostView {
+    PostView {
+        title: "Fish & <Chips>".to_string(),
+        description: "A \"classic\" dish".to_string(),
+        date: Some("2024-01-05".to_string()),
+        tags: vec![TagLink::new("food"), TagLink::new("UK")],
+        reading_time: 3,
+        body: "<p>Batter <em>well</em>.</p>\n".to_string(),
+        lang: None,
+        translations: vec![],
+    }
+}
+
+#[test]
+fn sut_renders_post_page_as_approved() {
+    // Arrange
+    let sut = HandlebarsRenderer::new().unwrap();
+
+    // Act
+    let actual = sut.render_post(&post_view()).unwrap();
+
+    // Assert
+    assert_matches_golden!("v6/post.html", actual);
+}
+
+#[test]
+fn sut_renders_translated_post_page_as_approved() {
+    // Arrange
+    let sut = HandlebarsRenderer::new().unwrap();
+    let view = PostView {
+        lang: Some("en".to_string()),
+        translations: vec![
+            TranslationLink {
+                lang: "fr".to_string(),
+                permalink: "/2024/poisson-frites/".to_string(),
+            },
+            TranslationLink {
+                lang: "ko".to_string(),
+                permalink: "/2024/pisi-aen-chibseu/".to_string(),
+            },
+        ],
+        ..post_view()
+    };
+
+    // Act
+    let actual = sut.render_post(&view).unwrap();
+
+    // Assert
+    assert_matches_golden!("v6/post_translated.html", actual);
+}
+
+#[test]
+fn sut_renders_undated_untagged_post_page_as_approved() {
+    // Arrange
+    let sut = HandlebarsRenderer::new().unwrap();
+    let view = PostView {
+        date: None,
+        tags: vec![],
+        ..post_view()
+    };
+
+    // Act
+    let actual = sut.render_post(&view).unwrap();
+
+    // Assert
+    assert_matches_golden!("v6/post_undated.html", actual);
+}
+
+#[test]
+fn sut_renders_index_page_as_approved() {
+    // Arrange
+    let sut = HandlebarsRenderer::new().unwrap();
+    let view = IndexView {
+        title: "Blog".to_string(),
+        posts: vec![
+            PostLink {
+                title: "Testing".to_string(),
+                permalink: "/2024/testing/".to_string(),
+                date: Some("2024-02-01".to_string()),
+            },
+            PostLink {
+                title: "Q&A".to_string(),
+                permalink: "/q-a/".to_string(),
+                date: None,
+            },
+        ],
+        previous: None,
+        next: Some("/page/2/".to_string()),
+    };
+
+    // Act
+    let actual = sut.render_index(&view).unwrap();
+
+    // Assert
+    assert_matches_golden!("v6/index.html", actual);
+}
+
+#[test]
+fn sut_renders_tag_page_with_previous_link_as_approved() {
+    // Arrange
+    let sut = HandlebarsRenderer::new().unwrap();
+    let view = IndexView {
+        title: "Posts tagged rust".to_string(),
+        posts: vec![PostLink {
+            title: "Hello".to_string(),
+            permalink: "/2024/hello/".to_string(),
+            date: Some("2024-01-05".to_string()),
+        }],
+        previous: Some("/".to_string()),
+        next: None,
+    };
+
+    // Act
+    let actual = sut.render_index(&view).unwrap();
+
+    // Assert
+    assert_matches_golden!("v6/tag.html", actual);
+}
+
+#[test]
+fn sut_renders_error_page_as_approved() {
+    // Arrange
+    let sut = HandlebarsRenderer::new().unwrap();
+    let view = ErrorView {
+        status: 404,
+        message: "There is no post 'missing'.".to_string(),
+    };
+
+    // Act
+    let actual = sut.render_error(&view).unwrap();
+
+    // Assert
+    assert_matches_golden!("v6/error.html", actual);
+}
+
+#[test]
+fn sut_returns_compile_error_with_source_if_template_is_malformed() {
+    // Act
+    let actual = HandlebarsRenderer::from_templates([("post", "{{#if title}}")])
+        .err()
+        .unwrap();
+
+    // Assert
+    assert!(matches!(actual, TemplateError::Compile { ref name, .. } if name == "post"));
+    assert!(actual.source().is_some());
+}
+
+#[test]
+fn sut_returns_render_error_with_source_if_template_is_missing() {
+    // Arrange
+    let sut = HandlebarsRenderer::from_templates([("index", "")]).unwrap();
+
+    // Act
+    let actual = sut.render_post(&post_view());
+
+    // Assert
+    assert_err_matches!(
+        actual,
+        TemplateError::Render { ref name, .. } if name == "post"
+    );
+    assert!(actual.unwrap_err().source().is_some());
+}
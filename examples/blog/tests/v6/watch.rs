@@ -0,0 +1,253 @@
+use std::collections::BTreeSet;
+use std::collections::VecDeque;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use tempfile::TempDir;
+use tempfile::tempdir;
+
+use blog::v6::site::Builder;
+use blog::v6::site::Options;
+use blog::v6::site::Report;
+use blog::v6::template::HandlebarsRenderer;
+use blog::v6::watch::ChangeSource;
+use blog::v6::watch::Next;
+use blog::v6::watch::debounce;
+use blog::v6::watch::watch;
+
+const WINDOW: Duration = Duration::from_millis(10);
+
+/// Replays scripted events, then reports the source as closed.
+struct ScriptedSource(VecDeque<Next>);
+
+impl ScriptedSource {
+    fn new(events: impl IntoIterator<Item = Next>) -> Self {
+        ScriptedSource(events.into_iter().collect())
+    }
+}
+
+impl ChangeSource for ScriptedSource {
+    fn next(&mut self, _timeout: Option<Duration>) -> Next {
+        self.0.pop_front().unwrap_or(Next::Closed)
+    }
+}
+
+fn change(path: &Path) -> Next {
+    Next::Change(path.to_path_buf())
+}
+
+#[test]
+fn sut_groups_changes_until_quiet_window_passes() {
+    // Arrange
+    let (a, b, c) = (Path::new("a.md"), Path::new("b.md"), Path::new("c.md"));
+    let mut source = ScriptedSource::new([
+        change(a),
+        change(b),
+        change(a),
+        Next::Timeout,
+        change(c),
+        Next::Timeout,
+    ]);
+
+    // Act
+    let first = debounce(&mut source, WINDOW);
+    let second = debounce(&mut source, WINDOW);
+    let third = debounce(&mut source, WINDOW);
+
+    // Assert
+    assert_eq!(Some(BTreeSet::from([a.into(), b.into()])), first);
+    assert_eq!(Some(BTreeSet::from([c.into()])), second);
+    assert_eq!(None, third);
+}
+
+#[test]
+fn sut_delivers_last_burst_if_source_closes() {
+    // Arrange
+    let mut source = ScriptedSource::new([change(Path::new("a.md"))]);
+
+    // Act
+    let actual = debounce(&mut source, WINDOW);
+
+    // Assert
+    assert_eq!(Some(BTreeSet::from([PathBuf::from("a.md")])), actual);
+}
+
+struct Site {
+    content: TempDir,
+    output: TempDir,
+}
+
+impl Site {
+    fn post(&self, name: &str) -> PathBuf {
+        self.content.path().join("posts").join(name)
+    }
+
+    fn page(&self, path: &str) -> PathBuf {
+        self.output.path().join(path).join("index.html")
+    }
+}
+
+#[rstest::fixture]
+fn site() -> Site {
+    let content = tempdir().unwrap();
+    fs::create_dir_all(content.path().join("posts")).unwrap();
+    fs::create_dir_all(content.path().join("assets")).unwrap();
+    let site = Site {
+        content,
+        output: tempdir().unwrap(),
+    };
+    fs::write(
+        site.post("hello.md"),
+        "Title: Hello\nDescription: First\nTags: rust\nDate: 2024-01-01\n---\nHello",
+    )
+    .unwrap();
+    fs::write(
+        site.post("testing.md"),
+        "Title: Testing\nDescription: Second\nTags: tdd\nDate: 2024-02-01\n---\nTesting",
+    )
+    .unwrap();
+    site
+}
+
+/// Builds the site, applies the edit, then rebuilds it for every scripted
+/// event.
+fn build_and_watch(site: &Site, edit: impl FnOnce(), events: Vec<Next>) -> Vec<Report> {
+    let options = Options::new(site.content.path(), site.output.path());
+    let renderer = HandlebarsRenderer::new().unwrap();
+    let mut builder = Builder::new(&options, &renderer);
+    builder.build().unwrap();
+    edit();
+
+    let mut reports = vec![];
+    let mut source = ScriptedSource::new(events);
+    watch(&mut builder, &mut source, WINDOW, |report| {
+        reports.push(report.clone())
+    })
+    .unwrap();
+    reports
+}
+
+#[rstest::rstest]
+fn sut_rewrites_only_changed_post_and_listings(site: Site) {
+    // Arrange
+    let hello = site.post("hello.md");
+    let edit = || {
+        let content =
+            "Title: Hello\nDescription: Edited\nTags: rust\nDate: 2024-01-01\n---\nEdited";
+        fs::write(&hello, content).unwrap();
+    };
+
+    // Act
+    let actual = build_and_watch(
+        &site,
+        edit,
+        vec![change(&hello), change(&hello), Next::Timeout],
+    );
+
+    // Assert
+    assert_eq!(1, actual.len());
+    let expected = vec![
+        site.page("2024/hello"),
+        site.page(""),
+        site.page("tags/rust"),
+    ];
+    assert_eq!(expected, actual[0].pages);
+    assert!(actual[0].removed.is_empty());
+    assert!(
+        fs::read_to_string(site.page("2024/hello"))
+            .unwrap()
+            .contains("Edited")
+    );
+}
+
+#[rstest::rstest]
+fn sut_rewrites_nothing_if_saved_content_is_unchanged(site: Site) {
+    // Arrange
+    let hello = site.post("hello.md");
+    let edit = || {
+        let content = fs::read_to_string(&hello).unwrap();
+        fs::write(&hello, content).unwrap();
+    };
+
+    // Act
+    let actual = build_and_watch(&site, edit, vec![change(&hello)]);
+
+    // Assert
+    assert!(actual[0].pages.is_empty());
+}
+
+#[rstest::rstest]
+fn sut_removes_pages_of_deleted_post(site: Site) {
+    // Arrange
+    let testing = site.post("testing.md");
+    let edit = || fs::remove_file(&testing).unwrap();
+
+    // Act
+    let actual = build_and_watch(&site, edit, vec![change(&testing)]);
+
+    // Assert
+    let expected = vec![site.page("2024/testing"), site.page("tags/tdd")];
+    assert_eq!(expected, actual[0].removed);
+    assert!(
+        !fs::read_to_string(site.page(""))
+            .unwrap()
+            .contains("Testing")
+    );
+}
+
+#[rstest::rstest]
+fn sut_moves_page_if_title_changes(site: Site) {
+    // Arrange
+    let hello = site.post("hello.md");
+    let edit = || {
+        let content = "Title: Goodbye\nDescription: First\nTags: rust\nDate: 2024-01-01\n---\nBye";
+        fs::write(&hello, content).unwrap();
+    };
+
+    // Act
+    let actual = build_and_watch(&site, edit, vec![change(&hello)]);
+
+    // Assert
+    assert_eq!(vec![site.page("2024/hello")], actual[0].removed);
+    assert!(actual[0].pages.contains(&site.page("2024/goodbye")));
+    assert!(site.page("2024/goodbye").is_file());
+}
+
+#[rstest::rstest]
+fn sut_reports_broken_post_until_it_is_fixed(site: Site) {
+    // Arrange
+    let options = Options::new(site.content.path(), site.output.path());
+    let renderer = HandlebarsRenderer::new().unwrap();
+    let mut sut = Builder::new(&options, &renderer);
+    sut.build().unwrap();
+    let hello = site.post("hello.md");
+    let changed = BTreeSet::from([hello.clone()]);
+
+    // Act
+    fs::write(&hello, "Title: Hello\n---\n").unwrap();
+    let broken = sut.rebuild(&changed).unwrap();
+    fs::write(&hello, "Title: Hello\nDescription: Fixed\nTags: \n---\n").unwrap();
+    let fixed = sut.rebuild(&changed).unwrap();
+
+    // Assert
+    assert_eq!(1, broken.errors.len());
+    assert_eq!(Some(hello), broken.errors[0].file);
+    assert!(fixed.errors.is_empty());
+}
+
+#[rstest::rstest]
+fn sut_copies_changed_asset_without_rewriting_pages(site: Site) {
+    // Arrange
+    let asset = site.content.path().join("assets").join("style.css");
+    let edit = || fs::write(&asset, "body {}").unwrap();
+
+    // Act
+    let actual = build_and_watch(&site, edit, vec![change(&asset)]);
+
+    // Assert
+    assert!(actual[0].pages.is_empty());
+    let copied = site.output.path().join("assets").join("style.css");
+    assert_eq!("body {}", fs::read_to_string(copied).unwrap());
+}
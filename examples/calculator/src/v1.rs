@@ -0,0 +1,89 @@
+pub mod lexer;
+pub mod parser;
+
+use parser::Expr;
+use parser::Operator;
+use parser::ParseError;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum CalculationError {
+    #[error(transparent)]
+    Parse(#[from] ParseError),
+
+    #[error("division by zero")]
+    DivisionByZero,
+}
+
+/// Parses `input` as an arithmetic expression and evaluates it.
+pub fn calculate(input: &str) -> Result<f64, CalculationError> {
+    let expr = parser::parse(input)?;
+    evaluate(&expr)
+}
+
+pub fn evaluate(expr: &Expr) -> Result<f64, CalculationError> {
+    match expr {
+        Expr::Number(n) => Ok(*n),
+        Expr::Negate(operand) => Ok(-evaluate(operand)?),
+        Expr::Binary(left, operator, right) => {
+            let (left, right) = (evaluate(left)?, evaluate(right)?);
+            match operator {
+                Operator::Add => Ok(left + right),
+                Operator::Subtract => Ok(left - right),
+                Operator::Multiply => Ok(left * right),
+                Operator::Divide if right == 0.0 => Err(CalculationError::DivisionByZero),
+                Operator::Divide => Ok(left / right),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod specs_for_calculate {
+    use super::CalculationError;
+    use super::calculate;
+    use super::parser::Expected;
+    use super::parser::ParseError;
+
+    #[rstest::rstest]
+    #[case::number("42", 42.0)]
+    #[case::addition("1 + 2", 3.0)]
+    #[case::subtraction("5 - 8", -3.0)]
+    #[case::multiplication("4 * 2.5", 10.0)]
+    #[case::division("7 / 2", 3.5)]
+    #[case::precedence("2 + 3 * 4", 14.0)]
+    #[case::left_associative_subtraction("10 - 4 - 3", 3.0)]
+    #[case::left_associative_division("16 / 4 / 2", 2.0)]
+    #[case::parentheses("(2 + 3) * 4", 20.0)]
+    #[case::nested_parentheses("((1 + 2) * (3 + 4)) / 7", 3.0)]
+    #[case::unary_minus("-3 + 5", 2.0)]
+    #[case::negated_parentheses("-(2 + 3)", -5.0)]
+    #[case::double_negation("--4", 4.0)]
+    #[case::negative_operand("2 * -3", -6.0)]
+    fn sut_evaluates_expression(#[case] input: &str, #[case] expected: f64) {
+        assert_eq!(Ok(expected), calculate(input));
+    }
+
+    #[rstest::rstest]
+    #[case::literal("1 / 0")]
+    #[case::computed("1 / (2 - 2)")]
+    fn sut_refuses_division_by_zero(#[case] input: &str) {
+        assert_eq!(Err(CalculationError::DivisionByZero), calculate(input));
+    }
+
+    #[test]
+    fn sut_returns_parse_error_as_is() {
+        // Act
+        let actual = calculate("2 * (3 +");
+
+        // Assert
+        let expected = ParseError {
+            position: 8,
+            expected: Expected::Operand,
+        };
+        assert_eq!(Err(CalculationError::Parse(expected)), actual);
+        assert_eq!(
+            "expected a number, `-` or `(` at position 8",
+            actual.unwrap_err().to_string()
+        );
+    }
+}
@@ -0,0 +1,138 @@
+use super::parser::Expected;
+use super::parser::ParseError;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Token {
+    Number(f64),
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    LeftParenthesis,
+    RightParenthesis,
+}
+
+/// `Spanned` is a token with the position in the input it starts at, so
+/// that errors can point at it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Spanned {
+    pub token: Token,
+    pub position: usize,
+}
+
+/// Splits `input` into tokens, skipping whitespace. Positions are byte
+/// offsets into `input`.
+pub fn tokenize(input: &str) -> Result<Vec<Spanned>, ParseError> {
+    let mut tokens = vec![];
+    let mut chars = input.char_indices().peekable();
+    while let Some(&(position, c)) = chars.peek() {
+        let token = match c {
+            c if c.is_whitespace() => {
+                chars.next();
+                continue;
+            }
+            '0'..='9' | '.' => {
+                let mut end = position;
+                while let Some(&(i, c)) = chars.peek() {
+                    if !(c.is_ascii_digit() || c == '.') {
+                        break;
+                    }
+                    end = i + c.len_utf8();
+                    chars.next();
+                }
+                let number = input[position..end].parse().map_err(|_| ParseError {
+                    position,
+                    expected: Expected::Number,
+                })?;
+                tokens.push(Spanned {
+                    token: Token::Number(number),
+                    position,
+                });
+                continue;
+            }
+            '+' => Token::Plus,
+            '-' => Token::Minus,
+            '*' => Token::Star,
+            '/' => Token::Slash,
+            '(' => Token::LeftParenthesis,
+            ')' => Token::RightParenthesis,
+            _ => {
+                return Err(ParseError {
+                    position,
+                    expected: Expected::Token,
+                });
+            }
+        };
+        chars.next();
+        tokens.push(Spanned { token, position });
+    }
+    Ok(tokens)
+}
+
+#[cfg(test)]
+mod specs_for_tokenize {
+    use super::Spanned;
+    use super::Token;
+    use super::Token::*;
+    use super::tokenize;
+    use crate::v1::parser::Expected;
+    use crate::v1::parser::ParseError;
+
+    fn tokens(input: &str) -> Vec<Token> {
+        tokenize(input)
+            .unwrap()
+            .into_iter()
+            .map(|spanned| spanned.token)
+            .collect()
+    }
+
+    #[rstest::rstest]
+    #[case::empty("", vec![])]
+    #[case::integer("42", vec![Number(42.0)])]
+    #[case::decimal("3.25", vec![Number(3.25)])]
+    #[case::leading_point(".5", vec![Number(0.5)])]
+    #[case::operators("+-*/", vec![Plus, Minus, Star, Slash])]
+    #[case::parentheses("()", vec![LeftParenthesis, RightParenthesis])]
+    #[case::expression("(1 + 2)*3", vec![
+        LeftParenthesis, Number(1.0), Plus, Number(2.0), RightParenthesis, Star, Number(3.0),
+    ])]
+    #[case::whitespace(" \t1\n", vec![Number(1.0)])]
+    fn sut_splits_input_into_tokens(#[case] input: &str, #[case] expected: Vec<Token>) {
+        assert_eq!(expected, tokens(input));
+    }
+
+    #[test]
+    fn sut_records_where_each_token_starts() {
+        // Act
+        let actual = tokenize("12 + 345").unwrap();
+
+        // Assert
+        let expected = vec![
+            Spanned {
+                token: Number(12.0),
+                position: 0,
+            },
+            Spanned {
+                token: Plus,
+                position: 3,
+            },
+            Spanned {
+                token: Number(345.0),
+                position: 5,
+            },
+        ];
+        assert_eq!(expected, actual);
+    }
+
+    #[rstest::rstest]
+    #[case::unknown_character("1 % 2", 2, Expected::Token)]
+    #[case::malformed_number("1 + 1.2.3", 4, Expected::Number)]
+    #[case::lone_point("2 * .", 4, Expected::Number)]
+    fn sut_points_at_what_it_cannot_read(
+        #[case] input: &str,
+        #[case] position: usize,
+        #[case] expected: Expected,
+    ) {
+        assert_eq!(Err(ParseError { position, expected }), tokenize(input));
+    }
+}
@@ -0,0 +1,234 @@
+use std::fmt;
+
+use super::lexer::Spanned;
+use super::lexer::Token;
+use super::lexer::tokenize;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Expr {
+    Number(f64),
+    Negate(Box<Expr>),
+    Binary(Box<Expr>, Operator, Box<Expr>),
+}
+
+/// `ParseError` tells where the input stopped making sense and what could
+/// have come there instead.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+#[error("expected {expected} at position {position}")]
+pub struct ParseError {
+    pub position: usize,
+    pub expected: Expected,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Expected {
+    /// Any token, where the input has a character no token starts with.
+    Token,
+    /// A well-formed number, where the input has digits and points that do
+    /// not make one.
+    Number,
+    /// A number, `-` or `(`.
+    Operand,
+    /// An operator, or the end of the input.
+    OperatorOrEnd,
+    /// A `)` closing a parenthesis opened before.
+    RightParenthesis,
+}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let expected = match self {
+            Expected::Token => "a number, an operator or a parenthesis",
+            Expected::Number => "a number",
+            Expected::Operand => "a number, `-` or `(`",
+            Expected::OperatorOrEnd => "an operator or the end of input",
+            Expected::RightParenthesis => "`)`",
+        };
+        f.write_str(expected)
+    }
+}
+
+/// Parses `input` into an expression. `*` and `/` bind tighter than `+`
+/// and `-`, operators of the same precedence group to the left, and a
+/// unary minus binds tighter than any of them:
+///
+/// ```text
+/// expression = term (("+" | "-") term)*
+/// term       = unary (("*" | "/") unary)*
+/// unary      = "-" unary | primary
+/// primary    = number | "(" expression ")"
+/// ```
+pub fn parse(input: &str) -> Result<Expr, ParseError> {
+    let tokens = tokenize(input)?;
+    let mut parser = Parser {
+        tokens: &tokens,
+        next: 0,
+        end: input.len(),
+    };
+    let expr = parser.expression()?;
+    if parser.peek().is_some() {
+        return Err(parser.error(Expected::OperatorOrEnd));
+    }
+    Ok(expr)
+}
+
+struct Parser<'a> {
+    tokens: &'a [Spanned],
+    next: usize,
+    /// The position of the end of the input, where errors past the last
+    /// token point.
+    end: usize,
+}
+
+impl Parser<'_> {
+    fn expression(&mut self) -> Result<Expr, ParseError> {
+        let mut left = self.term()?;
+        while let Some(operator) = self.operator(&[Operator::Add, Operator::Subtract]) {
+            let right = self.term()?;
+            left = Expr::Binary(Box::new(left), operator, Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn term(&mut self) -> Result<Expr, ParseError> {
+        let mut left = self.unary()?;
+        while let Some(operator) = self.operator(&[Operator::Multiply, Operator::Divide]) {
+            let right = self.unary()?;
+            left = Expr::Binary(Box::new(left), operator, Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn unary(&mut self) -> Result<Expr, ParseError> {
+        if self.peek() == Some(Token::Minus) {
+            self.next += 1;
+            return Ok(Expr::Negate(Box::new(self.unary()?)));
+        }
+        self.primary()
+    }
+
+    fn primary(&mut self) -> Result<Expr, ParseError> {
+        match self.peek() {
+            Some(Token::Number(number)) => {
+                self.next += 1;
+                Ok(Expr::Number(number))
+            }
+            Some(Token::LeftParenthesis) => {
+                self.next += 1;
+                let expr = self.expression()?;
+                if self.peek() != Some(Token::RightParenthesis) {
+                    return Err(self.error(Expected::RightParenthesis));
+                }
+                self.next += 1;
+                Ok(expr)
+            }
+            _ => Err(self.error(Expected::Operand)),
+        }
+    }
+
+    /// Takes the next token if it is one of `operators`.
+    fn operator(&mut self, operators: &[Operator]) -> Option<Operator> {
+        let operator = match self.peek()? {
+            Token::Plus => Operator::Add,
+            Token::Minus => Operator::Subtract,
+            Token::Star => Operator::Multiply,
+            Token::Slash => Operator::Divide,
+            _ => return None,
+        };
+        if !operators.contains(&operator) {
+            return None;
+        }
+        self.next += 1;
+        Some(operator)
+    }
+
+    fn peek(&self) -> Option<Token> {
+        self.tokens.get(self.next).map(|spanned| spanned.token)
+    }
+
+    fn error(&self, expected: Expected) -> ParseError {
+        let position = self
+            .tokens
+            .get(self.next)
+            .map_or(self.end, |spanned| spanned.position);
+        ParseError { position, expected }
+    }
+}
+
+#[cfg(test)]
+mod specs_for_parse {
+    use super::Expected;
+    use super::Expr;
+    use super::Expr::*;
+    use super::Operator::*;
+    use super::ParseError;
+    use super::parse;
+
+    fn number(n: f64) -> Box<Expr> {
+        Box::new(Number(n))
+    }
+
+    #[rstest::rstest]
+    #[case::number("7", Number(7.0))]
+    #[case::addition("1 + 2", Binary(number(1.0), Add, number(2.0)))]
+    #[case::left_associative(
+        "8 - 4 - 2",
+        Binary(
+            Box::new(Binary(number(8.0), Subtract, number(4.0))),
+            Subtract,
+            number(2.0)
+        )
+    )]
+    #[case::precedence(
+        "1 + 2 * 3",
+        Binary(number(1.0), Add, Box::new(Binary(number(2.0), Multiply, number(3.0))))
+    )]
+    #[case::parentheses(
+        "(1 + 2) * 3",
+        Binary(Box::new(Binary(number(1.0), Add, number(2.0))), Multiply, number(3.0))
+    )]
+    #[case::unary_minus("-2 * 3", Binary(Box::new(Negate(number(2.0))), Multiply, number(3.0)))]
+    #[case::double_negation("--2", Negate(Box::new(Negate(number(2.0)))))]
+    #[case::subtracting_negative(
+        "1 - -1",
+        Binary(number(1.0), Subtract, Box::new(Negate(number(1.0))))
+    )]
+    fn sut_parses_expression_by_precedence(#[case] input: &str, #[case] expected: Expr) {
+        assert_eq!(Ok(expected), parse(input));
+    }
+
+    #[rstest::rstest]
+    #[case::empty("", 0, Expected::Operand)]
+    #[case::missing_right_operand("1 +", 3, Expected::Operand)]
+    #[case::operator_first("* 2", 0, Expected::Operand)]
+    #[case::two_operators("1 + * 2", 4, Expected::Operand)]
+    #[case::two_numbers("1 2", 2, Expected::OperatorOrEnd)]
+    #[case::unclosed_parenthesis("(1 + 2", 6, Expected::RightParenthesis)]
+    #[case::unopened_parenthesis("1 + 2)", 5, Expected::OperatorOrEnd)]
+    #[case::empty_parentheses("()", 1, Expected::Operand)]
+    #[case::unknown_character("1 ^ 2", 2, Expected::Token)]
+    fn sut_tells_where_and_what_it_expected(
+        #[case] input: &str,
+        #[case] position: usize,
+        #[case] expected: Expected,
+    ) {
+        assert_eq!(Err(ParseError { position, expected }), parse(input));
+    }
+
+    #[test]
+    fn sut_describes_error() {
+        // Act
+        let actual = parse("(1 + 2").unwrap_err().to_string();
+
+        // Assert
+        assert_eq!("expected `)` at position 6", actual);
+    }
+}
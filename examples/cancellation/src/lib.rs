@@ -0,0 +1,185 @@
+//! Cancellation the examples share, so that every task that can be told to
+//! stop is told the same way.
+//!
+//! A `Scope` is cancelled once, and cancels every scope made from it with
+//! `child`, but not the other way round. A `ScopeGuard` cancels its scope
+//! when dropped, so that the tasks of a value stop once it is gone.
+
+use std::time::Duration;
+
+use tokio_util::sync::CancellationToken;
+use tokio_util::sync::DropGuard;
+
+/// `Interrupted` is why work run in a scope did not finish.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum Interrupted {
+    #[error("cancelled")]
+    Cancelled,
+
+    #[error("timed out after {0:?}")]
+    TimedOut(Duration),
+}
+
+/// `Scope` tells the work run in it when to stop. Clones share the same
+/// cancellation.
+#[derive(Clone, Debug, Default)]
+pub struct Scope {
+    token: CancellationToken,
+}
+
+impl Scope {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a scope cancelled along with this one, which can also be
+    /// cancelled on its own.
+    pub fn child(&self) -> Scope {
+        Scope {
+            token: self.token.child_token(),
+        }
+    }
+
+    pub fn cancel(&self) {
+        self.token.cancel();
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.token.is_cancelled()
+    }
+
+    /// Completes once the scope is cancelled.
+    pub async fn cancelled(&self) {
+        self.token.cancelled().await;
+    }
+
+    /// Runs `work` until it finishes or the scope is cancelled, in which case
+    /// the work is dropped.
+    pub async fn run<F: Future>(&self, work: F) -> Result<F::Output, Interrupted> {
+        tokio::select! {
+            biased;
+            () = self.token.cancelled() => Err(Interrupted::Cancelled),
+            output = work => Ok(output),
+        }
+    }
+
+    /// Runs `work` like `run`, also giving up on it once `timeout` passed.
+    pub async fn with_timeout<F: Future>(
+        &self,
+        timeout: Duration,
+        work: F,
+    ) -> Result<F::Output, Interrupted> {
+        tokio::time::timeout(timeout, self.run(work))
+            .await
+            .unwrap_or(Err(Interrupted::TimedOut(timeout)))
+    }
+
+    /// Returns a guard cancelling the scope when it is dropped.
+    pub fn guard(&self) -> ScopeGuard {
+        ScopeGuard(self.token.clone().drop_guard())
+    }
+
+    /// Returns the token underneath, for libraries that take one.
+    pub fn token(&self) -> &CancellationToken {
+        &self.token
+    }
+}
+
+/// `ScopeGuard` cancels its scope when dropped.
+#[derive(Debug)]
+pub struct ScopeGuard(DropGuard);
+
+impl ScopeGuard {
+    /// Drops the guard without cancelling the scope.
+    pub fn disarm(self) {
+        self.0.disarm();
+    }
+}
+
+#[cfg(test)]
+mod specs_for_scope {
+    use std::time::Duration;
+
+    use super::Interrupted;
+    use super::Scope;
+
+    #[tokio::test]
+    async fn sut_returns_output_of_work_finishing_first() {
+        // Arrange
+        let sut = Scope::new();
+
+        // Act
+        let actual = sut.run(async { 42 }).await;
+
+        // Assert
+        assert_eq!(Ok(42), actual);
+    }
+
+    #[tokio::test]
+    async fn sut_interrupts_work_once_cancelled() {
+        // Arrange
+        let sut = Scope::new();
+        sut.cancel();
+
+        // Act
+        let actual = sut.run(std::future::pending::<()>()).await;
+
+        // Assert
+        assert_eq!(Err(Interrupted::Cancelled), actual);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn sut_interrupts_work_taking_longer_than_timeout() {
+        // Arrange
+        let sut = Scope::new();
+        let timeout = Duration::from_millis(100);
+
+        // Act
+        let actual = sut
+            .with_timeout(timeout, tokio::time::sleep(Duration::from_secs(1)))
+            .await;
+
+        // Assert
+        assert_eq!(Err(Interrupted::TimedOut(timeout)), actual);
+    }
+
+    #[test]
+    fn sut_cancels_children_but_not_parent() {
+        // Arrange
+        let sut = Scope::new();
+        let child = sut.child();
+        let grandchild = child.child();
+
+        // Act
+        child.cancel();
+
+        // Assert
+        assert!(!sut.is_cancelled());
+        assert!(grandchild.is_cancelled());
+    }
+
+    #[test]
+    fn sut_is_cancelled_once_guard_is_dropped() {
+        // Arrange
+        let sut = Scope::new();
+        let guard = sut.guard();
+
+        // Act
+        drop(guard);
+
+        // Assert
+        assert!(sut.is_cancelled());
+    }
+
+    #[test]
+    fn sut_is_not_cancelled_by_disarmed_guard() {
+        // Arrange
+        let sut = Scope::new();
+
+        // Act
+        sut.guard().disarm();
+
+        // Assert
+        assert!(!sut.is_cancelled());
+    }
+}
@@ -0,0 +1,19 @@
+use std::env::args;
+use std::io::stdout;
+use std::path::Path;
+
+use chrono::Local;
+
+use clock::v2::HandMode;
+use clock::v2::svg_writer_with_theme;
+use clock::v2::theme::Theme;
+use clock::v2::theme::ThemeError;
+
+fn main() -> Result<(), ThemeError> {
+    let theme = match args().nth(1) {
+        Some(path) => Theme::from_file(Path::new(&path))?,
+        None => Theme::default(),
+    };
+    svg_writer_with_theme(&mut stdout(), Local::now().time(), HandMode::Sweep, &theme).unwrap();
+    Ok(())
+}
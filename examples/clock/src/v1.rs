@@ -0,0 +1,252 @@
+use std::f64::consts::FRAC_PI_2;
+use std::f64::consts::PI;
+use std::io::Write;
+
+use chrono::NaiveTime;
+use chrono::Timelike;
+
+pub use geometry::Point;
+
+const SECOND_HAND_LENGTH: f64 = 90.0;
+const MINUTE_HAND_LENGTH: f64 = 80.0;
+const HOUR_HAND_LENGTH: f64 = 50.0;
+const CLOCK_CENTRE_X: f64 = 150.0;
+const CLOCK_CENTRE_Y: f64 = 150.0;
+
+const SECONDS_IN_HALF_CLOCK: f64 = 30.0;
+const MINUTES_IN_HALF_CLOCK: f64 = 30.0;
+const MINUTES_IN_CLOCK: f64 = 2.0 * MINUTES_IN_HALF_CLOCK;
+const HOURS_IN_HALF_CLOCK: f64 = 6.0;
+const HOURS_IN_CLOCK: f64 = 2.0 * HOURS_IN_HALF_CLOCK;
+
+const SVG_START: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="no"?>
+<!DOCTYPE svg PUBLIC "-//W3C//DTD SVG 1.1//EN" "http://www.w3.org/Graphics/SVG/1.1/DTD/svg11.dtd">
+<svg xmlns="http://www.w3.org/2000/svg"
+     width="100%"
+     height="100%"
+     viewBox="0 0 300 300"
+     version="2.0">"#;
+const BEZEL: &str =
+    r#"<circle cx="150" cy="150" r="100" style="fill:#fff;stroke:#000;stroke-width:5px;"/>"#;
+const SVG_END: &str = "</svg>";
+
+pub fn seconds_in_radians(time: NaiveTime) -> f64 {
+    PI / (SECONDS_IN_HALF_CLOCK / time.second() as f64)
+}
+
+pub fn minutes_in_radians(time: NaiveTime) -> f64 {
+    seconds_in_radians(time) / MINUTES_IN_CLOCK
+        + PI / (MINUTES_IN_HALF_CLOCK / time.minute() as f64)
+}
+
+pub fn hours_in_radians(time: NaiveTime) -> f64 {
+    minutes_in_radians(time) / HOURS_IN_CLOCK
+        + PI / (HOURS_IN_HALF_CLOCK / (time.hour() as f64 % HOURS_IN_CLOCK))
+}
+
+pub fn second_hand_point(time: NaiveTime) -> Point {
+    angle_to_point(seconds_in_radians(time))
+}
+
+pub fn minute_hand_point(time: NaiveTime) -> Point {
+    angle_to_point(minutes_in_radians(time))
+}
+
+pub fn hour_hand_point(time: NaiveTime) -> Point {
+    angle_to_point(hours_in_radians(time))
+}
+
+pub fn svg_writer(w: &mut dyn Write, time: NaiveTime) -> std::io::Result<()> {
+    writeln!(w, "{}", SVG_START)?;
+    writeln!(w, "{}", BEZEL)?;
+    write_hand(w, second_hand_point(time), SECOND_HAND_LENGTH, "#f00")?;
+    write_hand(w, minute_hand_point(time), MINUTE_HAND_LENGTH, "#000")?;
+    write_hand(w, hour_hand_point(time), HOUR_HAND_LENGTH, "#000")?;
+    write!(w, "{}", SVG_END)
+}
+
+fn write_hand(w: &mut dyn Write, point: Point, length: f64, colour: &str) -> std::io::Result<()> {
+    let Point { x, y } = make_hand(point, length);
+    writeln!(
+        w,
+        r#"<line x1="150" y1="150" x2="{:.3}" y2="{:.3}" style="fill:none;stroke:{};stroke-width:3px;"/>"#,
+        x, y, colour
+    )
+}
+
+fn make_hand(point: Point, length: f64) -> Point {
+    point
+        .flip_y()
+        .scale(length)
+        .translate(CLOCK_CENTRE_X, CLOCK_CENTRE_Y)
+}
+
+fn angle_to_point(angle: f64) -> Point {
+    // Clock angles start at twelve o'clock and turn clockwise, whereas the unit
+    // circle starts at three o'clock and turns anticlockwise.
+    Point::on_unit_circle(FRAC_PI_2 - angle)
+}
+
+#[cfg(test)]
+fn simple_time(hours: u32, minutes: u32, seconds: u32) -> NaiveTime {
+    NaiveTime::from_hms_opt(hours, minutes, seconds).unwrap()
+}
+
+#[cfg(test)]
+mod specs_for_angles {
+    use std::f64::consts::PI;
+
+    use assertions::assert_approx_eq;
+    use chrono::NaiveTime;
+    use rstest::rstest;
+
+    use super::hours_in_radians;
+    use super::minutes_in_radians;
+    use super::seconds_in_radians;
+    use super::simple_time;
+
+    #[rstest]
+    #[case(simple_time(0, 0, 30), PI)]
+    #[case(simple_time(0, 0, 0), 0.0)]
+    #[case(simple_time(0, 0, 45), PI / 2.0 * 3.0)]
+    #[case(simple_time(0, 0, 7), PI / 30.0 * 7.0)]
+    fn sut_returns_seconds_in_radians_correctly(#[case] time: NaiveTime, #[case] expected: f64) {
+        // Act
+        let actual = seconds_in_radians(time);
+
+        // Assert
+        assert_approx_eq!(expected, actual);
+    }
+
+    #[rstest]
+    #[case(simple_time(0, 30, 0), PI)]
+    #[case(simple_time(0, 0, 7), 7.0 * PI / (30.0 * 60.0))]
+    fn sut_returns_minutes_in_radians_correctly(#[case] time: NaiveTime, #[case] expected: f64) {
+        // Act
+        let actual = minutes_in_radians(time);
+
+        // Assert
+        assert_approx_eq!(expected, actual);
+    }
+
+    #[rstest]
+    #[case(simple_time(6, 0, 0), PI)]
+    #[case(simple_time(0, 0, 0), 0.0)]
+    #[case(simple_time(21, 0, 0), PI * 1.5)]
+    #[case(simple_time(0, 1, 30), PI / ((6.0 * 60.0 * 60.0) / 90.0))]
+    fn sut_returns_hours_in_radians_correctly(#[case] time: NaiveTime, #[case] expected: f64) {
+        // Act
+        let actual = hours_in_radians(time);
+
+        // Assert
+        assert_approx_eq!(expected, actual);
+    }
+}
+
+#[cfg(test)]
+mod specs_for_hand_points {
+    use assertions::assert_approx_eq;
+    use chrono::NaiveTime;
+    use rstest::rstest;
+
+    use super::Point;
+    use super::hour_hand_point;
+    use super::minute_hand_point;
+    use super::second_hand_point;
+    use super::simple_time;
+
+    #[rstest]
+    #[case(simple_time(0, 0, 30), Point { x: 0.0, y: -1.0 })]
+    #[case(simple_time(0, 0, 45), Point { x: -1.0, y: 0.0 })]
+    fn sut_returns_second_hand_point_correctly(#[case] time: NaiveTime, #[case] expected: Point) {
+        // Act
+        let actual = second_hand_point(time);
+
+        // Assert
+        assert_approx_eq!((expected.x, expected.y), (actual.x, actual.y));
+    }
+
+    #[rstest]
+    #[case(simple_time(0, 30, 0), Point { x: 0.0, y: -1.0 })]
+    #[case(simple_time(0, 45, 0), Point { x: -1.0, y: 0.0 })]
+    fn sut_returns_minute_hand_point_correctly(#[case] time: NaiveTime, #[case] expected: Point) {
+        // Act
+        let actual = minute_hand_point(time);
+
+        // Assert
+        assert_approx_eq!((expected.x, expected.y), (actual.x, actual.y));
+    }
+
+    #[rstest]
+    #[case(simple_time(6, 0, 0), Point { x: 0.0, y: -1.0 })]
+    #[case(simple_time(21, 0, 0), Point { x: -1.0, y: 0.0 })]
+    fn sut_returns_hour_hand_point_correctly(#[case] time: NaiveTime, #[case] expected: Point) {
+        // Act
+        let actual = hour_hand_point(time);
+
+        // Assert
+        assert_approx_eq!((expected.x, expected.y), (actual.x, actual.y));
+    }
+}
+
+#[cfg(test)]
+mod specs_for_svg_writer {
+    use super::simple_time;
+    use super::svg_writer;
+
+    #[test]
+    fn sut_writes_second_hand_at_midnight_correctly() {
+        // Arrange
+        let mut buffer = Vec::new();
+
+        // Act
+        svg_writer(&mut buffer, simple_time(0, 0, 0)).unwrap();
+
+        // Assert
+        let actual = String::from_utf8(buffer).unwrap();
+        let expected = r#"<line x1="150" y1="150" x2="150.000" y2="60.000" style="fill:none;stroke:#f00;stroke-width:3px;"/>"#;
+        assert!(actual.contains(expected));
+    }
+
+    #[test]
+    fn sut_writes_minute_hand_at_midnight_correctly() {
+        // Arrange
+        let mut buffer = Vec::new();
+
+        // Act
+        svg_writer(&mut buffer, simple_time(0, 0, 0)).unwrap();
+
+        // Assert
+        let actual = String::from_utf8(buffer).unwrap();
+        let expected = r#"<line x1="150" y1="150" x2="150.000" y2="70.000" style="fill:none;stroke:#000;stroke-width:3px;"/>"#;
+        assert!(actual.contains(expected));
+    }
+
+    #[test]
+    fn sut_writes_hour_hand_at_6_oclock_correctly() {
+        // Arrange
+        let mut buffer = Vec::new();
+
+        // Act
+        svg_writer(&mut buffer, simple_time(6, 0, 0)).unwrap();
+
+        // Assert
+        let actual = String::from_utf8(buffer).unwrap();
+        let expected = r#"<line x1="150" y1="150" x2="150.000" y2="200.000" style="fill:none;stroke:#000;stroke-width:3px;"/>"#;
+        assert!(actual.contains(expected));
+    }
+
+    #[test]
+    fn sut_wraps_hands_in_svg_document() {
+        // Arrange
+        let mut buffer = Vec::new();
+
+        // Act
+        svg_writer(&mut buffer, simple_time(0, 0, 0)).unwrap();
+
+        // Assert
+        let actual = String::from_utf8(buffer).unwrap();
+        assert!(actual.starts_with("<?xml"));
+        assert!(actual.ends_with("</svg>"));
+    }
+}
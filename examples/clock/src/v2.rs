@@ -0,0 +1,490 @@
+use std::f64::consts::FRAC_PI_2;
+use std::f64::consts::PI;
+use std::io::Write;
+
+use chrono::NaiveTime;
+use chrono::Timelike;
+
+pub use geometry::Point;
+
+pub mod digital;
+#[cfg(feature = "raster")]
+pub mod raster;
+pub mod theme;
+pub mod world_clock;
+
+use theme::Theme;
+
+const SECOND_HAND_LENGTH: f64 = 90.0;
+const MINUTE_HAND_LENGTH: f64 = 80.0;
+const HOUR_HAND_LENGTH: f64 = 50.0;
+const CLOCK_CENTRE_X: f64 = 150.0;
+const CLOCK_CENTRE_Y: f64 = 150.0;
+
+const SECONDS_IN_HALF_CLOCK: f64 = 30.0;
+const MINUTES_IN_HALF_CLOCK: f64 = 30.0;
+const MINUTES_IN_CLOCK: f64 = 2.0 * MINUTES_IN_HALF_CLOCK;
+const HOURS_IN_HALF_CLOCK: f64 = 6.0;
+const HOURS_IN_CLOCK: f64 = 2.0 * HOURS_IN_HALF_CLOCK;
+
+const CLOCK_WIDTH: f64 = 300.0;
+const CLOCK_HEIGHT: f64 = 300.0;
+
+const SVG_PROLOGUE: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="no"?>
+<!DOCTYPE svg PUBLIC "-//W3C//DTD SVG 1.1//EN" "http://www.w3.org/Graphics/SVG/1.1/DTD/svg11.dtd">"#;
+const NUMBER_RADIUS: f64 = 70.0;
+const SVG_END: &str = "</svg>";
+
+const NANOSECONDS_IN_SECOND: f64 = 1_000_000_000.0;
+
+/// `HandMode` decides how the second hand moves between whole seconds.
+///
+/// `Tick` jumps from one second to the next like a quartz clock, while `Sweep`
+/// takes nanoseconds into account so the hand glides around the face.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum HandMode {
+    #[default]
+    Tick,
+    Sweep,
+}
+
+pub fn seconds_in_radians(time: NaiveTime, mode: HandMode) -> f64 {
+    PI / (SECONDS_IN_HALF_CLOCK / seconds(time, mode))
+}
+
+pub fn minutes_in_radians(time: NaiveTime, mode: HandMode) -> f64 {
+    seconds_in_radians(time, mode) / MINUTES_IN_CLOCK
+        + PI / (MINUTES_IN_HALF_CLOCK / time.minute() as f64)
+}
+
+pub fn hours_in_radians(time: NaiveTime, mode: HandMode) -> f64 {
+    minutes_in_radians(time, mode) / HOURS_IN_CLOCK
+        + PI / (HOURS_IN_HALF_CLOCK / (time.hour() as f64 % HOURS_IN_CLOCK))
+}
+
+pub fn second_hand_point(time: NaiveTime, mode: HandMode) -> Point {
+    angle_to_point(seconds_in_radians(time, mode))
+}
+
+pub fn minute_hand_point(time: NaiveTime, mode: HandMode) -> Point {
+    angle_to_point(minutes_in_radians(time, mode))
+}
+
+pub fn hour_hand_point(time: NaiveTime, mode: HandMode) -> Point {
+    angle_to_point(hours_in_radians(time, mode))
+}
+
+pub fn svg_writer(w: &mut dyn Write, time: NaiveTime, mode: HandMode) -> std::io::Result<()> {
+    svg_writer_with_theme(w, time, mode, &Theme::default())
+}
+
+pub fn svg_writer_with_theme(
+    w: &mut dyn Write,
+    time: NaiveTime,
+    mode: HandMode,
+    theme: &Theme,
+) -> std::io::Result<()> {
+    write_svg_start(w, CLOCK_WIDTH, CLOCK_HEIGHT)?;
+    write_face(w, time, mode, theme)?;
+    write!(w, "{}", SVG_END)
+}
+
+fn write_svg_start(w: &mut dyn Write, width: f64, height: f64) -> std::io::Result<()> {
+    writeln!(w, "{}", SVG_PROLOGUE)?;
+    writeln!(
+        w,
+        r#"<svg xmlns="http://www.w3.org/2000/svg"
+     width="100%"
+     height="100%"
+     viewBox="0 0 {} {}"
+     version="2.0">"#,
+        width, height
+    )
+}
+
+fn write_face(
+    w: &mut dyn Write,
+    time: NaiveTime,
+    mode: HandMode,
+    theme: &Theme,
+) -> std::io::Result<()> {
+    writeln!(
+        w,
+        r#"<circle cx="150" cy="150" r="100" style="fill:{};stroke:#000;stroke-width:{}px;"/>"#,
+        theme.face_color, theme.stroke_widths.bezel
+    )?;
+    if theme.show_numbers {
+        write_numbers(w)?;
+    }
+    write_hand(
+        w,
+        second_hand_point(time, mode),
+        SECOND_HAND_LENGTH,
+        &theme.hand_colors.second,
+        theme.stroke_widths.second,
+    )?;
+    write_hand(
+        w,
+        minute_hand_point(time, mode),
+        MINUTE_HAND_LENGTH,
+        &theme.hand_colors.minute,
+        theme.stroke_widths.minute,
+    )?;
+    write_hand(
+        w,
+        hour_hand_point(time, mode),
+        HOUR_HAND_LENGTH,
+        &theme.hand_colors.hour,
+        theme.stroke_widths.hour,
+    )
+}
+
+fn write_numbers(w: &mut dyn Write) -> std::io::Result<()> {
+    for hour in 1..=HOURS_IN_CLOCK as u32 {
+        let angle = PI / (HOURS_IN_HALF_CLOCK / hour as f64);
+        let Point { x, y } = make_hand(angle_to_point(angle), NUMBER_RADIUS);
+        writeln!(
+            w,
+            r#"<text x="{:.3}" y="{:.3}" text-anchor="middle" dominant-baseline="middle">{}</text>"#,
+            x, y, hour
+        )?;
+    }
+    Ok(())
+}
+
+fn write_hand(
+    w: &mut dyn Write,
+    point: Point,
+    length: f64,
+    color: &str,
+    width: f64,
+) -> std::io::Result<()> {
+    let Point { x, y } = make_hand(point, length);
+    writeln!(
+        w,
+        r#"<line x1="150" y1="150" x2="{:.3}" y2="{:.3}" style="fill:none;stroke:{};stroke-width:{}px;"/>"#,
+        x, y, color, width
+    )
+}
+
+fn seconds(time: NaiveTime, mode: HandMode) -> f64 {
+    match mode {
+        HandMode::Tick => time.second() as f64,
+        HandMode::Sweep => {
+            // Leap seconds are represented as nanoseconds beyond one second, so the
+            // fraction is capped to keep the hand from passing the next second.
+            let nanoseconds = time.nanosecond().min(999_999_999);
+            time.second() as f64 + nanoseconds as f64 / NANOSECONDS_IN_SECOND
+        }
+    }
+}
+
+fn make_hand(point: Point, length: f64) -> Point {
+    point
+        .flip_y()
+        .scale(length)
+        .translate(CLOCK_CENTRE_X, CLOCK_CENTRE_Y)
+}
+
+fn angle_to_point(angle: f64) -> Point {
+    // Clock angles start at twelve o'clock and turn clockwise, whereas the unit
+    // circle starts at three o'clock and turns anticlockwise.
+    Point::on_unit_circle(FRAC_PI_2 - angle)
+}
+
+#[cfg(test)]
+fn simple_time(hours: u32, minutes: u32, seconds: u32) -> NaiveTime {
+    NaiveTime::from_hms_opt(hours, minutes, seconds).unwrap()
+}
+
+#[cfg(test)]
+fn precise_time(hours: u32, minutes: u32, seconds: u32, nanoseconds: u32) -> NaiveTime {
+    NaiveTime::from_hms_nano_opt(hours, minutes, seconds, nanoseconds).unwrap()
+}
+
+#[cfg(test)]
+mod specs_for_angles {
+    use std::f64::consts::PI;
+
+    use assertions::assert_approx_eq;
+    use chrono::NaiveTime;
+    use rstest::rstest;
+
+    use super::HandMode;
+    use super::hours_in_radians;
+    use super::minutes_in_radians;
+    use super::precise_time;
+    use super::seconds_in_radians;
+    use super::simple_time;
+
+    #[rstest]
+    #[case(simple_time(0, 0, 30), PI)]
+    #[case(simple_time(0, 0, 0), 0.0)]
+    #[case(simple_time(0, 0, 45), PI / 2.0 * 3.0)]
+    #[case(simple_time(0, 0, 7), PI / 30.0 * 7.0)]
+    fn sut_returns_seconds_in_radians_correctly(#[case] time: NaiveTime, #[case] expected: f64) {
+        // Act
+        let actual = seconds_in_radians(time, HandMode::Tick);
+
+        // Assert
+        assert_approx_eq!(expected, actual);
+    }
+
+    #[rstest]
+    #[case(simple_time(0, 30, 0), PI)]
+    #[case(simple_time(0, 0, 7), 7.0 * PI / (30.0 * 60.0))]
+    fn sut_returns_minutes_in_radians_correctly(#[case] time: NaiveTime, #[case] expected: f64) {
+        // Act
+        let actual = minutes_in_radians(time, HandMode::Tick);
+
+        // Assert
+        assert_approx_eq!(expected, actual);
+    }
+
+    #[rstest]
+    #[case(simple_time(6, 0, 0), PI)]
+    #[case(simple_time(0, 0, 0), 0.0)]
+    #[case(simple_time(21, 0, 0), PI * 1.5)]
+    #[case(simple_time(0, 1, 30), PI / ((6.0 * 60.0 * 60.0) / 90.0))]
+    fn sut_returns_hours_in_radians_correctly(#[case] time: NaiveTime, #[case] expected: f64) {
+        // Act
+        let actual = hours_in_radians(time, HandMode::Tick);
+
+        // Assert
+        assert_approx_eq!(expected, actual);
+    }
+
+    #[rstest]
+    #[case(precise_time(0, 0, 30, 0), PI)]
+    #[case(precise_time(0, 0, 0, 500_000_000), PI / 60.0)]
+    #[case(precise_time(0, 0, 7, 250_000_000), PI / 30.0 * 7.25)]
+    #[case(precise_time(0, 0, 59, 999_999_999), PI / 30.0 * 59.999999999)]
+    fn sut_returns_seconds_in_radians_with_nanoseconds_if_mode_is_sweep(
+        #[case] time: NaiveTime,
+        #[case] expected: f64,
+    ) {
+        // Act
+        let actual = seconds_in_radians(time, HandMode::Sweep);
+
+        // Assert
+        assert_approx_eq!(expected, actual);
+    }
+
+    #[test]
+    fn sut_ignores_nanoseconds_if_mode_is_tick() {
+        // Arrange
+        let time = precise_time(0, 0, 7, 999_999_999);
+
+        // Act
+        let actual = seconds_in_radians(time, HandMode::Tick);
+
+        // Assert
+        let expected = PI / 30.0 * 7.0;
+        assert_approx_eq!(expected, actual);
+    }
+
+    #[test]
+    fn sut_does_not_sweep_past_next_second_during_leap_second() {
+        // Arrange
+        let time = precise_time(23, 59, 59, 1_500_000_000);
+
+        // Act
+        let actual = seconds_in_radians(time, HandMode::Sweep);
+
+        // Assert
+        assert!(actual < 2.0 * PI);
+    }
+
+    #[test]
+    fn sut_moves_minute_hand_smoothly_if_mode_is_sweep() {
+        // Arrange
+        let time = precise_time(0, 0, 0, 500_000_000);
+
+        // Act
+        let actual = minutes_in_radians(time, HandMode::Sweep);
+
+        // Assert
+        let expected = PI / 60.0 / 60.0;
+        assert_approx_eq!(expected, actual);
+    }
+}
+
+#[cfg(test)]
+mod specs_for_hand_points {
+    use assertions::assert_approx_eq;
+    use chrono::NaiveTime;
+    use rstest::rstest;
+
+    use super::HandMode;
+    use super::Point;
+    use super::hour_hand_point;
+    use super::minute_hand_point;
+    use super::second_hand_point;
+    use super::simple_time;
+
+    #[rstest]
+    #[case(simple_time(0, 0, 30), Point { x: 0.0, y: -1.0 })]
+    #[case(simple_time(0, 0, 45), Point { x: -1.0, y: 0.0 })]
+    fn sut_returns_second_hand_point_correctly(#[case] time: NaiveTime, #[case] expected: Point) {
+        // Act
+        let actual = second_hand_point(time, HandMode::Tick);
+
+        // Assert
+        assert_approx_eq!((expected.x, expected.y), (actual.x, actual.y));
+    }
+
+    #[rstest]
+    #[case(simple_time(0, 30, 0), Point { x: 0.0, y: -1.0 })]
+    #[case(simple_time(0, 45, 0), Point { x: -1.0, y: 0.0 })]
+    fn sut_returns_minute_hand_point_correctly(#[case] time: NaiveTime, #[case] expected: Point) {
+        // Act
+        let actual = minute_hand_point(time, HandMode::Tick);
+
+        // Assert
+        assert_approx_eq!((expected.x, expected.y), (actual.x, actual.y));
+    }
+
+    #[rstest]
+    #[case(simple_time(6, 0, 0), Point { x: 0.0, y: -1.0 })]
+    #[case(simple_time(21, 0, 0), Point { x: -1.0, y: 0.0 })]
+    fn sut_returns_hour_hand_point_correctly(#[case] time: NaiveTime, #[case] expected: Point) {
+        // Act
+        let actual = hour_hand_point(time, HandMode::Tick);
+
+        // Assert
+        assert_approx_eq!((expected.x, expected.y), (actual.x, actual.y));
+    }
+}
+
+#[cfg(test)]
+mod specs_for_svg_writer {
+    use super::HandMode;
+    use super::simple_time;
+    use super::svg_writer;
+
+    #[test]
+    fn sut_writes_second_hand_at_midnight_correctly() {
+        // Arrange
+        let mut buffer = Vec::new();
+
+        // Act
+        svg_writer(&mut buffer, simple_time(0, 0, 0), HandMode::Tick).unwrap();
+
+        // Assert
+        let actual = String::from_utf8(buffer).unwrap();
+        let expected = r#"<line x1="150" y1="150" x2="150.000" y2="60.000" style="fill:none;stroke:#f00;stroke-width:3px;"/>"#;
+        assert!(actual.contains(expected));
+    }
+
+    #[test]
+    fn sut_writes_minute_hand_at_midnight_correctly() {
+        // Arrange
+        let mut buffer = Vec::new();
+
+        // Act
+        svg_writer(&mut buffer, simple_time(0, 0, 0), HandMode::Tick).unwrap();
+
+        // Assert
+        let actual = String::from_utf8(buffer).unwrap();
+        let expected = r#"<line x1="150" y1="150" x2="150.000" y2="70.000" style="fill:none;stroke:#000;stroke-width:3px;"/>"#;
+        assert!(actual.contains(expected));
+    }
+
+    #[test]
+    fn sut_writes_hour_hand_at_6_oclock_correctly() {
+        // Arrange
+        let mut buffer = Vec::new();
+
+        // Act
+        svg_writer(&mut buffer, simple_time(6, 0, 0), HandMode::Tick).unwrap();
+
+        // Assert
+        let actual = String::from_utf8(buffer).unwrap();
+        let expected = r#"<line x1="150" y1="150" x2="150.000" y2="200.000" style="fill:none;stroke:#000;stroke-width:3px;"/>"#;
+        assert!(actual.contains(expected));
+    }
+
+    #[test]
+    fn sut_wraps_hands_in_svg_document() {
+        // Arrange
+        let mut buffer = Vec::new();
+
+        // Act
+        svg_writer(&mut buffer, simple_time(0, 0, 0), HandMode::Tick).unwrap();
+
+        // Assert
+        let actual = String::from_utf8(buffer).unwrap();
+        assert!(actual.starts_with("<?xml"));
+        assert!(actual.ends_with("</svg>"));
+    }
+}
+
+#[cfg(test)]
+mod specs_for_svg_writer_with_theme {
+    use chrono::NaiveTime;
+    use golden::assert_matches_golden;
+
+    use super::HandMode;
+    use super::svg_writer_with_theme;
+    use super::theme::HandColors;
+    use super::theme::StrokeWidths;
+    use super::theme::Theme;
+
+    fn render(theme: &Theme) -> String {
+        let mut buffer = Vec::new();
+        let time = NaiveTime::from_hms_opt(0, 0, 0).unwrap();
+        svg_writer_with_theme(&mut buffer, time, HandMode::Tick, theme).unwrap();
+        String::from_utf8(buffer).unwrap()
+    }
+
+    #[test]
+    fn sut_applies_custom_theme_to_face_and_hands() {
+        // Arrange
+        let theme = Theme {
+            face_color: String::from("#222"),
+            hand_colors: HandColors {
+                second: String::from("#0f0"),
+                minute: String::from("#eee"),
+                hour: String::from("#ccc"),
+            },
+            stroke_widths: StrokeWidths {
+                bezel: 2.0,
+                second: 1.0,
+                minute: 4.5,
+                hour: 6.0,
+            },
+            show_numbers: false,
+        };
+
+        // Act
+        let actual = render(&theme);
+
+        // Assert
+        assert_matches_golden!("v2/custom_theme.svg", actual);
+    }
+
+    #[test]
+    fn sut_writes_twelve_numbers_if_theme_shows_numbers() {
+        // Arrange
+        let theme = Theme {
+            show_numbers: true,
+            ..Theme::default()
+        };
+
+        // Act
+        let actual = render(&theme);
+
+        // Assert
+        assert_eq!(12, actual.matches("<text").count());
+        assert_matches_golden!("v2/numbers.svg", actual);
+    }
+
+    #[test]
+    fn sut_omits_numbers_by_default() {
+        // Act
+        let actual = render(&Theme::default());
+
+        // Assert
+        assert!(!actual.contains("<text"));
+    }
+}
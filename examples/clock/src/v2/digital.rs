@@ -0,0 +1,126 @@
+use std::io::Write;
+
+use chrono::NaiveTime;
+use chrono::Timelike;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Format {
+    H12,
+    #[default]
+    H24,
+}
+
+/// `format_digital` renders the time as zero-padded `HH:MM:SS`.
+///
+/// With `Format::H12` the hour runs from 01 to 12 and an `AM` or `PM` suffix is
+/// appended, so midnight is `12:00:00 AM` and noon is `12:00:00 PM`.
+pub fn format_digital(time: NaiveTime, format: Format) -> String {
+    match format {
+        Format::H24 => format!(
+            "{:02}:{:02}:{:02}",
+            time.hour(),
+            time.minute(),
+            time.second()
+        ),
+        Format::H12 => {
+            let (is_pm, hour) = time.hour12();
+            let suffix = if is_pm { "PM" } else { "AM" };
+            format!(
+                "{:02}:{:02}:{:02} {}",
+                hour,
+                time.minute(),
+                time.second(),
+                suffix
+            )
+        }
+    }
+}
+
+pub fn digital_writer(w: &mut dyn Write, time: NaiveTime, format: Format) -> std::io::Result<()> {
+    writeln!(w, "{}", format_digital(time, format))
+}
+
+#[cfg(test)]
+mod specs_for_format_digital {
+    use chrono::NaiveTime;
+    use rstest::rstest;
+
+    use super::Format;
+    use super::format_digital;
+
+    fn time(hour: u32, min: u32, sec: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, min, sec).unwrap()
+    }
+
+    #[rstest]
+    #[case(time(0, 0, 0), "00:00:00")]
+    #[case(time(9, 5, 3), "09:05:03")]
+    #[case(time(12, 0, 0), "12:00:00")]
+    #[case(time(21, 5, 3), "21:05:03")]
+    #[case(time(23, 59, 59), "23:59:59")]
+    fn sut_formats_time_in_24_hour_format_correctly(
+        #[case] time: NaiveTime,
+        #[case] expected: &str,
+    ) {
+        // Act
+        let actual = format_digital(time, Format::H24);
+
+        // Assert
+        assert_eq!(expected, actual);
+    }
+
+    #[rstest]
+    #[case(time(0, 0, 0), "12:00:00 AM")]
+    #[case(time(0, 30, 15), "12:30:15 AM")]
+    #[case(time(9, 5, 3), "09:05:03 AM")]
+    #[case(time(12, 0, 0), "12:00:00 PM")]
+    #[case(time(13, 0, 0), "01:00:00 PM")]
+    #[case(time(21, 5, 3), "09:05:03 PM")]
+    #[case(time(23, 59, 59), "11:59:59 PM")]
+    fn sut_formats_time_in_12_hour_format_correctly(
+        #[case] time: NaiveTime,
+        #[case] expected: &str,
+    ) {
+        // Act
+        let actual = format_digital(time, Format::H12);
+
+        // Assert
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn sut_drops_fractions_of_second() {
+        // Arrange
+        let time = NaiveTime::from_hms_milli_opt(9, 5, 3, 999).unwrap();
+
+        // Act
+        let actual = format_digital(time, Format::H24);
+
+        // Assert
+        let expected = "09:05:03";
+        assert_eq!(expected, actual);
+    }
+}
+
+#[cfg(test)]
+mod specs_for_digital_writer {
+    use chrono::NaiveTime;
+
+    use super::Format;
+    use super::digital_writer;
+
+    #[test]
+    fn sut_writes_formatted_time_as_a_line() {
+        // Arrange
+        let mut buffer = Vec::new();
+        let time = NaiveTime::from_hms_opt(21, 5, 3).unwrap();
+
+        // Act
+        digital_writer(&mut buffer, time, Format::H12).unwrap();
+
+        // Assert
+        let actual = String::from_utf8(buffer).unwrap();
+        let expected = "09:05:03 PM\n";
+        assert_eq!(expected, actual);
+    }
+}
@@ -0,0 +1,164 @@
+use std::io::Write;
+
+use chrono::NaiveTime;
+use tiny_skia::Color;
+use tiny_skia::FillRule;
+use tiny_skia::Paint;
+use tiny_skia::PathBuilder;
+use tiny_skia::Pixmap;
+use tiny_skia::Stroke;
+use tiny_skia::Transform;
+
+use super::CLOCK_CENTRE_X;
+use super::CLOCK_CENTRE_Y;
+use super::CLOCK_HEIGHT;
+use super::CLOCK_WIDTH;
+use super::HOUR_HAND_LENGTH;
+use super::HandMode;
+use super::MINUTE_HAND_LENGTH;
+use super::Point;
+use super::SECOND_HAND_LENGTH;
+use super::hour_hand_point;
+use super::make_hand;
+use super::minute_hand_point;
+use super::second_hand_point;
+
+const BEZEL_RADIUS: f32 = 100.0;
+const BEZEL_STROKE_WIDTH: f32 = 5.0;
+const HAND_STROKE_WIDTH: f32 = 3.0;
+
+/// `png_writer` draws the same face as `svg_writer` onto a pixel buffer and
+/// writes it out as a PNG image.
+pub fn png_writer(w: &mut dyn Write, time: NaiveTime, mode: HandMode) -> std::io::Result<()> {
+    let mut pixmap = Pixmap::new(CLOCK_WIDTH as u32, CLOCK_HEIGHT as u32)
+        .ok_or_else(|| std::io::Error::other("failed to allocate pixmap"))?;
+
+    draw_bezel(&mut pixmap);
+    draw_hand(
+        &mut pixmap,
+        second_hand_point(time, mode),
+        SECOND_HAND_LENGTH,
+        Color::from_rgba8(255, 0, 0, 255),
+    );
+    draw_hand(
+        &mut pixmap,
+        minute_hand_point(time, mode),
+        MINUTE_HAND_LENGTH,
+        Color::BLACK,
+    );
+    draw_hand(
+        &mut pixmap,
+        hour_hand_point(time, mode),
+        HOUR_HAND_LENGTH,
+        Color::BLACK,
+    );
+
+    let png = pixmap.encode_png().map_err(std::io::Error::other)?;
+    w.write_all(&png)
+}
+
+fn draw_bezel(pixmap: &mut Pixmap) {
+    let circle =
+        PathBuilder::from_circle(CLOCK_CENTRE_X as f32, CLOCK_CENTRE_Y as f32, BEZEL_RADIUS)
+            .unwrap();
+
+    let mut paint = Paint::default();
+    paint.set_color(Color::WHITE);
+    pixmap.fill_path(
+        &circle,
+        &paint,
+        FillRule::Winding,
+        Transform::identity(),
+        None,
+    );
+
+    paint.set_color(Color::BLACK);
+    let stroke = Stroke {
+        width: BEZEL_STROKE_WIDTH,
+        ..Stroke::default()
+    };
+    pixmap.stroke_path(&circle, &paint, &stroke, Transform::identity(), None);
+}
+
+fn draw_hand(pixmap: &mut Pixmap, point: Point, length: f64, colour: Color) {
+    let Point { x, y } = make_hand(point, length);
+    let mut builder = PathBuilder::new();
+    builder.move_to(CLOCK_CENTRE_X as f32, CLOCK_CENTRE_Y as f32);
+    builder.line_to(x as f32, y as f32);
+    let line = builder.finish().unwrap();
+
+    let mut paint = Paint::default();
+    paint.set_color(colour);
+    let stroke = Stroke {
+        width: HAND_STROKE_WIDTH,
+        ..Stroke::default()
+    };
+    pixmap.stroke_path(&line, &paint, &stroke, Transform::identity(), None);
+}
+
+#[cfg(test)]
+mod specs_for_png_writer {
+    use chrono::NaiveTime;
+    use tiny_skia::Pixmap;
+
+    use super::HandMode;
+    use super::png_writer;
+
+    fn render(time: NaiveTime) -> Pixmap {
+        let mut buffer = Vec::new();
+        png_writer(&mut buffer, time, HandMode::Tick).unwrap();
+        Pixmap::decode_png(&buffer).unwrap()
+    }
+
+    fn rgba(pixmap: &Pixmap, x: u32, y: u32) -> (u8, u8, u8, u8) {
+        let pixel = pixmap.pixel(x, y).unwrap().demultiply();
+        (pixel.red(), pixel.green(), pixel.blue(), pixel.alpha())
+    }
+
+    #[test]
+    fn sut_writes_image_of_clock_size() {
+        // Act
+        let actual = render(NaiveTime::from_hms_opt(0, 0, 0).unwrap());
+
+        // Assert
+        assert_eq!((300, 300), (actual.width(), actual.height()));
+    }
+
+    #[test]
+    fn sut_draws_second_hand_in_red_along_its_direction() {
+        // Arrange
+        let time = NaiveTime::from_hms_opt(0, 0, 15).unwrap();
+
+        // Act
+        let actual = render(time);
+
+        // Assert
+        for x in [170, 200, 230] {
+            assert_eq!((255, 0, 0, 255), rgba(&actual, x, 150));
+        }
+        assert_eq!((255, 255, 255, 255), rgba(&actual, 150, 200));
+    }
+
+    #[test]
+    fn sut_draws_minute_hand_in_black_along_its_direction() {
+        // Arrange
+        let time = NaiveTime::from_hms_opt(0, 45, 0).unwrap();
+
+        // Act
+        let actual = render(time);
+
+        // Assert
+        for x in [130, 100, 80] {
+            assert_eq!((0, 0, 0, 255), rgba(&actual, x, 150));
+        }
+    }
+
+    #[test]
+    fn sut_leaves_outside_of_bezel_transparent() {
+        // Act
+        let actual = render(NaiveTime::from_hms_opt(0, 0, 0).unwrap());
+
+        // Assert
+        assert_eq!(0, rgba(&actual, 5, 5).3);
+    }
+}
@@ -0,0 +1,223 @@
+use std::fs::read_to_string;
+use std::path::Path;
+
+use serde::Deserialize;
+
+/// `Theme` controls how a clock face looks when it is written as SVG.
+///
+/// Every field has a default, so a TOML file only needs to mention the values
+/// it wants to change.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct Theme {
+    pub face_color: String,
+    pub hand_colors: HandColors,
+    pub stroke_widths: StrokeWidths,
+    pub show_numbers: bool,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct HandColors {
+    pub second: String,
+    pub minute: String,
+    pub hour: String,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct StrokeWidths {
+    pub bezel: f64,
+    pub second: f64,
+    pub minute: f64,
+    pub hour: f64,
+}
+
+impl Theme {
+    /// Parses a theme. Colours are written into the SVG as they are, so any
+    /// that is not a hex colour such as `#f00` or a named one such as `red`
+    /// is rejected.
+    pub fn from_toml(content: &str) -> Result<Self, ThemeError> {
+        let theme: Theme = toml::from_str(content)?;
+        theme.check_colors()?;
+        Ok(theme)
+    }
+
+    pub fn from_file(path: &Path) -> Result<Self, ThemeError> {
+        Self::from_toml(&read_to_string(path)?)
+    }
+
+    fn check_colors(&self) -> Result<(), ThemeError> {
+        let colors = [
+            ("face_color", &self.face_color),
+            ("hand_colors.second", &self.hand_colors.second),
+            ("hand_colors.minute", &self.hand_colors.minute),
+            ("hand_colors.hour", &self.hand_colors.hour),
+        ];
+        match colors.into_iter().find(|(_, color)| !is_color(color)) {
+            Some((field, color)) => Err(ThemeError::InvalidColor {
+                field,
+                color: color.clone(),
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+fn is_color(value: &str) -> bool {
+    match value.strip_prefix('#') {
+        Some(hex) => {
+            matches!(hex.len(), 3 | 4 | 6 | 8) && hex.chars().all(|c| c.is_ascii_hexdigit())
+        }
+        None => !value.is_empty() && value.chars().all(|c| c.is_ascii_alphabetic()),
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme {
+            face_color: String::from("#fff"),
+            hand_colors: HandColors::default(),
+            stroke_widths: StrokeWidths::default(),
+            show_numbers: false,
+        }
+    }
+}
+
+impl Default for HandColors {
+    fn default() -> Self {
+        HandColors {
+            second: String::from("#f00"),
+            minute: String::from("#000"),
+            hour: String::from("#000"),
+        }
+    }
+}
+
+impl Default for StrokeWidths {
+    fn default() -> Self {
+        StrokeWidths {
+            bezel: 5.0,
+            second: 3.0,
+            minute: 3.0,
+            hour: 3.0,
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ThemeError {
+    #[error("failed to read theme file: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("failed to parse theme: {0}")]
+    Parse(#[from] toml::de::Error),
+
+    #[error("{field} is not a color: {color:?}")]
+    InvalidColor { field: &'static str, color: String },
+}
+
+#[cfg(test)]
+mod specs_for_theme {
+    use std::io::Write;
+
+    use tempfile::NamedTempFile;
+
+    use super::Theme;
+    use super::ThemeError;
+
+    #[test]
+    fn sut_parses_bundled_default_theme_as_default() {
+        // Arrange
+        let content = include_str!("../../themes/default.toml");
+
+        // Act
+        let actual = Theme::from_toml(content).unwrap();
+
+        // Assert
+        assert_eq!(Theme::default(), actual);
+    }
+
+    #[test]
+    fn sut_fills_missing_values_with_defaults() {
+        // Arrange
+        let content = r##"
+            show_numbers = true
+
+            [hand_colors]
+            second = "#00f"
+        "##;
+
+        // Act
+        let actual = Theme::from_toml(content).unwrap();
+
+        // Assert
+        assert!(actual.show_numbers);
+        assert_eq!("#00f", actual.hand_colors.second);
+        assert_eq!("#000", actual.hand_colors.minute);
+        assert_eq!(Theme::default().face_color, actual.face_color);
+        assert_eq!(Theme::default().stroke_widths, actual.stroke_widths);
+    }
+
+    #[test]
+    fn sut_loads_theme_from_file() {
+        // Arrange
+        let mut file = NamedTempFile::new().unwrap();
+        file.write_all(br##"face_color = "#123456""##).unwrap();
+
+        // Act
+        let actual = Theme::from_file(file.path()).unwrap();
+
+        // Assert
+        assert_eq!("#123456", actual.face_color);
+    }
+
+    #[test]
+    fn sut_returns_parse_error_if_value_has_wrong_type() {
+        // Arrange
+        let content = r#"show_numbers = "yes""#;
+
+        // Act
+        let actual = Theme::from_toml(content).unwrap_err();
+
+        // Assert
+        assert!(matches!(actual, ThemeError::Parse(_)));
+    }
+
+    #[rstest::rstest]
+    #[case::short_hex("#0f0")]
+    #[case::hex_with_alpha("#00ff0080")]
+    #[case::named("rebeccapurple")]
+    fn sut_accepts_hex_and_named_colors(#[case] color: &str) {
+        // Arrange
+        let content = format!(r#"face_color = "{}""#, color);
+
+        // Act
+        let actual = Theme::from_toml(&content).unwrap();
+
+        // Assert
+        assert_eq!(color, actual.face_color);
+    }
+
+    #[rstest::rstest]
+    #[case::empty("")]
+    #[case::not_hex("#ggg")]
+    #[case::more_style("#f00;stroke:url(evil)")]
+    #[case::attribute_break_out(r#"#f00"/><script>alert(1)</script>"#)]
+    fn sut_rejects_color_that_is_not_a_color(#[case] color: &str) {
+        // Arrange
+        let content = format!("[hand_colors]\nhour = '{}'", color);
+
+        // Act
+        let actual = Theme::from_toml(&content).unwrap_err();
+
+        // Assert
+        assert!(matches!(
+            actual,
+            ThemeError::InvalidColor {
+                field: "hand_colors.hour",
+                ..
+            }
+        ));
+    }
+}
@@ -0,0 +1,177 @@
+use std::io::Write;
+
+use chrono::DateTime;
+use chrono::NaiveTime;
+use chrono::Utc;
+use chrono_tz::Tz;
+
+use super::CLOCK_HEIGHT;
+use super::CLOCK_WIDTH;
+use super::HandMode;
+use super::SVG_END;
+use super::Theme;
+use super::write_face;
+use super::write_svg_start;
+
+const LABEL_Y: f64 = 280.0;
+
+/// `WorldClock` draws one labelled face per time zone, side by side, so that a
+/// single UTC instant can be compared across the world at a glance.
+pub struct WorldClock {
+    zones: Vec<Tz>,
+    mode: HandMode,
+    theme: Theme,
+}
+
+impl WorldClock {
+    pub fn new(zones: Vec<Tz>, mode: HandMode) -> Self {
+        WorldClock {
+            zones,
+            mode,
+            theme: Theme::default(),
+        }
+    }
+
+    pub fn with_theme(self, theme: Theme) -> Self {
+        WorldClock { theme, ..self }
+    }
+
+    /// Converts the instant into the wall clock time of every zone, including
+    /// the zone abbreviation in effect at that instant (e.g. `EST` or `EDT`).
+    pub fn local_times(&self, instant: DateTime<Utc>) -> Vec<(Tz, NaiveTime, String)> {
+        self.zones
+            .iter()
+            .map(|zone| {
+                let local = instant.with_timezone(zone);
+                (*zone, local.time(), local.format("%Z").to_string())
+            })
+            .collect()
+    }
+
+    pub fn svg_writer(&self, w: &mut dyn Write, instant: DateTime<Utc>) -> std::io::Result<()> {
+        let width = CLOCK_WIDTH * self.zones.len() as f64;
+        write_svg_start(w, width, CLOCK_HEIGHT)?;
+        for (n, (zone, time, abbreviation)) in self.local_times(instant).into_iter().enumerate() {
+            writeln!(
+                w,
+                r#"<g transform="translate({}, 0)">"#,
+                CLOCK_WIDTH * n as f64
+            )?;
+            write_face(w, time, self.mode, &self.theme)?;
+            writeln!(
+                w,
+                r#"<text x="{}" y="{}" text-anchor="middle">{} ({})</text>"#,
+                CLOCK_WIDTH / 2.0,
+                LABEL_Y,
+                zone.name(),
+                abbreviation
+            )?;
+            writeln!(w, "</g>")?;
+        }
+        write!(w, "{}", SVG_END)
+    }
+}
+
+#[cfg(test)]
+mod specs_for_world_clock {
+    use chrono::DateTime;
+    use chrono::NaiveTime;
+    use chrono::TimeZone;
+    use chrono::Utc;
+    use chrono_tz::America::New_York;
+    use chrono_tz::Asia::Seoul;
+    use chrono_tz::Europe::London;
+    use chrono_tz::Tz;
+    use golden::assert_matches_golden;
+    use rstest::rstest;
+
+    use super::HandMode;
+    use super::WorldClock;
+
+    fn instant(year: i32, month: u32, day: u32, hour: u32, min: u32, sec: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, hour, min, sec)
+            .unwrap()
+    }
+
+    fn time(hour: u32, min: u32, sec: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, min, sec).unwrap()
+    }
+
+    #[rstest]
+    #[case(instant(2024, 1, 15, 12, 0, 0), New_York, time(7, 0, 0), "EST")]
+    #[case(instant(2024, 7, 1, 12, 0, 0), New_York, time(8, 0, 0), "EDT")]
+    #[case(instant(2024, 1, 15, 12, 0, 0), London, time(12, 0, 0), "GMT")]
+    #[case(instant(2024, 7, 1, 12, 0, 0), London, time(13, 0, 0), "BST")]
+    #[case(instant(2024, 7, 1, 12, 0, 0), Seoul, time(21, 0, 0), "KST")]
+    fn sut_converts_instant_into_local_time_of_zone_correctly(
+        #[case] instant: DateTime<Utc>,
+        #[case] zone: Tz,
+        #[case] expected_time: NaiveTime,
+        #[case] expected_abbreviation: &str,
+    ) {
+        // Arrange
+        let sut = WorldClock::new(vec![zone], HandMode::Tick);
+
+        // Act
+        let actual = sut.local_times(instant);
+
+        // Assert
+        let expected = vec![(zone, expected_time, expected_abbreviation.to_string())];
+        assert_eq!(expected, actual);
+    }
+
+    #[rstest]
+    #[case(instant(2024, 3, 10, 6, 59, 59), time(1, 59, 59))]
+    #[case(instant(2024, 3, 10, 7, 0, 0), time(3, 0, 0))]
+    #[case(instant(2024, 11, 3, 5, 59, 59), time(1, 59, 59))]
+    #[case(instant(2024, 11, 3, 6, 0, 0), time(1, 0, 0))]
+    fn sut_follows_daylight_saving_transitions(
+        #[case] instant: DateTime<Utc>,
+        #[case] expected: NaiveTime,
+    ) {
+        // Arrange
+        let sut = WorldClock::new(vec![New_York], HandMode::Tick);
+
+        // Act
+        let actual = sut.local_times(instant)[0].1;
+
+        // Assert
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn sut_writes_a_labelled_face_per_zone_side_by_side() {
+        // Arrange
+        let sut = WorldClock::new(vec![London, New_York, Seoul], HandMode::Tick);
+        let mut buffer = Vec::new();
+
+        // Act
+        sut.svg_writer(&mut buffer, instant(2024, 7, 1, 12, 0, 0))
+            .unwrap();
+
+        // Assert
+        let actual = String::from_utf8(buffer).unwrap();
+        assert_eq!(3, actual.matches("<circle").count());
+        assert_matches_golden!("v2/world_clock.svg", actual);
+    }
+
+    #[test]
+    fn sut_points_hour_hands_at_local_hour_of_each_zone() {
+        // Arrange
+        let sut = WorldClock::new(vec![New_York, Seoul], HandMode::Tick);
+        let mut buffer = Vec::new();
+
+        // Act
+        sut.svg_writer(&mut buffer, instant(2024, 1, 15, 12, 0, 0))
+            .unwrap();
+
+        // Assert
+        let actual = String::from_utf8(buffer).unwrap();
+        let hour_hand_at_9 = r#"<line x1="150" y1="150" x2="100.000" y2="150.000" style="fill:none;stroke:#000;stroke-width:3px;"/>"#;
+        let hour_hand_at_7 = r#"<line x1="150" y1="150" x2="125.000" y2="193.301" style="fill:none;stroke:#000;stroke-width:3px;"/>"#;
+        let faces = actual.split("</g>").collect::<Vec<_>>();
+        let (new_york, seoul) = (faces[0], faces[1]);
+        assert!(new_york.contains(hour_hand_at_7));
+        assert!(seoul.contains(hour_hand_at_9));
+    }
+}
@@ -1,5 +1,9 @@
 use std::collections::HashMap;
 
+/// `WebsiteChecker` is written with the `async_trait` macro, which boxes the
+/// future of every check, as it is up to v4. From v5 on, the check is
+/// declared returning `impl Future` instead, which needs no macro and no
+/// allocation.
 #[async_trait::async_trait]
 pub trait WebsiteChecker {
     async fn check(&self, url: &str) -> bool;
@@ -0,0 +1,377 @@
+use std::collections::HashMap;
+
+use cancellation::Scope;
+use executor::Spawner;
+use executor::TokioSpawner;
+use futures::future::join_all;
+use retry::v1::Policy;
+use retry::v1::retry_async;
+
+/// `WebsiteChecker` checks a website on a task of its own, so the check
+/// has to be `Send`. Unlike v4's, it is declared with a native async
+/// method rather than `async_trait`, so no check is boxed.
+pub trait WebsiteChecker: Clone + Send + 'static {
+    fn check(&self, url: String) -> impl Future<Output = bool> + Send;
+}
+
+// `automock` cannot mock the `Clone` the trait requires, so the mock is
+// spelled out.
+#[cfg(test)]
+mockall::mock! {
+    pub WebsiteChecker {}
+
+    impl Clone for WebsiteChecker {
+        fn clone(&self) -> Self;
+    }
+
+    impl WebsiteChecker for WebsiteChecker {
+        async fn check(&self, url: String) -> bool;
+    }
+}
+
+/// Checks every website concurrently, like v4. With the `tracing` feature,
+/// each one is checked in a `check` span with its `url`, and whether it is
+/// `up`.
+pub async fn check_websites<'a>(
+    urls: &'a [&str],
+    checker: impl WebsiteChecker,
+) -> HashMap<&'a str, bool> {
+    check_websites_in(urls, checker, &Scope::new()).await
+}
+
+/// Checks every website concurrently like `check_websites`, until the scope
+/// is cancelled. The websites still being checked by then are left out.
+pub async fn check_websites_in<'a>(
+    urls: &'a [&str],
+    checker: impl WebsiteChecker,
+    scope: &Scope,
+) -> HashMap<&'a str, bool> {
+    check_websites_on(urls, checker, scope, &TokioSpawner).await
+}
+
+/// Checks every website like `check_websites_in`, each on a task spawned by
+/// `spawner`. A website whose check panicked is reported down.
+pub async fn check_websites_on<'a>(
+    urls: &'a [&str],
+    checker: impl WebsiteChecker,
+    scope: &Scope,
+    spawner: &impl Spawner,
+) -> HashMap<&'a str, bool> {
+    let handles = urls
+        .iter()
+        .map(|&url| {
+            #[cfg(feature = "tracing")]
+            let span = tracing::info_span!("check", url, up = tracing::field::Empty);
+            let url = url.to_string();
+            let checker = checker.clone();
+            let check = async move {
+                let is_up = checker.check(url).await;
+                #[cfg(feature = "tracing")]
+                tracing::Span::current().record("up", is_up);
+                is_up
+            };
+            #[cfg(feature = "tracing")]
+            let check = tracing::Instrument::instrument(check, span);
+            let scope = scope.clone();
+            spawner.spawn(async move { scope.run(check).await })
+        })
+        .collect::<Vec<_>>();
+    let responses = join_all(handles).await;
+
+    urls.iter()
+        .zip(responses)
+        .filter_map(|(&url, response)| match response {
+            Ok(Ok(is_up)) => Some((url, is_up)),
+            Ok(Err(_)) => None,
+            Err(_) => Some((url, false)),
+        })
+        .collect::<HashMap<_, _>>()
+}
+
+/// `RetryingChecker` checks a website that looks down again, as the policy
+/// tells, before reporting it down. A website only has to be up once.
+#[derive(Clone)]
+pub struct RetryingChecker<C, P> {
+    checker: C,
+    policy: P,
+}
+
+impl<C, P> RetryingChecker<C, P> {
+    pub fn new(checker: C, policy: P) -> Self {
+        RetryingChecker { checker, policy }
+    }
+}
+
+impl<C, P> WebsiteChecker for RetryingChecker<C, P>
+where
+    C: WebsiteChecker + Sync,
+    P: Policy<()> + Clone + Send + Sync + 'static,
+{
+    async fn check(&self, url: String) -> bool {
+        retry_async(&self.policy, || {
+            let url = url.clone();
+            async move {
+                if self.checker.check(url).await {
+                    Ok(())
+                } else {
+                    Err(())
+                }
+            }
+        })
+        .await
+        .is_ok()
+    }
+}
+
+#[cfg(test)]
+mod specs_for_retrying_checker {
+    use std::collections::HashMap;
+    use std::time::Duration;
+
+    use mockall::predicate::eq;
+    use retry::v1::Fixed;
+    use test_helpers::CallRecorder;
+
+    use super::MockWebsiteChecker;
+    use super::RetryingChecker;
+    use super::WebsiteChecker;
+    use super::check_websites;
+
+    const POLICY: Fixed = Fixed {
+        delay: Duration::from_millis(10),
+        max_attempts: 3,
+    };
+
+    /// `FlakyWebsiteChecker` reports every website down for its first
+    /// `failures` checks, recording the website of each check.
+    #[derive(Clone)]
+    struct FlakyWebsiteChecker {
+        failures: usize,
+        checks: CallRecorder<String>,
+    }
+
+    impl FlakyWebsiteChecker {
+        fn new(failures: usize) -> Self {
+            FlakyWebsiteChecker {
+                failures,
+                checks: CallRecorder::new(),
+            }
+        }
+    }
+
+    impl WebsiteChecker for FlakyWebsiteChecker {
+        async fn check(&self, url: String) -> bool {
+            self.checks.record(url.clone());
+            self.checks.count_of(&url) > self.failures
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn sut_reports_website_up_once_a_check_succeeds() {
+        // Arrange
+        let websites = ["http://google.com", "http://blog.gypsydave5.com"];
+        let checker = FlakyWebsiteChecker::new(2);
+
+        // Act
+        let actual = check_websites(&websites, RetryingChecker::new(checker.clone(), POLICY)).await;
+
+        // Assert
+        let expected = HashMap::from([
+            ("http://google.com", true),
+            ("http://blog.gypsydave5.com", true),
+        ]);
+        assert_eq!(expected, actual);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn sut_reports_website_down_after_last_attempt() {
+        // Arrange
+        let websites = ["http://google.com"];
+        let checker = FlakyWebsiteChecker::new(usize::MAX);
+
+        // Act
+        let actual = check_websites(&websites, RetryingChecker::new(checker.clone(), POLICY)).await;
+
+        // Assert
+        assert_eq!(HashMap::from([("http://google.com", false)]), actual);
+        assert_eq!(3, checker.checks.count_of(&"http://google.com".to_string()));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn sut_checks_website_down_as_many_times_as_policy_allows() {
+        // Arrange
+        let mut checker_mock = MockWebsiteChecker::new();
+        checker_mock
+            .expect_check()
+            .with(eq("http://google.com".to_string()))
+            .times(3)
+            .return_const(false);
+        let sut = RetryingChecker::new(checker_mock, POLICY);
+
+        // Act
+        let actual = sut.check("http://google.com".to_string()).await;
+
+        // Assert
+        assert!(!actual);
+    }
+}
+
+#[cfg(test)]
+mod specs_for_check_websites_on {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use std::sync::Mutex;
+
+    use cancellation::Scope;
+    use executor::TestExecutor;
+
+    use super::WebsiteChecker;
+    use super::check_websites_in;
+    use super::check_websites_on;
+
+    #[test]
+    fn sut_starts_every_check_before_any_finishes() {
+        // Arrange
+        let websites = ["http://a.com", "http://b.com"];
+        let checker = YieldingWebsiteChecker::default();
+        let executor = TestExecutor::new();
+
+        // Act
+        let actual = executor.block_on(check_websites_on(
+            &websites,
+            checker.clone(),
+            &Scope::new(),
+            &executor.spawner(),
+        ));
+
+        // Assert
+        assert_eq!(2, actual.len());
+        let expected = vec![
+            "http://a.com started",
+            "http://b.com started",
+            "http://a.com finished",
+            "http://b.com finished",
+        ];
+        assert_eq!(expected, *checker.log.lock().unwrap());
+    }
+
+    #[test]
+    fn sut_reports_website_down_if_its_check_panicked() {
+        // Arrange
+        let websites = ["http://google.com", "http://panicking.com"];
+        let executor = TestExecutor::new();
+
+        // Act
+        let actual = executor.block_on(check_websites_on(
+            &websites,
+            PanickingWebsiteChecker,
+            &Scope::new(),
+            &executor.spawner(),
+        ));
+
+        // Assert
+        let expected =
+            HashMap::from([("http://google.com", true), ("http://panicking.com", false)]);
+        assert_eq!(expected, actual);
+    }
+
+    /// `YieldingWebsiteChecker` logs when it starts and finishes checking a
+    /// website, yielding to the other checks in between.
+    #[derive(Clone, Default)]
+    struct YieldingWebsiteChecker {
+        log: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl WebsiteChecker for YieldingWebsiteChecker {
+        async fn check(&self, url: String) -> bool {
+            self.log.lock().unwrap().push(format!("{url} started"));
+            tokio::task::yield_now().await;
+            self.log.lock().unwrap().push(format!("{url} finished"));
+            true
+        }
+    }
+
+    /// `PanickingWebsiteChecker` panics checking a website with `panicking`
+    /// in its URL, and reports every other one up.
+    #[derive(Clone)]
+    struct PanickingWebsiteChecker;
+
+    impl WebsiteChecker for PanickingWebsiteChecker {
+        async fn check(&self, url: String) -> bool {
+            assert!(!url.contains("panicking"), "cannot check {url}");
+            true
+        }
+    }
+
+    #[tokio::test]
+    async fn sut_leaves_out_websites_still_checked_once_cancelled() {
+        // Arrange
+        let websites = ["http://google.com", "http://hanging.com"];
+        let scope = Scope::new();
+
+        // Act
+        let checking = check_websites_in(&websites, HangingWebsiteChecker, &scope);
+        let (actual, ()) = tokio::join!(checking, async {
+            tokio::task::yield_now().await;
+            scope.cancel();
+        });
+
+        // Assert
+        assert_eq!(HashMap::from([("http://google.com", true)]), actual);
+    }
+
+    /// `HangingWebsiteChecker` never finishes checking a website with
+    /// `hanging` in its URL, and reports every other one up.
+    #[derive(Clone)]
+    struct HangingWebsiteChecker;
+
+    impl WebsiteChecker for HangingWebsiteChecker {
+        async fn check(&self, url: String) -> bool {
+            if url.contains("hanging") {
+                std::future::pending::<()>().await;
+            }
+            true
+        }
+    }
+}
+
+#[cfg(all(test, feature = "tracing"))]
+mod specs_for_check_websites_tracing {
+    use test_helpers::TraceCapture;
+
+    use super::WebsiteChecker;
+    use super::check_websites;
+
+    #[derive(Clone)]
+    struct UpUnlessBadChecker;
+
+    impl WebsiteChecker for UpUnlessBadChecker {
+        async fn check(&self, url: String) -> bool {
+            !url.contains("bad")
+        }
+    }
+
+    #[tokio::test]
+    async fn sut_checks_every_website_in_check_span() {
+        // Arrange
+        let capture = TraceCapture::new();
+        let _guard = capture.set_default();
+        let websites = ["http://good.com", "http://bad.com"];
+
+        // Act
+        check_websites(&websites, UpUnlessBadChecker).await;
+
+        // Assert
+        let mut actual = capture
+            .spans("check")
+            .into_iter()
+            .map(|fields| (fields["url"].clone(), fields["up"].clone()))
+            .collect::<Vec<_>>();
+        actual.sort();
+        let expected = vec![
+            ("http://bad.com".to_string(), "false".to_string()),
+            ("http://good.com".to_string(), "true".to_string()),
+        ];
+        assert_eq!(expected, actual);
+    }
+}
@@ -0,0 +1,172 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use rate_limiter::v1::RateLimiter;
+
+pub use super::v5::WebsiteChecker;
+pub use super::v5::check_websites;
+pub use super::v5::check_websites_in;
+pub use super::v5::check_websites_on;
+
+/// `HostLimitedChecker` waits for a permit of the limiter of the host of a
+/// website before checking it, so that no host is checked faster than its
+/// limiter allows. Each host gets its own limiter, made the first time one
+/// of its websites is checked; clones share them.
+pub struct HostLimitedChecker<C, L> {
+    checker: C,
+    new_limiter: Arc<dyn Fn() -> L + Send + Sync>,
+    limiters: Arc<Mutex<HashMap<String, Arc<L>>>>,
+}
+
+impl<C, L> HostLimitedChecker<C, L> {
+    pub fn new(checker: C, new_limiter: impl Fn() -> L + Send + Sync + 'static) -> Self {
+        HostLimitedChecker {
+            checker,
+            new_limiter: Arc::new(new_limiter),
+            limiters: Arc::default(),
+        }
+    }
+
+    fn limiter(&self, host: &str) -> Arc<L> {
+        let mut limiters = self.limiters.lock().unwrap();
+        let limiter = limiters
+            .entry(host.to_string())
+            .or_insert_with(|| Arc::new((self.new_limiter)()));
+        Arc::clone(limiter)
+    }
+}
+
+impl<C: Clone, L> Clone for HostLimitedChecker<C, L> {
+    fn clone(&self) -> Self {
+        HostLimitedChecker {
+            checker: self.checker.clone(),
+            new_limiter: Arc::clone(&self.new_limiter),
+            limiters: Arc::clone(&self.limiters),
+        }
+    }
+}
+
+impl<C, L> WebsiteChecker for HostLimitedChecker<C, L>
+where
+    C: WebsiteChecker + Sync,
+    L: RateLimiter + 'static,
+{
+    async fn check(&self, url: String) -> bool {
+        let limiter = self.limiter(host(&url));
+        limiter.acquire_async().await;
+        self.checker.check(url).await
+    }
+}
+
+/// Returns the host of `url`, with its port if it has one.
+fn host(url: &str) -> &str {
+    let rest = url.split_once("://").map_or(url, |(_, rest)| rest);
+    rest.split(['/', '?', '#']).next().unwrap_or(rest)
+}
+
+#[cfg(test)]
+mod specs_for_host_limited_checker {
+    use std::num::NonZeroU32;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use rate_limiter::v1::token_bucket::TokenBucket;
+    use test_helpers::CallRecorder;
+    use tokio::time::Instant;
+
+    use super::HostLimitedChecker;
+    use super::WebsiteChecker;
+    use super::check_websites;
+
+    const INTERVAL: Duration = Duration::from_millis(100);
+
+    /// `RecordingWebsiteChecker` reports every website up, recording how
+    /// long after its creation each one was checked.
+    #[derive(Clone)]
+    struct RecordingWebsiteChecker {
+        start: Instant,
+        checks: CallRecorder<(String, Duration)>,
+    }
+
+    impl RecordingWebsiteChecker {
+        fn new() -> Self {
+            RecordingWebsiteChecker {
+                start: Instant::now(),
+                checks: CallRecorder::new(),
+            }
+        }
+
+        fn checked_after(&self, prefix: &str) -> Vec<Duration> {
+            let mut checked_after = self
+                .checks
+                .calls()
+                .into_iter()
+                .filter(|(url, _)| url.starts_with(prefix))
+                .map(|(_, after)| after)
+                .collect::<Vec<_>>();
+            checked_after.sort();
+            checked_after
+        }
+    }
+
+    impl WebsiteChecker for RecordingWebsiteChecker {
+        async fn check(&self, url: String) -> bool {
+            let after = self.start.elapsed();
+            self.checks.record((url, after));
+            true
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn sut_checks_websites_of_same_host_no_faster_than_limiter_allows() {
+        // Arrange
+        let websites = [
+            "http://google.com/a",
+            "http://google.com/b",
+            "http://google.com/c",
+            "http://blog.gypsydave5.com",
+        ];
+        let checker = RecordingWebsiteChecker::new();
+        let sut = HostLimitedChecker::new(checker.clone(), || {
+            TokenBucket::new(NonZeroU32::MIN, INTERVAL)
+        });
+
+        // Act
+        let actual = check_websites(&websites, sut).await;
+
+        // Assert
+        assert!(actual.values().all(|is_up| *is_up));
+        assert_eq!(
+            vec![Duration::ZERO, INTERVAL, INTERVAL * 2],
+            checker.checked_after("http://google.com")
+        );
+        assert_eq!(
+            vec![Duration::ZERO],
+            checker.checked_after("http://blog.gypsydave5.com")
+        );
+    }
+
+    #[rstest::rstest]
+    #[case::path("http://google.com/search?q=rust", "google.com")]
+    #[case::port("http://localhost:8080", "localhost:8080")]
+    #[case::no_scheme("google.com", "google.com")]
+    fn sut_limits_websites_by_host(#[case] url: &str, #[case] expected: &str) {
+        assert_eq!(expected, super::host(url));
+    }
+
+    #[test]
+    fn sut_shares_limiters_among_clones() {
+        // Arrange
+        let sut = HostLimitedChecker::new(RecordingWebsiteChecker::new(), || {
+            TokenBucket::new(NonZeroU32::MIN, INTERVAL)
+        });
+        let clone = sut.clone();
+
+        // Act
+        let actual = Arc::ptr_eq(&sut.limiter("google.com"), &clone.limiter("google.com"));
+
+        // Assert
+        assert!(actual);
+    }
+}
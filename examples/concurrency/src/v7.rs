@@ -0,0 +1,313 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use random_source::RandomSource;
+use retry::v1::Exponential;
+use retry::v1::Jitter;
+use retry::v1::retry_async_with_sleep;
+use virtual_clock::Clock;
+use virtual_clock::TokioClock;
+
+pub use super::v6::HostLimitedChecker;
+pub use super::v6::WebsiteChecker;
+pub use super::v6::check_websites;
+pub use super::v6::check_websites_in;
+pub use super::v6::check_websites_on;
+
+/// `RetryPolicy` tells a `RetryingWebsiteChecker` how often to check a
+/// website that looks down, and how long to wait in between. The wait
+/// doubles after every attempt, up to the longest delay, and with jitter
+/// is a random part of that.
+#[derive(Clone)]
+pub struct RetryPolicy {
+    backoff: Exponential,
+    jitter: Option<Arc<dyn Fn() -> f64 + Send + Sync>>,
+}
+
+impl RetryPolicy {
+    /// Creates a policy making 3 attempts, waiting 100ms after the first
+    /// and at most 5s, without jitter.
+    pub fn new() -> Self {
+        RetryPolicy {
+            backoff: Exponential {
+                initial: Duration::from_millis(100),
+                max_delay: Duration::from_secs(5),
+                max_attempts: 3,
+            },
+            jitter: None,
+        }
+    }
+
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.backoff.max_attempts = max_attempts;
+        self
+    }
+
+    pub fn with_initial_delay(mut self, initial: Duration) -> Self {
+        self.backoff.initial = initial;
+        self
+    }
+
+    pub fn with_max_delay(mut self, max_delay: Duration) -> Self {
+        self.backoff.max_delay = max_delay;
+        self
+    }
+
+    /// Waits a part of every delay drawn from `source`, so that websites
+    /// failing together are not all checked again at the same moment.
+    pub fn with_jitter(mut self, source: impl RandomSource + 'static) -> Self {
+        self.jitter = Some(Arc::new(move || source.next_f64()));
+        self
+    }
+
+    /// Builds the policy the checker retries with. Without jitter, it
+    /// waits the whole of every delay.
+    fn to_policy(&self) -> Jitter<Exponential> {
+        match self.jitter.clone() {
+            Some(random) => Jitter::with_random(self.backoff, move || random()),
+            None => Jitter::with_random(self.backoff, || 1.0),
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// `RetryingWebsiteChecker` checks a website that looks down again, as its
+/// policy tells, before reporting it down. It waits on its clock, so that
+/// specs handing it a `TestClock` do not wait at all.
+#[derive(Clone)]
+pub struct RetryingWebsiteChecker<C, K = TokioClock> {
+    checker: C,
+    policy: Jitter<Exponential>,
+    clock: K,
+}
+
+impl<C> RetryingWebsiteChecker<C> {
+    pub fn new(checker: C, policy: RetryPolicy) -> Self {
+        RetryingWebsiteChecker {
+            checker,
+            policy: policy.to_policy(),
+            clock: TokioClock,
+        }
+    }
+}
+
+impl<C, K> RetryingWebsiteChecker<C, K> {
+    /// Waits between attempts on `clock` instead.
+    pub fn with_clock<T: Clock>(self, clock: T) -> RetryingWebsiteChecker<C, T> {
+        RetryingWebsiteChecker {
+            checker: self.checker,
+            policy: self.policy,
+            clock,
+        }
+    }
+}
+
+impl<C, K> WebsiteChecker for RetryingWebsiteChecker<C, K>
+where
+    C: WebsiteChecker + Sync,
+    K: Clock + Clone + 'static,
+{
+    async fn check(&self, url: String) -> bool {
+        let sleep = |delay| self.clock.sleep(delay);
+        retry_async_with_sleep(&self.policy, sleep, || {
+            let url = url.clone();
+            async move {
+                if self.checker.check(url).await {
+                    Ok(())
+                } else {
+                    Err(())
+                }
+            }
+        })
+        .await
+        .is_ok()
+    }
+}
+
+#[cfg(test)]
+mod specs_for_retry_policy {
+    use std::time::Duration;
+
+    use random_source::RandomSource;
+    use retry::v1::Policy;
+
+    use super::RetryPolicy;
+
+    /// `Halves` always draws the middle of the range, so jitter waits half
+    /// of every delay.
+    struct Halves;
+
+    impl RandomSource for Halves {
+        fn next_u64(&self) -> u64 {
+            1 << 63
+        }
+    }
+
+    fn delays(policy: &RetryPolicy) -> Vec<Option<u128>> {
+        let policy = policy.to_policy();
+        (1..=5)
+            .map(|attempt| {
+                Policy::<()>::delay(&policy, attempt, &()).map(|delay| delay.as_millis())
+            })
+            .collect()
+    }
+
+    #[rstest::rstest]
+    #[case::default(RetryPolicy::new(), vec![Some(100), Some(200), None, None, None])]
+    #[case::more_attempts(
+        RetryPolicy::new().with_max_attempts(5),
+        vec![Some(100), Some(200), Some(400), Some(800), None],
+    )]
+    #[case::capped(
+        RetryPolicy::new()
+            .with_max_attempts(5)
+            .with_initial_delay(Duration::from_millis(50))
+            .with_max_delay(Duration::from_millis(150)),
+        vec![Some(50), Some(100), Some(150), Some(150), None],
+    )]
+    #[case::jitter(
+        RetryPolicy::new().with_jitter(Halves),
+        vec![Some(50), Some(100), None, None, None],
+    )]
+    fn sut_backs_off_exponentially_as_built(
+        #[case] sut: RetryPolicy,
+        #[case] expected: Vec<Option<u128>>,
+    ) {
+        // Act
+        let actual = delays(&sut);
+
+        // Assert
+        assert_eq!(expected, actual);
+    }
+}
+
+#[cfg(test)]
+mod specs_for_retrying_website_checker {
+    use std::collections::HashMap;
+    use std::time::Duration;
+    use std::time::Instant;
+
+    use test_helpers::CallRecorder;
+    use virtual_clock::Clock;
+    use virtual_clock::TestClock;
+
+    use super::RetryPolicy;
+    use super::RetryingWebsiteChecker;
+    use super::WebsiteChecker;
+    use super::check_websites;
+
+    /// `FlakyWebsiteChecker` reports every website down for its first
+    /// `failures` checks, recording when each check happened.
+    #[derive(Clone)]
+    struct FlakyWebsiteChecker {
+        failures: usize,
+        clock: TestClock,
+        checks: CallRecorder<Instant>,
+    }
+
+    impl FlakyWebsiteChecker {
+        fn new(failures: usize, clock: &TestClock) -> Self {
+            FlakyWebsiteChecker {
+                failures,
+                clock: clock.clone(),
+                checks: CallRecorder::new(),
+            }
+        }
+
+        fn waits(&self) -> Vec<Duration> {
+            self.checks
+                .calls()
+                .windows(2)
+                .map(|pair| pair[1] - pair[0])
+                .collect()
+        }
+    }
+
+    impl WebsiteChecker for FlakyWebsiteChecker {
+        async fn check(&self, _: String) -> bool {
+            self.checks.record(self.clock.now());
+            self.checks.count() > self.failures
+        }
+    }
+
+    #[tokio::test]
+    async fn sut_reports_website_up_once_a_check_succeeds() {
+        // Arrange
+        let clock = TestClock::auto_advancing();
+        let checker = FlakyWebsiteChecker::new(2, &clock);
+        let sut =
+            RetryingWebsiteChecker::new(checker.clone(), RetryPolicy::new()).with_clock(clock);
+
+        // Act
+        let actual = check_websites(&["http://google.com"], sut).await;
+
+        // Assert
+        assert_eq!(HashMap::from([("http://google.com", true)]), actual);
+        assert_eq!(3, checker.checks.count());
+    }
+
+    #[tokio::test]
+    async fn sut_reports_website_down_after_last_attempt() {
+        // Arrange
+        let clock = TestClock::auto_advancing();
+        let checker = FlakyWebsiteChecker::new(usize::MAX, &clock);
+        let policy = RetryPolicy::new().with_max_attempts(4);
+        let sut = RetryingWebsiteChecker::new(checker.clone(), policy).with_clock(clock);
+
+        // Act
+        let actual = sut.check("http://google.com".to_string()).await;
+
+        // Assert
+        assert!(!actual);
+        assert_eq!(4, checker.checks.count());
+    }
+
+    #[tokio::test]
+    async fn sut_waits_longer_after_every_failed_check() {
+        // Arrange
+        let clock = TestClock::auto_advancing();
+        let checker = FlakyWebsiteChecker::new(usize::MAX, &clock);
+        let policy = RetryPolicy::new()
+            .with_max_attempts(4)
+            .with_initial_delay(Duration::from_secs(1));
+        let sut = RetryingWebsiteChecker::new(checker.clone(), policy).with_clock(clock);
+
+        // Act
+        sut.check("http://google.com".to_string()).await;
+
+        // Assert
+        let expected = vec![
+            Duration::from_secs(1),
+            Duration::from_secs(2),
+            Duration::from_secs(4),
+        ];
+        assert_eq!(expected, checker.waits());
+    }
+
+    #[tokio::test]
+    async fn sut_waits_on_its_clock_before_checking_again() {
+        // Arrange
+        let clock = TestClock::new();
+        let checker = FlakyWebsiteChecker::new(1, &clock);
+        let sut = RetryingWebsiteChecker::new(checker.clone(), RetryPolicy::new())
+            .with_clock(clock.clone());
+        let checking =
+            tokio::spawn(async move { sut.check("http://google.com".to_string()).await });
+        while clock.sleepers() == 0 {
+            tokio::task::yield_now().await;
+        }
+
+        // Act
+        clock.advance(Duration::from_millis(100));
+        let actual = checking.await.unwrap();
+
+        // Assert
+        assert!(actual);
+        assert_eq!(2, checker.checks.count());
+    }
+}
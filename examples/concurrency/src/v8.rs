@@ -0,0 +1,620 @@
+use std::collections::HashMap;
+use std::panic::AssertUnwindSafe;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::Context;
+use std::task::Poll;
+use std::task::ready;
+use std::time::Duration;
+
+use cancellation::Interrupted;
+use cancellation::Scope;
+use cancellation::ScopeGuard;
+use executor::JoinError;
+use executor::Spawner;
+use executor::TokioSpawner;
+use futures::FutureExt;
+use futures::Stream;
+use futures::StreamExt;
+use futures::future::join_all;
+use futures::stream::FuturesUnordered;
+use logger::Logger;
+use tokio::time::Instant;
+
+/// `CheckError` is why a website was found down.
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum CheckError {
+    #[error("timed out")]
+    TimedOut,
+
+    #[error("could not connect: {0}")]
+    Connection(String),
+
+    #[error("responded with status {0}")]
+    Status(u16),
+
+    #[error("check panicked")]
+    Panicked,
+}
+
+/// `CheckResult` is what checking a website found: the status it responded
+/// with, if it did, how long the check took, and why the website is down,
+/// if it is.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CheckResult {
+    pub status: Option<u16>,
+    pub latency: Duration,
+    pub error: Option<CheckError>,
+}
+
+impl CheckResult {
+    /// Classifies what a check returned: a website responding with a
+    /// client or server error status is down, just like one that did not
+    /// respond.
+    fn new(response: Result<u16, CheckError>, latency: Duration) -> Self {
+        match response {
+            Ok(status) if status >= 400 => CheckResult {
+                status: Some(status),
+                latency,
+                error: Some(CheckError::Status(status)),
+            },
+            Ok(status) => CheckResult {
+                status: Some(status),
+                latency,
+                error: None,
+            },
+            Err(error) => CheckResult {
+                status: None,
+                latency,
+                error: Some(error),
+            },
+        }
+    }
+
+    pub fn is_up(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// `WebsiteChecker` requests a website and returns the status it responded
+/// with, or why it did not respond.
+pub trait WebsiteChecker: Clone + Send + 'static {
+    fn check(&self, url: String) -> impl Future<Output = Result<u16, CheckError>> + Send;
+}
+
+// `automock` cannot mock the `Clone` the trait requires, so the mock is
+// spelled out.
+#[cfg(test)]
+mockall::mock! {
+    pub WebsiteChecker {}
+
+    impl Clone for WebsiteChecker {
+        fn clone(&self) -> Self;
+    }
+
+    impl WebsiteChecker for WebsiteChecker {
+        async fn check(&self, url: String) -> Result<u16, CheckError>;
+    }
+}
+
+/// `LoggingChecker` logs every check its checker makes: the status of a
+/// website that responded, or why one did not.
+#[derive(Clone)]
+pub struct LoggingChecker<C> {
+    checker: C,
+    logger: Arc<dyn Logger>,
+}
+
+impl<C> LoggingChecker<C> {
+    pub fn new(checker: C, logger: Arc<dyn Logger>) -> Self {
+        LoggingChecker { checker, logger }
+    }
+}
+
+impl<C: WebsiteChecker + Sync> WebsiteChecker for LoggingChecker<C> {
+    async fn check(&self, url: String) -> Result<u16, CheckError> {
+        let response = self.checker.check(url.clone()).await;
+        match &response {
+            Ok(status) => self
+                .logger
+                .info("checked website", &[("url", &url), ("status", status)]),
+            Err(error) => self.logger.warn(
+                "website did not respond",
+                &[("url", &url), ("error", error)],
+            ),
+        }
+        response
+    }
+}
+
+/// Checks every website concurrently, timing each check. With the `tracing`
+/// feature, each one is checked in a `check` span with its `url`, and
+/// whether it is `up`.
+pub async fn check_websites<'a>(
+    urls: &'a [&str],
+    checker: impl WebsiteChecker,
+) -> HashMap<&'a str, CheckResult> {
+    check_websites_in(urls, checker, &Scope::new()).await
+}
+
+/// Checks every website concurrently like `check_websites`, until the scope
+/// is cancelled. The websites still being checked by then are left out.
+pub async fn check_websites_in<'a>(
+    urls: &'a [&str],
+    checker: impl WebsiteChecker,
+    scope: &Scope,
+) -> HashMap<&'a str, CheckResult> {
+    check_websites_on(urls, checker, scope, &TokioSpawner).await
+}
+
+/// Checks every website like `check_websites_in`, each on a task spawned by
+/// `spawner`. A website whose check panicked is reported down with
+/// `CheckError::Panicked`; one whose task the executor dropped is left
+/// out.
+pub async fn check_websites_on<'a>(
+    urls: &'a [&str],
+    checker: impl WebsiteChecker,
+    scope: &Scope,
+    spawner: &impl Spawner,
+) -> HashMap<&'a str, CheckResult> {
+    let handles = urls
+        .iter()
+        .map(|&url| {
+            let check = check(url.to_string(), checker.clone());
+            let scope = scope.clone();
+            spawner.spawn(async move { scope.run(check).await })
+        })
+        .collect::<Vec<_>>();
+    let responses = join_all(handles).await;
+
+    urls.iter()
+        .zip(responses)
+        .filter_map(|(&url, response)| into_result(response).map(|result| (url, result)))
+        .collect::<HashMap<_, _>>()
+}
+
+/// Checks every website concurrently like `check_websites_on`, yielding
+/// each website with its result as soon as its check finishes, so the
+/// fastest websites come first. Dropping the stream cancels the checks
+/// still running.
+pub fn check_websites_stream(
+    urls: &[&str],
+    checker: impl WebsiteChecker,
+    scope: &Scope,
+    spawner: &impl Spawner,
+) -> impl Stream<Item = (String, CheckResult)> + Send + 'static {
+    let scope = scope.child();
+    let checks = urls
+        .iter()
+        .map(|&url| {
+            let url = url.to_string();
+            let check = check(url.clone(), checker.clone());
+            let scope = scope.clone();
+            let handle = spawner.spawn(async move { scope.run(check).await });
+            async move { into_result(handle.await).map(|result| (url, result)) }
+        })
+        .collect::<FuturesUnordered<_>>();
+    CheckStream {
+        checks,
+        _guard: scope.guard(),
+    }
+}
+
+/// `CheckStream` yields the websites whose checks finished, and cancels
+/// the checks still running when dropped.
+struct CheckStream<F> {
+    checks: FuturesUnordered<F>,
+    _guard: ScopeGuard,
+}
+
+impl<F> Stream for CheckStream<F>
+where
+    F: Future<Output = Option<(String, CheckResult)>>,
+{
+    type Item = (String, CheckResult);
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            match ready!(self.checks.poll_next_unpin(cx)) {
+                Some(Some(item)) => return Poll::Ready(Some(item)),
+                Some(None) => continue,
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+}
+
+/// Turns what a spawned check returned into its result. A check that
+/// panicked is reported with `CheckError::Panicked`; one that was cancelled
+/// or dropped by its executor is left out.
+fn into_result(
+    response: Result<Result<CheckResult, Interrupted>, JoinError>,
+) -> Option<CheckResult> {
+    match response {
+        Ok(Ok(result)) => Some(result),
+        Ok(Err(_)) | Err(JoinError::Dropped) => None,
+        Err(JoinError::Panicked) => {
+            Some(CheckResult::new(Err(CheckError::Panicked), Duration::ZERO))
+        }
+    }
+}
+
+/// Checks `url`, timing the check and turning a panic into
+/// `CheckError::Panicked`. With the `tracing` feature, the check runs in a
+/// `check` span.
+fn check(url: String, checker: impl WebsiteChecker) -> impl Future<Output = CheckResult> + Send {
+    #[cfg(feature = "tracing")]
+    let span = tracing::info_span!("check", url, up = tracing::field::Empty);
+    let check = async move {
+        let start = Instant::now();
+        let response = AssertUnwindSafe(checker.check(url))
+            .catch_unwind()
+            .await
+            .unwrap_or(Err(CheckError::Panicked));
+        let result = CheckResult::new(response, start.elapsed());
+        #[cfg(feature = "tracing")]
+        tracing::Span::current().record("up", result.is_up());
+        result
+    };
+    #[cfg(feature = "tracing")]
+    let check = tracing::Instrument::instrument(check, span);
+    check
+}
+
+#[cfg(test)]
+mod specs_for_check_websites {
+    use std::collections::HashMap;
+    use std::time::Duration;
+
+    use cancellation::Scope;
+    use mockall::predicate::eq;
+
+    use super::CheckError;
+    use super::CheckResult;
+    use super::MockWebsiteChecker;
+    use super::WebsiteChecker;
+    use super::check_websites;
+    use super::check_websites_in;
+
+    /// `ScriptedWebsiteChecker` takes the time its script tells to check a
+    /// website, then returns what the script tells.
+    #[derive(Clone)]
+    struct ScriptedWebsiteChecker {
+        script: HashMap<String, (Duration, Result<u16, CheckError>)>,
+    }
+
+    impl ScriptedWebsiteChecker {
+        fn new<const N: usize>(script: [(&str, Duration, Result<u16, CheckError>); N]) -> Self {
+            ScriptedWebsiteChecker {
+                script: script
+                    .into_iter()
+                    .map(|(url, latency, response)| (url.to_string(), (latency, response)))
+                    .collect(),
+            }
+        }
+    }
+
+    impl WebsiteChecker for ScriptedWebsiteChecker {
+        async fn check(&self, url: String) -> Result<u16, CheckError> {
+            let (latency, response) = self.script[&url].clone();
+            tokio::time::sleep(latency).await;
+            response
+        }
+    }
+
+    #[rstest::rstest]
+    #[case::ok(Ok(200), Some(200), None)]
+    #[case::redirect(Ok(301), Some(301), None)]
+    #[case::client_error(Ok(404), Some(404), Some(CheckError::Status(404)))]
+    #[case::server_error(Ok(503), Some(503), Some(CheckError::Status(503)))]
+    #[case::timed_out(Err(CheckError::TimedOut), None, Some(CheckError::TimedOut))]
+    #[case::connection(
+        Err(CheckError::Connection("refused".to_string())),
+        None,
+        Some(CheckError::Connection("refused".to_string())),
+    )]
+    #[tokio::test(start_paused = true)]
+    async fn sut_classifies_what_website_checker_returned(
+        #[case] response: Result<u16, CheckError>,
+        #[case] status: Option<u16>,
+        #[case] error: Option<CheckError>,
+    ) {
+        // Arrange
+        let checker = ScriptedWebsiteChecker::new([(
+            "http://google.com",
+            Duration::from_millis(120),
+            response,
+        )]);
+
+        // Act
+        let actual = check_websites(&["http://google.com"], checker).await;
+
+        // Assert
+        let expected = CheckResult {
+            status,
+            latency: Duration::from_millis(120),
+            error,
+        };
+        assert_eq!(HashMap::from([("http://google.com", expected)]), actual);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn sut_times_each_check_on_its_own() {
+        // Arrange
+        let websites = ["http://fast.com", "http://slow.com"];
+        let checker = ScriptedWebsiteChecker::new([
+            ("http://fast.com", Duration::from_millis(50), Ok(200)),
+            ("http://slow.com", Duration::from_secs(2), Ok(200)),
+        ]);
+
+        // Act
+        let actual = check_websites(&websites, checker).await;
+
+        // Assert
+        assert_eq!(Duration::from_millis(50), actual["http://fast.com"].latency);
+        assert_eq!(Duration::from_secs(2), actual["http://slow.com"].latency);
+    }
+
+    #[tokio::test]
+    async fn sut_reports_website_down_if_its_check_panicked() {
+        // Arrange
+        let mut checker_mock = MockWebsiteChecker::new();
+        checker_mock.expect_clone().returning(|| {
+            let mut clone = MockWebsiteChecker::new();
+            clone
+                .expect_check()
+                .with(eq("http://panicking.com".to_string()))
+                .returning(|url| panic!("cannot check {url}"));
+            clone
+        });
+
+        // Act
+        let actual = check_websites(&["http://panicking.com"], checker_mock).await;
+
+        // Assert
+        let actual = &actual["http://panicking.com"];
+        assert!(!actual.is_up());
+        assert_eq!(Some(CheckError::Panicked), actual.error);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn sut_leaves_out_websites_still_checked_once_cancelled() {
+        // Arrange
+        let websites = ["http://google.com", "http://hanging.com"];
+        let checker = ScriptedWebsiteChecker::new([
+            ("http://google.com", Duration::ZERO, Ok(200)),
+            ("http://hanging.com", Duration::MAX, Ok(200)),
+        ]);
+        let scope = Scope::new();
+
+        // Act
+        let checking = check_websites_in(&websites, checker, &scope);
+        let (actual, ()) = tokio::join!(checking, async {
+            tokio::task::yield_now().await;
+            scope.cancel();
+        });
+
+        // Assert
+        assert_eq!(
+            vec!["http://google.com"],
+            actual.into_keys().collect::<Vec<_>>()
+        );
+    }
+}
+
+#[cfg(test)]
+mod specs_for_check_websites_stream {
+    use std::sync::Arc;
+    use std::sync::atomic::AtomicBool;
+    use std::sync::atomic::Ordering;
+    use std::time::Duration;
+
+    use cancellation::Scope;
+    use executor::TestExecutor;
+    use executor::TokioSpawner;
+    use futures::StreamExt;
+    use tokio::time::Instant;
+
+    use super::CheckError;
+    use super::WebsiteChecker;
+    use super::check_websites_stream;
+
+    /// `DelayedWebsiteChecker` reports every website up, after as many
+    /// tenths of a second as the length of its URL.
+    #[derive(Clone)]
+    struct DelayedWebsiteChecker;
+
+    impl WebsiteChecker for DelayedWebsiteChecker {
+        async fn check(&self, url: String) -> Result<u16, CheckError> {
+            tokio::time::sleep(Duration::from_millis(100) * url.len() as u32).await;
+            Ok(200)
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn sut_yields_fastest_websites_first() {
+        // Arrange
+        let websites = ["http://slowest.com", "http://a.com", "http://slow.com"];
+
+        // Act
+        let actual = check_websites_stream(
+            &websites,
+            DelayedWebsiteChecker,
+            &Scope::new(),
+            &TokioSpawner,
+        )
+        .map(|(url, result)| (url, result.latency))
+        .collect::<Vec<_>>()
+        .await;
+
+        // Assert
+        let expected = vec![
+            ("http://a.com".to_string(), Duration::from_millis(1200)),
+            ("http://slow.com".to_string(), Duration::from_millis(1500)),
+            (
+                "http://slowest.com".to_string(),
+                Duration::from_millis(1800),
+            ),
+        ];
+        assert_eq!(expected, actual);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn sut_yields_a_website_before_slower_ones_finish() {
+        // Arrange
+        let websites = ["http://slowest.com", "http://a.com"];
+        let start = Instant::now();
+        let mut sut = check_websites_stream(
+            &websites,
+            DelayedWebsiteChecker,
+            &Scope::new(),
+            &TokioSpawner,
+        );
+
+        // Act
+        let (url, result) = sut.next().await.unwrap();
+
+        // Assert
+        assert_eq!("http://a.com", url);
+        assert!(result.is_up());
+        assert_eq!(Duration::from_millis(1200), start.elapsed());
+    }
+
+    /// `HangingWebsiteChecker` never finishes a check, and raises its flag
+    /// when the check is dropped.
+    #[derive(Clone)]
+    struct HangingWebsiteChecker {
+        dropped: Arc<AtomicBool>,
+    }
+
+    struct RaiseOnDrop(Arc<AtomicBool>);
+
+    impl Drop for RaiseOnDrop {
+        fn drop(&mut self) {
+            self.0.store(true, Ordering::SeqCst);
+        }
+    }
+
+    impl WebsiteChecker for HangingWebsiteChecker {
+        async fn check(&self, _: String) -> Result<u16, CheckError> {
+            let _flag = RaiseOnDrop(self.dropped.clone());
+            std::future::pending().await
+        }
+    }
+
+    #[tokio::test]
+    async fn sut_stops_pending_checks_when_dropped() {
+        // Arrange
+        let checker = HangingWebsiteChecker {
+            dropped: Arc::new(AtomicBool::new(false)),
+        };
+        let sut = check_websites_stream(
+            &["http://hanging.com"],
+            checker.clone(),
+            &Scope::new(),
+            &TokioSpawner,
+        );
+        tokio::task::yield_now().await;
+
+        // Act
+        drop(sut);
+
+        // Assert
+        tokio::time::timeout(Duration::from_secs(1), async {
+            while !checker.dropped.load(Ordering::SeqCst) {
+                tokio::task::yield_now().await;
+            }
+        })
+        .await
+        .expect("check was not stopped");
+    }
+
+    /// `UpWebsiteChecker` reports every website up at once.
+    #[derive(Clone)]
+    struct UpWebsiteChecker;
+
+    impl WebsiteChecker for UpWebsiteChecker {
+        async fn check(&self, _: String) -> Result<u16, CheckError> {
+            Ok(200)
+        }
+    }
+
+    #[test]
+    fn sut_checks_on_the_given_spawner_without_a_runtime() {
+        // Arrange
+        let executor = TestExecutor::new();
+        let sut = check_websites_stream(
+            &["http://google.com"],
+            UpWebsiteChecker,
+            &Scope::new(),
+            &executor.spawner(),
+        );
+
+        // Act
+        let actual = executor.block_on(
+            sut.map(|(url, result)| (url, result.status))
+                .collect::<Vec<_>>(),
+        );
+
+        // Assert
+        assert_eq!(vec![("http://google.com".to_string(), Some(200))], actual);
+    }
+}
+
+#[cfg(test)]
+mod specs_for_logging_checker {
+    use std::sync::Arc;
+
+    use logger::CaptureLogger;
+    use logger::Fields;
+    use logger::Level;
+    use logger::Record;
+
+    use super::CheckError;
+    use super::LoggingChecker;
+    use super::MockWebsiteChecker;
+    use super::WebsiteChecker;
+
+    #[rstest::rstest]
+    #[case::responded(
+        Ok(200),
+        Level::Info,
+        "checked website",
+        ("status", "200"),
+    )]
+    #[case::did_not_respond(
+        Err(CheckError::TimedOut),
+        Level::Warn,
+        "website did not respond",
+        ("error", "timed out"),
+    )]
+    #[tokio::test]
+    async fn sut_logs_what_check_found(
+        #[case] response: Result<u16, CheckError>,
+        #[case] level: Level,
+        #[case] message: &str,
+        #[case] field: (&str, &str),
+    ) {
+        // Arrange
+        let mut checker_mock = MockWebsiteChecker::new();
+        checker_mock
+            .expect_check()
+            .return_once(move |_| response.clone());
+        let logger = CaptureLogger::new();
+        let sut = LoggingChecker::new(checker_mock, Arc::new(logger.clone()));
+
+        // Act
+        let _ = sut.check("http://google.com".to_string()).await;
+
+        // Assert
+        let expected = vec![Record {
+            level,
+            message: message.to_string(),
+            fields: Fields::from([
+                ("url".to_string(), "http://google.com".to_string()),
+                (field.0.to_string(), field.1.to_string()),
+            ]),
+        }];
+        assert_eq!(expected, logger.records());
+    }
+}
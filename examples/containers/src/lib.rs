@@ -0,0 +1,116 @@
+//! Databases in Docker containers, for specs that need a real one.
+//!
+//! `postgres()` and `redis()` start a container and wait until the database
+//! in it accepts connections, then hand out where to connect to. The
+//! container is removed when its handle is dropped, so a spec keeps the
+//! handle for as long as it talks to the database.
+//!
+//! The specs starting containers need Docker, so they are ignored by
+//! default; run them with `cargo test -- --ignored`.
+
+use testcontainers::ContainerAsync;
+use testcontainers::TestcontainersError;
+use testcontainers::runners::AsyncRunner;
+use testcontainers_modules::postgres::Postgres;
+use testcontainers_modules::redis::REDIS_PORT;
+use testcontainers_modules::redis::Redis;
+
+const POSTGRES_PORT: u16 = 5432;
+
+/// `PostgresContainer` is a running Postgres with a `postgres` database,
+/// user and password.
+pub struct PostgresContainer {
+    _container: ContainerAsync<Postgres>,
+    connection_string: String,
+}
+
+impl PostgresContainer {
+    /// Returns a `postgres://` URL to connect to the database with.
+    pub fn connection_string(&self) -> &str {
+        &self.connection_string
+    }
+}
+
+/// `RedisContainer` is a running Redis.
+pub struct RedisContainer {
+    _container: ContainerAsync<Redis>,
+    url: String,
+}
+
+impl RedisContainer {
+    /// Returns a `redis://` URL to connect to the server with.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+}
+
+/// Starts Postgres, returning once it is ready to accept connections.
+pub async fn postgres() -> Result<PostgresContainer, TestcontainersError> {
+    let container = Postgres::default().start().await?;
+    let host = container.get_host().await?;
+    let port = container.get_host_port_ipv4(POSTGRES_PORT).await?;
+    Ok(PostgresContainer {
+        _container: container,
+        connection_string: format!("postgres://postgres:postgres@{}:{}/postgres", host, port),
+    })
+}
+
+/// Starts Redis, returning once it is ready to accept connections.
+pub async fn redis() -> Result<RedisContainer, TestcontainersError> {
+    let container = Redis::default().start().await?;
+    let host = container.get_host().await?;
+    let port = container.get_host_port_ipv4(REDIS_PORT).await?;
+    Ok(RedisContainer {
+        _container: container,
+        url: format!("redis://{}:{}", host, port),
+    })
+}
+
+#[cfg(test)]
+mod specs_for_containers {
+    use super::postgres;
+    use super::redis;
+
+    #[tokio::test]
+    #[ignore = "needs Docker"]
+    async fn sut_hands_out_postgres_ready_for_queries() {
+        // Arrange
+        let container = postgres().await.unwrap();
+        let (client, connection) =
+            tokio_postgres::connect(container.connection_string(), tokio_postgres::NoTls)
+                .await
+                .unwrap();
+        tokio::spawn(connection);
+
+        // Act
+        let actual: i32 = client.query_one("SELECT 1", &[]).await.unwrap().get(0);
+
+        // Assert
+        assert_eq!(1, actual);
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Docker"]
+    async fn sut_hands_out_redis_ready_for_commands() {
+        // Arrange
+        let container = redis().await.unwrap();
+        let client = ::redis::Client::open(container.url()).unwrap();
+        let mut connection = client.get_multiplexed_async_connection().await.unwrap();
+
+        // Act
+        let _: () = ::redis::cmd("SET")
+            .arg("greeting")
+            .arg("hello")
+            .query_async(&mut connection)
+            .await
+            .unwrap();
+        let actual: String = ::redis::cmd("GET")
+            .arg("greeting")
+            .query_async(&mut connection)
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!("hello", actual);
+    }
+}
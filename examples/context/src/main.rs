@@ -0,0 +1,33 @@
+use std::io::stdout;
+use std::time::Duration;
+
+use context::v2::Cancelled;
+use context::v2::Store;
+use context::v2::handle;
+use tokio_util::sync::CancellationToken;
+
+const PATIENCE: Duration = Duration::from_secs(1);
+
+/// `SlowStore` takes longer to fetch than the client is willing to wait.
+struct SlowStore;
+
+impl Store for SlowStore {
+    async fn fetch(&self, token: &CancellationToken) -> Result<String, Cancelled> {
+        tokio::select! {
+            () = tokio::time::sleep(PATIENCE * 2) => Ok("hello, world\n".to_string()),
+            () = token.cancelled() => Err(Cancelled),
+        }
+    }
+}
+
+#[tokio::main]
+async fn main() -> std::io::Result<()> {
+    let token = CancellationToken::new();
+    let client = token.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(PATIENCE).await;
+        println!("client went away");
+        client.cancel();
+    });
+    handle(&SlowStore, &token, &mut stdout()).await
+}
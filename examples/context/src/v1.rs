@@ -0,0 +1,103 @@
+use std::io::Write;
+
+use tokio_util::sync::CancellationToken;
+
+/// `Store` fetches the data a request asks for, which may take a while.
+pub trait Store {
+    fn fetch(&self) -> impl Future<Output = String> + Send;
+    /// Stops the work behind a fetch nobody waits for anymore.
+    fn cancel(&self);
+}
+
+/// Answers a request with the data of the store.
+///
+/// If the client goes away, which cancels the token, before the data is
+/// there, the store is told to cancel and nothing is written.
+pub async fn handle(
+    store: &impl Store,
+    token: &CancellationToken,
+    response: &mut dyn Write,
+) -> std::io::Result<()> {
+    tokio::select! {
+        data = store.fetch() => response.write_all(data.as_bytes()),
+        () = token.cancelled() => {
+            store.cancel();
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod specs_for_handle {
+    use std::sync::atomic::AtomicBool;
+    use std::sync::atomic::Ordering;
+    use std::time::Duration;
+
+    use tokio_util::sync::CancellationToken;
+
+    use super::Store;
+    use super::handle;
+
+    const DATA: &str = "hello, world";
+
+    struct SpyStore {
+        response: String,
+        cancelled: AtomicBool,
+    }
+
+    impl SpyStore {
+        fn new(response: &str) -> Self {
+            SpyStore {
+                response: response.to_string(),
+                cancelled: AtomicBool::new(false),
+            }
+        }
+    }
+
+    impl Store for SpyStore {
+        async fn fetch(&self) -> String {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            self.response.clone()
+        }
+
+        fn cancel(&self) {
+            self.cancelled.store(true, Ordering::SeqCst);
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn sut_returns_data_from_store() {
+        // Arrange
+        let store = SpyStore::new(DATA);
+        let mut response = vec![];
+
+        // Act
+        handle(&store, &CancellationToken::new(), &mut response)
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(DATA.as_bytes(), response);
+        assert!(!store.cancelled.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn sut_tells_store_to_cancel_if_request_is_cancelled() {
+        // Arrange
+        let store = SpyStore::new(DATA);
+        let token = CancellationToken::new();
+        let mut response = vec![];
+        let client = token.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+            client.cancel();
+        });
+
+        // Act
+        handle(&store, &token, &mut response).await.unwrap();
+
+        // Assert
+        assert!(store.cancelled.load(Ordering::SeqCst));
+        assert!(response.is_empty());
+    }
+}
@@ -0,0 +1,134 @@
+use std::io::Write;
+
+use tokio_util::sync::CancellationToken;
+
+/// `Cancelled` is returned by a fetch that stopped because its token was
+/// cancelled.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+#[error("fetch was cancelled")]
+pub struct Cancelled;
+
+/// `Store` fetches the data a request asks for, which may take a while.
+///
+/// The fetch is handed the token of the request, so that the store itself
+/// notices when nobody waits for the data anymore.
+pub trait Store {
+    fn fetch(
+        &self,
+        token: &CancellationToken,
+    ) -> impl Future<Output = Result<String, Cancelled>> + Send;
+}
+
+/// Answers a request with the data of the store. Nothing is written if the
+/// fetch was cancelled, as there is no client left to read it.
+pub async fn handle(
+    store: &impl Store,
+    token: &CancellationToken,
+    response: &mut dyn Write,
+) -> std::io::Result<()> {
+    match store.fetch(token).await {
+        Ok(data) => response.write_all(data.as_bytes()),
+        Err(Cancelled) => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod specs_for_handle {
+    use std::io::Write;
+    use std::sync::atomic::AtomicBool;
+    use std::sync::atomic::Ordering;
+    use std::time::Duration;
+
+    use tokio_util::sync::CancellationToken;
+
+    use super::Cancelled;
+    use super::Store;
+    use super::handle;
+
+    const DATA: &str = "hello, world";
+
+    /// `SpyStore` builds its response one character at a time, giving up as
+    /// soon as the token is cancelled.
+    struct SpyStore {
+        response: String,
+        cancelled: AtomicBool,
+    }
+
+    impl SpyStore {
+        fn new(response: &str) -> Self {
+            SpyStore {
+                response: response.to_string(),
+                cancelled: AtomicBool::new(false),
+            }
+        }
+    }
+
+    impl Store for SpyStore {
+        async fn fetch(&self, token: &CancellationToken) -> Result<String, Cancelled> {
+            let mut result = String::new();
+            for c in self.response.chars() {
+                tokio::select! {
+                    () = tokio::time::sleep(Duration::from_millis(10)) => result.push(c),
+                    () = token.cancelled() => {
+                        self.cancelled.store(true, Ordering::SeqCst);
+                        return Err(Cancelled);
+                    }
+                }
+            }
+            Ok(result)
+        }
+    }
+
+    /// `SpyResponseWriter` remembers whether anything was written to it.
+    #[derive(Default)]
+    struct SpyResponseWriter {
+        written: bool,
+    }
+
+    impl Write for SpyResponseWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.written = true;
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn sut_returns_data_from_store() {
+        // Arrange
+        let store = SpyStore::new(DATA);
+        let mut response = vec![];
+
+        // Act
+        handle(&store, &CancellationToken::new(), &mut response)
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(DATA.as_bytes(), response);
+        assert!(!store.cancelled.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn sut_stops_store_and_writes_nothing_if_request_is_cancelled() {
+        // Arrange
+        let store = SpyStore::new(DATA);
+        let token = CancellationToken::new();
+        let mut response = SpyResponseWriter::default();
+        let client = token.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+            client.cancel();
+        });
+
+        // Act
+        handle(&store, &token, &mut response).await.unwrap();
+
+        // Assert
+        assert!(store.cancelled.load(Ordering::SeqCst));
+        assert!(!response.written);
+    }
+}
@@ -0,0 +1,64 @@
+use std::io;
+use std::io::Write;
+
+/// `GreetError` is why a greeting could not be written.
+#[derive(Debug, thiserror::Error)]
+pub enum GreetError {
+    #[error("failed to write greeting: {0}")]
+    Write(#[from] io::Error),
+}
+
+/// Greets `name` like `v1::greet`, returning the error of the writer
+/// instead of panicking on it.
+pub fn greet(writer: &mut dyn Write, name: &str) -> Result<(), GreetError> {
+    let greeting = format!("Hello, {}!", name);
+    writer.write_all(greeting.as_bytes())?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod specs_for_greet {
+    use std::io;
+    use std::io::Write;
+
+    use super::GreetError;
+    use super::greet;
+
+    #[test]
+    fn sut_writes_greeting_to_bytes_buffer_correctly() {
+        // Arrange
+        let mut buffer: Vec<u8> = Vec::new();
+
+        // Act
+        greet(&mut buffer, "Chris").unwrap();
+
+        // Assert
+        let actual = String::from_utf8(buffer).unwrap();
+        let expected = "Hello, Chris!";
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn sut_returns_write_error_if_writer_fails() {
+        // Act
+        let actual = greet(&mut BrokenWriter, "Chris").unwrap_err();
+
+        // Assert
+        assert!(matches!(
+            actual,
+            GreetError::Write(ref error) if error.kind() == io::ErrorKind::BrokenPipe
+        ));
+    }
+
+    struct BrokenWriter;
+
+    impl Write for BrokenWriter {
+        fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+            Err(io::ErrorKind::BrokenPipe.into())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use std::env;
+use std::process::ExitCode;
+
+use diamond::v1::diamond;
+
+fn main() -> ExitCode {
+    let letter = env::args()
+        .nth(1)
+        .and_then(|arg| arg.chars().next())
+        .unwrap_or('E');
+    match diamond(letter) {
+        Ok(diamond) => {
+            println!("{diamond}");
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("{error}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,133 @@
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum DiamondError {
+    #[error("expected a letter from A to Z, found {0:?}")]
+    NotALetter(char),
+}
+
+/// Returns the diamond for `letter`, a row per line, starting and ending
+/// with `A` and widest at `letter`:
+///
+/// ```text
+/// __A__
+/// _B_B_
+/// C___C
+/// _B_B_
+/// __A__
+/// ```
+///
+/// Spaces, drawn as `_` above, pad every row to the same width, so the
+/// diamond is as wide as it is tall. A lowercase letter draws a lowercase
+/// diamond.
+pub fn diamond(letter: char) -> Result<String, DiamondError> {
+    if !letter.is_ascii_alphabetic() {
+        return Err(DiamondError::NotALetter(letter));
+    }
+    let first = if letter.is_ascii_uppercase() {
+        b'A'
+    } else {
+        b'a'
+    };
+    let size = (letter as u8 - first) as usize;
+
+    let rows = (0..=size).chain((0..size).rev()).map(|i| {
+        let mut row = vec![' '; 2 * size + 1];
+        let letter = (first + i as u8) as char;
+        row[size - i] = letter;
+        row[size + i] = letter;
+        row.into_iter().collect::<String>()
+    });
+    Ok(rows.collect::<Vec<_>>().join("\n"))
+}
+
+#[cfg(test)]
+mod specs_for_diamond {
+    use super::DiamondError;
+    use super::diamond;
+
+    #[rstest::rstest]
+    #[case::a('A', "A")]
+    #[case::b('B', " A \nB B\n A ")]
+    #[case::c('C', "  A  \n B B \nC   C\n B B \n  A  ")]
+    #[case::lowercase('b', " a \nb b\n a ")]
+    fn sut_draws_diamond_for_letter(#[case] letter: char, #[case] expected: &str) {
+        assert_eq!(Ok(expected.to_string()), diamond(letter));
+    }
+
+    #[rstest::rstest]
+    #[case::digit('1')]
+    #[case::space(' ')]
+    #[case::punctuation('!')]
+    #[case::non_ascii_letter('Ä')]
+    fn sut_rejects_anything_but_letter(#[case] letter: char) {
+        assert_eq!(Err(DiamondError::NotALetter(letter)), diamond(letter));
+    }
+}
+
+#[cfg(test)]
+mod properties_of_diamond {
+    use proptest::prelude::*;
+
+    use super::diamond;
+
+    fn rows(letter: char) -> Vec<String> {
+        diamond(letter)
+            .unwrap()
+            .lines()
+            .map(str::to_string)
+            .collect()
+    }
+
+    proptest! {
+        #[test]
+        fn is_as_wide_as_it_is_tall(letter in "[A-Za-z]") {
+            let letter = letter.chars().next().unwrap();
+            let rows = rows(letter);
+            for row in &rows {
+                prop_assert_eq!(rows.len(), row.chars().count());
+            }
+        }
+
+        #[test]
+        fn is_symmetric_from_top_to_bottom(letter in "[A-Za-z]") {
+            let letter = letter.chars().next().unwrap();
+            let rows = rows(letter);
+            let reversed = rows.iter().rev().cloned().collect::<Vec<_>>();
+            prop_assert_eq!(reversed, rows);
+        }
+
+        #[test]
+        fn is_symmetric_from_left_to_right(letter in "[A-Za-z]") {
+            let letter = letter.chars().next().unwrap();
+            for row in rows(letter) {
+                let reversed = row.chars().rev().collect::<String>();
+                prop_assert_eq!(reversed, row);
+            }
+        }
+
+        #[test]
+        fn is_widest_at_input_letter(letter in "[A-Za-z]") {
+            let letter = letter.chars().next().unwrap();
+            let rows = rows(letter);
+            let middle = &rows[rows.len() / 2];
+            prop_assert!(middle.starts_with(letter));
+            prop_assert!(middle.ends_with(letter));
+        }
+
+        #[test]
+        fn has_two_letters_per_row_but_first_and_last(letter in "[A-Za-z]") {
+            let letter = letter.chars().next().unwrap();
+            let rows = rows(letter);
+            for (i, row) in rows.iter().enumerate() {
+                let letters = row.chars().filter(|c| *c != ' ').count();
+                let expected = if i == 0 || i == rows.len() - 1 { 1 } else { 2 };
+                prop_assert_eq!(expected, letters);
+            }
+        }
+
+        #[test]
+        fn rejects_anything_but_letter(character in any::<char>()) {
+            prop_assume!(!character.is_ascii_alphabetic());
+            prop_assert!(diamond(character).is_err());
+        }
+    }
+}
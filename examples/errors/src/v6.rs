@@ -0,0 +1,327 @@
+use std::ops::Bound;
+use std::ops::RangeBounds;
+use std::time::Instant;
+
+use logger::Logger;
+use virtual_clock::Clock;
+use virtual_clock::SystemClock;
+
+pub use super::v5::BitCoin;
+
+/// Whether a transaction put money in or took it out.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TransactionKind {
+    Deposit,
+    Withdrawal,
+}
+
+/// `Transaction` is a change to the balance, recorded in the ledger when it
+/// is made.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Transaction {
+    pub kind: TransactionKind,
+    pub amount: BitCoin,
+    pub timestamp: Instant,
+}
+
+/// `Wallet` keeps a ledger of every deposit and withdrawal, timed by its
+/// clock. A withdrawal that failed changed nothing, so it is not recorded.
+/// Given a logger, it also logs every transaction and refused withdrawal.
+pub struct Wallet<C = SystemClock> {
+    balance: BitCoin,
+    ledger: Vec<Transaction>,
+    clock: C,
+    logger: Option<Box<dyn Logger>>,
+}
+
+impl Wallet {
+    pub fn open() -> Self {
+        Self::open_with_clock(SystemClock)
+    }
+}
+
+impl<C: Clock> Wallet<C> {
+    pub fn open_with_clock(clock: C) -> Self {
+        Self {
+            balance: 0,
+            ledger: vec![],
+            clock,
+            logger: None,
+        }
+    }
+
+    pub fn with_logger(self, logger: impl Logger + 'static) -> Self {
+        Self {
+            logger: Some(Box::new(logger)),
+            ..self
+        }
+    }
+
+    pub fn deposit(&mut self, amount: BitCoin) {
+        self.balance += amount;
+        self.record(TransactionKind::Deposit, amount);
+    }
+
+    pub fn withdraw(&mut self, amount: BitCoin) -> Result<(), WalletError> {
+        if amount > self.balance {
+            if let Some(logger) = &self.logger {
+                logger.warn(
+                    "withdrawal refused",
+                    &[("requested", &amount), ("balance", &self.balance)],
+                );
+            }
+            return Err(WalletError::InsufficientFunds {
+                requested: amount,
+                balance: self.balance,
+            });
+        }
+        self.balance -= amount;
+        self.record(TransactionKind::Withdrawal, amount);
+        Ok(())
+    }
+
+    pub fn balance(&self) -> BitCoin {
+        self.balance
+    }
+
+    /// Returns every transaction, the oldest first.
+    pub fn history(&self) -> &[Transaction] {
+        &self.ledger
+    }
+
+    /// Returns the transactions made within `window`, the oldest first.
+    pub fn statement(&self, window: impl RangeBounds<Instant>) -> &[Transaction] {
+        // The clock never goes back, so the ledger is sorted by timestamp.
+        let start = match window.start_bound() {
+            Bound::Included(start) => self.ledger.partition_point(|t| t.timestamp < *start),
+            Bound::Excluded(start) => self.ledger.partition_point(|t| t.timestamp <= *start),
+            Bound::Unbounded => 0,
+        };
+        let end = match window.end_bound() {
+            Bound::Included(end) => self.ledger.partition_point(|t| t.timestamp <= *end),
+            Bound::Excluded(end) => self.ledger.partition_point(|t| t.timestamp < *end),
+            Bound::Unbounded => self.ledger.len(),
+        };
+        &self.ledger[start..end.max(start)]
+    }
+
+    fn record(&mut self, kind: TransactionKind, amount: BitCoin) {
+        if let Some(logger) = &self.logger {
+            let message = match kind {
+                TransactionKind::Deposit => "deposited",
+                TransactionKind::Withdrawal => "withdrew",
+            };
+            logger.info(message, &[("amount", &amount), ("balance", &self.balance)]);
+        }
+        self.ledger.push(Transaction {
+            kind,
+            amount,
+            timestamp: self.clock.now(),
+        });
+    }
+}
+
+/// `WalletError` tells what was asked of the wallet and what it held,
+/// rather than a message.
+#[derive(Debug, thiserror::Error)]
+pub enum WalletError {
+    #[error("cannot withdraw {requested}, insufficient funds in balance {balance}")]
+    InsufficientFunds {
+        requested: BitCoin,
+        balance: BitCoin,
+    },
+}
+
+#[cfg(test)]
+mod specs_for_wallet_error {
+    use virtual_clock::TestClock;
+
+    use super::Wallet;
+    use super::WalletError;
+
+    #[test]
+    fn sut_returns_requested_amount_and_balance_if_funds_are_insufficient() {
+        // Arrange
+        let mut wallet = Wallet::open_with_clock(TestClock::new());
+        wallet.deposit(20);
+
+        // Act
+        let actual = wallet.withdraw(30).unwrap_err();
+
+        // Assert
+        assert!(matches!(
+            actual,
+            WalletError::InsufficientFunds {
+                requested: 30,
+                balance: 20,
+            }
+        ));
+        assert_eq!(
+            actual.to_string(),
+            "cannot withdraw 30, insufficient funds in balance 20"
+        );
+    }
+}
+
+#[cfg(test)]
+mod specs_for_wallet_logging {
+    use logger::CaptureLogger;
+    use logger::Fields;
+    use logger::Level;
+    use logger::Record;
+    use virtual_clock::TestClock;
+
+    use super::Wallet;
+
+    fn fields(amount: &str, balance: &str) -> Fields {
+        Fields::from([
+            ("amount".to_string(), amount.to_string()),
+            ("balance".to_string(), balance.to_string()),
+        ])
+    }
+
+    #[test]
+    fn sut_logs_every_transaction_with_balance_after_it() {
+        // Arrange
+        let logger = CaptureLogger::new();
+        let mut wallet = Wallet::open_with_clock(TestClock::new()).with_logger(logger.clone());
+
+        // Act
+        wallet.deposit(20);
+        wallet.withdraw(5).unwrap();
+
+        // Assert
+        let expected = vec![
+            Record {
+                level: Level::Info,
+                message: "deposited".to_string(),
+                fields: fields("20", "20"),
+            },
+            Record {
+                level: Level::Info,
+                message: "withdrew".to_string(),
+                fields: fields("5", "15"),
+            },
+        ];
+        assert_eq!(expected, logger.records());
+    }
+
+    #[test]
+    fn sut_warns_of_withdrawal_refused_for_insufficient_funds() {
+        // Arrange
+        let logger = CaptureLogger::new();
+        let mut wallet = Wallet::open_with_clock(TestClock::new()).with_logger(logger.clone());
+        wallet.deposit(20);
+
+        // Act
+        let _ = wallet.withdraw(30);
+
+        // Assert
+        let expected = Record {
+            level: Level::Warn,
+            message: "withdrawal refused".to_string(),
+            fields: Fields::from([
+                ("requested".to_string(), "30".to_string()),
+                ("balance".to_string(), "20".to_string()),
+            ]),
+        };
+        assert_eq!(Some(&expected), logger.records().last());
+    }
+}
+
+#[cfg(test)]
+mod specs_for_wallet_history {
+    use std::time::Duration;
+
+    use virtual_clock::Clock;
+    use virtual_clock::TestClock;
+
+    use super::Transaction;
+    use super::TransactionKind;
+    use super::Wallet;
+
+    #[test]
+    fn sut_records_every_deposit_and_withdrawal_at_time_made() {
+        // Arrange
+        let clock = TestClock::new();
+        let mut wallet = Wallet::open_with_clock(clock.clone());
+        let opened = clock.now();
+
+        // Act
+        wallet.deposit(20);
+        clock.advance(Duration::from_secs(60));
+        wallet.withdraw(5).unwrap();
+
+        // Assert
+        let expected = [
+            Transaction {
+                kind: TransactionKind::Deposit,
+                amount: 20,
+                timestamp: opened,
+            },
+            Transaction {
+                kind: TransactionKind::Withdrawal,
+                amount: 5,
+                timestamp: opened + Duration::from_secs(60),
+            },
+        ];
+        assert_eq!(expected, wallet.history());
+    }
+
+    #[test]
+    fn sut_does_not_record_failed_withdrawal() {
+        // Arrange
+        let mut wallet = Wallet::open_with_clock(TestClock::new());
+        wallet.deposit(20);
+
+        // Act
+        let _ = wallet.withdraw(30);
+
+        // Assert
+        assert_eq!(1, wallet.history().len());
+        assert_eq!(20, wallet.balance());
+    }
+}
+
+#[cfg(test)]
+mod specs_for_wallet_statement {
+    use std::ops::Bound;
+    use std::ops::Bound::Excluded;
+    use std::ops::Bound::Included;
+    use std::ops::Bound::Unbounded;
+    use std::time::Duration;
+
+    use virtual_clock::Clock;
+    use virtual_clock::TestClock;
+
+    use super::Wallet;
+
+    #[rstest::rstest]
+    #[case::half_open(Included(60), Excluded(180), vec![2, 3])]
+    #[case::closed(Included(60), Included(180), vec![2, 3, 4])]
+    #[case::from(Included(120), Unbounded, vec![3, 4])]
+    #[case::until(Unbounded, Excluded(60), vec![1])]
+    #[case::empty(Excluded(180), Excluded(180), vec![])]
+    fn sut_returns_transactions_made_within_window(
+        #[case] from: Bound<u64>,
+        #[case] until: Bound<u64>,
+        #[case] expected: Vec<u64>,
+    ) {
+        // Arrange
+        let clock = TestClock::new();
+        let start = clock.now();
+        let mut wallet = Wallet::open_with_clock(clock.clone());
+        for amount in 1..=4 {
+            wallet.deposit(amount);
+            clock.advance(Duration::from_secs(60));
+        }
+        let at = |seconds: Bound<u64>| seconds.map(|s| start + Duration::from_secs(s));
+
+        // Act
+        let actual = wallet.statement((at(from), at(until)));
+
+        // Assert
+        let actual = actual.iter().map(|t| t.amount).collect::<Vec<_>>();
+        assert_eq!(expected, actual);
+    }
+}
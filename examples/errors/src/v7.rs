@@ -0,0 +1,442 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The currencies a wallet can hold.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Currency {
+    BitCoin,
+    Ether,
+    Dollar,
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let code = match self {
+            Currency::BitCoin => "BTC",
+            Currency::Ether => "ETH",
+            Currency::Dollar => "USD",
+        };
+        f.write_str(code)
+    }
+}
+
+/// `Money` is an amount in a currency. Amounts in different currencies
+/// cannot be added up without converting one of them first.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Money {
+    pub amount: u64,
+    pub currency: Currency,
+}
+
+impl Money {
+    pub fn new(amount: u64, currency: Currency) -> Self {
+        Money { amount, currency }
+    }
+
+    pub fn checked_add(self, other: Money) -> Result<Money, WalletError> {
+        if self.currency != other.currency {
+            return Err(WalletError::MixedCurrencies {
+                left: self.currency,
+                right: other.currency,
+            });
+        }
+        let amount = self
+            .amount
+            .checked_add(other.amount)
+            .ok_or(WalletError::Overflow(self.currency))?;
+        Ok(Money::new(amount, self.currency))
+    }
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.amount, self.currency)
+    }
+}
+
+/// `ExchangeRateProvider` tells how much of a currency one unit of another
+/// is worth.
+pub trait ExchangeRateProvider {
+    /// Returns how many units of `to` one unit of `from` buys, or `None`
+    /// if the pair is not traded.
+    fn rate(&self, from: Currency, to: Currency) -> Option<f64>;
+}
+
+/// `Wallet` holds a balance in each currency deposited. It serializes as
+/// its balances by currency.
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Wallet {
+    balances: BTreeMap<Currency, u64>,
+}
+
+impl Wallet {
+    pub fn open() -> Self {
+        Self::default()
+    }
+
+    pub fn deposit(&mut self, money: Money) {
+        *self.balances.entry(money.currency).or_default() += money.amount;
+    }
+
+    pub fn withdraw(&mut self, money: Money) -> Result<(), WalletError> {
+        let Some(balance) = self.balances.get_mut(&money.currency) else {
+            return Err(WalletError::CurrencyNotHeld(money.currency));
+        };
+        if money.amount > *balance {
+            return Err(WalletError::InsufficientFunds {
+                requested: money,
+                balance: Money::new(*balance, money.currency),
+            });
+        }
+        *balance -= money.amount;
+        Ok(())
+    }
+
+    /// Returns the balance in `currency`, which is nothing if it was never
+    /// deposited.
+    pub fn balance(&self, currency: Currency) -> Money {
+        let amount = self.balances.get(&currency).copied().unwrap_or_default();
+        Money::new(amount, currency)
+    }
+
+    /// Returns the state of the wallet as JSON, to be restored later.
+    pub fn snapshot(&self) -> Vec<u8> {
+        serde_json::to_vec(self).expect("balances by currency always serialize")
+    }
+
+    /// Rehydrates the wallet a snapshot was taken of.
+    pub fn restore(snapshot: &[u8]) -> Result<Wallet, RestoreError> {
+        Ok(serde_json::from_slice(snapshot)?)
+    }
+
+    /// Withdraws `money` and deposits what it is worth in `to`, rounded
+    /// down. Returns the money deposited.
+    pub fn exchange(
+        &mut self,
+        money: Money,
+        to: Currency,
+        rates: &impl ExchangeRateProvider,
+    ) -> Result<Money, WalletError> {
+        let converted = convert(money, to, rates)?;
+        self.withdraw(money)?;
+        self.deposit(converted);
+        Ok(converted)
+    }
+
+    /// Returns what every balance is worth in `currency`, added up.
+    pub fn total(
+        &self,
+        currency: Currency,
+        rates: &impl ExchangeRateProvider,
+    ) -> Result<Money, WalletError> {
+        self.balances
+            .iter()
+            .map(|(&held, &amount)| convert(Money::new(amount, held), currency, rates))
+            .try_fold(Money::new(0, currency), |total, money| {
+                total.checked_add(money?)
+            })
+    }
+}
+
+fn convert(
+    money: Money,
+    to: Currency,
+    rates: &impl ExchangeRateProvider,
+) -> Result<Money, WalletError> {
+    if money.currency == to {
+        return Ok(money);
+    }
+    let rate = rates
+        .rate(money.currency, to)
+        .ok_or(WalletError::RateUnavailable {
+            from: money.currency,
+            to,
+        })?;
+    Ok(Money::new((money.amount as f64 * rate).floor() as u64, to))
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum WalletError {
+    #[error("cannot withdraw {requested}, insufficient funds in balance {balance}")]
+    InsufficientFunds { requested: Money, balance: Money },
+
+    #[error("cannot withdraw in {0}, which the wallet does not hold")]
+    CurrencyNotHeld(Currency),
+
+    #[error("cannot add {left} to {right} without converting one of them")]
+    MixedCurrencies { left: Currency, right: Currency },
+
+    #[error("no exchange rate from {from} to {to}")]
+    RateUnavailable { from: Currency, to: Currency },
+
+    #[error("the sum in {0} is too large to hold")]
+    Overflow(Currency),
+}
+
+/// `RestoreError` is why a wallet could not be restored from a snapshot.
+#[derive(Debug, thiserror::Error)]
+#[error("failed to restore wallet from snapshot: {0}")]
+pub struct RestoreError(#[from] serde_json::Error);
+
+#[cfg(test)]
+mod specs_for_wallet_snapshot {
+    use super::Currency;
+    use super::Money;
+    use super::Wallet;
+
+    fn wallet() -> Wallet {
+        let mut wallet = Wallet::open();
+        wallet.deposit(Money::new(20, Currency::BitCoin));
+        wallet.deposit(Money::new(3, Currency::Ether));
+        wallet
+    }
+
+    #[test]
+    fn sut_restores_wallet_snapshot_was_taken_of() {
+        // Arrange
+        let wallet = wallet();
+
+        // Act
+        let actual = Wallet::restore(&wallet.snapshot()).unwrap();
+
+        // Assert
+        assert_eq!(wallet, actual);
+    }
+
+    #[test]
+    fn sut_takes_snapshot_of_balances_by_currency() {
+        // Arrange
+        let wallet = wallet();
+
+        // Act
+        let actual = String::from_utf8(wallet.snapshot()).unwrap();
+
+        // Assert
+        assert_eq!(r#"{"balances":{"BitCoin":20,"Ether":3}}"#, actual);
+    }
+
+    #[test]
+    fn sut_returns_error_if_snapshot_is_not_of_wallet() {
+        // Act
+        let actual = Wallet::restore(br#"{"balances":{"Euro":20}}"#).unwrap_err();
+
+        // Assert
+        assert!(
+            actual
+                .to_string()
+                .starts_with("failed to restore wallet from snapshot")
+        );
+    }
+}
+
+#[cfg(test)]
+mod specs_for_money {
+    use super::Currency;
+    use super::Money;
+    use super::WalletError;
+
+    #[test]
+    fn sut_adds_up_amounts_in_same_currency() {
+        // Arrange
+        let money = Money::new(20, Currency::BitCoin);
+
+        // Act
+        let actual = money.checked_add(Money::new(5, Currency::BitCoin));
+
+        // Assert
+        assert_eq!(Ok(Money::new(25, Currency::BitCoin)), actual);
+    }
+
+    #[test]
+    fn sut_returns_error_if_adding_up_amounts_in_different_currencies() {
+        // Arrange
+        let money = Money::new(20, Currency::BitCoin);
+
+        // Act
+        let actual = money
+            .checked_add(Money::new(5, Currency::Ether))
+            .unwrap_err();
+
+        // Assert
+        let expected = WalletError::MixedCurrencies {
+            left: Currency::BitCoin,
+            right: Currency::Ether,
+        };
+        assert_eq!(expected, actual);
+        assert_eq!(
+            "cannot add BTC to ETH without converting one of them",
+            actual.to_string()
+        );
+    }
+
+    #[test]
+    fn sut_returns_error_if_sum_is_too_large_to_hold() {
+        // Arrange
+        let money = Money::new(u64::MAX, Currency::BitCoin);
+
+        // Act
+        let actual = money
+            .checked_add(Money::new(1, Currency::BitCoin))
+            .unwrap_err();
+
+        // Assert
+        assert_eq!(WalletError::Overflow(Currency::BitCoin), actual);
+        assert_eq!("the sum in BTC is too large to hold", actual.to_string());
+    }
+}
+
+#[cfg(test)]
+mod specs_for_wallet {
+    use std::collections::HashMap;
+
+    use super::Currency;
+    use super::ExchangeRateProvider;
+    use super::Money;
+    use super::Wallet;
+    use super::WalletError;
+
+    /// `StubRates` trades only the pairs it was given.
+    struct StubRates(HashMap<(Currency, Currency), f64>);
+
+    impl ExchangeRateProvider for StubRates {
+        fn rate(&self, from: Currency, to: Currency) -> Option<f64> {
+            self.0.get(&(from, to)).copied()
+        }
+    }
+
+    fn rates() -> StubRates {
+        StubRates(HashMap::from([
+            ((Currency::BitCoin, Currency::Dollar), 60_000.0),
+            ((Currency::Ether, Currency::Dollar), 2_500.5),
+        ]))
+    }
+
+    fn btc(amount: u64) -> Money {
+        Money::new(amount, Currency::BitCoin)
+    }
+
+    fn usd(amount: u64) -> Money {
+        Money::new(amount, Currency::Dollar)
+    }
+
+    #[test]
+    fn sut_keeps_balance_of_each_currency_apart() {
+        // Arrange
+        let mut wallet = Wallet::open();
+
+        // Act
+        wallet.deposit(btc(20));
+        wallet.deposit(Money::new(3, Currency::Ether));
+        wallet.withdraw(btc(5)).unwrap();
+
+        // Assert
+        assert_eq!(btc(15), wallet.balance(Currency::BitCoin));
+        assert_eq!(
+            Money::new(3, Currency::Ether),
+            wallet.balance(Currency::Ether)
+        );
+        assert_eq!(usd(0), wallet.balance(Currency::Dollar));
+    }
+
+    #[test]
+    fn sut_returns_error_if_withdrawing_in_currency_not_held() {
+        // Arrange
+        let mut wallet = Wallet::open();
+        wallet.deposit(btc(20));
+
+        // Act
+        let actual = wallet.withdraw(usd(10)).unwrap_err();
+
+        // Assert
+        assert_eq!(WalletError::CurrencyNotHeld(Currency::Dollar), actual);
+        assert_eq!(
+            "cannot withdraw in USD, which the wallet does not hold",
+            actual.to_string()
+        );
+    }
+
+    #[test]
+    fn sut_returns_error_if_withdrawing_more_than_balance_in_currency() {
+        // Arrange
+        let mut wallet = Wallet::open();
+        wallet.deposit(btc(20));
+
+        // Act
+        let actual = wallet.withdraw(btc(30)).unwrap_err();
+
+        // Assert
+        let expected = WalletError::InsufficientFunds {
+            requested: btc(30),
+            balance: btc(20),
+        };
+        assert_eq!(expected, actual);
+        assert_eq!(
+            "cannot withdraw 30 BTC, insufficient funds in balance 20 BTC",
+            actual.to_string()
+        );
+    }
+
+    #[test]
+    fn sut_exchanges_money_at_rate_provided() {
+        // Arrange
+        let mut wallet = Wallet::open();
+        wallet.deposit(btc(2));
+
+        // Act
+        let actual = wallet.exchange(btc(1), Currency::Dollar, &rates());
+
+        // Assert
+        assert_eq!(Ok(usd(60_000)), actual);
+        assert_eq!(btc(1), wallet.balance(Currency::BitCoin));
+        assert_eq!(usd(60_000), wallet.balance(Currency::Dollar));
+    }
+
+    #[test]
+    fn sut_leaves_balances_as_they_were_if_no_rate_is_provided() {
+        // Arrange
+        let mut wallet = Wallet::open();
+        wallet.deposit(usd(100));
+
+        // Act
+        let actual = wallet.exchange(usd(100), Currency::BitCoin, &rates());
+
+        // Assert
+        let expected = WalletError::RateUnavailable {
+            from: Currency::Dollar,
+            to: Currency::BitCoin,
+        };
+        assert_eq!(Err(expected), actual);
+        assert_eq!(usd(100), wallet.balance(Currency::Dollar));
+    }
+
+    #[test]
+    fn sut_totals_every_balance_converted_and_rounded_down() {
+        // Arrange
+        let mut wallet = Wallet::open();
+        wallet.deposit(btc(1));
+        wallet.deposit(Money::new(3, Currency::Ether));
+        wallet.deposit(usd(10));
+
+        // Act
+        let actual = wallet.total(Currency::Dollar, &rates());
+
+        // Assert
+        assert_eq!(Ok(usd(60_000 + 7_501 + 10)), actual);
+    }
+    #[test]
+    fn sut_returns_error_if_total_is_too_large_to_hold() {
+        // Arrange
+        let mut wallet = Wallet::open();
+        wallet.deposit(btc(1));
+        wallet.deposit(usd(u64::MAX));
+
+        // Act
+        let actual = wallet.total(Currency::Dollar, &rates());
+
+        // Assert
+        assert_eq!(Err(WalletError::Overflow(Currency::Dollar)), actual);
+    }
+}
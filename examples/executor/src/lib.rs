@@ -0,0 +1,75 @@
+//! An executor the examples can be handed instead of spawning on tokio
+//! themselves, so that specs decide how their tasks run.
+//!
+//! - `TokioSpawner` spawns on the tokio runtime it is called from.
+//! - `ThreadPoolSpawner` spawns on a pool of plain threads.
+//! - `TestExecutor` runs its tasks on the calling thread, one step at a
+//!   time, in the order they were spawned or woken.
+
+use std::panic::AssertUnwindSafe;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+
+use futures::FutureExt;
+use futures::channel::oneshot;
+use futures::future::BoxFuture;
+
+mod pool;
+mod runtime;
+mod stepped;
+
+pub use pool::ThreadPoolSpawner;
+pub use runtime::TokioSpawner;
+pub use stepped::TestExecutor;
+pub use stepped::TestSpawner;
+
+/// `Spawner` runs futures as tasks of their own. Clones spawn on the same
+/// executor.
+pub trait Spawner: Clone + Send + Sync + 'static {
+    /// Runs `task` to completion, detached from the caller.
+    fn spawn_task(&self, task: BoxFuture<'static, ()>);
+
+    /// Runs `future` as a task, returning a handle to await its output.
+    fn spawn<F>(&self, future: F) -> JoinHandle<F::Output>
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        let (sender, receiver) = oneshot::channel();
+        self.spawn_task(Box::pin(async move {
+            let output = AssertUnwindSafe(future).catch_unwind().await;
+            // The handle may have been dropped, leaving no one to tell.
+            let _ = sender.send(output.map_err(|_| JoinError::Panicked));
+        }));
+        JoinHandle { receiver }
+    }
+}
+
+/// `JoinError` is why a task gave no output.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum JoinError {
+    #[error("task panicked")]
+    Panicked,
+
+    /// The executor dropped the task before it finished.
+    #[error("task was dropped before it finished")]
+    Dropped,
+}
+
+/// `JoinHandle` completes with the output of a spawned task. Dropping it
+/// leaves the task running.
+#[derive(Debug)]
+pub struct JoinHandle<T> {
+    receiver: oneshot::Receiver<Result<T, JoinError>>,
+}
+
+impl<T> Future for JoinHandle<T> {
+    type Output = Result<T, JoinError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.receiver
+            .poll_unpin(cx)
+            .map(|output| output.unwrap_or(Err(JoinError::Dropped)))
+    }
+}
@@ -0,0 +1,53 @@
+use std::io;
+
+use futures::executor::ThreadPool;
+use futures::future::BoxFuture;
+
+use super::Spawner;
+
+/// `ThreadPoolSpawner` spawns on a pool of plain threads, with no runtime
+/// of its own. Clones share the pool, which stops once the last is dropped.
+#[derive(Clone, Debug)]
+pub struct ThreadPoolSpawner {
+    pool: ThreadPool,
+}
+
+impl ThreadPoolSpawner {
+    pub fn new(threads: usize) -> io::Result<Self> {
+        let pool = ThreadPool::builder().pool_size(threads).create()?;
+        Ok(ThreadPoolSpawner { pool })
+    }
+}
+
+impl Spawner for ThreadPoolSpawner {
+    fn spawn_task(&self, task: BoxFuture<'static, ()>) {
+        self.pool.spawn_ok(task);
+    }
+}
+
+#[cfg(test)]
+mod specs_for_thread_pool_spawner {
+    use std::thread;
+
+    use futures::executor::block_on;
+    use futures::future::join_all;
+
+    use super::super::Spawner;
+    use super::ThreadPoolSpawner;
+
+    #[test]
+    fn sut_runs_tasks_off_calling_thread() {
+        // Arrange
+        let sut = ThreadPoolSpawner::new(2).unwrap();
+        let caller = thread::current().id();
+
+        // Act
+        let handles = (0..4)
+            .map(|_| sut.spawn(async { thread::current().id() }))
+            .collect::<Vec<_>>();
+        let actual = block_on(join_all(handles));
+
+        // Assert
+        assert!(actual.into_iter().all(|id| id.unwrap() != caller));
+    }
+}
@@ -0,0 +1,48 @@
+use futures::future::BoxFuture;
+
+use super::Spawner;
+
+/// `TokioSpawner` spawns on the tokio runtime it is called from.
+///
+/// # Panics
+///
+/// Spawning panics outside of a tokio runtime.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TokioSpawner;
+
+impl Spawner for TokioSpawner {
+    fn spawn_task(&self, task: BoxFuture<'static, ()>) {
+        tokio::spawn(task);
+    }
+}
+
+#[cfg(test)]
+mod specs_for_tokio_spawner {
+    use super::super::JoinError;
+    use super::super::Spawner;
+    use super::TokioSpawner;
+
+    #[tokio::test]
+    async fn sut_hands_output_of_task_to_its_handle() {
+        // Arrange
+        let sut = TokioSpawner;
+
+        // Act
+        let actual = sut.spawn(async { 1 + 1 }).await;
+
+        // Assert
+        assert_eq!(Ok(2), actual);
+    }
+
+    #[tokio::test]
+    async fn sut_tells_handle_that_task_panicked() {
+        // Arrange
+        let sut = TokioSpawner;
+
+        // Act
+        let actual = sut.spawn(async { panic!("boom") }).await;
+
+        // Assert
+        assert_eq!(Err::<(), _>(JoinError::Panicked), actual);
+    }
+}
@@ -0,0 +1,238 @@
+use std::collections::VecDeque;
+use std::pin::pin;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::Weak;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::task::Context;
+use std::task::Poll;
+
+use futures::future::BoxFuture;
+use futures::task::ArcWake;
+use futures::task::noop_waker_ref;
+use futures::task::waker_ref;
+
+use super::Spawner;
+
+/// The tasks ready to be polled, in the order they are polled.
+type RunQueue = Mutex<VecDeque<Arc<Task>>>;
+
+/// `TestExecutor` runs its tasks on the calling thread, and only when told
+/// to. Tasks are polled in the order they were spawned or woken, so a spec
+/// can step through them and see the very same interleaving on every run.
+#[derive(Debug, Default)]
+pub struct TestExecutor {
+    queue: Arc<RunQueue>,
+}
+
+/// `TestSpawner` spawns on the `TestExecutor` it came from.
+#[derive(Clone, Debug)]
+pub struct TestSpawner {
+    queue: Arc<RunQueue>,
+}
+
+struct Task {
+    future: Mutex<Option<BoxFuture<'static, ()>>>,
+    scheduled: AtomicBool,
+    queue: Weak<RunQueue>,
+}
+
+impl std::fmt::Debug for Task {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Task")
+            .field("scheduled", &self.scheduled)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ArcWake for Task {
+    fn wake_by_ref(task: &Arc<Self>) {
+        // A task woken twice before it is polled is polled once.
+        if task.scheduled.swap(true, Ordering::AcqRel) {
+            return;
+        }
+        if let Some(queue) = task.queue.upgrade() {
+            queue.lock().unwrap().push_back(task.clone());
+        }
+    }
+}
+
+impl TestExecutor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn spawner(&self) -> TestSpawner {
+        TestSpawner {
+            queue: self.queue.clone(),
+        }
+    }
+
+    /// Returns how many tasks are ready to be polled.
+    pub fn scheduled(&self) -> usize {
+        self.queue.lock().unwrap().len()
+    }
+
+    /// Polls the first task ready, once. Returns false if none was.
+    pub fn step(&self) -> bool {
+        let Some(task) = self.queue.lock().unwrap().pop_front() else {
+            return false;
+        };
+        task.scheduled.store(false, Ordering::Release);
+        let mut future = task.future.lock().unwrap();
+        if let Some(mut running) = future.take() {
+            let waker = waker_ref(&task);
+            if running
+                .as_mut()
+                .poll(&mut Context::from_waker(&waker))
+                .is_pending()
+            {
+                *future = Some(running);
+            }
+        }
+        true
+    }
+
+    /// Steps until no task is ready, returning how many steps were taken.
+    pub fn run_until_stalled(&self) -> usize {
+        let mut steps = 0;
+        while self.step() {
+            steps += 1;
+        }
+        steps
+    }
+
+    /// Polls `future` on the calling thread, stepping a task whenever it
+    /// cannot go on, until it completes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `future` waits while no task is ready, as it would then
+    /// wait forever.
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        let mut future = pin!(future);
+        let mut cx = Context::from_waker(noop_waker_ref());
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return output;
+            }
+            assert!(self.step(), "future is waiting while no task is ready");
+        }
+    }
+}
+
+impl Spawner for TestSpawner {
+    fn spawn_task(&self, task: BoxFuture<'static, ()>) {
+        let task = Arc::new(Task {
+            future: Mutex::new(Some(task)),
+            scheduled: AtomicBool::new(true),
+            queue: Arc::downgrade(&self.queue),
+        });
+        self.queue.lock().unwrap().push_back(task);
+    }
+}
+
+#[cfg(test)]
+mod specs_for_test_executor {
+    use std::sync::Arc;
+    use std::sync::Mutex;
+
+    use super::super::JoinError;
+    use super::super::Spawner;
+    use super::TestExecutor;
+
+    type Log = Arc<Mutex<Vec<String>>>;
+
+    /// Logs `name` before and after yielding once.
+    async fn yielding(name: &'static str, log: Log) {
+        log.lock().unwrap().push(format!("{name} started"));
+        tokio::task::yield_now().await;
+        log.lock().unwrap().push(format!("{name} finished"));
+    }
+
+    #[test]
+    fn sut_polls_one_task_per_step_in_order_spawned() {
+        // Arrange
+        let sut = TestExecutor::new();
+        let log = Log::default();
+        sut.spawner().spawn(yielding("a", log.clone()));
+        sut.spawner().spawn(yielding("b", log.clone()));
+
+        // Act
+        sut.step();
+        let after_one_step = log.lock().unwrap().clone();
+        sut.run_until_stalled();
+
+        // Assert
+        assert_eq!(vec!["a started"], after_one_step);
+        let expected = vec!["a started", "b started", "a finished", "b finished"];
+        assert_eq!(expected, *log.lock().unwrap());
+    }
+
+    #[test]
+    fn sut_polls_nothing_until_stepped() {
+        // Arrange
+        let sut = TestExecutor::new();
+        let log = Log::default();
+
+        // Act
+        sut.spawner().spawn(yielding("a", log.clone()));
+
+        // Assert
+        assert_eq!(1, sut.scheduled());
+        assert!(log.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn sut_runs_tasks_future_waits_for() {
+        // Arrange
+        let sut = TestExecutor::new();
+        let handles = (1..=3)
+            .map(|n| sut.spawner().spawn(async move { n * 10 }))
+            .collect::<Vec<_>>();
+
+        // Act
+        let actual = sut.block_on(futures::future::join_all(handles));
+
+        // Assert
+        assert_eq!(vec![Ok(10), Ok(20), Ok(30)], actual);
+    }
+
+    #[test]
+    fn sut_tells_handle_that_task_panicked() {
+        // Arrange
+        let sut = TestExecutor::new();
+        let handle = sut.spawner().spawn(async { panic!("boom") });
+
+        // Act
+        let actual = sut.block_on(handle);
+
+        // Assert
+        assert_eq!(Err::<(), _>(JoinError::Panicked), actual);
+    }
+
+    #[test]
+    fn sut_tells_handle_that_task_was_dropped_with_executor() {
+        // Arrange
+        let sut = TestExecutor::new();
+        let handle = sut.spawner().spawn(async { 1 });
+
+        // Act
+        drop(sut);
+        let actual = futures::executor::block_on(handle);
+
+        // Assert
+        assert_eq!(Err(JoinError::Dropped), actual);
+    }
+
+    #[test]
+    #[should_panic(expected = "future is waiting while no task is ready")]
+    fn sut_panics_if_future_waits_on_no_task() {
+        // Arrange
+        let sut = TestExecutor::new();
+
+        // Act
+        sut.block_on(std::future::pending::<()>());
+    }
+}
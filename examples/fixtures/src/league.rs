@@ -0,0 +1,100 @@
+use std::cmp::Reverse;
+use std::collections::BTreeSet;
+
+use fake::Fake;
+use fake::faker::name::en::FirstName;
+use http_server::v1::Player;
+
+/// `LeagueBuilder` builds a league of `http_server::v1` players, the most
+/// wins first. Fake players have plain ASCII names, fit for a URL, and no
+/// two players share a name or a number of wins, so the order is certain.
+#[derive(Clone, Debug, Default)]
+pub struct LeagueBuilder {
+    players: Vec<Player>,
+}
+
+impl LeagueBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn player(mut self, name: impl Into<String>, wins: u32) -> Self {
+        self.players.push(Player {
+            name: name.into(),
+            wins,
+        });
+        self
+    }
+
+    /// Adds `n` fake players.
+    pub fn players(mut self, n: usize) -> Self {
+        let mut names = self
+            .players
+            .iter()
+            .map(|player| player.name.clone())
+            .collect::<BTreeSet<_>>();
+        let mut wins = self
+            .players
+            .iter()
+            .map(|player| player.wins)
+            .collect::<BTreeSet<_>>();
+        for _ in 0..n {
+            let name = loop {
+                let name: String = FirstName().fake();
+                if name.chars().all(|c| c.is_ascii_alphabetic()) && names.insert(name.clone()) {
+                    break name;
+                }
+            };
+            let won = loop {
+                let won = (1..=10 * (n as u32 + wins.len() as u32)).fake();
+                if wins.insert(won) {
+                    break won;
+                }
+            };
+            self = self.player(name, won);
+        }
+        self
+    }
+
+    pub fn build(mut self) -> Vec<Player> {
+        self.players.sort_by_key(|player| Reverse(player.wins));
+        self.players
+    }
+}
+
+#[cfg(test)]
+mod specs_for_league_builder {
+    use super::LeagueBuilder;
+
+    #[test]
+    fn sut_orders_players_by_wins() {
+        // Act
+        let actual = LeagueBuilder::new()
+            .player("Cleo", 1)
+            .player("Chris", 3)
+            .player("Pepper", 2)
+            .build();
+
+        // Assert
+        let names = actual
+            .iter()
+            .map(|player| player.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["Chris", "Pepper", "Cleo"], names);
+    }
+
+    #[test]
+    fn sut_adds_fake_players_with_distinct_names_and_wins() {
+        // Act
+        let actual = LeagueBuilder::new().player("Cleo", 1).players(10).build();
+
+        // Assert
+        assert_eq!(11, actual.len());
+        assert!(actual.windows(2).all(|pair| pair[0].wins > pair[1].wins));
+        assert!(actual.iter().all(|player| player.wins > 0));
+        let mut names = actual.iter().map(|player| &player.name).collect::<Vec<_>>();
+        names.sort();
+        names.dedup();
+        assert_eq!(11, names.len());
+    }
+}
@@ -0,0 +1,33 @@
+//! Builders of fake domain values for the examples' specs.
+//!
+//! Every builder fills in what a spec does not care about with fake data,
+//! so a spec only spells out the fields it is about:
+//!
+//! ```
+//! use fixtures::PostBuilder;
+//!
+//! let post = PostBuilder::new().title("Hello").draft(true).build();
+//!
+//! assert_eq!("Hello", post.title);
+//! ```
+//!
+//! Each builder sits behind the feature named after its domain, so a crate
+//! only builds the examples it needs.
+
+#[cfg(feature = "league")]
+mod league;
+#[cfg(feature = "blog")]
+mod post;
+#[cfg(feature = "shapes")]
+mod shape;
+#[cfg(feature = "wallet")]
+mod wallet;
+
+#[cfg(feature = "league")]
+pub use league::LeagueBuilder;
+#[cfg(feature = "blog")]
+pub use post::PostBuilder;
+#[cfg(feature = "shapes")]
+pub use shape::ShapeBuilder;
+#[cfg(feature = "wallet")]
+pub use wallet::WalletBuilder;
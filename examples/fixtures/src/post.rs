@@ -0,0 +1,155 @@
+use blog::v6::Post;
+use chrono::Days;
+use chrono::NaiveDate;
+use fake::Fake;
+use fake::Faker;
+use fake::faker::lorem::en::Paragraph;
+use random_source::RandomSource;
+use random_source::ThreadRandom;
+
+/// `PostBuilder` builds a `blog::v6::Post` with a fake title, description,
+/// tags, date, draft flag and body. The post is in no particular language.
+#[derive(Clone, Debug)]
+pub struct PostBuilder {
+    post: Post,
+}
+
+impl PostBuilder {
+    pub fn new() -> Self {
+        Self::from_source(&ThreadRandom)
+    }
+
+    /// Fakes the post from `source`, so that a seeded source builds the same
+    /// post every run.
+    pub fn from_source(source: &impl RandomSource) -> Self {
+        let rng = &mut source.rng();
+        let tags = (0..Faker.fake_with_rng::<u8, _>(rng) % 10 + 1)
+            .map(|_| Faker.fake_with_rng::<String, _>(rng))
+            .collect();
+        let date = Faker.fake_with_rng::<bool, _>(rng).then(|| {
+            NaiveDate::from_ymd_opt(2000, 1, 1).unwrap() + Days::new((0..10_000).fake_with_rng(rng))
+        });
+        PostBuilder {
+            post: Post {
+                title: Faker.fake_with_rng(rng),
+                description: Faker.fake_with_rng(rng),
+                tags,
+                date,
+                draft: Faker.fake_with_rng(rng),
+                body: Paragraph(3..10).fake_with_rng(rng),
+                lang: None,
+                translation_key: None,
+            },
+        }
+    }
+
+    /// Builds `n` posts, each with fake data of its own.
+    pub fn many(n: usize) -> Vec<Post> {
+        (0..n).map(|_| Self::new().build()).collect()
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.post.title = title.into();
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.post.description = description.into();
+        self
+    }
+
+    pub fn tags(mut self, tags: &[&str]) -> Self {
+        self.post.tags = tags.iter().map(|tag| tag.to_string()).collect();
+        self
+    }
+
+    pub fn date(mut self, date: Option<NaiveDate>) -> Self {
+        self.post.date = date;
+        self
+    }
+
+    pub fn draft(mut self, draft: bool) -> Self {
+        self.post.draft = draft;
+        self
+    }
+
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.post.body = body.into();
+        self
+    }
+
+    /// Makes the post the translation into `lang` of the posts sharing `key`.
+    pub fn translation(mut self, key: impl Into<String>, lang: impl Into<String>) -> Self {
+        self.post.translation_key = Some(key.into());
+        self.post.lang = Some(lang.into());
+        self
+    }
+
+    pub fn build(self) -> Post {
+        self.post
+    }
+}
+
+impl Default for PostBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod specs_for_post_builder {
+    use chrono::NaiveDate;
+    use random_source::SeededRandom;
+
+    use super::PostBuilder;
+
+    #[test]
+    fn sut_fills_in_fields_not_given() {
+        // Act
+        let actual = PostBuilder::new().build();
+
+        // Assert
+        assert!(!actual.title.is_empty());
+        assert!(!actual.tags.is_empty());
+        assert!(!actual.body.is_empty());
+        assert_eq!(None, actual.lang);
+    }
+
+    #[test]
+    fn sut_fakes_same_post_from_same_seed() {
+        // Act
+        let actual = PostBuilder::from_source(&SeededRandom::new(42)).build();
+
+        // Assert
+        let expected = PostBuilder::from_source(&SeededRandom::new(42)).build();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn sut_keeps_fields_given() {
+        // Arrange
+        let date = NaiveDate::from_ymd_opt(2024, 1, 31);
+
+        // Act
+        let actual = PostBuilder::new()
+            .title("Hello")
+            .tags(&["rust", "tdd"])
+            .date(date)
+            .draft(true)
+            .translation("hello", "en")
+            .build();
+
+        // Assert
+        assert_eq!("Hello", actual.title);
+        assert_eq!(vec!["rust", "tdd"], actual.tags);
+        assert_eq!(date, actual.date);
+        assert!(actual.draft);
+        assert_eq!(Some("hello".to_string()), actual.translation_key);
+        assert_eq!(Some("en".to_string()), actual.lang);
+    }
+
+    #[test]
+    fn sut_builds_as_many_posts_as_asked() {
+        assert_eq!(5, PostBuilder::many(5).len());
+    }
+}
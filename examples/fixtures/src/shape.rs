@@ -0,0 +1,100 @@
+use std::ops::Range;
+
+use fake::Fake;
+use geometry::Point;
+use structs::v6::Circle;
+use structs::v6::Rectangle;
+use structs::v6::Shape;
+use structs::v6::Triangle;
+
+/// `ShapeBuilder` builds `structs::v6` shapes of fake sizes. Every length of
+/// a shape, and the height of a triangle, falls within the size range, so
+/// no shape is degenerate.
+#[derive(Clone, Debug)]
+pub struct ShapeBuilder {
+    size: Range<f64>,
+}
+
+impl ShapeBuilder {
+    pub fn new() -> Self {
+        ShapeBuilder { size: 1.0..100.0 }
+    }
+
+    pub fn size(mut self, size: Range<f64>) -> Self {
+        self.size = size;
+        self
+    }
+
+    fn length(&self) -> f64 {
+        self.size.clone().fake()
+    }
+
+    pub fn rectangle(&self) -> Rectangle {
+        Rectangle {
+            width: self.length(),
+            height: self.length(),
+        }
+    }
+
+    pub fn circle(&self) -> Circle {
+        Circle {
+            radius: self.length(),
+        }
+    }
+
+    pub fn triangle(&self) -> Triangle {
+        let base = self.length();
+        Triangle {
+            a: Point::new(0.0, 0.0),
+            b: Point::new(base, 0.0),
+            c: Point::new((0.0..base).fake(), self.length()),
+        }
+    }
+
+    /// Builds a shape of a fake kind.
+    pub fn any(&self) -> Box<dyn Shape> {
+        match (0..3).fake::<u8>() {
+            0 => Box::new(self.rectangle()),
+            1 => Box::new(self.circle()),
+            _ => Box::new(self.triangle()),
+        }
+    }
+}
+
+impl Default for ShapeBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod specs_for_shape_builder {
+    use super::ShapeBuilder;
+
+    #[test]
+    fn sut_builds_shapes_within_size_range() {
+        // Arrange
+        let sut = ShapeBuilder::new().size(2.0..3.0);
+
+        // Act
+        let rectangle = sut.rectangle();
+        let circle = sut.circle();
+
+        // Assert
+        assert!((2.0..3.0).contains(&rectangle.width));
+        assert!((2.0..3.0).contains(&rectangle.height));
+        assert!((2.0..3.0).contains(&circle.radius));
+    }
+
+    #[test]
+    fn sut_builds_shapes_with_area() {
+        // Arrange
+        let sut = ShapeBuilder::new();
+
+        // Act
+        let actual = (0..20).map(|_| sut.any()).collect::<Vec<_>>();
+
+        // Assert
+        assert!(actual.iter().all(|shape| shape.area() > 0.0));
+    }
+}
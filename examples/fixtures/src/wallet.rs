@@ -0,0 +1,73 @@
+use errors::v5::BitCoin;
+use errors::v5::Wallet;
+use fake::Fake;
+use random_source::RandomSource;
+use random_source::ThreadRandom;
+
+/// `WalletBuilder` builds an `errors::v5::Wallet` holding a fake balance of
+/// up to 1000.
+#[derive(Clone, Debug)]
+pub struct WalletBuilder {
+    balance: BitCoin,
+}
+
+impl WalletBuilder {
+    pub fn new() -> Self {
+        Self::from_source(&ThreadRandom)
+    }
+
+    /// Fakes the balance from `source`, so that a seeded source builds the
+    /// same wallet every run.
+    pub fn from_source(source: &impl RandomSource) -> Self {
+        WalletBuilder {
+            balance: (0..=1000).fake_with_rng(&mut source.rng()),
+        }
+    }
+
+    pub fn balance(mut self, balance: BitCoin) -> Self {
+        self.balance = balance;
+        self
+    }
+
+    pub fn empty(self) -> Self {
+        self.balance(0)
+    }
+
+    pub fn build(self) -> Wallet {
+        let mut wallet = Wallet::open();
+        wallet.deposit(self.balance);
+        wallet
+    }
+}
+
+impl Default for WalletBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod specs_for_wallet_builder {
+    use random_source::SeededRandom;
+
+    use super::WalletBuilder;
+
+    #[test]
+    fn sut_opens_wallet_with_balance_given() {
+        assert_eq!(42, WalletBuilder::new().balance(42).build().balance());
+        assert_eq!(0, WalletBuilder::new().empty().build().balance());
+    }
+
+    #[test]
+    fn sut_opens_wallet_with_fake_balance_of_up_to_1000() {
+        assert!(WalletBuilder::new().build().balance() <= 1000);
+    }
+
+    #[test]
+    fn sut_opens_wallet_with_same_balance_from_same_seed() {
+        let actual = WalletBuilder::from_source(&SeededRandom::new(42)).build();
+
+        let expected = WalletBuilder::from_source(&SeededRandom::new(42)).build();
+        assert_eq!(expected.balance(), actual.balance());
+    }
+}
@@ -0,0 +1,10 @@
+use generics::v2::Stack;
+
+fn main() {
+    let mut stack = Stack::new();
+    stack.push("Hello");
+    stack.push("world");
+    while let Some(word) = stack.pop() {
+        println!("{}", word);
+    }
+}
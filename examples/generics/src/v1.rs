@@ -0,0 +1,95 @@
+use core::fmt::Debug;
+
+/// Panics with both values if they differ. Works for any type that can be
+/// compared and printed, so it needs no copy per type.
+#[track_caller]
+pub fn assert_equal<T: PartialEq + Debug>(expected: T, actual: T) {
+    if expected != actual {
+        panic!("expected {:?}, got {:?}", expected, actual);
+    }
+}
+
+/// Panics with the value if both are the same.
+#[track_caller]
+pub fn assert_not_equal<T: PartialEq + Debug>(unexpected: T, actual: T) {
+    if unexpected == actual {
+        panic!("did not expect {:?}", actual);
+    }
+}
+
+#[track_caller]
+pub fn assert_true(actual: bool) {
+    assert_equal(true, actual);
+}
+
+#[track_caller]
+pub fn assert_false(actual: bool) {
+    assert_equal(false, actual);
+}
+
+#[cfg(test)]
+mod specs_for_assert_equal {
+    use super::assert_equal;
+
+    #[test]
+    fn sut_passes_for_equal_integers() {
+        assert_equal(1, 1);
+    }
+
+    #[test]
+    fn sut_passes_for_equal_strings() {
+        assert_equal("hello", "hello");
+    }
+
+    #[test]
+    #[should_panic(expected = "expected 1, got 2")]
+    fn sut_panics_for_different_integers() {
+        assert_equal(1, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"expected "hello", got "Grace""#)]
+    fn sut_panics_for_different_strings() {
+        assert_equal("hello", "Grace");
+    }
+}
+
+#[cfg(test)]
+mod specs_for_assert_not_equal {
+    use super::assert_not_equal;
+
+    #[test]
+    fn sut_passes_for_different_values() {
+        assert_not_equal(1, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"did not expect "hello""#)]
+    fn sut_panics_for_equal_values() {
+        assert_not_equal("hello", "hello");
+    }
+}
+
+#[cfg(test)]
+mod specs_for_assert_true_and_false {
+    use super::assert_false;
+    use super::assert_true;
+
+    #[test]
+    fn sut_passes_for_matching_booleans() {
+        assert_true(true);
+        assert_false(false);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected true, got false")]
+    fn sut_panics_if_false_is_not_true() {
+        assert_true(false);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected false, got true")]
+    fn sut_panics_if_true_is_not_false() {
+        assert_false(true);
+    }
+}
@@ -0,0 +1,130 @@
+use alloc::vec::Vec;
+
+/// `Stack` is a last in, first out collection of any type.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Stack<T> {
+    values: Vec<T>,
+}
+
+impl<T> Stack<T> {
+    pub fn new() -> Self {
+        Stack { values: Vec::new() }
+    }
+
+    pub fn push(&mut self, value: T) {
+        self.values.push(value);
+    }
+
+    /// Removes and returns the value pushed last. An empty stack returns
+    /// `None` instead of panicking.
+    ///
+    /// ```
+    /// use generics::v2::Stack;
+    ///
+    /// let mut stack = Stack::new();
+    /// stack.push(1);
+    ///
+    /// assert_eq!(Some(1), stack.pop());
+    /// assert_eq!(None, stack.pop());
+    /// ```
+    pub fn pop(&mut self) -> Option<T> {
+        self.values.pop()
+    }
+
+    /// Returns the value pushed last without removing it.
+    pub fn peek(&self) -> Option<&T> {
+        self.values.last()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+impl<T> Default for Stack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod specs_for_stack {
+    use super::Stack;
+    use crate::v1::assert_equal;
+    use crate::v1::assert_false;
+    use crate::v1::assert_true;
+
+    #[test]
+    fn sut_of_integers_pops_values_in_reverse_order() {
+        // Arrange
+        let mut sut = Stack::new();
+        assert_true(sut.is_empty());
+
+        // Act
+        sut.push(123);
+        sut.push(456);
+
+        // Assert
+        assert_false(sut.is_empty());
+        assert_equal(Some(456), sut.pop());
+        assert_equal(Some(123), sut.pop());
+        assert_true(sut.is_empty());
+    }
+
+    #[test]
+    fn sut_of_strings_pops_values_in_reverse_order() {
+        // Arrange
+        let mut sut = Stack::new();
+        assert_true(sut.is_empty());
+
+        // Act
+        sut.push("123".to_string());
+        sut.push("456".to_string());
+
+        // Assert
+        assert_false(sut.is_empty());
+        assert_equal(Some("456".to_string()), sut.pop());
+        assert_equal(Some("123".to_string()), sut.pop());
+        assert_true(sut.is_empty());
+    }
+
+    #[test]
+    fn sut_lets_values_be_used_as_their_own_type() {
+        // Arrange
+        let mut sut = Stack::new();
+        sut.push(1);
+        sut.push(2);
+
+        // Act
+        let actual = sut.pop().unwrap() + sut.pop().unwrap();
+
+        // Assert
+        assert_equal(3, actual);
+    }
+
+    #[test]
+    fn sut_peeks_without_removing_value() {
+        // Arrange
+        let mut sut = Stack::new();
+        sut.push("Grace");
+
+        // Act
+        let actual = sut.peek().copied();
+
+        // Assert
+        assert_equal(Some("Grace"), actual);
+        assert_false(sut.is_empty());
+    }
+
+    #[test]
+    fn sut_returns_none_if_empty() {
+        // Arrange
+        let mut sut = Stack::<i32>::default();
+
+        // Act
+        let actual = (sut.peek().copied(), sut.pop());
+
+        // Assert
+        assert_equal((None, None), actual);
+    }
+}
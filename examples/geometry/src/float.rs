@@ -0,0 +1,19 @@
+#[cfg(feature = "std")]
+pub(crate) fn hypot(x: f64, y: f64) -> f64 {
+    x.hypot(y)
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn hypot(x: f64, y: f64) -> f64 {
+    libm::hypot(x, y)
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn sin_cos(angle: f64) -> (f64, f64) {
+    angle.sin_cos()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn sin_cos(angle: f64) -> (f64, f64) {
+    libm::sincos(angle)
+}
@@ -0,0 +1,9 @@
+//! Without the default `std` feature, the crate is `no_std` and takes the
+//! float functions `core` lacks from `libm`.
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+mod float;
+mod point;
+
+pub use point::Point;
@@ -0,0 +1,205 @@
+use super::float::hypot;
+use super::float::sin_cos;
+
+/// `Point` is a 2D vector with the usual mathematical orientation: the x axis
+/// points right, the y axis points up, and positive angles turn anticlockwise.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Point {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl Point {
+    pub const fn new(x: f64, y: f64) -> Self {
+        Point { x, y }
+    }
+
+    /// Returns the point on the unit circle at `angle` radians from the x axis.
+    pub fn on_unit_circle(angle: f64) -> Self {
+        let (sin, cos) = sin_cos(angle);
+        Point { x: cos, y: sin }
+    }
+
+    pub fn length(&self) -> f64 {
+        hypot(self.x, self.y)
+    }
+
+    pub fn distance_to(&self, other: Point) -> f64 {
+        self.translate(-other.x, -other.y).length()
+    }
+
+    /// Rotates the point anticlockwise around the origin by `angle` radians.
+    pub fn rotate(&self, angle: f64) -> Self {
+        let (sin, cos) = sin_cos(angle);
+        Point {
+            x: self.x * cos - self.y * sin,
+            y: self.x * sin + self.y * cos,
+        }
+    }
+
+    pub fn scale(&self, factor: f64) -> Self {
+        Point {
+            x: self.x * factor,
+            y: self.y * factor,
+        }
+    }
+
+    pub fn translate(&self, dx: f64, dy: f64) -> Self {
+        Point {
+            x: self.x + dx,
+            y: self.y + dy,
+        }
+    }
+
+    /// Mirrors the point across the x axis, which turns a y-up coordinate into
+    /// the y-down coordinate used by SVG and raster images.
+    pub fn flip_y(&self) -> Self {
+        Point {
+            x: self.x,
+            y: -self.y,
+        }
+    }
+}
+
+#[cfg(test)]
+fn roughly_equal_float(a: f64, b: f64) -> bool {
+    const EQUALITY_THRESHOLD: f64 = 1e-7;
+    (a - b).abs() <= EQUALITY_THRESHOLD * a.abs().max(b.abs()).max(1.0)
+}
+
+#[cfg(test)]
+fn roughly_equal_point(a: Point, b: Point) -> bool {
+    roughly_equal_float(a.x, b.x) && roughly_equal_float(a.y, b.y)
+}
+
+#[cfg(test)]
+mod specs_for_point {
+    use std::f64::consts::FRAC_PI_2;
+    use std::f64::consts::PI;
+
+    use rstest::rstest;
+
+    use super::Point;
+    use super::roughly_equal_float;
+    use super::roughly_equal_point;
+
+    #[rstest]
+    #[case(0.0, Point::new(1.0, 0.0))]
+    #[case(FRAC_PI_2, Point::new(0.0, 1.0))]
+    #[case(PI, Point::new(-1.0, 0.0))]
+    #[case(3.0 * FRAC_PI_2, Point::new(0.0, -1.0))]
+    fn sut_returns_point_on_unit_circle_correctly(#[case] angle: f64, #[case] expected: Point) {
+        // Act
+        let actual = Point::on_unit_circle(angle);
+
+        // Assert
+        assert!(roughly_equal_point(expected, actual));
+    }
+
+    #[test]
+    fn sut_rotates_anticlockwise_around_origin() {
+        // Arrange
+        let sut = Point::new(2.0, 0.0);
+
+        // Act
+        let actual = sut.rotate(FRAC_PI_2);
+
+        // Assert
+        let expected = Point::new(0.0, 2.0);
+        assert!(roughly_equal_point(expected, actual));
+    }
+
+    #[test]
+    fn sut_scales_and_translates_correctly() {
+        // Arrange
+        let sut = Point::new(1.0, -2.0);
+
+        // Act
+        let actual = sut.scale(3.0).translate(10.0, 20.0);
+
+        // Assert
+        let expected = Point::new(13.0, 14.0);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn sut_flips_y_axis() {
+        // Arrange
+        let sut = Point::new(1.0, 2.0);
+
+        // Act
+        let actual = sut.flip_y();
+
+        // Assert
+        let expected = Point::new(1.0, -2.0);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn sut_measures_distance_between_points() {
+        // Arrange
+        let sut = Point::new(1.0, 1.0);
+
+        // Act
+        let actual = sut.distance_to(Point::new(4.0, 5.0));
+
+        // Assert
+        assert!(roughly_equal_float(5.0, actual));
+    }
+}
+
+#[cfg(test)]
+mod properties_of_point {
+    use std::f64::consts::PI;
+
+    use super::Point;
+    use super::roughly_equal_float;
+    use super::roughly_equal_point;
+
+    #[derive(Clone, Debug)]
+    struct PointFixture(Point);
+
+    impl quickcheck::Arbitrary for PointFixture {
+        fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+            let x = i32::arbitrary(g) % 10_000;
+            let y = i32::arbitrary(g) % 10_000;
+            PointFixture(Point::new(x as f64 / 10.0, y as f64 / 10.0))
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    struct AngleFixture(f64);
+
+    impl quickcheck::Arbitrary for AngleFixture {
+        fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+            let degrees = i32::arbitrary(g) % 3600;
+            AngleFixture(degrees as f64 / 10.0 * PI / 180.0)
+        }
+    }
+
+    #[quickcheck_macros::quickcheck]
+    fn rotation_preserves_length(point: PointFixture, angle: AngleFixture) -> bool {
+        let actual = point.0.rotate(angle.0).length();
+        let expected = point.0.length();
+        roughly_equal_float(expected, actual)
+    }
+
+    #[quickcheck_macros::quickcheck]
+    fn rotation_and_inverse_is_identity(point: PointFixture, angle: AngleFixture) -> bool {
+        let actual = point.0.rotate(angle.0).rotate(-angle.0);
+        roughly_equal_point(point.0, actual)
+    }
+
+    #[quickcheck_macros::quickcheck]
+    fn scaling_multiplies_length_by_factor(point: PointFixture, factor: i8) -> bool {
+        let factor = factor as f64;
+        let actual = point.0.scale(factor).length();
+        let expected = point.0.length() * factor.abs();
+        roughly_equal_float(expected, actual)
+    }
+
+    #[quickcheck_macros::quickcheck]
+    fn points_on_unit_circle_have_unit_length(angle: AngleFixture) -> bool {
+        roughly_equal_float(1.0, Point::on_unit_circle(angle.0).length())
+    }
+}
@@ -0,0 +1,195 @@
+//! Approval testing against golden files checked in next to the specs.
+//!
+//! `assert_matches_golden!("v2/face.svg", actual)` compares the output with
+//! `golden/v2/face.svg` under the crate being tested. Run the specs with
+//! `UPDATE_GOLDEN=1` to write the output to the golden files instead, then
+//! review the changes to them like any other diff.
+
+use std::env;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// The environment variable that turns comparing into approving.
+pub const UPDATE_VAR: &str = "UPDATE_GOLDEN";
+
+#[derive(Debug, thiserror::Error)]
+pub enum GoldenError {
+    #[error("golden file {0} is missing, run with {UPDATE_VAR}=1 to create it")]
+    Missing(String),
+
+    #[error(
+        "output differs from golden file {path} at line {line}, run with {UPDATE_VAR}=1 to approve it\n  expected: {expected:?}\n    actual: {actual:?}"
+    )]
+    Differs {
+        path: String,
+        line: usize,
+        expected: String,
+        actual: String,
+    },
+
+    #[error("failed to access golden file {path}: {source}")]
+    Io { path: String, source: io::Error },
+}
+
+/// Compares `actual` with the golden file at `path`, or writes it there if
+/// `update` is set.
+pub fn check(path: &Path, actual: &str, update: bool) -> Result<(), GoldenError> {
+    let shown = path.display().to_string();
+    let io = |source| GoldenError::Io {
+        path: shown.clone(),
+        source,
+    };
+    if update {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(io)?;
+        }
+        return fs::write(path, actual).map_err(io);
+    }
+    let expected = match fs::read_to_string(path) {
+        Ok(expected) => expected,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => {
+            return Err(GoldenError::Missing(shown));
+        }
+        Err(error) => return Err(io(error)),
+    };
+    if expected == actual {
+        return Ok(());
+    }
+    let mut expected_lines = expected.split_inclusive('\n');
+    let mut actual_lines = actual.split_inclusive('\n');
+    let mut line = 1;
+    loop {
+        match (expected_lines.next(), actual_lines.next()) {
+            (Some(e), Some(a)) if e == a => line += 1,
+            (e, a) => {
+                return Err(GoldenError::Differs {
+                    path: shown,
+                    line,
+                    expected: e.unwrap_or_default().to_string(),
+                    actual: a.unwrap_or_default().to_string(),
+                });
+            }
+        }
+    }
+}
+
+/// Panics unless `actual` matches the golden file at `path`, approving it
+/// instead when `UPDATE_GOLDEN` is set.
+#[track_caller]
+pub fn assert_matches(path: &Path, actual: &str) {
+    let update = env::var_os(UPDATE_VAR).is_some();
+    if let Err(error) = check(path, actual, update) {
+        panic!("{error}");
+    }
+}
+
+/// Asserts that the output matches the golden file of the name, which is
+/// looked up in the `golden` directory of the crate being tested:
+///
+/// ```no_run
+/// use golden::assert_matches_golden;
+///
+/// let page = format!("<h1>{}</h1>\n", "Hello");
+///
+/// assert_matches_golden!("hello.html", page);
+/// ```
+#[macro_export]
+macro_rules! assert_matches_golden {
+    ($name:expr, $actual:expr $(,)?) => {
+        $crate::assert_matches(
+            &::std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("golden")
+                .join($name),
+            ::std::convert::AsRef::<str>::as_ref(&$actual),
+        )
+    };
+}
+
+#[cfg(test)]
+mod specs_for_check {
+    use std::fs;
+
+    use super::GoldenError;
+    use super::check;
+
+    #[test]
+    fn sut_accepts_output_equal_to_golden_file() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("page.html");
+        fs::write(&path, "<h1>\nHello\n</h1>\n").unwrap();
+
+        // Act
+        let actual = check(&path, "<h1>\nHello\n</h1>\n", false);
+
+        // Assert
+        assert!(actual.is_ok());
+    }
+
+    #[test]
+    fn sut_tells_first_line_that_differs() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("page.html");
+        fs::write(&path, "<h1>\nHello\n</h1>\n").unwrap();
+
+        // Act
+        let actual = check(&path, "<h1>\nBye\n</h1>\n", false).unwrap_err();
+
+        // Assert
+        let GoldenError::Differs {
+            line,
+            expected,
+            actual,
+            ..
+        } = actual
+        else {
+            panic!("expected output to differ, got {actual:?}");
+        };
+        assert_eq!(2, line);
+        assert_eq!("Hello\n", expected);
+        assert_eq!("Bye\n", actual);
+    }
+
+    #[test]
+    fn sut_tells_line_after_shorter_output_ends() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("page.html");
+        fs::write(&path, "Hello\nWorld\n").unwrap();
+
+        // Act
+        let actual = check(&path, "Hello\n", false).unwrap_err();
+
+        // Assert
+        assert!(matches!(actual, GoldenError::Differs { line: 2, .. }));
+    }
+
+    #[test]
+    fn sut_raises_missing_error_if_golden_file_does_not_exist() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+
+        // Act
+        let actual = check(&dir.path().join("page.html"), "Hello", false).unwrap_err();
+
+        // Assert
+        assert!(matches!(actual, GoldenError::Missing(_)));
+        assert!(actual.to_string().contains("UPDATE_GOLDEN=1"));
+    }
+
+    #[test]
+    fn sut_writes_golden_file_if_updating() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("v1").join("page.html");
+
+        // Act
+        check(&path, "Hello", true).unwrap();
+
+        // Assert
+        assert_eq!("Hello", fs::read_to_string(&path).unwrap());
+        assert!(check(&path, "Hello", false).is_ok());
+    }
+}
@@ -0,0 +1,13 @@
+use std::error::Error;
+
+// The vendored `protoc` is used, so building needs no `protoc` installed.
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut config = prost_build::Config::new();
+    config.protoc_executable(protoc_bin_vendored::protoc_bin_path()?);
+    tonic_build::configure().compile_protos_with_config(
+        config,
+        &["proto/racer.proto"],
+        &["proto"],
+    )?;
+    Ok(())
+}
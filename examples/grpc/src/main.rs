@@ -0,0 +1,17 @@
+use std::error::Error;
+
+use grpc::v1::RacerService;
+use grpc::v1::proto::racer_server::RacerServer;
+use tonic::transport::Server;
+
+const ADDRESS: &str = "127.0.0.1:50051";
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    println!("listening on {}", ADDRESS);
+    Server::builder()
+        .add_service(RacerServer::new(RacerService::new()))
+        .serve(ADDRESS.parse()?)
+        .await?;
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use std::time::Duration;
+
+use select::v5::race_with_configuration;
+use tonic::Request;
+use tonic::Response;
+use tonic::Status;
+
+pub mod proto {
+    tonic::include_proto!("racer.v1");
+}
+
+use proto::RaceReply;
+use proto::RaceRequest;
+use proto::racer_server::Racer;
+
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// `RacerService` serves the racer of the select example over gRPC.
+#[derive(Debug)]
+pub struct RacerService {
+    timeout: Duration,
+}
+
+impl RacerService {
+    pub fn new() -> Self {
+        Self::with_timeout(DEFAULT_TIMEOUT)
+    }
+
+    /// Gives up on URLs that do not answer within the timeout.
+    pub fn with_timeout(timeout: Duration) -> Self {
+        RacerService { timeout }
+    }
+}
+
+impl Default for RacerService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[tonic::async_trait]
+impl Racer for RacerService {
+    /// Races exactly two URLs. Any other number is an invalid argument, and
+    /// a race neither URL finishes in time is unavailable.
+    async fn race(&self, request: Request<RaceRequest>) -> Result<Response<RaceReply>, Status> {
+        let urls = request.into_inner().urls;
+        let [url_1, url_2] = urls.as_slice() else {
+            let message = format!("expected 2 URLs, got {}", urls.len());
+            return Err(Status::invalid_argument(message));
+        };
+        match race_with_configuration(url_1, url_2, Some(self.timeout)).await {
+            Ok(winner) => Ok(Response::new(RaceReply {
+                winner: winner.to_string(),
+            })),
+            Err(message) => Err(Status::unavailable(message)),
+        }
+    }
+}
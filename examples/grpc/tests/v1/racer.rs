@@ -0,0 +1,97 @@
+use std::time::Duration;
+
+use grpc::v1::RacerService;
+use grpc::v1::proto::RaceRequest;
+use grpc::v1::proto::racer_client::RacerClient;
+use grpc::v1::proto::racer_server::RacerServer;
+use hyper_util::rt::TokioIo;
+use scenarios::delayed_server;
+use tonic::Code;
+use tonic::transport::Channel;
+use tonic::transport::Endpoint;
+use tonic::transport::Server;
+use tonic::transport::Uri;
+use tower::service_fn;
+
+/// Serves the racer on an in-memory duplex stream and returns a client
+/// connected to it, so that no port is needed.
+async fn client(service: RacerService) -> RacerClient<Channel> {
+    let (client_io, server_io) = tokio::io::duplex(1024);
+    tokio::spawn(async move {
+        Server::builder()
+            .add_service(RacerServer::new(service))
+            .serve_with_incoming(tokio_stream::once(Ok::<_, std::io::Error>(server_io)))
+            .await
+    });
+
+    // The URI is never dialled: the connector hands out the stream instead.
+    let mut client_io = Some(client_io);
+    let channel = Endpoint::try_from("http://[::]:50051")
+        .unwrap()
+        .connect_with_connector(service_fn(move |_: Uri| {
+            let io = client_io.take();
+            async move {
+                io.map(TokioIo::new)
+                    .ok_or_else(|| std::io::Error::other("stream is already connected"))
+            }
+        }))
+        .await
+        .unwrap();
+    RacerClient::new(channel)
+}
+
+fn race_request(urls: &[&str]) -> RaceRequest {
+    RaceRequest {
+        urls: urls.iter().map(|url| url.to_string()).collect(),
+    }
+}
+
+#[tokio::test]
+async fn sut_returns_fastest_url() {
+    // Arrange
+    let slow = delayed_server(Duration::from_millis(50)).await;
+    let fast = delayed_server(Duration::ZERO).await;
+    let mut sut = client(RacerService::new()).await;
+
+    // Act
+    let actual = sut
+        .race(race_request(&[&slow.uri(), &fast.uri()]))
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(fast.uri(), actual.into_inner().winner);
+}
+
+#[tokio::test]
+async fn sut_returns_unavailable_if_no_url_answers_in_time() {
+    // Arrange
+    let slow = delayed_server(Duration::from_millis(50)).await;
+    let mut sut = client(RacerService::with_timeout(Duration::from_millis(10))).await;
+
+    // Act
+    let actual = sut
+        .race(race_request(&[&slow.uri(), &slow.uri()]))
+        .await
+        .unwrap_err();
+
+    // Assert
+    assert_eq!(Code::Unavailable, actual.code());
+    assert_eq!("no successful response received", actual.message());
+}
+
+#[tokio::test]
+async fn sut_rejects_race_without_two_urls() {
+    // Arrange
+    let mut sut = client(RacerService::new()).await;
+
+    // Act
+    let actual = sut
+        .race(race_request(&["http://localhost"]))
+        .await
+        .unwrap_err();
+
+    // Assert
+    assert_eq!(Code::InvalidArgument, actual.code());
+    assert_eq!("expected 2 URLs, got 1", actual.message());
+}
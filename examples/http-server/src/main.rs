@@ -0,0 +1,38 @@
+use std::env;
+use std::error::Error;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use http_server::v1::PlayerStore;
+use http_server::v1::database::PostgresPlayerStore;
+use http_server::v1::file_system::FileSystemPlayerStore;
+use http_server::v1::graceful::GracefulServer;
+use http_server::v1::graceful::Shutdown;
+use http_server::v1::graceful::shutdown_signal;
+
+const ADDRESS: &str = "127.0.0.1:5000";
+const DATABASE_FILE: &str = "game.db.json";
+const DATABASE_URL: &str = "DATABASE_URL";
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    tracing_subscriber::fmt::init();
+    // Both can be overridden, as in `http-server 127.0.0.1:0 league.json`.
+    let mut args = env::args().skip(1);
+    let address = args.next().unwrap_or_else(|| ADDRESS.to_string());
+    let database = args
+        .next()
+        .map_or_else(|| PathBuf::from(DATABASE_FILE), PathBuf::from);
+    // With `DATABASE_URL` set, the league is kept in Postgres instead.
+    let store: Arc<dyn PlayerStore> = match env::var(DATABASE_URL) {
+        Ok(url) => Arc::new(PostgresPlayerStore::connect(&url)?),
+        Err(_) => Arc::new(FileSystemPlayerStore::from_path(&database)?),
+    };
+    let listener = tokio::net::TcpListener::bind(address).await?;
+    println!("listening on http://{}", listener.local_addr()?);
+    let server = GracefulServer::new(store);
+    if server.serve(listener, shutdown_signal()).await? == Shutdown::TimedOut {
+        eprintln!("shut down before every request was answered");
+    }
+    Ok(())
+}
@@ -0,0 +1,395 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::Extension;
+use axum::Json;
+use axum::Router;
+use axum::extract::Path;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::response::Response;
+use axum::routing::get;
+use logger::Logger;
+use logger::TextLogger;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use game::GameState;
+
+#[cfg(test)]
+mod contract;
+pub mod database;
+pub mod file_system;
+pub mod game;
+pub mod graceful;
+pub mod in_memory;
+pub mod logging;
+
+/// `PlayerStore` keeps the score of every player.
+///
+/// The methods take `&self` so that one store can be shared by every request;
+/// implementations synchronise access themselves.
+#[cfg_attr(test, mockall::automock)]
+pub trait PlayerStore: Send + Sync {
+    /// Returns the score of the player, or `None` if the player is unknown.
+    fn get_player_score(&self, name: &str) -> Option<u32>;
+    fn record_win(&self, name: &str) -> Result<(), StoreError>;
+    /// Returns every player, the most wins first.
+    fn get_league(&self) -> Vec<Player>;
+
+    /// Makes sure every recorded win has reached storage. Called when the
+    /// server shuts down; stores that keep nothing buffered have nothing to do.
+    fn flush(&self) -> Result<(), StoreError> {
+        Ok(())
+    }
+}
+
+/// `Player` is an entry of the league, serialized as
+/// `{"name": "Cleo", "wins": 10}` both in the league file and by `GET /league`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Player {
+    pub name: String,
+    pub wins: u32,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum StoreError {
+    #[error("failed to access league: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("league is corrupt: {0}")]
+    Corrupt(#[from] serde_json::Error),
+
+    #[error("failed to query league: {0}")]
+    Database(#[from] postgres::Error),
+}
+
+/// Sorts the league by wins, the most first. Players with as many wins keep
+/// alphabetical order, so that the league reads the same on every request.
+pub fn rank(league: &mut [Player]) {
+    league.sort_by(|a, b| b.wins.cmp(&a.wins).then_with(|| a.name.cmp(&b.name)));
+}
+
+/// Builds the routes of the player server:
+///
+/// - `GET /league` returns every player as JSON, the most wins first
+/// - `GET /players/{name}` returns the score of the player
+/// - `POST /players/{name}` records a win for the player
+/// - `GET /ws` plays a game over a WebSocket, as `game::play` describes
+///
+/// A win the store fails to record is answered with 500, and logged with
+/// why to stdout. Every request is logged as `logging::traced` describes.
+pub fn router(store: Arc<dyn PlayerStore>) -> Router {
+    router_with_blind_unit(store, game::BLIND_UNIT)
+}
+
+/// Builds the routes like `router`, with games raising blinds in `blind_unit`
+/// instead of minutes.
+pub fn router_with_blind_unit(store: Arc<dyn PlayerStore>, blind_unit: Duration) -> Router {
+    routes(store, blind_unit, Arc::new(TextLogger::stdout()))
+}
+
+/// Builds the routes like `router`, logging the wins the store fails to
+/// record to `logger`.
+pub fn router_with_logger(store: Arc<dyn PlayerStore>, logger: Arc<dyn Logger>) -> Router {
+    routes(store, game::BLIND_UNIT, logger)
+}
+
+fn routes(store: Arc<dyn PlayerStore>, blind_unit: Duration, logger: Arc<dyn Logger>) -> Router {
+    let game = GameState {
+        store: store.clone(),
+        blind_unit,
+    };
+    let routes = Router::new()
+        .route("/league", get(show_league))
+        .route("/players/{name}", get(show_score).post(process_win))
+        .with_state(store)
+        .merge(Router::new().route("/ws", get(game::play)).with_state(game))
+        .layer(Extension(logger));
+    logging::traced(routes)
+}
+
+async fn show_league(State(store): State<Arc<dyn PlayerStore>>) -> Json<Vec<Player>> {
+    Json(store.get_league())
+}
+
+async fn show_score(
+    State(store): State<Arc<dyn PlayerStore>>,
+    Path(name): Path<String>,
+) -> Response {
+    match store.get_player_score(&name) {
+        Some(score) => score.to_string().into_response(),
+        None => StatusCode::NOT_FOUND.into_response(),
+    }
+}
+
+async fn process_win(
+    State(store): State<Arc<dyn PlayerStore>>,
+    Extension(logger): Extension<Arc<dyn Logger>>,
+    Path(name): Path<String>,
+) -> StatusCode {
+    match store.record_win(&name) {
+        Ok(()) => StatusCode::ACCEPTED,
+        Err(error) => {
+            logger.error(
+                "failed to record win",
+                &[("player", &name), ("error", &error)],
+            );
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    }
+}
+
+#[cfg(test)]
+mod specs_for_router {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use std::sync::Mutex;
+
+    use axum::body::Body;
+    use axum::http::Method;
+    use axum::http::Request;
+    use axum::http::StatusCode;
+    use axum::http::header::CONTENT_TYPE;
+    use http_body_util::BodyExt;
+    use logger::CaptureLogger;
+    use logger::Fields;
+    use logger::Level;
+    use logger::Record;
+    use mockall::predicate::eq;
+    use tower::ServiceExt;
+
+    use super::MockPlayerStore;
+    use super::Player;
+    use super::PlayerStore;
+    use super::StoreError;
+    use super::router;
+    use super::router_with_logger;
+
+    #[derive(Default)]
+    struct StubPlayerStore {
+        scores: HashMap<String, u32>,
+        wins: Mutex<Vec<String>>,
+        league: Vec<Player>,
+        broken: bool,
+    }
+
+    impl PlayerStore for StubPlayerStore {
+        fn get_player_score(&self, name: &str) -> Option<u32> {
+            self.scores.get(name).copied()
+        }
+
+        fn record_win(&self, name: &str) -> Result<(), StoreError> {
+            if self.broken {
+                return Err(StoreError::Io(std::io::Error::other("disk full")));
+            }
+            self.wins.lock().unwrap().push(name.to_string());
+            Ok(())
+        }
+
+        fn get_league(&self) -> Vec<Player> {
+            self.league.clone()
+        }
+    }
+
+    #[rstest::fixture]
+    fn store() -> Arc<StubPlayerStore> {
+        Arc::new(StubPlayerStore {
+            scores: HashMap::from([("Pepper".to_string(), 20), ("Floyd".to_string(), 10)]),
+            ..StubPlayerStore::default()
+        })
+    }
+
+    async fn send(store: Arc<StubPlayerStore>, method: Method, uri: &str) -> (StatusCode, String) {
+        let (status, _, body) = send_for_content(store, method, uri).await;
+        (status, body)
+    }
+
+    /// Sends the request like `send`, also returning the content type.
+    async fn send_for_content(
+        store: Arc<StubPlayerStore>,
+        method: Method,
+        uri: &str,
+    ) -> (StatusCode, Option<String>, String) {
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .body(Body::empty())
+            .unwrap();
+        let response = router(store).oneshot(request).await.unwrap();
+        let status = response.status();
+        let content_type = response
+            .headers()
+            .get(CONTENT_TYPE)
+            .map(|value| value.to_str().unwrap().to_string());
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        (
+            status,
+            content_type,
+            String::from_utf8(body.to_vec()).unwrap(),
+        )
+    }
+
+    #[rstest::rstest]
+    #[case("/players/Pepper", "20")]
+    #[case("/players/Floyd", "10")]
+    #[tokio::test]
+    async fn sut_returns_score_of_player(
+        store: Arc<StubPlayerStore>,
+        #[case] uri: &str,
+        #[case] expected: &str,
+    ) {
+        // Act
+        let (status, actual) = send(store, Method::GET, uri).await;
+
+        // Assert
+        assert_eq!(StatusCode::OK, status);
+        assert_eq!(expected, actual);
+    }
+
+    #[rstest::rstest]
+    #[tokio::test]
+    async fn sut_returns_not_found_for_unknown_player(store: Arc<StubPlayerStore>) {
+        // Act
+        let (status, _) = send(store, Method::GET, "/players/Apollo").await;
+
+        // Assert
+        assert_eq!(StatusCode::NOT_FOUND, status);
+    }
+
+    #[rstest::rstest]
+    #[tokio::test]
+    async fn sut_records_win_on_post(store: Arc<StubPlayerStore>) {
+        // Act
+        let (status, _) = send(store.clone(), Method::POST, "/players/Pepper").await;
+
+        // Assert
+        assert_eq!(StatusCode::ACCEPTED, status);
+        assert_eq!(vec!["Pepper"], *store.wins.lock().unwrap());
+    }
+
+    #[tokio::test]
+    async fn sut_records_win_on_post_as_mock_expects() {
+        // Arrange
+        let mut store_mock = MockPlayerStore::new();
+        store_mock
+            .expect_record_win()
+            .with(eq("Pepper"))
+            .times(1)
+            .returning(|_| Ok(()));
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri("/players/Pepper")
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = router(Arc::new(store_mock)).oneshot(request).await.unwrap();
+
+        // Assert
+        assert_eq!(StatusCode::ACCEPTED, response.status());
+    }
+
+    #[tokio::test]
+    async fn sut_returns_server_error_if_win_cannot_be_recorded() {
+        // Arrange
+        let store = Arc::new(StubPlayerStore {
+            broken: true,
+            ..StubPlayerStore::default()
+        });
+
+        // Act
+        let (status, _) = send(store, Method::POST, "/players/Pepper").await;
+
+        // Assert
+        assert_eq!(StatusCode::INTERNAL_SERVER_ERROR, status);
+    }
+
+    #[tokio::test]
+    async fn sut_logs_win_it_cannot_record_with_why() {
+        // Arrange
+        let store = Arc::new(StubPlayerStore {
+            broken: true,
+            ..StubPlayerStore::default()
+        });
+        let logger = CaptureLogger::new();
+        let sut = router_with_logger(store, Arc::new(logger.clone()));
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri("/players/Pepper")
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        sut.oneshot(request).await.unwrap();
+
+        // Assert
+        let expected = vec![Record {
+            level: Level::Error,
+            message: "failed to record win".to_string(),
+            fields: Fields::from([
+                ("player".to_string(), "Pepper".to_string()),
+                (
+                    "error".to_string(),
+                    "failed to access league: disk full".to_string(),
+                ),
+            ]),
+        }];
+        assert_eq!(expected, logger.records());
+    }
+
+    #[tokio::test]
+    async fn sut_returns_league_as_json() {
+        // Arrange
+        let league = vec![
+            Player {
+                name: "Chris".to_string(),
+                wins: 33,
+            },
+            Player {
+                name: "Cleo".to_string(),
+                wins: 10,
+            },
+        ];
+        let store = Arc::new(StubPlayerStore {
+            league: league.clone(),
+            ..StubPlayerStore::default()
+        });
+
+        // Act
+        let (status, content_type, body) = send_for_content(store, Method::GET, "/league").await;
+
+        // Assert
+        assert_eq!(StatusCode::OK, status);
+        assert_eq!(Some("application/json"), content_type.as_deref());
+        assert_eq!(
+            r#"[{"name":"Chris","wins":33},{"name":"Cleo","wins":10}]"#,
+            body
+        );
+        assert_eq!(league, serde_json::from_str::<Vec<Player>>(&body).unwrap());
+    }
+
+    #[rstest::rstest]
+    #[tokio::test]
+    async fn sut_returns_empty_league_as_empty_array(store: Arc<StubPlayerStore>) {
+        // Act
+        let (status, content_type, body) = send_for_content(store, Method::GET, "/league").await;
+
+        // Assert
+        assert_eq!(StatusCode::OK, status);
+        assert_eq!(Some("application/json"), content_type.as_deref());
+        assert_eq!("[]", body);
+    }
+
+    #[rstest::rstest]
+    #[tokio::test]
+    async fn sut_returns_score_as_plain_text(store: Arc<StubPlayerStore>) {
+        // Act
+        let (_, content_type, _) = send_for_content(store, Method::GET, "/players/Pepper").await;
+
+        // Assert
+        assert_eq!(Some("text/plain; charset=utf-8"), content_type.as_deref());
+    }
+}
@@ -0,0 +1,107 @@
+//! Specs every `PlayerStore` has to meet, whatever keeps the scores.
+//!
+//! `player_store_contract!` expands to a test module running the specs against
+//! the store the expression creates. The expression is evaluated afresh for
+//! every spec, so each one starts from an empty league. Attributes given
+//! after the expression, such as `#[ignore]`, are put on every spec.
+
+macro_rules! player_store_contract {
+    ($store:expr $(, #[$attribute:meta])* $(,)?) => {
+        mod specs_for_player_store_contract {
+            use super::*;
+            use crate::v1::Player;
+            use crate::v1::PlayerStore;
+
+            fn player(name: &str, wins: u32) -> Player {
+                Player {
+                    name: name.to_string(),
+                    wins,
+                }
+            }
+
+            fn record_wins(sut: &dyn PlayerStore, name: &str, wins: u32) {
+                for _ in 0..wins {
+                    sut.record_win(name).unwrap();
+                }
+            }
+
+            #[test]
+            $(#[$attribute])*
+            fn sut_returns_none_for_unknown_player() {
+                // Arrange
+                let sut = $store;
+
+                // Act
+                let actual = sut.get_player_score("Apollo");
+
+                // Assert
+                assert_eq!(None, actual);
+            }
+
+            #[test]
+            $(#[$attribute])*
+            fn sut_returns_recorded_wins() {
+                // Arrange
+                let sut = $store;
+                record_wins(&sut, "Pepper", 2);
+                record_wins(&sut, "Floyd", 1);
+
+                // Act
+                let actual = (
+                    sut.get_player_score("Pepper"),
+                    sut.get_player_score("Floyd"),
+                );
+
+                // Assert
+                assert_eq!((Some(2), Some(1)), actual);
+            }
+
+            #[test]
+            $(#[$attribute])*
+            fn sut_returns_empty_league_before_any_win() {
+                // Arrange
+                let sut = $store;
+
+                // Act
+                let actual = sut.get_league();
+
+                // Assert
+                assert_eq!(Vec::<Player>::new(), actual);
+            }
+
+            #[test]
+            $(#[$attribute])*
+            fn sut_returns_league_sorted_by_wins() {
+                // Arrange
+                let sut = $store;
+                record_wins(&sut, "Cleo", 1);
+                record_wins(&sut, "Chris", 3);
+                record_wins(&sut, "Pepper", 2);
+
+                // Act
+                let actual = sut.get_league();
+
+                // Assert
+                let expected = vec![player("Chris", 3), player("Pepper", 2), player("Cleo", 1)];
+                assert_eq!(expected, actual);
+            }
+
+            #[test]
+            $(#[$attribute])*
+            fn sut_orders_players_with_as_many_wins_by_name() {
+                // Arrange
+                let sut = $store;
+                record_wins(&sut, "Pepper", 1);
+                record_wins(&sut, "Cleo", 1);
+
+                // Act
+                let actual = sut.get_league();
+
+                // Assert
+                assert_eq!(vec![player("Cleo", 1), player("Pepper", 1)], actual);
+            }
+        }
+    };
+}
+
+pub(crate) use player_store_contract;
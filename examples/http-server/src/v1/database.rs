@@ -0,0 +1,164 @@
+use std::sync::mpsc;
+use std::sync::mpsc::Sender;
+use std::thread;
+
+use postgres::Client;
+use postgres::NoTls;
+
+use super::Player;
+use super::PlayerStore;
+use super::StoreError;
+use super::rank;
+
+const SCHEMA: &str = "CREATE TABLE IF NOT EXISTS players (
+    name TEXT PRIMARY KEY,
+    wins INTEGER NOT NULL
+)";
+
+type Query = Box<dyn FnOnce(&mut Client) + Send>;
+
+/// `PostgresPlayerStore` keeps the scores in a `players` table of a Postgres
+/// database, creating the table if it does not exist yet.
+///
+/// The synchronous client cannot block a thread that runs async tasks, so
+/// it lives on a thread of its own and the store sends it every query.
+#[derive(Debug)]
+pub struct PostgresPlayerStore {
+    queries: Sender<Query>,
+}
+
+impl PostgresPlayerStore {
+    /// Connects to the database at a `postgres://` URL.
+    pub fn connect(url: &str) -> Result<Self, StoreError> {
+        let url = url.to_string();
+        let (queries, received) = mpsc::channel::<Query>();
+        let (connected, connecting) = mpsc::channel();
+        thread::spawn(move || {
+            let client = Client::connect(&url, NoTls).and_then(|mut client| {
+                client.batch_execute(SCHEMA)?;
+                Ok(client)
+            });
+            let mut client = match client {
+                Ok(client) => {
+                    let _ = connected.send(Ok(()));
+                    client
+                }
+                Err(error) => {
+                    let _ = connected.send(Err(error));
+                    return;
+                }
+            };
+            for query in received {
+                query(&mut client);
+            }
+        });
+        connecting.recv().expect("database thread panicked")?;
+        Ok(PostgresPlayerStore { queries })
+    }
+
+    /// Runs `query` with the client, waiting for what it returns.
+    fn query<T: Send + 'static>(
+        &self,
+        query: impl FnOnce(&mut Client) -> Result<T, postgres::Error> + Send + 'static,
+    ) -> Result<T, StoreError> {
+        let (reply, replied) = mpsc::channel();
+        self.queries
+            .send(Box::new(move |client| {
+                let _ = reply.send(query(client));
+            }))
+            .expect("database thread panicked");
+        Ok(replied.recv().expect("database thread panicked")?)
+    }
+}
+
+/// A query failing to read is treated like finding nothing, as the trait
+/// has no way to report it: an unknown player, or an empty league.
+impl PlayerStore for PostgresPlayerStore {
+    fn get_player_score(&self, name: &str) -> Option<u32> {
+        let name = name.to_string();
+        self.query(move |client| {
+            client.query_opt("SELECT wins FROM players WHERE name = $1", &[&name])
+        })
+        .ok()
+        .flatten()
+        .map(|row| row.get::<_, i32>(0) as u32)
+    }
+
+    fn record_win(&self, name: &str) -> Result<(), StoreError> {
+        let name = name.to_string();
+        // Inserting or incrementing in one statement keeps the update atomic.
+        self.query(move |client| {
+            client.execute(
+                "INSERT INTO players (name, wins) VALUES ($1, 1)
+                 ON CONFLICT (name) DO UPDATE SET wins = players.wins + 1",
+                &[&name],
+            )
+        })?;
+        Ok(())
+    }
+
+    fn get_league(&self) -> Vec<Player> {
+        let rows = self
+            .query(|client| client.query("SELECT name, wins FROM players", &[]))
+            .unwrap_or_default();
+        let mut league = rows
+            .iter()
+            .map(|row| Player {
+                name: row.get(0),
+                wins: row.get::<_, i32>(1) as u32,
+            })
+            .collect::<Vec<_>>();
+        // Sorted here rather than by the query, so that names compare the
+        // same as in the other stores whatever the database collation.
+        rank(&mut league);
+        league
+    }
+}
+
+#[cfg(test)]
+mod specs_for_postgres_player_store {
+    use containers::PostgresContainer;
+    use tokio::runtime::Runtime;
+
+    use super::PostgresPlayerStore;
+    use crate::v1::Player;
+    use crate::v1::PlayerStore;
+    use crate::v1::StoreError;
+
+    /// `ContainedStore` is a store connected to a database of its own, which
+    /// is removed with the store.
+    struct ContainedStore {
+        store: PostgresPlayerStore,
+        _container: PostgresContainer,
+        _runtime: Runtime,
+    }
+
+    impl PlayerStore for ContainedStore {
+        fn get_player_score(&self, name: &str) -> Option<u32> {
+            self.store.get_player_score(name)
+        }
+
+        fn record_win(&self, name: &str) -> Result<(), StoreError> {
+            self.store.record_win(name)
+        }
+
+        fn get_league(&self) -> Vec<Player> {
+            self.store.get_league()
+        }
+    }
+
+    fn contained_store() -> ContainedStore {
+        let runtime = Runtime::new().unwrap();
+        let container = runtime.block_on(containers::postgres()).unwrap();
+        ContainedStore {
+            store: PostgresPlayerStore::connect(container.connection_string()).unwrap(),
+            _container: container,
+            _runtime: runtime,
+        }
+    }
+
+    crate::v1::contract::player_store_contract!(
+        contained_store(),
+        #[ignore = "needs Docker"]
+    );
+}
@@ -0,0 +1,284 @@
+use std::fs::File;
+use std::fs::OpenOptions;
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+use std::io::Write;
+use std::path::Path;
+use std::sync::Mutex;
+
+use super::Player;
+use super::PlayerStore;
+use super::StoreError;
+use super::rank;
+
+/// `Tape` rewrites a file from its start.
+///
+/// The file is truncated before every write, so that content shorter than the
+/// previous one does not leave the end of the old content behind.
+#[derive(Debug)]
+pub struct Tape {
+    file: File,
+}
+
+impl Tape {
+    pub fn new(file: File) -> Self {
+        Tape { file }
+    }
+
+    /// Replaces the whole content of the file.
+    pub fn rewrite(&mut self, content: &[u8]) -> std::io::Result<()> {
+        self.file.seek(SeekFrom::Start(0))?;
+        self.file.set_len(0)?;
+        self.file.write_all(content)?;
+        self.file.flush()
+    }
+
+    /// Waits until the content has been written to disk.
+    pub fn sync(&self) -> std::io::Result<()> {
+        self.file.sync_all()
+    }
+}
+
+/// `FileSystemPlayerStore` keeps the league as a JSON array in a file, such as
+/// `[{"name":"Cleo","wins":10}]`.
+///
+/// The league is read once when the store is created and written back after
+/// every win.
+#[derive(Debug)]
+pub struct FileSystemPlayerStore {
+    state: Mutex<(Tape, Vec<Player>)>,
+}
+
+impl FileSystemPlayerStore {
+    /// Reads the league from the file. An empty file is an empty league.
+    pub fn new(mut file: File) -> Result<Self, StoreError> {
+        let mut content = String::new();
+        file.seek(SeekFrom::Start(0))?;
+        file.read_to_string(&mut content)?;
+        let league = if content.trim().is_empty() {
+            vec![]
+        } else {
+            serde_json::from_str(&content)?
+        };
+        Ok(FileSystemPlayerStore {
+            state: Mutex::new((Tape::new(file), league)),
+        })
+    }
+
+    /// Opens the file at the path, creating it if it does not exist yet.
+    pub fn from_path(path: &Path) -> Result<Self, StoreError> {
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)?;
+        Self::new(file)
+    }
+}
+
+impl PlayerStore for FileSystemPlayerStore {
+    fn get_player_score(&self, name: &str) -> Option<u32> {
+        let (_, league) = &*self.state.lock().unwrap();
+        league
+            .iter()
+            .find(|player| player.name == name)
+            .map(|player| player.wins)
+    }
+
+    fn record_win(&self, name: &str) -> Result<(), StoreError> {
+        let (tape, league) = &mut *self.state.lock().unwrap();
+        match league.iter_mut().find(|player| player.name == name) {
+            Some(player) => player.wins += 1,
+            None => league.push(Player {
+                name: name.to_string(),
+                wins: 1,
+            }),
+        }
+        tape.rewrite(&serde_json::to_vec(league)?)?;
+        Ok(())
+    }
+
+    fn get_league(&self) -> Vec<Player> {
+        let (_, league) = &*self.state.lock().unwrap();
+        let mut league = league.clone();
+        rank(&mut league);
+        league
+    }
+
+    fn flush(&self) -> Result<(), StoreError> {
+        let (tape, _) = &*self.state.lock().unwrap();
+        tape.sync()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod specs_for_tape {
+    use std::fs;
+    use std::fs::OpenOptions;
+
+    use tempfile::NamedTempFile;
+
+    use super::Tape;
+
+    #[rstest::rstest]
+    #[case("12345", "abc")]
+    #[case("abc", "12345")]
+    #[case("12345", "")]
+    fn sut_replaces_whole_content(#[case] before: &str, #[case] after: &str) {
+        // Arrange
+        let file = NamedTempFile::new().unwrap();
+        fs::write(file.path(), before).unwrap();
+        let mut sut = Tape::new(OpenOptions::new().write(true).open(file.path()).unwrap());
+
+        // Act
+        sut.rewrite(after.as_bytes()).unwrap();
+
+        // Assert
+        assert_eq!(after, fs::read_to_string(file.path()).unwrap());
+    }
+}
+
+#[cfg(test)]
+mod specs_for_file_system_player_store {
+    use std::fs;
+
+    use assertions::assert_err_matches;
+    use tempfile::NamedTempFile;
+
+    use super::FileSystemPlayerStore;
+    use crate::v1::Player;
+    use crate::v1::PlayerStore;
+    use crate::v1::StoreError;
+
+    const LEAGUE: &str = r#"[{"name":"Cleo","wins":10},{"name":"Chris","wins":33}]"#;
+
+    crate::v1::contract::player_store_contract!(
+        FileSystemPlayerStore::new(tempfile::tempfile().unwrap()).unwrap()
+    );
+
+    fn database(content: &str) -> NamedTempFile {
+        let file = NamedTempFile::new().unwrap();
+        fs::write(file.path(), content).unwrap();
+        file
+    }
+
+    #[rstest::rstest]
+    #[case("Cleo", Some(10))]
+    #[case("Chris", Some(33))]
+    #[case("Apollo", None)]
+    fn sut_returns_score_from_file(#[case] name: &str, #[case] expected: Option<u32>) {
+        // Arrange
+        let file = database(LEAGUE);
+        let sut = FileSystemPlayerStore::from_path(file.path()).unwrap();
+
+        // Act
+        let actual = sut.get_player_score(name);
+
+        // Assert
+        assert_eq!(expected, actual);
+    }
+
+    #[rstest::rstest]
+    #[case("Chris", 34)]
+    #[case("Pepper", 1)]
+    fn sut_records_win_in_file(#[case] name: &str, #[case] expected: u32) {
+        // Arrange
+        let file = database(LEAGUE);
+        let sut = FileSystemPlayerStore::from_path(file.path()).unwrap();
+
+        // Act
+        sut.record_win(name).unwrap();
+
+        // Assert
+        assert_eq!(Some(expected), sut.get_player_score(name));
+        let reopened = FileSystemPlayerStore::from_path(file.path()).unwrap();
+        assert_eq!(Some(expected), reopened.get_player_score(name));
+    }
+
+    #[test]
+    fn sut_leaves_only_valid_json_in_file() {
+        // Arrange
+        let file = database(&format!("{}\n\n\n", LEAGUE));
+        let sut = FileSystemPlayerStore::from_path(file.path()).unwrap();
+
+        // Act
+        sut.record_win("Cleo").unwrap();
+
+        // Assert
+        let content = fs::read_to_string(file.path()).unwrap();
+        let actual = serde_json::from_str::<Vec<Player>>(&content).unwrap();
+        assert_eq!(11, actual[0].wins);
+    }
+
+    #[rstest::rstest]
+    #[case("")]
+    #[case("  \n")]
+    fn sut_starts_empty_league_if_file_is_empty(#[case] content: &str) {
+        // Arrange
+        let file = database(content);
+
+        // Act
+        let sut = FileSystemPlayerStore::from_path(file.path()).unwrap();
+
+        // Assert
+        assert_eq!(None, sut.get_player_score("Cleo"));
+    }
+
+    #[test]
+    fn sut_creates_missing_file() {
+        // Arrange
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("league.json");
+        let sut = FileSystemPlayerStore::from_path(&path).unwrap();
+
+        // Act
+        sut.record_win("Cleo").unwrap();
+
+        // Assert
+        let expected = r#"[{"name":"Cleo","wins":1}]"#;
+        assert_eq!(expected, fs::read_to_string(path).unwrap());
+    }
+
+    #[rstest::rstest]
+    #[case("not json")]
+    #[case(r#"[{"name":"Cleo","wins":10}"#)]
+    #[case(r#"{"name":"Cleo","wins":10}"#)]
+    #[case(r#"[{"name":"Cleo","wins":-1}]"#)]
+    #[case(r#"[{"name":"Cleo"}]"#)]
+    fn sut_rejects_corrupt_file(#[case] content: &str) {
+        // Arrange
+        let file = database(content);
+
+        // Act
+        let actual = FileSystemPlayerStore::from_path(file.path());
+
+        // Assert
+        assert_err_matches!(actual, StoreError::Corrupt(_));
+    }
+
+    #[test]
+    fn sut_returns_league_from_file_sorted_by_wins() {
+        // Arrange
+        let file = database(LEAGUE);
+        let sut = FileSystemPlayerStore::from_path(file.path()).unwrap();
+
+        // Act
+        let actual = sut.get_league();
+
+        // Assert
+        let expected = vec![
+            Player {
+                name: "Chris".to_string(),
+                wins: 33,
+            },
+            Player {
+                name: "Cleo".to_string(),
+                wins: 10,
+            },
+        ];
+        assert_eq!(expected, actual);
+    }
+}
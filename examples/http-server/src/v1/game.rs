@@ -0,0 +1,124 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::extract::State;
+use axum::extract::WebSocketUpgrade;
+use axum::extract::ws::Message;
+use axum::extract::ws::WebSocket;
+use axum::response::Response;
+use tokio::time::Instant;
+
+use super::PlayerStore;
+
+/// The blind amounts of a game, raised one after the other.
+pub const BLINDS: [u32; 11] = [100, 200, 300, 400, 500, 600, 800, 1000, 2000, 4000, 8000];
+
+/// The unit blind intervals are counted in. A game is played in minutes.
+pub const BLIND_UNIT: Duration = Duration::from_secs(60);
+
+/// Returns when each blind is raised, counted from the start of the game.
+///
+/// The first blind is due at once and every next one `5 + players` units
+/// later, so that a bigger table gets more time per blind.
+pub fn blind_schedule(players: u32, unit: Duration) -> Vec<(Duration, u32)> {
+    let increment = unit * (5 + players);
+    BLINDS
+        .iter()
+        .zip(0..)
+        .map(|(&amount, n)| (increment * n, amount))
+        .collect()
+}
+
+/// `GameState` is what a game played over a WebSocket needs.
+#[derive(Clone)]
+pub struct GameState {
+    pub store: Arc<dyn PlayerStore>,
+    pub blind_unit: Duration,
+}
+
+/// Upgrades the request to a WebSocket to play a game on.
+///
+/// The client first sends the number of players. The server then pushes a
+/// `Blind is now {amount}` message whenever the blind is raised, until the
+/// client sends the name of the winner, whose win is recorded.
+pub async fn play(State(state): State<GameState>, upgrade: WebSocketUpgrade) -> Response {
+    upgrade.on_upgrade(move |socket| play_game(socket, state))
+}
+
+async fn play_game(mut socket: WebSocket, state: GameState) {
+    run_game(&mut socket, state).await;
+    socket.send(Message::Close(None)).await.ok();
+}
+
+/// Plays the game until the winner is known or the client goes.
+async fn run_game(socket: &mut WebSocket, state: GameState) {
+    let Some(players) = receive_text(socket)
+        .await
+        .and_then(|text| text.trim().parse().ok())
+    else {
+        return;
+    };
+
+    let start = Instant::now();
+    let mut alerts = blind_schedule(players, state.blind_unit).into_iter();
+    let mut next = alerts.next();
+    loop {
+        let due = start + next.map_or(Duration::ZERO, |(at, _)| at);
+        tokio::select! {
+            winner = receive_text(socket) => {
+                if let Some(winner) = winner
+                    && let Err(error) = state.store.record_win(winner.trim())
+                {
+                    tracing::warn!(%error, "failed to record win");
+                }
+                break;
+            }
+            () = tokio::time::sleep_until(due), if next.is_some() => {
+                if let Some((_, amount)) = next {
+                    let alert = format!("Blind is now {}", amount);
+                    if socket.send(Message::Text(alert.into())).await.is_err() {
+                        break;
+                    }
+                }
+                next = alerts.next();
+            }
+        }
+    }
+}
+
+/// Returns the next text message, skipping pings and the like, or `None` once
+/// the client has gone.
+async fn receive_text(socket: &mut WebSocket) -> Option<String> {
+    while let Some(message) = socket.recv().await {
+        match message.ok()? {
+            Message::Text(text) => return Some(text.to_string()),
+            Message::Close(_) => return None,
+            _ => {}
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod specs_for_blind_schedule {
+    use std::time::Duration;
+
+    use super::blind_schedule;
+
+    #[rstest::rstest]
+    #[case(5, 10)]
+    #[case(7, 12)]
+    fn sut_raises_blind_every_5_plus_players_units(#[case] players: u32, #[case] minutes: u64) {
+        // Arrange
+        let unit = Duration::from_secs(60);
+
+        // Act
+        let actual = blind_schedule(players, unit);
+
+        // Assert
+        let increment = Duration::from_secs(60 * minutes);
+        assert_eq!((Duration::ZERO, 100), actual[0]);
+        assert_eq!((increment, 200), actual[1]);
+        assert_eq!((increment * 10, 8000), actual[10]);
+    }
+}
@@ -0,0 +1,255 @@
+use std::future::Future;
+use std::future::IntoFuture;
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::Router;
+use cancellation::Scope;
+use tokio::net::TcpListener;
+
+use super::PlayerStore;
+use super::StoreError;
+use super::router;
+
+/// How long requests still in flight get to finish once shutdown starts.
+pub const DEFAULT_DEADLINE: Duration = Duration::from_secs(10);
+
+/// `Shutdown` tells how the server stopped.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Shutdown {
+    /// Every request in flight was answered.
+    Drained,
+    /// The deadline passed with requests still in flight. They are abandoned.
+    TimedOut,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ServeError {
+    #[error("failed to serve: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("failed to flush store: {0}")]
+    Store(#[from] StoreError),
+}
+
+/// `GracefulServer` serves the player routes until it is told to shut down.
+///
+/// Shutting down stops accepting connections, gives the requests in flight
+/// until the deadline to finish and then flushes the store, so that no win
+/// that was answered is lost.
+pub struct GracefulServer {
+    store: Arc<dyn PlayerStore>,
+    deadline: Duration,
+}
+
+impl GracefulServer {
+    pub fn new(store: Arc<dyn PlayerStore>) -> Self {
+        GracefulServer {
+            store,
+            deadline: DEFAULT_DEADLINE,
+        }
+    }
+
+    pub fn with_deadline(self, deadline: Duration) -> Self {
+        GracefulServer { deadline, ..self }
+    }
+
+    /// Serves on the listener until `shutdown` completes.
+    pub async fn serve(
+        self,
+        listener: TcpListener,
+        shutdown: impl Future<Output = ()> + Send + 'static,
+    ) -> Result<Shutdown, ServeError> {
+        let app: Router = router(self.store.clone());
+        let started = Scope::new();
+        let signal = {
+            let started = started.clone();
+            async move {
+                shutdown.await;
+                started.cancel();
+            }
+        };
+        let server = axum::serve(listener, app)
+            .with_graceful_shutdown(signal)
+            .into_future();
+        // The deadline only starts counting once shutdown does.
+        let deadline = async {
+            started.cancelled().await;
+            tokio::time::sleep(self.deadline).await;
+        };
+
+        let shutdown = tokio::select! {
+            result = server => {
+                result?;
+                Shutdown::Drained
+            }
+            () = deadline => Shutdown::TimedOut,
+        };
+        self.store.flush()?;
+        Ok(shutdown)
+    }
+}
+
+/// Completes when the process receives SIGINT or, on Unix, SIGTERM.
+pub async fn shutdown_signal() {
+    let interrupt = async {
+        tokio::signal::ctrl_c()
+            .await
+            .expect("failed to listen for SIGINT");
+    };
+    #[cfg(unix)]
+    let terminate = async {
+        tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+            .expect("failed to listen for SIGTERM")
+            .recv()
+            .await;
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        () = interrupt => {}
+        () = terminate => {}
+    }
+}
+
+#[cfg(test)]
+mod specs_for_graceful_server {
+    use std::net::SocketAddr;
+    use std::sync::Arc;
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+    use std::time::Duration;
+
+    use tokio::io::AsyncReadExt;
+    use tokio::io::AsyncWriteExt;
+    use tokio::net::TcpListener;
+    use tokio::net::TcpStream;
+    use tokio::sync::Notify;
+    use tokio::sync::oneshot;
+    use tokio::task::JoinHandle;
+
+    use super::GracefulServer;
+    use super::ServeError;
+    use super::Shutdown;
+    use crate::v1::Player;
+    use crate::v1::PlayerStore;
+    use crate::v1::StoreError;
+
+    /// `SlowPlayerStore` takes its time to record a win, and tells when it
+    /// starts to.
+    struct SlowPlayerStore {
+        delay: Duration,
+        recording: Notify,
+        wins: AtomicUsize,
+        flushes: AtomicUsize,
+    }
+
+    impl SlowPlayerStore {
+        fn new(delay: Duration) -> Arc<Self> {
+            Arc::new(SlowPlayerStore {
+                delay,
+                recording: Notify::new(),
+                wins: AtomicUsize::new(0),
+                flushes: AtomicUsize::new(0),
+            })
+        }
+    }
+
+    impl PlayerStore for SlowPlayerStore {
+        fn get_player_score(&self, _: &str) -> Option<u32> {
+            None
+        }
+
+        fn record_win(&self, _: &str) -> Result<(), StoreError> {
+            self.recording.notify_one();
+            std::thread::sleep(self.delay);
+            self.wins.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+
+        fn get_league(&self) -> Vec<Player> {
+            vec![]
+        }
+
+        fn flush(&self) -> Result<(), StoreError> {
+            self.flushes.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    type Server = JoinHandle<Result<Shutdown, ServeError>>;
+
+    async fn start(sut: GracefulServer) -> (SocketAddr, oneshot::Sender<()>, Server) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let (shutdown, signal) = oneshot::channel();
+        let server = tokio::spawn(sut.serve(listener, async {
+            signal.await.ok();
+        }));
+        (address, shutdown, server)
+    }
+
+    /// Records a win over a raw connection and returns the status line.
+    async fn record_win(address: SocketAddr) -> String {
+        let mut stream = TcpStream::connect(address).await.unwrap();
+        let request = "POST /players/Pepper HTTP/1.1\r\nHost: localhost\r\n\
+                       Content-Length: 0\r\nConnection: close\r\n\r\n";
+        stream.write_all(request.as_bytes()).await.unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        response.lines().next().unwrap_or_default().to_string()
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn sut_answers_request_in_flight_before_shutting_down() {
+        // Arrange
+        let store = SlowPlayerStore::new(Duration::from_millis(200));
+        let sut = GracefulServer::new(store.clone());
+        let (address, shutdown, server) = start(sut).await;
+        let request = tokio::spawn(record_win(address));
+        store.recording.notified().await;
+
+        // Act
+        shutdown.send(()).unwrap();
+        let actual = server.await.unwrap().unwrap();
+
+        // Assert
+        assert_eq!(Shutdown::Drained, actual);
+        assert_eq!("HTTP/1.1 202 Accepted", request.await.unwrap());
+        assert_eq!(1, store.wins.load(Ordering::SeqCst));
+        assert_eq!(1, store.flushes.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn sut_stops_accepting_connections_once_shut_down() {
+        // Arrange
+        let store = SlowPlayerStore::new(Duration::ZERO);
+        let (address, shutdown, server) = start(GracefulServer::new(store)).await;
+
+        // Act
+        shutdown.send(()).unwrap();
+        server.await.unwrap().unwrap();
+
+        // Assert
+        assert!(TcpStream::connect(address).await.is_err());
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn sut_gives_up_on_request_in_flight_after_deadline() {
+        // Arrange
+        let store = SlowPlayerStore::new(Duration::from_secs(1));
+        let sut = GracefulServer::new(store.clone()).with_deadline(Duration::from_millis(50));
+        let (address, shutdown, server) = start(sut).await;
+        tokio::spawn(record_win(address));
+        store.recording.notified().await;
+
+        // Act
+        shutdown.send(()).unwrap();
+        let actual = server.await.unwrap().unwrap();
+
+        // Assert
+        assert_eq!(Shutdown::TimedOut, actual);
+        assert_eq!(1, store.flushes.load(Ordering::SeqCst));
+    }
+}
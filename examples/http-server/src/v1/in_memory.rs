@@ -0,0 +1,127 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use super::Player;
+use super::PlayerStore;
+use super::StoreError;
+use super::rank;
+
+/// `InMemoryPlayerStore` keeps the scores in a map guarded by a lock, so that
+/// concurrent wins for the same player are all counted.
+#[derive(Debug, Default)]
+pub struct InMemoryPlayerStore {
+    scores: Mutex<HashMap<String, u32>>,
+}
+
+impl InMemoryPlayerStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl PlayerStore for InMemoryPlayerStore {
+    fn get_player_score(&self, name: &str) -> Option<u32> {
+        self.scores.lock().unwrap().get(name).copied()
+    }
+
+    fn record_win(&self, name: &str) -> Result<(), StoreError> {
+        // Reading and incrementing under one lock keeps the update atomic.
+        *self
+            .scores
+            .lock()
+            .unwrap()
+            .entry(name.to_string())
+            .or_insert(0) += 1;
+        Ok(())
+    }
+
+    fn get_league(&self) -> Vec<Player> {
+        let mut league = self
+            .scores
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(name, wins)| Player {
+                name: name.clone(),
+                wins: *wins,
+            })
+            .collect::<Vec<_>>();
+        rank(&mut league);
+        league
+    }
+}
+
+#[cfg(test)]
+mod specs_for_in_memory_player_store {
+    use std::sync::Arc;
+
+    use axum::body::Body;
+    use axum::http::Method;
+    use axum::http::Request;
+    use axum::http::StatusCode;
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    use super::InMemoryPlayerStore;
+    use crate::v1::PlayerStore;
+    use crate::v1::router;
+
+    const WINS: usize = 500;
+
+    crate::v1::contract::player_store_contract!(InMemoryPlayerStore::new());
+
+    fn request(method: Method, uri: &str) -> Request<Body> {
+        Request::builder()
+            .method(method)
+            .uri(uri)
+            .body(Body::empty())
+            .unwrap()
+    }
+
+    #[test]
+    fn sut_counts_wins_per_player() {
+        // Arrange
+        let sut = InMemoryPlayerStore::new();
+
+        // Act
+        sut.record_win("Pepper").unwrap();
+        sut.record_win("Pepper").unwrap();
+        sut.record_win("Floyd").unwrap();
+
+        // Assert
+        assert_eq!(Some(2), sut.get_player_score("Pepper"));
+        assert_eq!(Some(1), sut.get_player_score("Floyd"));
+        assert_eq!(None, sut.get_player_score("Apollo"));
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 8)]
+    async fn sut_counts_every_win_of_concurrent_requests() {
+        // Arrange
+        let sut = router(Arc::new(InMemoryPlayerStore::new()));
+
+        // Act
+        let requests = (0..WINS)
+            .map(|_| {
+                let sut = sut.clone();
+                tokio::spawn(async move {
+                    let response = sut
+                        .oneshot(request(Method::POST, "/players/Pepper"))
+                        .await
+                        .unwrap();
+                    response.status()
+                })
+            })
+            .collect::<Vec<_>>();
+        for request in requests {
+            assert_eq!(StatusCode::ACCEPTED, request.await.unwrap());
+        }
+
+        // Assert
+        let response = sut
+            .oneshot(request(Method::GET, "/players/Pepper"))
+            .await
+            .unwrap();
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        assert_eq!(WINS.to_string(), String::from_utf8(body.to_vec()).unwrap());
+    }
+}
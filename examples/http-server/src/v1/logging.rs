@@ -0,0 +1,145 @@
+use std::time::Duration;
+
+use axum::Router;
+use axum::body::Body;
+use axum::http::HeaderName;
+use axum::http::Request;
+use axum::http::Response;
+use tower::ServiceBuilder;
+use tower_http::request_id::MakeRequestUuid;
+use tower_http::request_id::PropagateRequestIdLayer;
+use tower_http::request_id::SetRequestIdLayer;
+use tower_http::trace::TraceLayer;
+use tracing::Span;
+use tracing::field::Empty;
+
+/// The header carrying the id of a request, both ways.
+pub const REQUEST_ID: HeaderName = HeaderName::from_static("x-request-id");
+
+/// Wraps the routes so that every request is logged in a `request` span with
+/// its method, path and id, and the status and latency once it is answered.
+///
+/// A request without an `x-request-id` header is given a new id. Either way
+/// the id is sent back in the response, so that clients can quote it.
+pub fn traced(router: Router) -> Router {
+    router.layer(
+        ServiceBuilder::new()
+            .layer(SetRequestIdLayer::new(REQUEST_ID, MakeRequestUuid))
+            .layer(
+                TraceLayer::new_for_http()
+                    .make_span_with(make_span)
+                    .on_request(())
+                    .on_response(on_response),
+            )
+            .layer(PropagateRequestIdLayer::new(REQUEST_ID)),
+    )
+}
+
+fn make_span(request: &Request<Body>) -> Span {
+    let request_id = request
+        .headers()
+        .get(REQUEST_ID)
+        .and_then(|id| id.to_str().ok())
+        .unwrap_or_default();
+    tracing::info_span!(
+        "request",
+        method = %request.method(),
+        path = request.uri().path(),
+        request_id,
+        status = Empty,
+        latency_ms = Empty,
+    )
+}
+
+fn on_response<B>(response: &Response<B>, latency: Duration, span: &Span) {
+    span.record("status", response.status().as_u16());
+    span.record("latency_ms", latency.as_millis() as u64);
+    tracing::info!("answered request");
+}
+
+#[cfg(test)]
+mod specs_for_traced {
+    use axum::Router;
+    use axum::body::Body;
+    use axum::http::Request;
+    use axum::http::StatusCode;
+    use axum::routing::get;
+    use http_body_util::BodyExt;
+    use test_helpers::Fields;
+    use test_helpers::TraceCapture;
+    use tower::ServiceExt;
+
+    use super::REQUEST_ID;
+    use super::traced;
+
+    async fn send(request: Request<Body>) -> (StatusCode, Option<String>, Vec<Fields>) {
+        let capture = TraceCapture::new();
+        let _guard = capture.set_default();
+        let sut = traced(Router::new().route("/players/{name}", get(|| async { "20" })));
+
+        let response = sut.oneshot(request).await.unwrap();
+        let status = response.status();
+        let request_id = response
+            .headers()
+            .get(REQUEST_ID)
+            .map(|id| id.to_str().unwrap().to_string());
+        // The span lasts until the body has been sent.
+        response.into_body().collect().await.unwrap();
+
+        (status, request_id, capture.spans("request"))
+    }
+
+    #[rstest::rstest]
+    #[case("/players/Pepper", "200")]
+    #[case("/league/missing", "404")]
+    #[tokio::test]
+    async fn sut_records_request_in_span(#[case] path: &str, #[case] status: &str) {
+        // Arrange
+        let request = Request::get(path).body(Body::empty()).unwrap();
+
+        // Act
+        let (_, _, spans) = send(request).await;
+
+        // Assert
+        assert_eq!(1, spans.len());
+        let span = &spans[0];
+        assert_eq!(Some("GET"), span.get("method").map(String::as_str));
+        assert_eq!(Some(path), span.get("path").map(String::as_str));
+        assert_eq!(Some(status), span.get("status").map(String::as_str));
+        assert!(span.contains_key("latency_ms"));
+    }
+
+    #[tokio::test]
+    async fn sut_gives_request_new_id() {
+        // Arrange
+        let request = Request::get("/players/Pepper").body(Body::empty()).unwrap();
+
+        // Act
+        let (status, request_id, spans) = send(request).await;
+
+        // Assert
+        assert_eq!(StatusCode::OK, status);
+        let request_id = request_id.unwrap();
+        assert_eq!(36, request_id.len());
+        assert_eq!(Some(&request_id), spans[0].get("request_id"));
+    }
+
+    #[tokio::test]
+    async fn sut_propagates_id_of_request() {
+        // Arrange
+        let request = Request::get("/players/Pepper")
+            .header(REQUEST_ID, "abc-123")
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let (_, request_id, spans) = send(request).await;
+
+        // Assert
+        assert_eq!(Some("abc-123"), request_id.as_deref());
+        assert_eq!(
+            Some("abc-123"),
+            spans[0].get("request_id").map(String::as_str)
+        );
+    }
+}
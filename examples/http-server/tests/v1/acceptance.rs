@@ -0,0 +1,24 @@
+use crate::drivers::HttpDriver;
+use crate::drivers::InProcessDriver;
+use crate::specifications::league_specification;
+use crate::specifications::player_score_specification;
+
+#[tokio::test]
+async fn sut_in_process_meets_player_score_specification() {
+    player_score_specification(&InProcessDriver::new()).await;
+}
+
+#[tokio::test]
+async fn sut_in_process_meets_league_specification() {
+    league_specification(&InProcessDriver::new()).await;
+}
+
+#[tokio::test]
+async fn sut_binary_meets_player_score_specification() {
+    player_score_specification(&HttpDriver::against_binary()).await;
+}
+
+#[tokio::test]
+async fn sut_binary_meets_league_specification() {
+    league_specification(&HttpDriver::against_binary()).await;
+}
@@ -0,0 +1,158 @@
+use std::io::BufRead;
+use std::io::BufReader;
+use std::process::Child;
+use std::process::Command;
+use std::process::Stdio;
+use std::sync::Arc;
+
+use axum::Router;
+use axum::body::Body;
+use axum::http::Method;
+use axum::http::Request;
+use axum::http::StatusCode;
+use http_body_util::BodyExt;
+use http_server::v1::Player;
+use http_server::v1::in_memory::InMemoryPlayerStore;
+use http_server::v1::router;
+use tempfile::TempDir;
+use tower::ServiceExt;
+
+use crate::specifications::Driver;
+
+/// `InProcessDriver` sends requests straight to the routes, with the
+/// league kept in memory.
+pub struct InProcessDriver {
+    router: Router,
+}
+
+impl InProcessDriver {
+    pub fn new() -> Self {
+        InProcessDriver {
+            router: router(Arc::new(InMemoryPlayerStore::new())),
+        }
+    }
+
+    async fn send(&self, method: Method, uri: &str) -> (StatusCode, String) {
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .body(Body::empty())
+            .unwrap();
+        let response = self.router.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        (status, String::from_utf8(body.to_vec()).unwrap())
+    }
+}
+
+impl Driver for InProcessDriver {
+    async fn record_win(&self, name: &str) {
+        let (status, _) = self.send(Method::POST, &format!("/players/{}", name)).await;
+        assert_eq!(StatusCode::ACCEPTED, status);
+    }
+
+    async fn get_score(&self, name: &str) -> Option<u32> {
+        let (status, body) = self.send(Method::GET, &format!("/players/{}", name)).await;
+        (status == StatusCode::OK).then(|| body.parse().unwrap())
+    }
+
+    async fn get_league(&self) -> Vec<Player> {
+        let (_, body) = self.send(Method::GET, "/league").await;
+        serde_json::from_str(&body).unwrap()
+    }
+}
+
+/// `Binary` is the built server running in a process of its own, with the
+/// league in a temporary directory. The process is killed when it is
+/// dropped.
+struct Binary {
+    process: Child,
+    _directory: TempDir,
+}
+
+impl Binary {
+    /// Starts the binary on a free port and returns it with its base URL.
+    fn start() -> (Self, String) {
+        let directory = tempfile::tempdir().unwrap();
+        let mut process = Command::new(env!("CARGO_BIN_EXE_http-server"))
+            .arg("127.0.0.1:0")
+            .arg(directory.path().join("game.db.json"))
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .unwrap();
+        // The server tells the port it got once it is listening.
+        let mut line = String::new();
+        BufReader::new(process.stdout.take().unwrap())
+            .read_line(&mut line)
+            .unwrap();
+        let base_url = line
+            .trim()
+            .strip_prefix("listening on ")
+            .unwrap()
+            .to_string();
+        let binary = Binary {
+            process,
+            _directory: directory,
+        };
+        (binary, base_url)
+    }
+}
+
+impl Drop for Binary {
+    fn drop(&mut self) {
+        self.process.kill().ok();
+        self.process.wait().ok();
+    }
+}
+
+/// `HttpDriver` talks to a server over the network, as clients of the
+/// deployed server do.
+pub struct HttpDriver {
+    client: reqwest::Client,
+    base_url: String,
+    _binary: Binary,
+}
+
+impl HttpDriver {
+    /// Starts the built binary and drives it.
+    pub fn against_binary() -> Self {
+        let (binary, base_url) = Binary::start();
+        HttpDriver {
+            client: reqwest::Client::new(),
+            base_url,
+            _binary: binary,
+        }
+    }
+}
+
+impl Driver for HttpDriver {
+    async fn record_win(&self, name: &str) {
+        let url = format!("{}/players/{}", self.base_url, name);
+        let response = self.client.post(url).send().await.unwrap();
+        assert_eq!(StatusCode::ACCEPTED, response.status());
+    }
+
+    async fn get_score(&self, name: &str) -> Option<u32> {
+        let url = format!("{}/players/{}", self.base_url, name);
+        let response = self.client.get(url).send().await.unwrap();
+        if response.status() != StatusCode::OK {
+            return None;
+        }
+        Some(response.text().await.unwrap().parse().unwrap())
+    }
+
+    async fn get_league(&self) -> Vec<Player> {
+        let url = format!("{}/league", self.base_url);
+        let body = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .unwrap()
+            .text()
+            .await
+            .unwrap();
+        serde_json::from_str(&body).unwrap()
+    }
+}
@@ -0,0 +1,4 @@
+mod acceptance;
+mod drivers;
+mod specifications;
+mod websocket;
@@ -0,0 +1,42 @@
+//! Acceptance specifications of the player server.
+//!
+//! The specifications only talk to the server through a `Driver`, so the
+//! same ones run against the routes in process and against the built binary.
+
+use fixtures::LeagueBuilder;
+use http_server::v1::Player;
+
+/// `Driver` is how a specification uses the player server. A driver panics
+/// if it cannot reach the server, which fails the specification.
+pub trait Driver {
+    async fn record_win(&self, name: &str);
+    async fn get_score(&self, name: &str) -> Option<u32>;
+    async fn get_league(&self) -> Vec<Player>;
+}
+
+/// Wins are counted per player, and a player without wins has no score.
+pub async fn player_score_specification(driver: &impl Driver) {
+    assert_eq!(None, driver.get_score("Pepper").await);
+
+    for _ in 0..3 {
+        driver.record_win("Pepper").await;
+    }
+    driver.record_win("Floyd").await;
+
+    assert_eq!(Some(3), driver.get_score("Pepper").await);
+    assert_eq!(Some(1), driver.get_score("Floyd").await);
+}
+
+/// The league lists every player who won, the most wins first.
+pub async fn league_specification(driver: &impl Driver) {
+    assert_eq!(Vec::<Player>::new(), driver.get_league().await);
+
+    let league = LeagueBuilder::new().players(3).build();
+    for player in &league {
+        for _ in 0..player.wins {
+            driver.record_win(&player.name).await;
+        }
+    }
+
+    assert_eq!(league, driver.get_league().await);
+}
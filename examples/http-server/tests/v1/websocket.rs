@@ -0,0 +1,97 @@
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures_util::SinkExt;
+use futures_util::StreamExt;
+use http_server::v1::PlayerStore;
+use http_server::v1::in_memory::InMemoryPlayerStore;
+use http_server::v1::router_with_blind_unit;
+use tokio::net::TcpListener;
+use tokio_tungstenite::MaybeTlsStream;
+use tokio_tungstenite::WebSocketStream;
+use tokio_tungstenite::connect_async;
+use tokio_tungstenite::tungstenite::Message;
+
+const BLIND_UNIT: Duration = Duration::from_millis(5);
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+type Client = WebSocketStream<MaybeTlsStream<tokio::net::TcpStream>>;
+
+async fn serve(store: Arc<InMemoryPlayerStore>) -> SocketAddr {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    let app = router_with_blind_unit(store, BLIND_UNIT);
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+    address
+}
+
+async fn connect(address: SocketAddr) -> Client {
+    let (client, _) = connect_async(format!("ws://{}/ws", address)).await.unwrap();
+    client
+}
+
+/// Returns the next text message, or `None` once the server closed the
+/// socket.
+async fn receive(client: &mut Client) -> Option<String> {
+    loop {
+        let message = tokio::time::timeout(TIMEOUT, client.next())
+            .await
+            .unwrap()?;
+        match message.unwrap() {
+            Message::Text(text) => return Some(text.to_string()),
+            Message::Close(_) => return None,
+            _ => {}
+        }
+    }
+}
+
+#[tokio::test]
+async fn sut_pushes_blind_alerts_after_number_of_players() {
+    // Arrange
+    let address = serve(Arc::new(InMemoryPlayerStore::new())).await;
+    let mut client = connect(address).await;
+
+    // Act
+    client.send(Message::text("3")).await.unwrap();
+
+    // Assert
+    let mut actual = vec![];
+    for _ in 0..3 {
+        actual.push(receive(&mut client).await.unwrap());
+    }
+    let expected = vec!["Blind is now 100", "Blind is now 200", "Blind is now 300"];
+    assert_eq!(expected, actual);
+}
+
+#[tokio::test]
+async fn sut_records_winner_and_closes_socket() {
+    // Arrange
+    let store = Arc::new(InMemoryPlayerStore::new());
+    let address = serve(store.clone()).await;
+    let mut client = connect(address).await;
+    client.send(Message::text("3")).await.unwrap();
+    receive(&mut client).await.unwrap();
+
+    // Act
+    client.send(Message::text("Ruth")).await.unwrap();
+
+    // Assert
+    while receive(&mut client).await.is_some() {}
+    assert_eq!(Some(1), store.get_player_score("Ruth"));
+}
+
+#[tokio::test]
+async fn sut_closes_socket_if_number_of_players_is_not_a_number() {
+    // Arrange
+    let store = Arc::new(InMemoryPlayerStore::new());
+    let address = serve(store.clone()).await;
+    let mut client = connect(address).await;
+
+    // Act
+    client.send(Message::text("three")).await.unwrap();
+
+    // Assert
+    assert_eq!(None, receive(&mut client).await);
+    assert!(store.get_league().is_empty());
+}
@@ -1,7 +1,7 @@
 /// `add` takes two integers and returns the sum of them.
 ///
 /// ```
-/// use integers::add;
+/// use integers::v2::add;
 ///
 /// let sum = add(1, 5);
 /// assert_eq!(6, sum);
@@ -0,0 +1,240 @@
+/// `ArithmeticError` is why a checked operation has no result.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum ArithmeticError {
+    #[error("result does not fit in i64")]
+    Overflow,
+
+    #[error("cannot divide by zero")]
+    DivisionByZero,
+}
+
+/// `add` takes two integers and returns the sum of them.
+///
+/// The sum must fit in `i64`: an overflow panics in debug builds and wraps
+/// around in release builds. Use `checked_add` or `saturating_add` when it
+/// might not.
+///
+/// ```
+/// use integers::v3::add;
+///
+/// assert_eq!(6, add(1, 5));
+/// assert_eq!(-4, add(1, -5));
+/// ```
+pub fn add(x: i64, y: i64) -> i64 {
+    x + y
+}
+
+/// `subtract` returns `x` minus `y`, with the same caveat as `add`.
+///
+/// ```
+/// use integers::v3::subtract;
+///
+/// assert_eq!(-4, subtract(1, 5));
+/// ```
+pub fn subtract(x: i64, y: i64) -> i64 {
+    x - y
+}
+
+/// `multiply` returns the product of the integers, with the same caveat as
+/// `add`.
+///
+/// ```
+/// use integers::v3::multiply;
+///
+/// assert_eq!(-10, multiply(2, -5));
+/// ```
+pub fn multiply(x: i64, y: i64) -> i64 {
+    x * y
+}
+
+/// Returns the sum, or an error if it does not fit in `i64`.
+///
+/// ```
+/// use integers::v3::ArithmeticError;
+/// use integers::v3::checked_add;
+///
+/// assert_eq!(Ok(6), checked_add(1, 5));
+/// assert_eq!(Err(ArithmeticError::Overflow), checked_add(i64::MAX, 1));
+/// ```
+pub fn checked_add(x: i64, y: i64) -> Result<i64, ArithmeticError> {
+    x.checked_add(y).ok_or(ArithmeticError::Overflow)
+}
+
+/// Returns the difference, or an error if it does not fit in `i64`.
+///
+/// ```
+/// use integers::v3::ArithmeticError;
+/// use integers::v3::checked_subtract;
+///
+/// assert_eq!(Ok(-4), checked_subtract(1, 5));
+/// assert_eq!(Err(ArithmeticError::Overflow), checked_subtract(i64::MIN, 1));
+/// ```
+pub fn checked_subtract(x: i64, y: i64) -> Result<i64, ArithmeticError> {
+    x.checked_sub(y).ok_or(ArithmeticError::Overflow)
+}
+
+/// Returns the product, or an error if it does not fit in `i64`.
+///
+/// ```
+/// use integers::v3::ArithmeticError;
+/// use integers::v3::checked_multiply;
+///
+/// assert_eq!(Ok(10), checked_multiply(2, 5));
+/// assert_eq!(Err(ArithmeticError::Overflow), checked_multiply(i64::MAX, 2));
+/// ```
+pub fn checked_multiply(x: i64, y: i64) -> Result<i64, ArithmeticError> {
+    x.checked_mul(y).ok_or(ArithmeticError::Overflow)
+}
+
+/// Returns `x` divided by `y`, rounded towards zero.
+///
+/// Dividing by zero is an error rather than a panic, and so is dividing
+/// `i64::MIN` by `-1`, whose result is one more than `i64::MAX`.
+///
+/// ```
+/// use integers::v3::ArithmeticError;
+/// use integers::v3::checked_divide;
+///
+/// assert_eq!(Ok(3), checked_divide(7, 2));
+/// assert_eq!(Err(ArithmeticError::DivisionByZero), checked_divide(7, 0));
+/// ```
+pub fn checked_divide(x: i64, y: i64) -> Result<i64, ArithmeticError> {
+    if y == 0 {
+        return Err(ArithmeticError::DivisionByZero);
+    }
+    x.checked_div(y).ok_or(ArithmeticError::Overflow)
+}
+
+/// Returns the sum, stopping at `i64::MAX` or `i64::MIN` instead of
+/// overflowing.
+///
+/// ```
+/// use integers::v3::saturating_add;
+///
+/// assert_eq!(6, saturating_add(1, 5));
+/// assert_eq!(i64::MAX, saturating_add(i64::MAX, 1));
+/// ```
+pub fn saturating_add(x: i64, y: i64) -> i64 {
+    x.saturating_add(y)
+}
+
+/// Returns the difference, stopping at the bounds of `i64`.
+///
+/// ```
+/// use integers::v3::saturating_subtract;
+///
+/// assert_eq!(i64::MIN, saturating_subtract(i64::MIN, 1));
+/// ```
+pub fn saturating_subtract(x: i64, y: i64) -> i64 {
+    x.saturating_sub(y)
+}
+
+/// Returns the product, stopping at the bounds of `i64`.
+///
+/// ```
+/// use integers::v3::saturating_multiply;
+///
+/// assert_eq!(i64::MIN, saturating_multiply(i64::MAX, -2));
+/// ```
+pub fn saturating_multiply(x: i64, y: i64) -> i64 {
+    x.saturating_mul(y)
+}
+
+#[cfg(test)]
+mod specs_for_unchecked_operations {
+    use super::add;
+    use super::multiply;
+    use super::subtract;
+
+    #[rstest::rstest]
+    #[case(2, 2, 4)]
+    #[case(-2, 5, 3)]
+    #[case(0, 0, 0)]
+    fn sut_adds_integers(#[case] x: i64, #[case] y: i64, #[case] expected: i64) {
+        // Act
+        let actual = add(x, y);
+
+        // Assert
+        assert_eq!(expected, actual);
+    }
+
+    #[rstest::rstest]
+    #[case(5, 2, 3)]
+    #[case(2, 5, -3)]
+    fn sut_subtracts_integers(#[case] x: i64, #[case] y: i64, #[case] expected: i64) {
+        // Act
+        let actual = subtract(x, y);
+
+        // Assert
+        assert_eq!(expected, actual);
+    }
+
+    #[rstest::rstest]
+    #[case(3, 4, 12)]
+    #[case(-3, 4, -12)]
+    #[case(7, 0, 0)]
+    fn sut_multiplies_integers(#[case] x: i64, #[case] y: i64, #[case] expected: i64) {
+        // Act
+        let actual = multiply(x, y);
+
+        // Assert
+        assert_eq!(expected, actual);
+    }
+}
+
+#[cfg(test)]
+mod specs_for_checked_operations {
+    use super::ArithmeticError;
+    use super::checked_add;
+    use super::checked_divide;
+    use super::checked_multiply;
+    use super::checked_subtract;
+
+    #[rstest::rstest]
+    #[case::add(checked_add(2, 2), Ok(4))]
+    #[case::add_overflow(checked_add(i64::MAX, 1), Err(ArithmeticError::Overflow))]
+    #[case::add_underflow(checked_add(i64::MIN, -1), Err(ArithmeticError::Overflow))]
+    #[case::subtract(checked_subtract(2, 5), Ok(-3))]
+    #[case::subtract_overflow(checked_subtract(i64::MAX, -1), Err(ArithmeticError::Overflow))]
+    #[case::multiply(checked_multiply(-3, 4), Ok(-12))]
+    #[case::multiply_overflow(checked_multiply(i64::MIN, -1), Err(ArithmeticError::Overflow))]
+    #[case::divide(checked_divide(-7, 2), Ok(-3))]
+    #[case::divide_by_zero(checked_divide(7, 0), Err(ArithmeticError::DivisionByZero))]
+    #[case::divide_overflow(checked_divide(i64::MIN, -1), Err(ArithmeticError::Overflow))]
+    fn sut_returns_result_or_error(
+        #[case] actual: Result<i64, ArithmeticError>,
+        #[case] expected: Result<i64, ArithmeticError>,
+    ) {
+        assert_eq!(expected, actual);
+    }
+
+    #[rstest::rstest]
+    #[case(ArithmeticError::Overflow, "result does not fit in i64")]
+    #[case(ArithmeticError::DivisionByZero, "cannot divide by zero")]
+    fn sut_describes_error(#[case] error: ArithmeticError, #[case] expected: &str) {
+        // Act
+        let actual = error.to_string();
+
+        // Assert
+        assert_eq!(expected, actual);
+    }
+}
+
+#[cfg(test)]
+mod specs_for_saturating_operations {
+    use super::saturating_add;
+    use super::saturating_multiply;
+    use super::saturating_subtract;
+
+    #[rstest::rstest]
+    #[case::add(saturating_add(2, 2), 4)]
+    #[case::add_above_max(saturating_add(i64::MAX, 1), i64::MAX)]
+    #[case::add_below_min(saturating_add(i64::MIN, -1), i64::MIN)]
+    #[case::subtract_below_min(saturating_subtract(i64::MIN, 1), i64::MIN)]
+    #[case::subtract_above_max(saturating_subtract(i64::MAX, -1), i64::MAX)]
+    #[case::multiply_above_max(saturating_multiply(i64::MIN, -1), i64::MAX)]
+    #[case::multiply_below_min(saturating_multiply(i64::MAX, -2), i64::MIN)]
+    fn sut_stops_at_bounds_of_i64(#[case] actual: i64, #[case] expected: i64) {
+        assert_eq!(expected, actual);
+    }
+}
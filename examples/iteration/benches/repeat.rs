@@ -1,9 +1,15 @@
+use criterion::BenchmarkId;
 use criterion::Criterion;
 use criterion::black_box;
 use criterion::criterion_group;
 use criterion::criterion_main;
 
-use iteration::repeat;
+use iteration::v3::repeat;
+use iteration::v4;
+
+const COUNTS: [usize; 3] = [10, 100, 1000];
+
+type Repeat = fn(&str, usize) -> String;
 
 pub fn bench_repeat(c: &mut Criterion) {
     c.bench_function("repeat a", |b| {
@@ -13,5 +19,23 @@ pub fn bench_repeat(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, bench_repeat);
+/// Compares the ways of building a repeated string for growing counts.
+pub fn bench_repeat_strategies(c: &mut Criterion) {
+    let strategies: [(&str, Repeat); 3] = [
+        ("push_str", v4::repeat_with_push_str),
+        ("str::repeat", v4::repeat),
+        ("collect", v4::repeat_with_collect),
+    ];
+    let mut group = c.benchmark_group("repeat strategies");
+    for count in COUNTS {
+        for (name, strategy) in strategies {
+            group.bench_with_input(BenchmarkId::new(name, count), &count, |b, &count| {
+                b.iter(|| strategy(black_box("a"), count))
+            });
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_repeat, bench_repeat_strategies);
 criterion_main!(benches);
@@ -1,3 +1,5 @@
+// The chapter starts from `+` on purpose, to have something to improve on.
+#[allow(clippy::assign_op_pattern)]
 pub fn repeat(c: &str) -> String {
     let mut repeated = String::new();
     for _ in 0..10 {
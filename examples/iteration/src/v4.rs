@@ -0,0 +1,76 @@
+/// Repeats `s` `count` times.
+///
+/// ```
+/// use iteration::v4::repeat;
+///
+/// assert_eq!("ababab", repeat("ab", 3));
+/// ```
+pub fn repeat(s: &str, count: usize) -> String {
+    s.repeat(count)
+}
+
+/// Repeats `s` `count` times by pushing it onto a string sized up front.
+pub fn repeat_with_push_str(s: &str, count: usize) -> String {
+    let mut repeated = String::with_capacity(count * s.len());
+    for _ in 0..count {
+        repeated.push_str(s);
+    }
+    repeated
+}
+
+/// Repeats `s` `count` times by collecting an iterator of it.
+pub fn repeat_with_collect(s: &str, count: usize) -> String {
+    std::iter::repeat_n(s, count).collect()
+}
+
+/// Repeats `s` `count` times with `separator` in between, as in `a, a, a`.
+pub fn repeat_joined(s: &str, count: usize, separator: &str) -> String {
+    vec![s; count].join(separator)
+}
+
+#[cfg(test)]
+mod specs_for_repeat {
+    use super::repeat;
+    use super::repeat_joined;
+    use super::repeat_with_collect;
+    use super::repeat_with_push_str;
+
+    #[rstest::rstest]
+    #[case("a", 5, "aaaaa")]
+    #[case("ab", 3, "ababab")]
+    #[case("한", 2, "한한")]
+    #[case("a", 0, "")]
+    #[case("", 3, "")]
+    fn sut_repeats_given_argument_given_times(
+        #[case] input: &str,
+        #[case] count: usize,
+        #[case] expected: &str,
+    ) {
+        // Act
+        let actual = [
+            repeat(input, count),
+            repeat_with_push_str(input, count),
+            repeat_with_collect(input, count),
+        ];
+
+        // Assert
+        assert_eq!([expected; 3], actual);
+    }
+
+    #[rstest::rstest]
+    #[case("a", 3, ", ", "a, a, a")]
+    #[case("a", 1, ", ", "a")]
+    #[case("a", 0, ", ", "")]
+    fn sut_puts_separator_between_repetitions(
+        #[case] input: &str,
+        #[case] count: usize,
+        #[case] separator: &str,
+        #[case] expected: &str,
+    ) {
+        // Act
+        let actual = repeat_joined(input, count, separator);
+
+        // Assert
+        assert_eq!(expected, actual);
+    }
+}
@@ -0,0 +1,3 @@
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+pub mod v1;
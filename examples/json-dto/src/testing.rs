@@ -0,0 +1,58 @@
+//! Generators of orders for property tests, shared with the crates
+//! depending on this one through the `testing` feature.
+
+use chrono::Days;
+use chrono::NaiveDate;
+use proptest::prelude::*;
+
+use crate::v1::Currency;
+use crate::v1::Money;
+use crate::v1::Order;
+use crate::v1::Status;
+
+/// Amounts generated stay within a trillion of the major unit either way,
+/// which is more than any order totals.
+pub const MAX_MINOR_UNITS: i64 = 100_000_000_000_000;
+
+pub fn currency() -> impl Strategy<Value = Currency> {
+    prop_oneof![
+        Just(Currency::Usd),
+        Just(Currency::Eur),
+        Just(Currency::Gbp)
+    ]
+}
+
+/// Generates amounts of any currency, refunds included.
+pub fn money() -> impl Strategy<Value = Money> {
+    (-MAX_MINOR_UNITS..=MAX_MINOR_UNITS, currency()).prop_map(|(minor_units, currency)| Money {
+        minor_units,
+        currency,
+    })
+}
+
+pub fn status() -> impl Strategy<Value = Status> {
+    prop_oneof![
+        Just(Status::Placed),
+        Just(Status::Shipped),
+        Just(Status::Delivered),
+        Just(Status::Cancelled),
+    ]
+}
+
+/// Generates valid orders, placed since 2000.
+pub fn order() -> impl Strategy<Value = Order> {
+    (
+        "[A-Z]-[0-9]{1,6}",
+        "\\PC{0,20}",
+        0..10_000u64,
+        money(),
+        status(),
+    )
+        .prop_map(|(id, customer, days, total, status)| Order {
+            id,
+            customer,
+            placed_on: NaiveDate::from_ymd_opt(2000, 1, 1).unwrap() + Days::new(days),
+            total,
+            status,
+        })
+}
@@ -0,0 +1,101 @@
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::NaiveDate;
+
+pub mod dto;
+
+/// `Order` is the domain model. It knows nothing of JSON: how an order is
+/// written down, and how that changed over time, is left to `dto`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Order {
+    pub id: String,
+    pub customer: String,
+    pub placed_on: NaiveDate,
+    pub total: Money,
+    pub status: Status,
+}
+
+/// `Money` is an amount in the minor unit of its currency, such as cents,
+/// so that it adds up without rounding errors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Money {
+    pub minor_units: i64,
+    pub currency: Currency,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Currency {
+    Usd,
+    Eur,
+    Gbp,
+}
+
+impl Currency {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Currency::Usd => "USD",
+            Currency::Eur => "EUR",
+            Currency::Gbp => "GBP",
+        }
+    }
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+impl FromStr for Currency {
+    type Err = InvalidOrder;
+
+    fn from_str(code: &str) -> Result<Self, Self::Err> {
+        match code {
+            "USD" => Ok(Currency::Usd),
+            "EUR" => Ok(Currency::Eur),
+            "GBP" => Ok(Currency::Gbp),
+            _ => Err(InvalidOrder::UnknownCurrency(code.to_string())),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    Placed,
+    Shipped,
+    Delivered,
+    Cancelled,
+}
+
+/// `InvalidOrder` is returned when a document is well-formed JSON but does
+/// not make a valid order.
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum InvalidOrder {
+    #[error("order id must not be empty")]
+    MissingId,
+
+    #[error("unknown currency: {0}")]
+    UnknownCurrency(String),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum LoadError {
+    #[error("failed to read order: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error(transparent)]
+    Invalid(#[from] InvalidOrder),
+}
+
+/// Reads an order written by any version of the schema.
+pub fn load(json: &str) -> Result<Order, LoadError> {
+    let dto: dto::OrderDto = serde_json::from_str(json)?;
+    Ok(Order::try_from(dto)?)
+}
+
+/// Writes an order in the current version of the schema.
+pub fn save(order: &Order) -> String {
+    serde_json::to_string_pretty(&dto::OrderDto::from(order))
+        .expect("an order is always serializable")
+}
@@ -0,0 +1,324 @@
+use chrono::NaiveDate;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::Currency;
+use super::InvalidOrder;
+use super::Money;
+use super::Order;
+use super::Status;
+
+/// `OrderDto` is an order as it is written in JSON. The schema changed over
+/// time, and every version of it still has to load:
+///
+/// 1. `placed` was a `DD/MM/YYYY` date, `total` a JSON number, and every
+///    order was in US dollars and just placed.
+/// 2. `placed` was renamed `placed_on` and became an ISO 8601 date. `total`
+///    became a string so that it is exact, and `currency` and `status` were
+///    added.
+///
+/// Fields added later default to what older documents meant, and fields not
+/// known yet are ignored, so that documents of a newer version still load.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrderDto {
+    pub id: String,
+    pub customer: String,
+    #[serde(alias = "placed", with = "date")]
+    pub placed_on: NaiveDate,
+    #[serde(with = "money")]
+    pub total: i64,
+    #[serde(default = "default_currency")]
+    pub currency: String,
+    #[serde(default)]
+    pub status: StatusDto,
+}
+
+fn default_currency() -> String {
+    Currency::Usd.code().to_string()
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StatusDto {
+    #[default]
+    Placed,
+    Shipped,
+    Delivered,
+    Cancelled,
+}
+
+impl TryFrom<OrderDto> for Order {
+    type Error = InvalidOrder;
+
+    fn try_from(dto: OrderDto) -> Result<Self, Self::Error> {
+        if dto.id.is_empty() {
+            return Err(InvalidOrder::MissingId);
+        }
+        Ok(Order {
+            id: dto.id,
+            customer: dto.customer,
+            placed_on: dto.placed_on,
+            total: Money {
+                minor_units: dto.total,
+                currency: dto.currency.parse()?,
+            },
+            status: match dto.status {
+                StatusDto::Placed => Status::Placed,
+                StatusDto::Shipped => Status::Shipped,
+                StatusDto::Delivered => Status::Delivered,
+                StatusDto::Cancelled => Status::Cancelled,
+            },
+        })
+    }
+}
+
+impl From<&Order> for OrderDto {
+    fn from(order: &Order) -> Self {
+        OrderDto {
+            id: order.id.clone(),
+            customer: order.customer.clone(),
+            placed_on: order.placed_on,
+            total: order.total.minor_units,
+            currency: order.total.currency.code().to_string(),
+            status: match order.status {
+                Status::Placed => StatusDto::Placed,
+                Status::Shipped => StatusDto::Shipped,
+                Status::Delivered => StatusDto::Delivered,
+                Status::Cancelled => StatusDto::Cancelled,
+            },
+        }
+    }
+}
+
+/// Writes dates as `YYYY-MM-DD`, and reads the `DD/MM/YYYY` dates of the
+/// first version too.
+mod date {
+    use chrono::NaiveDate;
+    use serde::Deserialize;
+    use serde::Deserializer;
+    use serde::Serializer;
+    use serde::de;
+
+    const FORMAT: &str = "%Y-%m-%d";
+    const LEGACY_FORMAT: &str = "%d/%m/%Y";
+
+    pub fn serialize<S: Serializer>(date: &NaiveDate, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&date.format(FORMAT))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<NaiveDate, D::Error> {
+        let date = String::deserialize(deserializer)?;
+        NaiveDate::parse_from_str(&date, FORMAT)
+            .or_else(|_| NaiveDate::parse_from_str(&date, LEGACY_FORMAT))
+            .map_err(|_| de::Error::custom(format!("invalid date: {date}")))
+    }
+}
+
+/// Writes amounts in minor units as exact decimal strings such as `"19.99"`,
+/// and reads the JSON numbers of the first version too.
+mod money {
+    use serde::Deserialize;
+    use serde::Deserializer;
+    use serde::Serializer;
+    use serde::de;
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Amount {
+        Exact(String),
+        Legacy(f64),
+    }
+
+    pub fn serialize<S: Serializer>(minor_units: &i64, serializer: S) -> Result<S::Ok, S::Error> {
+        let sign = if *minor_units < 0 { "-" } else { "" };
+        let abs = minor_units.unsigned_abs();
+        serializer.collect_str(&format_args!("{sign}{}.{:02}", abs / 100, abs % 100))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<i64, D::Error> {
+        match Amount::deserialize(deserializer)? {
+            Amount::Exact(amount) => {
+                parse(&amount).ok_or_else(|| de::Error::custom(format!("invalid amount: {amount}")))
+            }
+            // Numbers of the first version never had more than two decimals,
+            // so rounding only drops the error of the float.
+            Amount::Legacy(amount) if amount.is_finite() => Ok((amount * 100.0).round() as i64),
+            Amount::Legacy(amount) => Err(de::Error::custom(format!("invalid amount: {amount}"))),
+        }
+    }
+
+    fn parse(amount: &str) -> Option<i64> {
+        let (negative, amount) = match amount.strip_prefix('-') {
+            Some(amount) => (true, amount),
+            None => (false, amount),
+        };
+        let (units, cents) = amount.split_once('.').unwrap_or((amount, "0"));
+        let all_digits = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+        if !all_digits(units) || !all_digits(cents) || cents.len() > 2 {
+            return None;
+        }
+        let cents = cents.parse::<i64>().ok()? * if cents.len() == 1 { 10 } else { 1 };
+        let minor_units = units
+            .parse::<i64>()
+            .ok()?
+            .checked_mul(100)?
+            .checked_add(cents)?;
+        Some(if negative { -minor_units } else { minor_units })
+    }
+}
+
+#[cfg(test)]
+mod specs_for_order_dto {
+    use assertions::assert_err_matches;
+    use chrono::NaiveDate;
+    use serde_json::Value;
+    use serde_json::json;
+
+    use crate::v1::Currency;
+    use crate::v1::InvalidOrder;
+    use crate::v1::LoadError;
+    use crate::v1::Money;
+    use crate::v1::Order;
+    use crate::v1::Status;
+    use crate::v1::load;
+    use crate::v1::save;
+
+    const VERSION_1: &str = include_str!("../../fixtures/v1/order-1.json");
+    const VERSION_2: &str = include_str!("../../fixtures/v1/order-2.json");
+    const VERSION_3: &str = include_str!("../../fixtures/v1/order-3.json");
+
+    fn order(id: &str, placed_on: (i32, u32, u32), total: Money, status: Status) -> Order {
+        let (year, month, day) = placed_on;
+        Order {
+            id: id.to_string(),
+            customer: "Pepper".to_string(),
+            placed_on: NaiveDate::from_ymd_opt(year, month, day).unwrap(),
+            total,
+            status,
+        }
+    }
+
+    fn money(minor_units: i64, currency: Currency) -> Money {
+        Money {
+            minor_units,
+            currency,
+        }
+    }
+
+    #[rstest::rstest]
+    #[case::version_1(
+        VERSION_1,
+        order("A-1001", (2024, 1, 31), money(1999, Currency::Usd), Status::Placed),
+    )]
+    #[case::version_2(
+        VERSION_2,
+        order("A-1002", (2024, 2, 29), money(125000, Currency::Eur), Status::Shipped),
+    )]
+    #[case::newer_version(
+        VERSION_3,
+        order("A-1003", (2024, 3, 15), money(50, Currency::Gbp), Status::Delivered),
+    )]
+    fn sut_loads_order_of_every_schema_version(#[case] json: &str, #[case] expected: Order) {
+        assert_eq!(expected, load(json).unwrap());
+    }
+
+    #[rstest::rstest]
+    #[case::version_1(VERSION_1)]
+    #[case::version_2(VERSION_2)]
+    fn sut_saves_loaded_order_in_current_version_without_loss(#[case] json: &str) {
+        // Arrange
+        let order = load(json).unwrap();
+
+        // Act
+        let actual = load(&save(&order)).unwrap();
+
+        // Assert
+        assert_eq!(order, actual);
+    }
+
+    #[test]
+    fn sut_saves_order_as_current_version_of_schema() {
+        // Arrange
+        let order = load(VERSION_1).unwrap();
+
+        // Act
+        let actual = serde_json::from_str::<Value>(&save(&order)).unwrap();
+
+        // Assert
+        let expected = json!({
+            "id": "A-1001",
+            "customer": "Pepper",
+            "placed_on": "2024-01-31",
+            "total": "19.99",
+            "currency": "USD",
+            "status": "placed",
+        });
+        assert_eq!(expected, actual);
+    }
+
+    #[rstest::rstest]
+    #[case::whole("7", 700)]
+    #[case::one_decimal("7.5", 750)]
+    #[case::negative("-0.05", -5)]
+    fn sut_reads_exact_amounts(#[case] total: &str, #[case] expected: i64) {
+        // Arrange
+        let json =
+            json!({"id": "A", "customer": "Pepper", "placed_on": "2024-01-31", "total": total});
+
+        // Act
+        let actual = load(&json.to_string()).unwrap();
+
+        // Assert
+        assert_eq!(expected, actual.total.minor_units);
+    }
+
+    #[rstest::rstest]
+    #[case::bad_date(json!({"id": "A", "customer": "Pepper", "placed_on": "31.01.2024", "total": "1"}))]
+    #[case::three_decimals(json!({"id": "A", "customer": "Pepper", "placed_on": "2024-01-31", "total": "1.999"}))]
+    #[case::not_a_number(json!({"id": "A", "customer": "Pepper", "placed_on": "2024-01-31", "total": "ten"}))]
+    #[case::unknown_status(json!({"id": "A", "customer": "Pepper", "placed_on": "2024-01-31", "total": "1", "status": "lost"}))]
+    #[case::missing_customer(json!({"id": "A", "placed_on": "2024-01-31", "total": "1"}))]
+    fn sut_refuses_malformed_document(#[case] json: Value) {
+        // Act
+        let actual = load(&json.to_string());
+
+        // Assert
+        assert_err_matches!(actual, LoadError::Json(_));
+    }
+
+    #[rstest::rstest]
+    #[case::empty_id(
+        json!({"id": "", "customer": "Pepper", "placed_on": "2024-01-31", "total": "1"}),
+        InvalidOrder::MissingId,
+    )]
+    #[case::unknown_currency(
+        json!({"id": "A", "customer": "Pepper", "placed_on": "2024-01-31", "total": "1", "currency": "XYZ"}),
+        InvalidOrder::UnknownCurrency("XYZ".to_string()),
+    )]
+    fn sut_refuses_document_that_is_not_valid_order(
+        #[case] json: Value,
+        #[case] expected: InvalidOrder,
+    ) {
+        // Act
+        let actual = load(&json.to_string());
+
+        // Assert
+        assert_err_matches!(actual, LoadError::Invalid(error) if error == expected);
+    }
+}
+
+#[cfg(test)]
+mod properties_of_order_dto {
+    use proptest::prelude::*;
+
+    use crate::testing::order;
+    use crate::v1::load;
+    use crate::v1::save;
+
+    proptest! {
+        #[test]
+        fn loads_back_every_order_saved(order in order()) {
+            prop_assert_eq!(order.clone(), load(&save(&order)).unwrap());
+        }
+    }
+}
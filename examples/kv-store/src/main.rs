@@ -0,0 +1,20 @@
+use std::error::Error;
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use kv_store::v1::Store;
+use kv_store::v1::router;
+use kv_store::v1::wal::Wal;
+
+const ADDRESS: &str = "127.0.0.1:5002";
+const LOG_FILE: &str = "keys.wal.jsonl";
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    let store = Store::open(Wal::from_path(Path::new(LOG_FILE))?)?;
+    let listener = tokio::net::TcpListener::bind(ADDRESS).await?;
+    println!("listening on http://{}", listener.local_addr()?);
+    axum::serve(listener, router(Arc::new(Mutex::new(store)))).await?;
+    Ok(())
+}
@@ -0,0 +1,733 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use axum::Json;
+use axum::Router;
+use axum::extract::Path;
+use axum::extract::State;
+use axum::http::HeaderMap;
+use axum::http::HeaderValue;
+use axum::http::StatusCode;
+use axum::http::header::ETAG;
+use axum::http::header::IF_MATCH;
+use axum::http::header::IF_NONE_MATCH;
+use axum::response::IntoResponse;
+use axum::response::Response;
+use axum::routing::get;
+use maps::v6::Dictionary;
+use maps::v6::DictionaryError;
+use serde::Deserialize;
+use serde::Serialize;
+
+use wal::Record;
+use wal::Wal;
+use wal::WalError;
+
+pub mod wal;
+
+/// `ETag` names a version of a value. Every write gets a new one, so a
+/// client can tell whether the value changed since it read it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ETag(u64);
+
+impl fmt::Display for ETag {
+    /// Writes the tag quoted, as it goes in the `ETag` header.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\"{}\"", self.0)
+    }
+}
+
+impl std::str::FromStr for ETag {
+    type Err = StoreError;
+
+    fn from_str(s: &str) -> Result<Self, StoreError> {
+        s.strip_prefix('"')
+            .and_then(|s| s.strip_suffix('"'))
+            .and_then(|version| version.parse().ok())
+            .map(ETag)
+            .ok_or_else(|| StoreError::InvalidCondition(s.to_string()))
+    }
+}
+
+/// `Condition` is what a write expects of the key for it to go through.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Condition {
+    Always,
+    /// The value is still the version the client read, as `If-Match: <tag>`.
+    IfMatch(ETag),
+    /// The key has a value, as `If-Match: *`.
+    IfExists,
+    /// The key has no value, as `If-None-Match: *`.
+    IfAbsent,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum StoreError {
+    #[error(transparent)]
+    Dictionary(#[from] DictionaryError),
+
+    #[error("the key '{key}' was changed since version {expected}")]
+    Stale { key: String, expected: ETag },
+
+    #[error("the condition '{0}' is not supported")]
+    InvalidCondition(String),
+
+    #[error(transparent)]
+    Wal(#[from] WalError),
+}
+
+/// `Store` keeps the values in a `Dictionary`, along with the version of
+/// each for optimistic concurrency.
+///
+/// With a log, every write is appended to it before it is applied.
+#[derive(Default)]
+pub struct Store {
+    dictionary: Dictionary,
+    versions: HashMap<String, u64>,
+    last_version: u64,
+    wal: Option<Wal>,
+}
+
+impl Store {
+    /// Creates an empty store that keeps nothing once dropped.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replays the log into a store that keeps writing to it.
+    pub fn open(mut wal: Wal) -> Result<Self, StoreError> {
+        let mut store = Store::new();
+        for record in wal.records()? {
+            store.apply(record)?;
+        }
+        store.wal = Some(wal);
+        Ok(store)
+    }
+
+    pub fn get(&self, key: &str) -> Result<(&str, ETag), StoreError> {
+        let value = self.dictionary.search(key)?;
+        Ok((value, ETag(self.versions[key])))
+    }
+
+    /// Sets the value of the key if the condition holds. Returns the tag of
+    /// the new version and whether the key had no value before.
+    pub fn put(
+        &mut self,
+        key: &str,
+        value: &str,
+        condition: Condition,
+    ) -> Result<(ETag, bool), StoreError> {
+        let created = !self.versions.contains_key(key);
+        self.check(key, condition)?;
+        let version = self.last_version + 1;
+        self.write(Record::Put {
+            key: key.to_string(),
+            value: value.to_string(),
+            version,
+        })?;
+        Ok((ETag(version), created))
+    }
+
+    /// Removes the key if the condition holds.
+    pub fn delete(&mut self, key: &str, condition: Condition) -> Result<(), StoreError> {
+        if !self.versions.contains_key(key) {
+            return Err(DictionaryError::NotFound(key.to_string()).into());
+        }
+        self.check(key, condition)?;
+        self.write(Record::Delete {
+            key: key.to_string(),
+        })
+    }
+
+    fn check(&self, key: &str, condition: Condition) -> Result<(), StoreError> {
+        let version = self.versions.get(key).copied();
+        match (condition, version) {
+            (Condition::Always, _) => Ok(()),
+            (Condition::IfAbsent, None) => Ok(()),
+            (Condition::IfAbsent, Some(_)) => {
+                Err(DictionaryError::AlreadyExists(key.to_string()).into())
+            }
+            (Condition::IfExists | Condition::IfMatch(_), None) => {
+                Err(DictionaryError::NotFound(key.to_string()).into())
+            }
+            (Condition::IfExists, Some(_)) => Ok(()),
+            (Condition::IfMatch(expected), Some(version)) if expected == ETag(version) => Ok(()),
+            (Condition::IfMatch(expected), Some(_)) => Err(StoreError::Stale {
+                key: key.to_string(),
+                expected,
+            }),
+        }
+    }
+
+    fn write(&mut self, record: Record) -> Result<(), StoreError> {
+        if let Some(wal) = &mut self.wal {
+            wal.append(&record)?;
+        }
+        self.apply(record)
+    }
+
+    fn apply(&mut self, record: Record) -> Result<(), StoreError> {
+        match record {
+            Record::Put {
+                key,
+                value,
+                version,
+            } => {
+                if self.versions.contains_key(&key) {
+                    self.dictionary.update(key.clone(), value)?;
+                } else {
+                    self.dictionary.add(key.clone(), value)?;
+                }
+                self.versions.insert(key, version);
+                self.last_version = self.last_version.max(version);
+            }
+            Record::Delete { key } => {
+                self.dictionary.delete(key.clone())?;
+                self.versions.remove(&key);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// `ErrorBody` is the JSON body of a failed request, such as
+/// `{"error": "not_found", "message": "the key 'pepper' was not found"}`.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ErrorBody {
+    pub error: String,
+    pub message: String,
+}
+
+impl IntoResponse for StoreError {
+    fn into_response(self) -> Response {
+        let (status, error) = match &self {
+            StoreError::Dictionary(DictionaryError::NotFound(_)) => {
+                (StatusCode::NOT_FOUND, "not_found")
+            }
+            StoreError::Dictionary(DictionaryError::AlreadyExists(_)) => {
+                (StatusCode::PRECONDITION_FAILED, "already_exists")
+            }
+            StoreError::Stale { .. } => (StatusCode::PRECONDITION_FAILED, "stale"),
+            StoreError::InvalidCondition(_) => (StatusCode::BAD_REQUEST, "invalid_condition"),
+            StoreError::Wal(_) => (StatusCode::INTERNAL_SERVER_ERROR, "storage"),
+        };
+        let body = ErrorBody {
+            error: error.to_string(),
+            message: self.to_string(),
+        };
+        (status, Json(body)).into_response()
+    }
+}
+
+/// Builds the routes of the key-value store:
+///
+/// - `GET /keys/{key}` returns the value, with its tag in `ETag`
+/// - `PUT /keys/{key}` sets the value to the body, answering 201 for a new
+///   key and 200 otherwise, with the new tag in `ETag`
+/// - `DELETE /keys/{key}` removes the value, answering 204
+///
+/// Writes honour `If-Match` with a tag or `*`, and `If-None-Match: *`. A
+/// condition that does not hold is answered with 412, and a missing key
+/// with 404, both with an `ErrorBody`.
+pub fn router(store: Arc<Mutex<Store>>) -> Router {
+    Router::new()
+        .route("/keys/{key}", get(show).put(set).delete(remove))
+        .with_state(store)
+}
+
+async fn show(
+    State(store): State<Arc<Mutex<Store>>>,
+    Path(key): Path<String>,
+) -> Result<Response, StoreError> {
+    let store = store.lock().unwrap();
+    let (value, tag) = store.get(&key)?;
+    Ok(([(ETAG, header(tag))], value.to_string()).into_response())
+}
+
+async fn set(
+    State(store): State<Arc<Mutex<Store>>>,
+    Path(key): Path<String>,
+    headers: HeaderMap,
+    value: String,
+) -> Result<Response, StoreError> {
+    let condition = condition(&headers)?;
+    let (tag, created) = store.lock().unwrap().put(&key, &value, condition)?;
+    let status = if created {
+        StatusCode::CREATED
+    } else {
+        StatusCode::OK
+    };
+    Ok((status, [(ETAG, header(tag))]).into_response())
+}
+
+async fn remove(
+    State(store): State<Arc<Mutex<Store>>>,
+    Path(key): Path<String>,
+    headers: HeaderMap,
+) -> Result<StatusCode, StoreError> {
+    let condition = condition(&headers)?;
+    store.lock().unwrap().delete(&key, condition)?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Reads the condition of a write from its headers. Only a single tag or
+/// `*` is supported in `If-Match`, and only `*` in `If-None-Match`.
+fn condition(headers: &HeaderMap) -> Result<Condition, StoreError> {
+    let invalid = |value: &HeaderValue| {
+        StoreError::InvalidCondition(String::from_utf8_lossy(value.as_bytes()).to_string())
+    };
+    match (headers.get(IF_MATCH), headers.get(IF_NONE_MATCH)) {
+        (None, None) => Ok(Condition::Always),
+        (Some(value), None) => match value.to_str().map_err(|_| invalid(value))? {
+            "*" => Ok(Condition::IfExists),
+            tag => Ok(Condition::IfMatch(tag.parse()?)),
+        },
+        (None, Some(value)) if value == "*" => Ok(Condition::IfAbsent),
+        (None, Some(value)) | (Some(_), Some(value)) => Err(invalid(value)),
+    }
+}
+
+fn header(tag: ETag) -> HeaderValue {
+    HeaderValue::from_str(&tag.to_string()).unwrap()
+}
+
+#[cfg(test)]
+mod specs_for_store {
+    use maps::v6::DictionaryError;
+    use tempfile::NamedTempFile;
+
+    use super::Condition;
+    use super::ETag;
+    use super::Store;
+    use super::StoreError;
+    use super::wal::Wal;
+
+    fn store_with(key: &str, value: &str) -> (Store, ETag) {
+        let mut store = Store::new();
+        let (tag, _) = store.put(key, value, Condition::Always).unwrap();
+        (store, tag)
+    }
+
+    #[test]
+    fn sut_returns_value_with_tag_of_its_version() {
+        // Arrange
+        let (sut, tag) = store_with("pepper", "hot");
+
+        // Act
+        let actual = sut.get("pepper").unwrap();
+
+        // Assert
+        assert_eq!(("hot", tag), actual);
+    }
+
+    #[test]
+    fn sut_returns_not_found_error_for_unknown_key() {
+        // Arrange
+        let sut = Store::new();
+
+        // Act
+        let actual = sut.get("pepper").unwrap_err();
+
+        // Assert
+        assert!(matches!(
+            actual,
+            StoreError::Dictionary(DictionaryError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn sut_gives_every_write_a_new_tag() {
+        // Arrange
+        let (mut sut, first) = store_with("pepper", "hot");
+
+        // Act
+        let (second, created) = sut.put("pepper", "hotter", Condition::Always).unwrap();
+
+        // Assert
+        assert!(!created);
+        assert_ne!(first, second);
+        assert_eq!(("hotter", second), sut.get("pepper").unwrap());
+    }
+
+    #[test]
+    fn sut_does_not_reuse_tag_of_deleted_key() {
+        // Arrange
+        let (mut sut, first) = store_with("pepper", "hot");
+        sut.delete("pepper", Condition::Always).unwrap();
+
+        // Act
+        let (second, created) = sut.put("pepper", "hot", Condition::Always).unwrap();
+
+        // Assert
+        assert!(created);
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn sut_writes_if_tag_matches() {
+        // Arrange
+        let (mut sut, tag) = store_with("pepper", "hot");
+
+        // Act
+        sut.put("pepper", "hotter", Condition::IfMatch(tag))
+            .unwrap();
+
+        // Assert
+        assert_eq!("hotter", sut.get("pepper").unwrap().0);
+    }
+
+    #[test]
+    fn sut_refuses_write_with_stale_tag() {
+        // Arrange
+        let (mut sut, tag) = store_with("pepper", "hot");
+        sut.put("pepper", "hotter", Condition::Always).unwrap();
+
+        // Act
+        let actual = sut
+            .put("pepper", "mild", Condition::IfMatch(tag))
+            .unwrap_err();
+
+        // Assert
+        assert!(matches!(actual, StoreError::Stale { expected, .. } if expected == tag));
+        assert_eq!("hotter", sut.get("pepper").unwrap().0);
+    }
+
+    #[rstest::rstest]
+    #[case::if_absent(Condition::IfAbsent, true)]
+    #[case::if_exists(Condition::IfExists, false)]
+    #[case::if_match(Condition::IfMatch(ETag(1)), false)]
+    fn sut_checks_condition_of_write_to_unknown_key(
+        #[case] condition: Condition,
+        #[case] expected: bool,
+    ) {
+        // Arrange
+        let mut sut = Store::new();
+
+        // Act
+        let actual = sut.put("pepper", "hot", condition);
+
+        // Assert
+        assert_eq!(expected, actual.is_ok());
+    }
+
+    #[test]
+    fn sut_refuses_to_create_key_that_already_exists() {
+        // Arrange
+        let (mut sut, _) = store_with("pepper", "hot");
+
+        // Act
+        let actual = sut.put("pepper", "mild", Condition::IfAbsent).unwrap_err();
+
+        // Assert
+        assert!(matches!(
+            actual,
+            StoreError::Dictionary(DictionaryError::AlreadyExists(_))
+        ));
+    }
+
+    #[test]
+    fn sut_refuses_delete_with_stale_tag() {
+        // Arrange
+        let (mut sut, tag) = store_with("pepper", "hot");
+        sut.put("pepper", "hotter", Condition::Always).unwrap();
+
+        // Act
+        let actual = sut.delete("pepper", Condition::IfMatch(tag)).unwrap_err();
+
+        // Assert
+        assert!(matches!(actual, StoreError::Stale { .. }));
+        assert!(sut.get("pepper").is_ok());
+    }
+
+    #[test]
+    fn sut_returns_not_found_error_when_deleting_unknown_key() {
+        // Arrange
+        let mut sut = Store::new();
+
+        // Act
+        let actual = sut.delete("pepper", Condition::Always).unwrap_err();
+
+        // Assert
+        assert!(matches!(
+            actual,
+            StoreError::Dictionary(DictionaryError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn sut_replays_writes_from_log_with_their_tags() {
+        // Arrange
+        let file = NamedTempFile::new().unwrap();
+        let mut sut = Store::open(Wal::from_path(file.path()).unwrap()).unwrap();
+        let (tag, _) = sut.put("pepper", "hot", Condition::Always).unwrap();
+        sut.put("salt", "salty", Condition::Always).unwrap();
+        sut.delete("salt", Condition::Always).unwrap();
+        drop(sut);
+
+        // Act
+        let mut actual = Store::open(Wal::from_path(file.path()).unwrap()).unwrap();
+
+        // Assert
+        assert_eq!(("hot", tag), actual.get("pepper").unwrap());
+        assert!(actual.get("salt").is_err());
+        let (next, _) = actual.put("salt", "salty", Condition::Always).unwrap();
+        assert_ne!(tag, next);
+    }
+
+    #[test]
+    fn sut_does_not_log_refused_write() {
+        // Arrange
+        let file = NamedTempFile::new().unwrap();
+        let mut sut = Store::open(Wal::from_path(file.path()).unwrap()).unwrap();
+        sut.put("pepper", "hot", Condition::Always).unwrap();
+        sut.put("pepper", "mild", Condition::IfAbsent).unwrap_err();
+        drop(sut);
+
+        // Act
+        let actual = Store::open(Wal::from_path(file.path()).unwrap()).unwrap();
+
+        // Assert
+        assert_eq!("hot", actual.get("pepper").unwrap().0);
+    }
+}
+
+#[cfg(test)]
+mod specs_for_router {
+    use std::sync::Arc;
+    use std::sync::Mutex;
+
+    use axum::body::Body;
+    use axum::http::Method;
+    use axum::http::Request;
+    use axum::http::StatusCode;
+    use axum::http::header::ETAG;
+    use axum::http::header::IF_MATCH;
+    use axum::http::header::IF_NONE_MATCH;
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    use super::Condition;
+    use super::ErrorBody;
+    use super::Store;
+    use super::router;
+
+    /// `Answer` is the part of a response the specs look at.
+    struct Answer {
+        status: StatusCode,
+        etag: Option<String>,
+        body: String,
+    }
+
+    impl Answer {
+        fn error(&self) -> ErrorBody {
+            serde_json::from_str(&self.body).unwrap()
+        }
+    }
+
+    #[rstest::fixture]
+    fn store() -> Arc<Mutex<Store>> {
+        let mut store = Store::new();
+        store.put("pepper", "hot", Condition::Always).unwrap();
+        Arc::new(Mutex::new(store))
+    }
+
+    async fn send(store: Arc<Mutex<Store>>, request: Request<Body>) -> Answer {
+        let response = router(store).oneshot(request).await.unwrap();
+        let status = response.status();
+        let etag = response
+            .headers()
+            .get(ETAG)
+            .map(|value| value.to_str().unwrap().to_string());
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        Answer {
+            status,
+            etag,
+            body: String::from_utf8(body.to_vec()).unwrap(),
+        }
+    }
+
+    fn request(method: Method, key: &str, headers: &[(&str, &str)], body: &str) -> Request<Body> {
+        let mut builder = Request::builder()
+            .method(method)
+            .uri(format!("/keys/{}", key));
+        for (name, value) in headers {
+            builder = builder.header(*name, *value);
+        }
+        builder.body(Body::from(body.to_string())).unwrap()
+    }
+
+    async fn etag_of(store: &Arc<Mutex<Store>>, key: &str) -> String {
+        let answer = send(store.clone(), request(Method::GET, key, &[], "")).await;
+        answer.etag.unwrap()
+    }
+
+    #[rstest::rstest]
+    #[tokio::test]
+    async fn sut_returns_value_with_etag(store: Arc<Mutex<Store>>) {
+        // Act
+        let actual = send(store, request(Method::GET, "pepper", &[], "")).await;
+
+        // Assert
+        assert_eq!(StatusCode::OK, actual.status);
+        assert_eq!(Some("\"1\"".to_string()), actual.etag);
+        assert_eq!("hot", actual.body);
+    }
+
+    #[rstest::rstest]
+    #[tokio::test]
+    async fn sut_returns_json_not_found_error_for_unknown_key(store: Arc<Mutex<Store>>) {
+        // Act
+        let actual = send(store, request(Method::GET, "salt", &[], "")).await;
+
+        // Assert
+        assert_eq!(StatusCode::NOT_FOUND, actual.status);
+        let expected = ErrorBody {
+            error: "not_found".to_string(),
+            message: "the key 'salt' was not found".to_string(),
+        };
+        assert_eq!(expected, actual.error());
+    }
+
+    #[rstest::rstest]
+    #[case::new_key("salt", StatusCode::CREATED)]
+    #[case::existing_key("pepper", StatusCode::OK)]
+    #[tokio::test]
+    async fn sut_sets_value_and_returns_its_new_etag(
+        store: Arc<Mutex<Store>>,
+        #[case] key: &str,
+        #[case] expected: StatusCode,
+    ) {
+        // Act
+        let actual = send(store.clone(), request(Method::PUT, key, &[], "value")).await;
+
+        // Assert
+        assert_eq!(expected, actual.status);
+        assert_eq!(actual.etag.unwrap(), etag_of(&store, key).await);
+        assert_eq!("value", store.lock().unwrap().get(key).unwrap().0);
+    }
+
+    #[rstest::rstest]
+    #[tokio::test]
+    async fn sut_sets_value_if_etag_matches(store: Arc<Mutex<Store>>) {
+        // Arrange
+        let etag = etag_of(&store, "pepper").await;
+
+        // Act
+        let actual = send(
+            store.clone(),
+            request(Method::PUT, "pepper", &[(IF_MATCH.as_str(), &etag)], "mild"),
+        )
+        .await;
+
+        // Assert
+        assert_eq!(StatusCode::OK, actual.status);
+        assert_eq!("mild", store.lock().unwrap().get("pepper").unwrap().0);
+    }
+
+    #[rstest::rstest]
+    #[tokio::test]
+    async fn sut_refuses_second_of_two_writes_from_same_read(store: Arc<Mutex<Store>>) {
+        // Arrange
+        let etag = etag_of(&store, "pepper").await;
+        let if_match = [(IF_MATCH.as_str(), etag.as_str())];
+        send(
+            store.clone(),
+            request(Method::PUT, "pepper", &if_match, "mild"),
+        )
+        .await;
+
+        // Act
+        let actual = send(
+            store.clone(),
+            request(Method::PUT, "pepper", &if_match, "cold"),
+        )
+        .await;
+
+        // Assert
+        assert_eq!(StatusCode::PRECONDITION_FAILED, actual.status);
+        assert_eq!("stale", actual.error().error);
+        assert_eq!("mild", store.lock().unwrap().get("pepper").unwrap().0);
+    }
+
+    #[rstest::rstest]
+    #[tokio::test]
+    async fn sut_refuses_to_create_existing_key(store: Arc<Mutex<Store>>) {
+        // Act
+        let actual = send(
+            store,
+            request(
+                Method::PUT,
+                "pepper",
+                &[(IF_NONE_MATCH.as_str(), "*")],
+                "mild",
+            ),
+        )
+        .await;
+
+        // Assert
+        assert_eq!(StatusCode::PRECONDITION_FAILED, actual.status);
+        let expected = ErrorBody {
+            error: "already_exists".to_string(),
+            message: "the key 'pepper' already exists".to_string(),
+        };
+        assert_eq!(expected, actual.error());
+    }
+
+    #[rstest::rstest]
+    #[case::weak_etag(IF_MATCH.as_str(), "W/\"1\"")]
+    #[case::several_etags(IF_MATCH.as_str(), "\"1\", \"2\"")]
+    #[case::if_none_match_etag(IF_NONE_MATCH.as_str(), "\"1\"")]
+    #[tokio::test]
+    async fn sut_rejects_unsupported_condition(
+        store: Arc<Mutex<Store>>,
+        #[case] name: &str,
+        #[case] value: &str,
+    ) {
+        // Act
+        let actual = send(
+            store,
+            request(Method::PUT, "pepper", &[(name, value)], "mild"),
+        )
+        .await;
+
+        // Assert
+        assert_eq!(StatusCode::BAD_REQUEST, actual.status);
+        assert_eq!("invalid_condition", actual.error().error);
+    }
+
+    #[rstest::rstest]
+    #[tokio::test]
+    async fn sut_deletes_value(store: Arc<Mutex<Store>>) {
+        // Act
+        let actual = send(store.clone(), request(Method::DELETE, "pepper", &[], "")).await;
+
+        // Assert
+        assert_eq!(StatusCode::NO_CONTENT, actual.status);
+        assert!(store.lock().unwrap().get("pepper").is_err());
+    }
+
+    #[rstest::rstest]
+    #[tokio::test]
+    async fn sut_refuses_delete_with_stale_etag(store: Arc<Mutex<Store>>) {
+        // Arrange
+        let etag = etag_of(&store, "pepper").await;
+        store
+            .lock()
+            .unwrap()
+            .put("pepper", "mild", Condition::Always)
+            .unwrap();
+
+        // Act
+        let actual = send(
+            store.clone(),
+            request(Method::DELETE, "pepper", &[(IF_MATCH.as_str(), &etag)], ""),
+        )
+        .await;
+
+        // Assert
+        assert_eq!(StatusCode::PRECONDITION_FAILED, actual.status);
+        assert!(store.lock().unwrap().get("pepper").is_ok());
+    }
+}
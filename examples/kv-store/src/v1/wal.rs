@@ -0,0 +1,195 @@
+use std::fs::File;
+use std::fs::OpenOptions;
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+use std::io::Write;
+use std::path::Path;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// `Record` is a change to the store, written to the log as one JSON line
+/// such as `{"op":"put","key":"pepper","value":"hot","version":3}`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "lowercase")]
+pub enum Record {
+    Put {
+        key: String,
+        value: String,
+        version: u64,
+    },
+    Delete {
+        key: String,
+    },
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum WalError {
+    #[error("failed to access log: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("log is corrupt at line {line}: {source}")]
+    Corrupt {
+        line: usize,
+        source: serde_json::Error,
+    },
+}
+
+/// `Wal` is a write-ahead log: every change is appended and synced before it
+/// is applied, so replaying the log rebuilds the store after a restart.
+#[derive(Debug)]
+pub struct Wal {
+    file: File,
+}
+
+impl Wal {
+    pub fn new(file: File) -> Self {
+        Wal { file }
+    }
+
+    /// Opens the log at the path, creating it if it does not exist yet.
+    pub fn from_path(path: &Path) -> Result<Self, WalError> {
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)?;
+        Ok(Self::new(file))
+    }
+
+    /// Reads every record, the oldest first.
+    ///
+    /// A last line without a newline is a write that a crash cut short. Its
+    /// change was never applied, so it is dropped from the file.
+    pub fn records(&mut self) -> Result<Vec<Record>, WalError> {
+        let mut content = String::new();
+        self.file.seek(SeekFrom::Start(0))?;
+        self.file.read_to_string(&mut content)?;
+        let complete = content.rfind('\n').map_or(0, |end| end + 1);
+        if complete < content.len() {
+            self.file.set_len(complete as u64)?;
+        }
+        content[..complete]
+            .lines()
+            .enumerate()
+            .map(|(index, line)| {
+                serde_json::from_str(line).map_err(|source| WalError::Corrupt {
+                    line: index + 1,
+                    source,
+                })
+            })
+            .collect()
+    }
+
+    pub fn append(&mut self, record: &Record) -> Result<(), WalError> {
+        let mut line = serde_json::to_vec(record).map_err(std::io::Error::other)?;
+        line.push(b'\n');
+        self.file.seek(SeekFrom::End(0))?;
+        self.file.write_all(&line)?;
+        self.file.sync_data()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod specs_for_wal {
+    use std::fs;
+
+    use tempfile::NamedTempFile;
+
+    use super::Record;
+    use super::Wal;
+    use super::WalError;
+
+    fn put(key: &str, value: &str, version: u64) -> Record {
+        Record::Put {
+            key: key.to_string(),
+            value: value.to_string(),
+            version,
+        }
+    }
+
+    #[test]
+    fn sut_reads_records_it_appended() {
+        // Arrange
+        let file = NamedTempFile::new().unwrap();
+        let mut sut = Wal::from_path(file.path()).unwrap();
+        sut.append(&put("pepper", "hot", 1)).unwrap();
+        sut.append(&Record::Delete {
+            key: "pepper".to_string(),
+        })
+        .unwrap();
+
+        // Act
+        let actual = Wal::from_path(file.path()).unwrap().records().unwrap();
+
+        // Assert
+        let expected = vec![
+            put("pepper", "hot", 1),
+            Record::Delete {
+                key: "pepper".to_string(),
+            },
+        ];
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn sut_writes_one_json_line_per_record() {
+        // Arrange
+        let file = NamedTempFile::new().unwrap();
+        let mut sut = Wal::from_path(file.path()).unwrap();
+
+        // Act
+        sut.append(&put("pepper", "hot", 1)).unwrap();
+
+        // Assert
+        let actual = fs::read_to_string(file.path()).unwrap();
+        assert_eq!(
+            "{\"op\":\"put\",\"key\":\"pepper\",\"value\":\"hot\",\"version\":1}\n",
+            actual
+        );
+    }
+
+    #[test]
+    fn sut_drops_record_cut_short_by_crash() {
+        // Arrange
+        let file = NamedTempFile::new().unwrap();
+        fs::write(
+            file.path(),
+            "{\"op\":\"put\",\"key\":\"a\",\"value\":\"1\",\"version\":1}\n{\"op\":\"pu",
+        )
+        .unwrap();
+        let mut sut = Wal::from_path(file.path()).unwrap();
+
+        // Act
+        let actual = sut.records().unwrap();
+        sut.append(&put("b", "2", 2)).unwrap();
+
+        // Assert
+        assert_eq!(vec![put("a", "1", 1)], actual);
+        assert_eq!(
+            vec![put("a", "1", 1), put("b", "2", 2)],
+            sut.records().unwrap()
+        );
+    }
+
+    #[test]
+    fn sut_returns_corrupt_error_with_line_of_invalid_record() {
+        // Arrange
+        let file = NamedTempFile::new().unwrap();
+        fs::write(
+            file.path(),
+            "{\"op\":\"delete\",\"key\":\"a\"}\nnot a record\n",
+        )
+        .unwrap();
+        let mut sut = Wal::from_path(file.path()).unwrap();
+
+        // Act
+        let actual = sut.records().unwrap_err();
+
+        // Assert
+        assert!(matches!(actual, WalError::Corrupt { line: 2, .. }));
+    }
+}
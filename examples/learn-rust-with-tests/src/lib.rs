@@ -0,0 +1,41 @@
+//! The final version of each example in the book, in one crate.
+//!
+//! Every chapter is behind a cargo feature of the same name, so depending
+//! on this crate only compiles the chapters that are switched on:
+//!
+//! ```toml
+//! learn-rust-with-tests = { path = "examples/learn-rust-with-tests", features = ["maps", "select"] }
+//! ```
+//!
+//! The `full` feature switches on every chapter.
+
+#[cfg(feature = "arrays")]
+pub use arrays::v6 as arrays;
+#[cfg(feature = "blog")]
+pub use blog::v6 as blog;
+#[cfg(feature = "clock")]
+pub use clock::v2 as clock;
+#[cfg(feature = "concurrency")]
+pub use concurrency::v8 as concurrency;
+#[cfg(feature = "di")]
+pub use di::v2 as di;
+#[cfg(feature = "errors")]
+pub use errors::v7 as errors;
+#[cfg(feature = "hello")]
+pub use hello::v8 as hello;
+#[cfg(feature = "integers")]
+pub use integers::v3 as integers;
+#[cfg(feature = "iteration")]
+pub use iteration::v4 as iteration;
+#[cfg(feature = "maps")]
+pub use maps::v10 as maps;
+#[cfg(feature = "mocking")]
+pub use mocking::v7 as mocking;
+#[cfg(feature = "roman")]
+pub use roman::v11 as roman;
+#[cfg(feature = "select")]
+pub use select::v7 as select;
+#[cfg(feature = "structs")]
+pub use structs::v6 as structs;
+#[cfg(feature = "sync")]
+pub use sync::v3 as sync;
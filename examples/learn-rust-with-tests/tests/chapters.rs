@@ -0,0 +1,36 @@
+#[cfg(feature = "hello")]
+#[test]
+fn sut_exposes_final_hello_as_hello() {
+    // Act
+    let actual = learn_rust_with_tests::hello::greet("Elodie", "French");
+
+    // Assert
+    assert_eq!("Bonjour, Elodie!", actual);
+}
+
+#[cfg(feature = "maps")]
+#[test]
+fn sut_exposes_final_dictionary_as_maps() {
+    // Arrange
+    let mut dictionary: learn_rust_with_tests::maps::Dictionary =
+        learn_rust_with_tests::maps::Dictionary::new();
+    dictionary
+        .add("test".to_string(), "this is just a test".to_string())
+        .unwrap();
+
+    // Act
+    let actual = dictionary.search("test");
+
+    // Assert
+    assert_eq!("this is just a test", actual.unwrap());
+}
+
+#[cfg(feature = "roman")]
+#[test]
+fn sut_exposes_final_roman_numerals_as_roman() {
+    // Act
+    let actual = learn_rust_with_tests::roman::convert_to_roman(1984);
+
+    // Assert
+    assert_eq!("MCMLXXXIV", actual);
+}
@@ -0,0 +1,20 @@
+use std::io::stdout;
+
+use life::v1::Edges;
+use life::v1::Grid;
+use life::v1::pattern::Pattern;
+use life::v1::render::Renderer;
+use life::v1::render::TerminalRenderer;
+
+const GLIDER: &str = "!Name: Glider\n.O.\n..O\nOOO";
+
+fn main() {
+    let mut grid = Grid::new(8, 8, Edges::Toroidal);
+    grid.place(&Pattern::from_plaintext(GLIDER).unwrap(), (0, 0));
+    let renderer = TerminalRenderer::default();
+    for generation in 0..4 {
+        println!("generation {generation}");
+        renderer.render(&mut stdout(), &grid).unwrap();
+        grid = grid.step();
+    }
+}
@@ -0,0 +1,234 @@
+pub mod pattern;
+pub mod render;
+
+use pattern::Pattern;
+
+/// `Edges` tells what lies beyond the edges of a grid.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Edges {
+    /// The grid wraps around, so a cell on the right edge neighbours the
+    /// cells on the left edge, and the same for top and bottom.
+    Toroidal,
+    /// Every cell beyond the edges is dead.
+    Bounded,
+}
+
+/// `Grid` is a generation of Conway's Game of Life on a grid of fixed size.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid {
+    width: usize,
+    height: usize,
+    edges: Edges,
+    cells: Vec<bool>,
+}
+
+impl Grid {
+    /// Creates a grid with every cell dead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` or `height` is zero.
+    pub fn new(width: usize, height: usize, edges: Edges) -> Self {
+        assert!(width > 0 && height > 0, "grid must not be empty");
+        Grid {
+            width,
+            height,
+            edges,
+            cells: vec![false; width * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_alive(&self, x: usize, y: usize) -> bool {
+        self.cells[y * self.width + x]
+    }
+
+    /// Returns the live cells as `(x, y)`, row by row.
+    pub fn alive_cells(&self) -> Vec<(usize, usize)> {
+        (0..self.height)
+            .flat_map(|y| (0..self.width).map(move |x| (x, y)))
+            .filter(|&(x, y)| self.is_alive(x, y))
+            .collect()
+    }
+
+    /// Brings the cell to life, or kills it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the cell is outside the grid.
+    pub fn set(&mut self, x: usize, y: usize, alive: bool) {
+        assert!(
+            x < self.width && y < self.height,
+            "({x}, {y}) is outside the grid"
+        );
+        self.cells[y * self.width + x] = alive;
+    }
+
+    /// Places the live cells of `pattern` with its top left corner at
+    /// `(x, y)`. Cells beyond the edges wrap around on a toroidal grid and
+    /// are dropped on a bounded one.
+    pub fn place(&mut self, pattern: &Pattern, (x, y): (usize, usize)) {
+        for &(dx, dy) in pattern.cells() {
+            let (x, y) = (x + dx, y + dy);
+            match self.edges {
+                Edges::Toroidal => self.set(x % self.width, y % self.height, true),
+                Edges::Bounded if x < self.width && y < self.height => self.set(x, y, true),
+                Edges::Bounded => {}
+            }
+        }
+    }
+
+    /// Returns the next generation:
+    ///
+    /// - A live cell with two or three live neighbours lives on.
+    /// - A dead cell with exactly three live neighbours comes to life.
+    /// - Every other cell dies, or stays dead.
+    pub fn step(&self) -> Grid {
+        let mut next = Grid::new(self.width, self.height, self.edges);
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let neighbours = self.live_neighbours(x, y);
+                let alive = matches!((self.is_alive(x, y), neighbours), (true, 2) | (_, 3));
+                next.set(x, y, alive);
+            }
+        }
+        next
+    }
+
+    fn live_neighbours(&self, x: usize, y: usize) -> usize {
+        const OFFSETS: [(isize, isize); 8] = [
+            (-1, -1),
+            (0, -1),
+            (1, -1),
+            (-1, 0),
+            (1, 0),
+            (-1, 1),
+            (0, 1),
+            (1, 1),
+        ];
+        OFFSETS
+            .iter()
+            .filter_map(|&(dx, dy)| self.neighbour(x, y, dx, dy))
+            .filter(|&(x, y)| self.is_alive(x, y))
+            .count()
+    }
+
+    fn neighbour(&self, x: usize, y: usize, dx: isize, dy: isize) -> Option<(usize, usize)> {
+        match self.edges {
+            Edges::Toroidal => Some((
+                (x + self.width).wrapping_add_signed(dx) % self.width,
+                (y + self.height).wrapping_add_signed(dy) % self.height,
+            )),
+            Edges::Bounded => {
+                let x = x.checked_add_signed(dx).filter(|&x| x < self.width)?;
+                let y = y.checked_add_signed(dy).filter(|&y| y < self.height)?;
+                Some((x, y))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod specs_for_grid {
+    use super::Edges;
+    use super::Grid;
+    use super::pattern::Pattern;
+
+    const BLINKER: &str = "OOO";
+    const BLOCK: &str = "OO\nOO";
+    const GLIDER: &str = "!Name: Glider\n.O.\n..O\nOOO";
+
+    fn grid(size: usize, edges: Edges, pattern: &str, at: (usize, usize)) -> Grid {
+        let mut grid = Grid::new(size, size, edges);
+        grid.place(&Pattern::from_plaintext(pattern).unwrap(), at);
+        grid
+    }
+
+    fn generation(grid: &Grid, n: usize) -> Grid {
+        (0..n).fold(grid.clone(), |grid, _| grid.step())
+    }
+
+    #[rstest::rstest]
+    #[case::toroidal(Edges::Toroidal)]
+    #[case::bounded(Edges::Bounded)]
+    fn sut_keeps_block_as_it_is(#[case] edges: Edges) {
+        // Arrange
+        let sut = grid(4, edges, BLOCK, (1, 1));
+
+        // Act
+        let actual = sut.step();
+
+        // Assert
+        assert_eq!(sut, actual);
+    }
+
+    #[test]
+    fn sut_turns_blinker_from_horizontal_to_vertical_and_back() {
+        // Arrange
+        let sut = grid(5, Edges::Bounded, BLINKER, (1, 2));
+
+        // Act
+        let actual = [generation(&sut, 1), generation(&sut, 2)];
+
+        // Assert
+        assert_eq!(vec![(2, 1), (2, 2), (2, 3)], actual[0].alive_cells());
+        assert_eq!(sut, actual[1]);
+    }
+
+    #[test]
+    fn sut_moves_glider_one_cell_diagonally_every_4_generations() {
+        // Arrange
+        let sut = grid(8, Edges::Toroidal, GLIDER, (0, 0));
+
+        // Act
+        let actual = generation(&sut, 4);
+
+        // Assert
+        assert_eq!(grid(8, Edges::Toroidal, GLIDER, (1, 1)), actual);
+    }
+
+    #[test]
+    fn sut_wraps_glider_around_toroidal_grid() {
+        // Arrange
+        let sut = grid(6, Edges::Toroidal, GLIDER, (3, 3));
+
+        // Act
+        let actual = generation(&sut, 4 * 6);
+
+        // Assert
+        assert_eq!(sut, actual);
+    }
+
+    #[test]
+    fn sut_stops_glider_at_edge_of_bounded_grid() {
+        // Arrange
+        let sut = grid(6, Edges::Bounded, GLIDER, (3, 3));
+
+        // Act
+        let actual = generation(&sut, 4 * 6);
+
+        // Assert
+        assert_eq!(grid(6, Edges::Bounded, BLOCK, (4, 4)), actual);
+    }
+
+    #[rstest::rstest]
+    #[case::toroidal(Edges::Toroidal, vec![(0, 0), (1, 0), (2, 0)])]
+    #[case::bounded(Edges::Bounded, vec![(2, 0)])]
+    fn sut_places_pattern_beyond_edges_as_edges_tell(
+        #[case] edges: Edges,
+        #[case] expected: Vec<(usize, usize)>,
+    ) {
+        // Act
+        let actual = grid(3, edges, BLINKER, (2, 0));
+
+        // Assert
+        assert_eq!(expected, actual.alive_cells());
+    }
+}
@@ -0,0 +1,90 @@
+/// `Pattern` is a set of live cells, relative to its top left corner, to
+/// place on a grid.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Pattern {
+    cells: Vec<(usize, usize)>,
+}
+
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum PatternError {
+    #[error("line {line}, column {column}: expected 'O' or '.', found {character:?}")]
+    UnexpectedCharacter {
+        line: usize,
+        column: usize,
+        character: char,
+    },
+}
+
+impl Pattern {
+    /// Reads a pattern in the plaintext format, where `O` is a live cell,
+    /// `.` a dead one and lines starting with `!` are comments:
+    ///
+    /// ```text
+    /// !Name: Glider
+    /// .O.
+    /// ..O
+    /// OOO
+    /// ```
+    ///
+    /// Lines and columns in errors are counted from 1.
+    pub fn from_plaintext(text: &str) -> Result<Self, PatternError> {
+        let mut cells = vec![];
+        let rows = text
+            .lines()
+            .enumerate()
+            .filter(|(_, row)| !row.starts_with('!'));
+        for (y, (line, row)) in rows.enumerate() {
+            for (x, character) in row.trim_end().chars().enumerate() {
+                match character {
+                    'O' => cells.push((x, y)),
+                    '.' => {}
+                    _ => {
+                        return Err(PatternError::UnexpectedCharacter {
+                            line: line + 1,
+                            column: x + 1,
+                            character,
+                        });
+                    }
+                }
+            }
+        }
+        Ok(Pattern { cells })
+    }
+
+    /// Returns the live cells as `(x, y)`, row by row.
+    pub fn cells(&self) -> &[(usize, usize)] {
+        &self.cells
+    }
+}
+
+#[cfg(test)]
+mod specs_for_pattern {
+    use super::Pattern;
+    use super::PatternError;
+
+    #[rstest::rstest]
+    #[case::blinker("OOO", vec![(0, 0), (1, 0), (2, 0)])]
+    #[case::glider("!Name: Glider\n!\n.O.\n..O\nOOO\n", vec![(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)])]
+    #[case::empty_rows("O\n\n.O", vec![(0, 0), (1, 2)])]
+    #[case::trailing_whitespace("O. \r\n.O", vec![(0, 0), (1, 1)])]
+    fn sut_reads_live_cells_from_plaintext(
+        #[case] text: &str,
+        #[case] expected: Vec<(usize, usize)>,
+    ) {
+        assert_eq!(expected, Pattern::from_plaintext(text).unwrap().cells());
+    }
+
+    #[test]
+    fn sut_points_at_unexpected_character() {
+        // Act
+        let actual = Pattern::from_plaintext("!Name: Typo\n.O.\n.o.");
+
+        // Assert
+        let expected = PatternError::UnexpectedCharacter {
+            line: 3,
+            column: 2,
+            character: 'o',
+        };
+        assert_eq!(Err(expected), actual);
+    }
+}
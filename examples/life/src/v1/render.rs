@@ -0,0 +1,138 @@
+use std::io::Write;
+
+use super::Grid;
+
+/// `Renderer` draws a generation. It writes to any writer, so that the
+/// specs can render into a buffer.
+pub trait Renderer {
+    fn render(&self, w: &mut dyn Write, grid: &Grid) -> std::io::Result<()>;
+}
+
+/// `TerminalRenderer` draws one line of characters per row.
+#[derive(Clone, Copy, Debug)]
+pub struct TerminalRenderer {
+    pub alive: char,
+    pub dead: char,
+}
+
+impl Default for TerminalRenderer {
+    fn default() -> Self {
+        TerminalRenderer {
+            alive: '█',
+            dead: '·',
+        }
+    }
+}
+
+impl Renderer for TerminalRenderer {
+    fn render(&self, w: &mut dyn Write, grid: &Grid) -> std::io::Result<()> {
+        for y in 0..grid.height() {
+            let row = (0..grid.width())
+                .map(|x| {
+                    if grid.is_alive(x, y) {
+                        self.alive
+                    } else {
+                        self.dead
+                    }
+                })
+                .collect::<String>();
+            writeln!(w, "{row}")?;
+        }
+        Ok(())
+    }
+}
+
+/// `SvgRenderer` draws a square of `cell_size` for every live cell.
+#[derive(Clone, Copy, Debug)]
+pub struct SvgRenderer {
+    pub cell_size: usize,
+}
+
+impl Default for SvgRenderer {
+    fn default() -> Self {
+        SvgRenderer { cell_size: 10 }
+    }
+}
+
+impl Renderer for SvgRenderer {
+    fn render(&self, w: &mut dyn Write, grid: &Grid) -> std::io::Result<()> {
+        let (width, height) = (
+            grid.width() * self.cell_size,
+            grid.height() * self.cell_size,
+        );
+        writeln!(
+            w,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}">"#
+        )?;
+        writeln!(
+            w,
+            r##"<rect width="{width}" height="{height}" fill="#fff"/>"##
+        )?;
+        for (x, y) in grid.alive_cells() {
+            writeln!(
+                w,
+                r##"<rect x="{}" y="{}" width="{size}" height="{size}" fill="#000"/>"##,
+                x * self.cell_size,
+                y * self.cell_size,
+                size = self.cell_size,
+            )?;
+        }
+        writeln!(w, "</svg>")
+    }
+}
+
+#[cfg(test)]
+mod specs_for_renderers {
+    use super::Renderer;
+    use super::SvgRenderer;
+    use super::TerminalRenderer;
+    use crate::v1::Edges;
+    use crate::v1::Grid;
+    use crate::v1::pattern::Pattern;
+
+    fn blinker() -> Grid {
+        let mut grid = Grid::new(3, 2, Edges::Bounded);
+        grid.place(&Pattern::from_plaintext("OOO").unwrap(), (0, 1));
+        grid
+    }
+
+    fn render(renderer: &impl Renderer, grid: &Grid) -> String {
+        let mut buffer = Vec::new();
+        renderer.render(&mut buffer, grid).unwrap();
+        String::from_utf8(buffer).unwrap()
+    }
+
+    #[test]
+    fn sut_draws_a_line_per_row_in_terminal() {
+        // Arrange
+        let sut = TerminalRenderer {
+            alive: 'O',
+            dead: '.',
+        };
+
+        // Act
+        let actual = render(&sut, &blinker());
+
+        // Assert
+        assert_eq!("...\nOOO\n", actual);
+    }
+
+    #[test]
+    fn sut_draws_a_square_per_live_cell_in_svg() {
+        // Arrange
+        let sut = SvgRenderer { cell_size: 5 };
+
+        // Act
+        let actual = render(&sut, &blinker());
+
+        // Assert
+        let expected = r##"<svg xmlns="http://www.w3.org/2000/svg" width="15" height="10" viewBox="0 0 15 10">
+<rect width="15" height="10" fill="#fff"/>
+<rect x="0" y="5" width="5" height="5" fill="#000"/>
+<rect x="5" y="5" width="5" height="5" fill="#000"/>
+<rect x="10" y="5" width="5" height="5" fill="#000"/>
+</svg>
+"##;
+        assert_eq!(expected, actual);
+    }
+}
@@ -0,0 +1,89 @@
+use std::fmt::Display;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use super::Fields;
+use super::Level;
+use super::Logger;
+use super::to_fields;
+
+/// A record a `CaptureLogger` kept.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Record {
+    pub level: Level,
+    pub message: String,
+    pub fields: Fields,
+}
+
+/// `CaptureLogger` keeps every record, in the order they were logged.
+/// Clones share the records, so a spec can keep one and hand out another.
+#[derive(Clone, Debug, Default)]
+pub struct CaptureLogger {
+    records: Arc<Mutex<Vec<Record>>>,
+}
+
+impl CaptureLogger {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn records(&self) -> Vec<Record> {
+        self.records.lock().unwrap().clone()
+    }
+
+    /// Returns the messages of the records, in the order they were logged.
+    pub fn messages(&self) -> Vec<String> {
+        self.records()
+            .into_iter()
+            .map(|record| record.message)
+            .collect()
+    }
+}
+
+impl Logger for CaptureLogger {
+    fn log(&self, level: Level, message: &str, fields: &[(&str, &dyn Display)]) {
+        self.records.lock().unwrap().push(Record {
+            level,
+            message: message.to_string(),
+            fields: to_fields(fields),
+        });
+    }
+}
+
+#[cfg(test)]
+mod specs_for_capture_logger {
+    use super::CaptureLogger;
+    use super::Record;
+    use crate::Fields;
+    use crate::Level;
+    use crate::Logger;
+
+    #[test]
+    fn sut_keeps_records_in_order_they_were_logged() {
+        // Arrange
+        let sut = CaptureLogger::new();
+        let logger = sut.clone();
+
+        // Act
+        logger.info("recorded win", &[("player", &"Pepper"), ("wins", &3)]);
+        logger.error("store failed", &[]);
+
+        // Assert
+        let expected = vec![
+            Record {
+                level: Level::Info,
+                message: "recorded win".to_string(),
+                fields: Fields::from([
+                    ("player".to_string(), "Pepper".to_string()),
+                    ("wins".to_string(), "3".to_string()),
+                ]),
+            },
+            Record {
+                level: Level::Error,
+                message: "store failed".to_string(),
+                fields: Fields::new(),
+            },
+        ];
+        assert_eq!(expected, sut.records());
+    }
+}
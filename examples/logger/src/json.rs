@@ -0,0 +1,76 @@
+use std::fmt::Display;
+use std::io;
+use std::io::Write;
+use std::sync::Mutex;
+
+use serde_json::Map;
+use serde_json::Value;
+
+use super::Level;
+use super::Logger;
+use super::to_fields;
+
+/// `JsonLogger` writes each record as a JSON object on a line of its own,
+/// with its `level`, `message` and fields side by side.
+///
+/// Records that cannot be written are dropped, as logging must not fail
+/// the code doing it.
+#[derive(Debug)]
+pub struct JsonLogger<W> {
+    out: Mutex<W>,
+}
+
+impl<W: Write + Send> JsonLogger<W> {
+    pub fn new(out: W) -> Self {
+        JsonLogger {
+            out: Mutex::new(out),
+        }
+    }
+}
+
+impl JsonLogger<io::Stdout> {
+    pub fn stdout() -> Self {
+        Self::new(io::stdout())
+    }
+}
+
+impl<W: Write + Send> Logger for JsonLogger<W> {
+    fn log(&self, level: Level, message: &str, fields: &[(&str, &dyn Display)]) {
+        let mut object = to_fields(fields)
+            .into_iter()
+            .map(|(name, value)| (name, Value::String(value)))
+            .collect::<Map<_, _>>();
+        object.insert("level".to_string(), level.to_string().to_lowercase().into());
+        object.insert("message".to_string(), message.into());
+        let _ = writeln!(self.out.lock().unwrap(), "{}", Value::Object(object));
+    }
+}
+
+#[cfg(test)]
+mod specs_for_json_logger {
+    use serde_json::Value;
+    use serde_json::json;
+
+    use super::JsonLogger;
+    use crate::Logger;
+
+    #[test]
+    fn sut_writes_record_as_json_object_on_line() {
+        // Arrange
+        let sut = JsonLogger::new(Vec::new());
+
+        // Act
+        sut.error("store failed", &[("player", &"Pepper"), ("wins", &3)]);
+
+        // Assert
+        let output = String::from_utf8(sut.out.into_inner().unwrap()).unwrap();
+        let actual: Value = serde_json::from_str(output.strip_suffix('\n').unwrap()).unwrap();
+        let expected = json!({
+            "level": "error",
+            "message": "store failed",
+            "player": "Pepper",
+            "wins": "3",
+        });
+        assert_eq!(expected, actual);
+    }
+}
@@ -0,0 +1,66 @@
+//! A logger the examples can be handed instead of printing themselves, so
+//! that specs can tell what was logged.
+//!
+//! - `TextLogger` writes a line of text per record.
+//! - `JsonLogger` writes a JSON object per record.
+//! - `CaptureLogger` keeps the records for a spec to look at.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fmt::Display;
+
+mod capture;
+mod json;
+mod text;
+
+pub use capture::CaptureLogger;
+pub use capture::Record;
+pub use json::JsonLogger;
+pub use text::TextLogger;
+
+/// The fields of a record, by name, as they would be printed.
+pub type Fields = BTreeMap<String, String>;
+
+/// How much a record matters.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Info,
+    Warn,
+    Error,
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Level::Info => "INFO",
+            Level::Warn => "WARN",
+            Level::Error => "ERROR",
+        };
+        f.write_str(name)
+    }
+}
+
+/// `Logger` records a message with fields, as in
+/// `logger.info("recorded win", &[("player", &name)])`.
+pub trait Logger: Send + Sync {
+    fn log(&self, level: Level, message: &str, fields: &[(&str, &dyn Display)]);
+
+    fn info(&self, message: &str, fields: &[(&str, &dyn Display)]) {
+        self.log(Level::Info, message, fields);
+    }
+
+    fn warn(&self, message: &str, fields: &[(&str, &dyn Display)]) {
+        self.log(Level::Warn, message, fields);
+    }
+
+    fn error(&self, message: &str, fields: &[(&str, &dyn Display)]) {
+        self.log(Level::Error, message, fields);
+    }
+}
+
+fn to_fields(fields: &[(&str, &dyn Display)]) -> Fields {
+    fields
+        .iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect()
+}
@@ -0,0 +1,64 @@
+use std::fmt::Display;
+use std::io;
+use std::io::Write;
+use std::sync::Mutex;
+
+use super::Level;
+use super::Logger;
+
+/// `TextLogger` writes each record as a line of its level, message and
+/// fields, as in `WARN failed to record win player=Pepper`.
+///
+/// Records that cannot be written are dropped, as logging must not fail
+/// the code doing it.
+#[derive(Debug)]
+pub struct TextLogger<W> {
+    out: Mutex<W>,
+}
+
+impl<W: Write + Send> TextLogger<W> {
+    pub fn new(out: W) -> Self {
+        TextLogger {
+            out: Mutex::new(out),
+        }
+    }
+}
+
+impl TextLogger<io::Stdout> {
+    pub fn stdout() -> Self {
+        Self::new(io::stdout())
+    }
+}
+
+impl<W: Write + Send> Logger for TextLogger<W> {
+    fn log(&self, level: Level, message: &str, fields: &[(&str, &dyn Display)]) {
+        let mut line = format!("{} {}", level, message);
+        for (name, value) in fields {
+            line.push_str(&format!(" {}={}", name, value));
+        }
+        let _ = writeln!(self.out.lock().unwrap(), "{}", line);
+    }
+}
+
+#[cfg(test)]
+mod specs_for_text_logger {
+    use super::TextLogger;
+    use crate::Logger;
+
+    #[test]
+    fn sut_writes_level_message_and_fields_on_line() {
+        // Arrange
+        let sut = TextLogger::new(Vec::new());
+
+        // Act
+        sut.warn("failed to record win", &[("player", &"Pepper")]);
+        sut.info("answered", &[]);
+
+        // Assert
+        let actual = String::from_utf8(sut.out.into_inner().unwrap()).unwrap();
+        assert_eq!(
+            "WARN failed to record win player=Pepper\nINFO answered\n",
+            actual
+        );
+    }
+}
@@ -0,0 +1,57 @@
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use criterion::BenchmarkId;
+use criterion::Criterion;
+use criterion::black_box;
+use criterion::criterion_group;
+use criterion::criterion_main;
+
+use maps::v7::Dictionary;
+use maps::v7::Entries;
+
+const SIZES: [usize; 3] = [10, 1000, 100_000];
+
+fn filled<E: Entries>(size: usize) -> Dictionary<E> {
+    let mut dictionary = Dictionary::new();
+    for i in 0..size {
+        dictionary
+            .add(format!("key{i}"), format!("value{i}"))
+            .unwrap();
+    }
+    dictionary
+}
+
+/// Compares searching a dictionary kept in a `HashMap` and in a `BTreeMap`.
+pub fn bench_search(c: &mut Criterion) {
+    let mut group = c.benchmark_group("dictionary search");
+    for size in SIZES {
+        let key = format!("key{}", size / 2);
+        let hash_map = filled::<HashMap<String, String>>(size);
+        group.bench_with_input(BenchmarkId::new("HashMap", size), &key, |b, key| {
+            b.iter(|| hash_map.search(black_box(key)).unwrap().len())
+        });
+        let btree_map = filled::<BTreeMap<String, String>>(size);
+        group.bench_with_input(BenchmarkId::new("BTreeMap", size), &key, |b, key| {
+            b.iter(|| btree_map.search(black_box(key)).unwrap().len())
+        });
+    }
+    group.finish();
+}
+
+/// Compares filling a dictionary kept in a `HashMap` and in a `BTreeMap`.
+pub fn bench_add(c: &mut Criterion) {
+    let mut group = c.benchmark_group("dictionary add");
+    for size in SIZES {
+        group.bench_with_input(BenchmarkId::new("HashMap", size), &size, |b, &size| {
+            b.iter(|| filled::<HashMap<String, String>>(black_box(size)))
+        });
+        group.bench_with_input(BenchmarkId::new("BTreeMap", size), &size, |b, &size| {
+            b.iter(|| filled::<BTreeMap<String, String>>(black_box(size)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_search, bench_add);
+criterion_main!(benches);
@@ -1,6 +1,11 @@
 pub mod v1;
+pub mod v10;
+pub mod v11;
 pub mod v2;
 pub mod v3;
 pub mod v4;
 pub mod v5;
 pub mod v6;
+pub mod v7;
+pub mod v8;
+pub mod v9;
@@ -0,0 +1,239 @@
+use std::borrow::Borrow;
+use std::fs;
+use std::hash::Hash;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+pub use super::v9::ConcurrentDictionary;
+pub use super::v9::Dictionary;
+pub use super::v9::DictionaryError;
+
+/// `PersistError` is why a dictionary could not be saved or loaded.
+#[derive(Debug, thiserror::Error)]
+pub enum PersistError {
+    #[error("failed to access dictionary file: {0}")]
+    Io(#[from] io::Error),
+
+    #[error("dictionary file is not valid JSON: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+/// `Persist` saves a dictionary to a JSON file, and loads it back. The file
+/// holds a JSON object, so the keys have to serialize as strings or numbers.
+pub trait Persist: Sized {
+    /// Writes the dictionary to `path`, replacing the file at once so that
+    /// it is never left half written.
+    fn save_to(&self, path: &Path) -> Result<(), PersistError>;
+
+    fn load_from(path: &Path) -> Result<Self, PersistError>;
+}
+
+impl<K, V> Persist for Dictionary<K, V>
+where
+    K: Eq + Hash + Serialize + DeserializeOwned,
+    V: Serialize + DeserializeOwned,
+{
+    fn save_to(&self, path: &Path) -> Result<(), PersistError> {
+        let json = serde_json::to_vec_pretty(&self.0)?;
+        let written = path.with_extension("tmp");
+        fs::write(&written, json)?;
+        fs::rename(&written, path)?;
+        Ok(())
+    }
+
+    fn load_from(path: &Path) -> Result<Self, PersistError> {
+        let json = fs::read(path)?;
+        Ok(Dictionary(serde_json::from_slice(&json)?))
+    }
+}
+
+/// `WriteError` is why a change to a `PersistentDictionary` failed: the
+/// change itself was refused, or it could not be saved.
+#[derive(Debug, thiserror::Error)]
+pub enum WriteError<K> {
+    #[error(transparent)]
+    Dictionary(#[from] DictionaryError<K>),
+
+    #[error(transparent)]
+    Persist(#[from] PersistError),
+}
+
+/// `PersistentDictionary` saves itself to its file after every change. A
+/// change that could not be saved is still kept in memory, and is saved
+/// along with the next one.
+#[derive(Debug)]
+pub struct PersistentDictionary<K = String, V = String> {
+    path: PathBuf,
+    dictionary: Dictionary<K, V>,
+}
+
+impl<K, V> PersistentDictionary<K, V>
+where
+    K: Eq + Hash + Serialize + DeserializeOwned,
+    V: Serialize + DeserializeOwned,
+{
+    /// Opens the dictionary saved at `path`, or an empty one if there is no
+    /// file there yet.
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self, PersistError> {
+        let path = path.into();
+        let dictionary = match Dictionary::load_from(&path) {
+            Ok(dictionary) => dictionary,
+            Err(PersistError::Io(error)) if error.kind() == io::ErrorKind::NotFound => {
+                Dictionary::new()
+            }
+            Err(error) => return Err(error),
+        };
+        Ok(PersistentDictionary { path, dictionary })
+    }
+
+    pub fn search<Q>(&self, key: &Q) -> Result<&V, DictionaryError<K>>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ToOwned<Owned = K> + ?Sized,
+    {
+        self.dictionary.search(key)
+    }
+
+    pub fn add(&mut self, key: K, value: V) -> Result<(), WriteError<K>> {
+        self.dictionary.add(key, value)?;
+        Ok(self.dictionary.save_to(&self.path)?)
+    }
+
+    pub fn update(&mut self, key: K, value: V) -> Result<(), WriteError<K>> {
+        self.dictionary.update(key, value)?;
+        Ok(self.dictionary.save_to(&self.path)?)
+    }
+
+    pub fn delete(&mut self, key: K) -> Result<(), WriteError<K>> {
+        self.dictionary.delete(key)?;
+        Ok(self.dictionary.save_to(&self.path)?)
+    }
+}
+
+#[cfg(test)]
+mod specs_for_persist {
+    use std::fs;
+
+    use tempfile::tempdir;
+
+    use super::Dictionary;
+    use super::Persist;
+    use super::PersistError;
+
+    #[test]
+    fn sut_loads_back_every_entry_saved() {
+        // Arrange
+        let directory = tempdir().unwrap();
+        let path = directory.path().join("dictionary.json");
+        let dictionary = Dictionary::from([
+            ("test".to_string(), "value".to_string()),
+            ("rust".to_string(), "language".to_string()),
+        ]);
+
+        // Act
+        dictionary.save_to(&path).unwrap();
+        let actual = Dictionary::<String, String>::load_from(&path).unwrap();
+
+        // Assert
+        assert_eq!("value", actual.search("test").unwrap());
+        assert_eq!("language", actual.search("rust").unwrap());
+    }
+
+    #[test]
+    fn sut_returns_json_error_if_file_is_not_dictionary() {
+        // Arrange
+        let directory = tempdir().unwrap();
+        let path = directory.path().join("dictionary.json");
+        fs::write(&path, "[1, 2, 3]").unwrap();
+
+        // Act
+        let actual = Dictionary::<String, String>::load_from(&path).unwrap_err();
+
+        // Assert
+        assert!(matches!(actual, PersistError::Json(_)));
+    }
+}
+
+#[cfg(test)]
+mod specs_for_persistent_dictionary {
+    use std::fs;
+
+    use tempfile::tempdir;
+
+    use super::DictionaryError;
+    use super::PersistError;
+    use super::PersistentDictionary;
+    use super::WriteError;
+
+    #[test]
+    fn sut_opens_empty_if_file_does_not_exist() {
+        // Arrange
+        let directory = tempdir().unwrap();
+
+        // Act
+        let actual =
+            PersistentDictionary::<String, String>::open(directory.path().join("new.json"));
+
+        // Assert
+        let actual = actual.unwrap().search("test").unwrap_err();
+        assert_eq!(DictionaryError::NotFound("test".to_string()), actual);
+    }
+
+    #[test]
+    fn sut_writes_every_change_through_to_file() {
+        // Arrange
+        let directory = tempdir().unwrap();
+        let path = directory.path().join("dictionary.json");
+        let mut sut = PersistentDictionary::open(&path).unwrap();
+
+        // Act
+        sut.add("one".to_string(), "1".to_string()).unwrap();
+        sut.add("two".to_string(), "2".to_string()).unwrap();
+        sut.update("one".to_string(), "uno".to_string()).unwrap();
+        sut.delete("two".to_string()).unwrap();
+
+        // Assert
+        let reopened = PersistentDictionary::<String, String>::open(&path).unwrap();
+        assert_eq!("uno", reopened.search("one").unwrap());
+        assert!(reopened.search("two").is_err());
+    }
+
+    #[test]
+    fn sut_returns_dictionary_error_and_leaves_file_as_is_if_change_is_refused() {
+        // Arrange
+        let directory = tempdir().unwrap();
+        let path = directory.path().join("dictionary.json");
+        let mut sut = PersistentDictionary::open(&path).unwrap();
+        sut.add("one".to_string(), "1".to_string()).unwrap();
+        let saved = fs::read_to_string(&path).unwrap();
+
+        // Act
+        let actual = sut.add("one".to_string(), "uno".to_string()).unwrap_err();
+
+        // Assert
+        assert!(matches!(
+            actual,
+            WriteError::Dictionary(DictionaryError::AlreadyExists(_))
+        ));
+        assert_eq!(saved, fs::read_to_string(&path).unwrap());
+    }
+
+    #[test]
+    fn sut_returns_persist_error_if_change_cannot_be_saved() {
+        // Arrange
+        let directory = tempdir().unwrap();
+        let path = directory.path().join("missing").join("dictionary.json");
+        let mut sut = PersistentDictionary::open(&path).unwrap();
+
+        // Act
+        let actual = sut.add("one".to_string(), "1".to_string()).unwrap_err();
+
+        // Assert
+        assert!(matches!(actual, WriteError::Persist(PersistError::Io(_))));
+        assert_eq!("1", sut.search("one").unwrap());
+    }
+}
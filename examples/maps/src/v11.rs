@@ -0,0 +1,243 @@
+use std::collections::BTreeMap;
+use std::ops::Bound;
+
+pub use super::v8::DictionaryError;
+
+/// `Dictionary` keeps its entries sorted by key, so that the entries whose
+/// keys share a prefix sit next to each other and are found without looking
+/// at the others.
+#[derive(Debug, Default)]
+pub struct Dictionary(BTreeMap<String, String>);
+
+impl Dictionary {
+    pub fn new() -> Self {
+        Dictionary::default()
+    }
+
+    pub fn search(&self, key: &str) -> Result<&str, DictionaryError<String>> {
+        self.0
+            .get(key)
+            .map(|value| value.as_str())
+            .ok_or_else(|| DictionaryError::NotFound(key.to_string()))
+    }
+
+    /// Returns the entries whose keys start with `prefix`, sorted by key.
+    pub fn search_by_prefix(&self, prefix: &str) -> Vec<(&str, &str)> {
+        self.0
+            .range::<str, _>((Bound::Included(prefix), Bound::Unbounded))
+            .take_while(|(key, _)| key.starts_with(prefix))
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+            .collect()
+    }
+
+    /// Returns the entries whose keys are at most `max_distance` edits away
+    /// from `key`, an edit being a character inserted, removed or replaced.
+    /// The closest entries come first, sorted by key among equally close
+    /// ones.
+    pub fn search_similar(&self, key: &str, max_distance: usize) -> Vec<(&str, &str)> {
+        let mut similar = self
+            .0
+            .iter()
+            .filter_map(|(candidate, value)| {
+                let distance = levenshtein(key, candidate, max_distance)?;
+                Some((distance, candidate.as_str(), value.as_str()))
+            })
+            .collect::<Vec<_>>();
+        // The entries come sorted by key, and the sort is stable.
+        similar.sort_by_key(|(distance, _, _)| *distance);
+        similar
+            .into_iter()
+            .map(|(_, key, value)| (key, value))
+            .collect()
+    }
+
+    pub fn add(&mut self, key: String, value: String) -> Result<(), DictionaryError<String>> {
+        if self.0.contains_key(&key) {
+            return Err(DictionaryError::AlreadyExists(key));
+        }
+        self.0.insert(key, value);
+        Ok(())
+    }
+
+    pub fn update(&mut self, key: String, value: String) -> Result<(), DictionaryError<String>> {
+        match self.0.get_mut(&key) {
+            Some(entry) => {
+                *entry = value;
+                Ok(())
+            }
+            None => Err(DictionaryError::NotFound(key)),
+        }
+    }
+
+    pub fn delete(&mut self, key: String) -> Result<(), DictionaryError<String>> {
+        match self.0.remove(&key) {
+            Some(_) => Ok(()),
+            None => Err(DictionaryError::NotFound(key)),
+        }
+    }
+}
+
+impl<const N: usize> From<[(String, String); N]> for Dictionary {
+    fn from(entries: [(String, String); N]) -> Self {
+        Dictionary(BTreeMap::from(entries))
+    }
+}
+
+/// Returns the edit distance between `a` and `b`, or `None` as soon as it
+/// is known to be over `max`.
+fn levenshtein(a: &str, b: &str, max: usize) -> Option<usize> {
+    let a = a.chars().collect::<Vec<_>>();
+    let b = b.chars().collect::<Vec<_>>();
+    if a.len().abs_diff(b.len()) > max {
+        return None;
+    }
+    // `previous[j]` is the distance between the first `i - 1` characters of
+    // `a` and the first `j` of `b`.
+    let mut previous = (0..=b.len()).collect::<Vec<_>>();
+    for (i, a_char) in a.iter().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let replaced = previous[j] + usize::from(a_char != b_char);
+            current[j + 1] = replaced.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        if current.iter().all(|distance| *distance > max) {
+            return None;
+        }
+        previous = current;
+    }
+    Some(previous[b.len()]).filter(|distance| *distance <= max)
+}
+
+#[cfg(test)]
+mod specs_for_dictionary_search_by_prefix {
+    use super::Dictionary;
+
+    fn dictionary() -> Dictionary {
+        Dictionary::from(
+            ["car", "card", "care", "cat", "dog"].map(|key| (key.to_string(), key.to_uppercase())),
+        )
+    }
+
+    #[rstest::rstest]
+    #[case::shared_prefix("car", vec![("car", "CAR"), ("card", "CARD"), ("care", "CARE")])]
+    #[case::whole_key("dog", vec![("dog", "DOG")])]
+    #[case::empty_prefix("", vec![("car", "CAR"), ("card", "CARD"), ("care", "CARE"), ("cat", "CAT"), ("dog", "DOG")])]
+    #[case::no_key("cow", vec![])]
+    fn sut_returns_entries_whose_keys_start_with_prefix_sorted_by_key(
+        #[case] prefix: &str,
+        #[case] expected: Vec<(&str, &str)>,
+    ) {
+        // Arrange
+        let dictionary = dictionary();
+
+        // Act
+        let actual = dictionary.search_by_prefix(prefix);
+
+        // Assert
+        assert_eq!(expected, actual);
+    }
+}
+
+#[cfg(test)]
+mod specs_for_dictionary_search_similar {
+    use super::Dictionary;
+
+    fn dictionary() -> Dictionary {
+        Dictionary::from(
+            ["bat", "cart", "cat", "cut", "dog"].map(|key| (key.to_string(), key.to_uppercase())),
+        )
+    }
+
+    #[rstest::rstest]
+    #[case::exact_only("cat", 0, vec![("cat", "CAT")])]
+    #[case::one_edit("cat", 1, vec![("cat", "CAT"), ("bat", "BAT"), ("cart", "CART"), ("cut", "CUT")])]
+    #[case::too_far("cow", 1, vec![])]
+    #[case::shorter_key("ct", 1, vec![("cat", "CAT"), ("cut", "CUT")])]
+    fn sut_returns_entries_within_edit_distance_closest_first(
+        #[case] key: &str,
+        #[case] max_distance: usize,
+        #[case] expected: Vec<(&str, &str)>,
+    ) {
+        // Arrange
+        let dictionary = dictionary();
+
+        // Act
+        let actual = dictionary.search_similar(key, max_distance);
+
+        // Assert
+        assert_eq!(expected, actual);
+    }
+}
+
+#[cfg(test)]
+mod specs_for_levenshtein {
+    use super::levenshtein;
+
+    #[rstest::rstest]
+    #[case::same("kitten", "kitten", 3, Some(0))]
+    #[case::classic("kitten", "sitting", 3, Some(3))]
+    #[case::from_empty("", "abc", 3, Some(3))]
+    #[case::unicode("café", "cafe", 3, Some(1))]
+    #[case::over_max("kitten", "sitting", 2, None)]
+    fn sut_counts_edits_up_to_max(
+        #[case] a: &str,
+        #[case] b: &str,
+        #[case] max: usize,
+        #[case] expected: Option<usize>,
+    ) {
+        // Act
+        let actual = levenshtein(a, b, max);
+
+        // Assert
+        assert_eq!(expected, actual);
+    }
+}
+
+#[cfg(test)]
+mod specs_for_dictionary {
+    use super::Dictionary;
+    use super::DictionaryError;
+
+    #[test]
+    fn sut_returns_updated_value() {
+        // Arrange
+        let mut dictionary = Dictionary::from([("test".to_string(), "value1".to_string())]);
+
+        // Act
+        dictionary
+            .update("test".to_string(), "value2".to_string())
+            .unwrap();
+
+        // Assert
+        assert_eq!("value2", dictionary.search("test").unwrap());
+    }
+
+    #[test]
+    fn sut_leaves_out_deleted_entry_from_prefix_search() {
+        // Arrange
+        let mut dictionary = Dictionary::from([
+            ("test".to_string(), "value".to_string()),
+            ("tester".to_string(), "value".to_string()),
+        ]);
+
+        // Act
+        dictionary.delete("tester".to_string()).unwrap();
+
+        // Assert
+        assert_eq!(vec![("test", "value")], dictionary.search_by_prefix("test"));
+    }
+
+    #[test]
+    fn sut_returns_already_exists_error_if_entry_already_exists() {
+        // Arrange
+        let mut dictionary = Dictionary::from([("test".to_string(), "value1".to_string())]);
+
+        // Act
+        let actual = dictionary
+            .add("test".to_string(), "value2".to_string())
+            .unwrap_err();
+
+        // Assert
+        assert_eq!(DictionaryError::AlreadyExists("test".to_string()), actual);
+    }
+}
@@ -0,0 +1,262 @@
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+/// `Entries` is the map a `Dictionary` keeps its entries in.
+pub trait Entries: Default {
+    fn get(&self, key: &str) -> Option<&String>;
+    fn contains_key(&self, key: &str) -> bool;
+    fn insert(&mut self, key: String, value: String);
+    fn remove(&mut self, key: &str) -> Option<String>;
+}
+
+impl Entries for HashMap<String, String> {
+    fn get(&self, key: &str) -> Option<&String> {
+        HashMap::get(self, key)
+    }
+
+    fn contains_key(&self, key: &str) -> bool {
+        HashMap::contains_key(self, key)
+    }
+
+    fn insert(&mut self, key: String, value: String) {
+        HashMap::insert(self, key, value);
+    }
+
+    fn remove(&mut self, key: &str) -> Option<String> {
+        HashMap::remove(self, key)
+    }
+}
+
+impl Entries for BTreeMap<String, String> {
+    fn get(&self, key: &str) -> Option<&String> {
+        BTreeMap::get(self, key)
+    }
+
+    fn contains_key(&self, key: &str) -> bool {
+        BTreeMap::contains_key(self, key)
+    }
+
+    fn insert(&mut self, key: String, value: String) {
+        BTreeMap::insert(self, key, value);
+    }
+
+    fn remove(&mut self, key: &str) -> Option<String> {
+        BTreeMap::remove(self, key)
+    }
+}
+
+/// `Dictionary` keeps its entries in a `HashMap` unless told otherwise, so
+/// that the maps can be compared by the benchmarks.
+#[derive(Default)]
+pub struct Dictionary<E = HashMap<String, String>>(E);
+
+impl<E: Entries> Dictionary<E> {
+    pub fn new() -> Self {
+        Dictionary::default()
+    }
+
+    pub fn search(&self, key: &str) -> Result<&str, DictionaryError> {
+        self.0
+            .get(key)
+            .map(|value| value.as_str())
+            .ok_or_else(|| DictionaryError::NotFound(key.to_string()))
+    }
+
+    pub fn add(&mut self, key: String, value: String) -> Result<(), DictionaryError> {
+        if self.0.contains_key(&key) {
+            return Err(DictionaryError::AlreadyExists(key));
+        }
+        self.0.insert(key, value);
+        Ok(())
+    }
+
+    pub fn update(&mut self, key: String, value: String) -> Result<(), DictionaryError> {
+        if !self.0.contains_key(&key) {
+            return Err(DictionaryError::NotFound(key));
+        }
+        self.0.insert(key, value);
+        Ok(())
+    }
+
+    pub fn delete(&mut self, key: String) -> Result<(), DictionaryError> {
+        match self.0.remove(&key) {
+            Some(_) => Ok(()),
+            None => Err(DictionaryError::NotFound(key)),
+        }
+    }
+
+    /// Adds every entry whose key is not in the dictionary yet, in order.
+    /// An entry whose key is taken, by the dictionary or by an earlier entry,
+    /// is left out and reported with an `AlreadyExists` error of its own.
+    pub fn add_many(
+        &mut self,
+        entries: impl IntoIterator<Item = (String, String)>,
+    ) -> Result<(), Vec<DictionaryError>> {
+        let conflicts = entries
+            .into_iter()
+            .filter_map(|(key, value)| self.add(key, value).err())
+            .collect::<Vec<_>>();
+        if conflicts.is_empty() {
+            Ok(())
+        } else {
+            Err(conflicts)
+        }
+    }
+}
+
+impl<E: Entries, const N: usize> From<[(String, String); N]> for Dictionary<E> {
+    fn from(entries: [(String, String); N]) -> Self {
+        let mut map = E::default();
+        for (key, value) in entries {
+            map.insert(key, value);
+        }
+        Dictionary(map)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum DictionaryError {
+    #[error("the key '{0}' was not found")]
+    NotFound(String),
+
+    #[error("the key '{0}' already exists")]
+    AlreadyExists(String),
+}
+
+#[cfg(test)]
+mod specs_for_dictionary {
+    macro_rules! dictionary_specs {
+        ($name:ident, $entries:ty) => {
+            mod $name {
+                #[allow(unused_imports)]
+                use std::collections::BTreeMap;
+                #[allow(unused_imports)]
+                use std::collections::HashMap;
+
+                use crate::v7::Dictionary;
+                use crate::v7::DictionaryError;
+
+                type Sut = Dictionary<$entries>;
+
+                #[test]
+                fn sut_returns_value_of_added_entry() {
+                    // Arrange
+                    let mut dictionary = Sut::new();
+
+                    // Act
+                    dictionary
+                        .add("test".to_string(), "value".to_string())
+                        .unwrap();
+
+                    // Assert
+                    let actual = dictionary.search("test").unwrap();
+                    assert_eq!("value", actual);
+                }
+
+                #[test]
+                fn sut_raises_already_exists_error_if_entry_already_exists() {
+                    // Arrange
+                    let mut dictionary = Sut::from([("test".to_string(), "value1".to_string())]);
+
+                    // Act
+                    let actual = dictionary
+                        .add("test".to_string(), "value2".to_string())
+                        .unwrap_err();
+
+                    // Assert
+                    assert!(matches!(actual, DictionaryError::AlreadyExists(_)));
+                }
+
+                #[test]
+                fn sut_returns_updated_value() {
+                    // Arrange
+                    let mut dictionary = Sut::from([("test".to_string(), "value1".to_string())]);
+
+                    // Act
+                    dictionary
+                        .update("test".to_string(), "value2".to_string())
+                        .unwrap();
+
+                    // Assert
+                    let actual = dictionary.search("test").unwrap();
+                    assert_eq!("value2", actual);
+                }
+
+                #[test]
+                fn sut_returns_not_found_error_if_updated_key_does_not_exist() {
+                    // Arrange
+                    let mut dictionary = Sut::new();
+
+                    // Act
+                    let actual = dictionary
+                        .update("test".to_string(), "value".to_string())
+                        .unwrap_err();
+
+                    // Assert
+                    assert!(matches!(actual, DictionaryError::NotFound(_)));
+                }
+
+                #[test]
+                fn sut_is_not_able_to_search_deleted_entry() {
+                    // Arrange
+                    let mut dictionary = Sut::from([("test".to_string(), "value".to_string())]);
+
+                    // Act
+                    dictionary.delete("test".to_string()).unwrap();
+
+                    // Assert
+                    let actual = dictionary.search("test").unwrap_err();
+                    assert!(matches!(actual, DictionaryError::NotFound(_)));
+                }
+
+                #[test]
+                fn sut_returns_value_of_every_entry_added_at_once() {
+                    // Arrange
+                    let mut dictionary = Sut::new();
+
+                    // Act
+                    dictionary
+                        .add_many([
+                            ("one".to_string(), "1".to_string()),
+                            ("two".to_string(), "2".to_string()),
+                        ])
+                        .unwrap();
+
+                    // Assert
+                    assert_eq!("1", dictionary.search("one").unwrap());
+                    assert_eq!("2", dictionary.search("two").unwrap());
+                }
+
+                #[test]
+                fn sut_adds_entries_without_conflict_and_reports_each_conflicting_key() {
+                    // Arrange
+                    let mut dictionary = Sut::from([("one".to_string(), "1".to_string())]);
+
+                    // Act
+                    let actual = dictionary
+                        .add_many([
+                            ("one".to_string(), "uno".to_string()),
+                            ("two".to_string(), "2".to_string()),
+                            ("two".to_string(), "dos".to_string()),
+                        ])
+                        .unwrap_err();
+
+                    // Assert
+                    let conflicts = actual
+                        .iter()
+                        .map(|error| match error {
+                            DictionaryError::AlreadyExists(key) => key.as_str(),
+                            DictionaryError::NotFound(key) => panic!("{key} was not found"),
+                        })
+                        .collect::<Vec<_>>();
+                    assert_eq!(vec!["one", "two"], conflicts);
+                    assert_eq!("1", dictionary.search("one").unwrap());
+                    assert_eq!("2", dictionary.search("two").unwrap());
+                }
+            }
+        };
+    }
+
+    dictionary_specs!(with_hash_map, HashMap<String, String>);
+    dictionary_specs!(with_btree_map, BTreeMap<String, String>);
+}
@@ -0,0 +1,332 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::collections::hash_map;
+use std::collections::hash_map::Entry;
+use std::hash::Hash;
+
+/// `Dictionary` maps keys of any hashable type to values of any type. Keys
+/// and values are strings unless told otherwise, like in earlier versions.
+#[derive(Debug)]
+pub struct Dictionary<K = String, V = String>(pub(crate) HashMap<K, V>);
+
+impl<K, V> Default for Dictionary<K, V> {
+    fn default() -> Self {
+        Dictionary(HashMap::new())
+    }
+}
+
+impl<K: Eq + Hash, V> Dictionary<K, V> {
+    pub fn new() -> Self {
+        Dictionary::default()
+    }
+
+    /// Searches the value of `key`, which may be borrowed from a key like a
+    /// `&str` from a `String`.
+    pub fn search<Q>(&self, key: &Q) -> Result<&V, DictionaryError<K>>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ToOwned<Owned = K> + ?Sized,
+    {
+        self.0
+            .get(key)
+            .ok_or_else(|| DictionaryError::NotFound(key.to_owned()))
+    }
+
+    pub fn add(&mut self, key: K, value: V) -> Result<(), DictionaryError<K>> {
+        if self.0.contains_key(&key) {
+            return Err(DictionaryError::AlreadyExists(key));
+        }
+        self.0.insert(key, value);
+        Ok(())
+    }
+
+    pub fn update(&mut self, key: K, value: V) -> Result<(), DictionaryError<K>> {
+        match self.0.entry(key) {
+            Entry::Occupied(mut entry) => {
+                entry.insert(value);
+                Ok(())
+            }
+            Entry::Vacant(entry) => Err(DictionaryError::NotFound(entry.into_key())),
+        }
+    }
+
+    pub fn delete(&mut self, key: K) -> Result<(), DictionaryError<K>> {
+        match self.0.remove(&key) {
+            Some(_) => Ok(()),
+            None => Err(DictionaryError::NotFound(key)),
+        }
+    }
+
+    /// Adds every entry whose key is not in the dictionary yet, in order.
+    /// An entry whose key is taken, by the dictionary or by an earlier entry,
+    /// is left out and reported with an `AlreadyExists` error of its own.
+    pub fn add_many(
+        &mut self,
+        entries: impl IntoIterator<Item = (K, V)>,
+    ) -> Result<(), Vec<DictionaryError<K>>> {
+        let conflicts = entries
+            .into_iter()
+            .filter_map(|(key, value)| self.add(key, value).err())
+            .collect::<Vec<_>>();
+        if conflicts.is_empty() {
+            Ok(())
+        } else {
+            Err(conflicts)
+        }
+    }
+}
+
+impl<K, V> Dictionary<K, V> {
+    /// Returns the entries in no particular order.
+    pub fn iter(&self) -> hash_map::Iter<'_, K, V> {
+        self.0.iter()
+    }
+
+    pub fn keys(&self) -> hash_map::Keys<'_, K, V> {
+        self.0.keys()
+    }
+
+    pub fn values(&self) -> hash_map::Values<'_, K, V> {
+        self.0.values()
+    }
+}
+
+impl<K, V> IntoIterator for Dictionary<K, V> {
+    type Item = (K, V);
+    type IntoIter = hash_map::IntoIter<K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a Dictionary<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = hash_map::Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<K: Eq + Hash, V, const N: usize> From<[(K, V); N]> for Dictionary<K, V> {
+    fn from(entries: [(K, V); N]) -> Self {
+        Dictionary(HashMap::from(entries))
+    }
+}
+
+/// `DictionaryError` tells the key that was missing or taken, displayed as
+/// the key displays itself.
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum DictionaryError<K> {
+    #[error("the key '{0}' was not found")]
+    NotFound(K),
+
+    #[error("the key '{0}' already exists")]
+    AlreadyExists(K),
+}
+
+#[cfg(test)]
+mod specs_for_dictionary_iteration {
+    use super::Dictionary;
+
+    fn dictionary() -> Dictionary<&'static str, u32> {
+        Dictionary::from([("one", 1), ("two", 2), ("three", 3)])
+    }
+
+    #[test]
+    fn sut_iterates_over_every_entry_by_reference() {
+        // Arrange
+        let dictionary = dictionary();
+
+        // Act
+        let mut actual = dictionary.iter().collect::<Vec<_>>();
+
+        // Assert
+        actual.sort();
+        assert_eq!(vec![(&"one", &1), (&"three", &3), (&"two", &2)], actual);
+    }
+
+    #[test]
+    fn sut_iterates_over_every_key_and_every_value() {
+        // Arrange
+        let dictionary = dictionary();
+
+        // Act
+        let mut keys = dictionary.keys().copied().collect::<Vec<_>>();
+        let mut values = dictionary.values().copied().collect::<Vec<_>>();
+
+        // Assert
+        keys.sort();
+        values.sort();
+        assert_eq!(vec!["one", "three", "two"], keys);
+        assert_eq!(vec![1, 2, 3], values);
+    }
+
+    #[test]
+    fn sut_is_iterated_over_in_for_loop_by_reference_and_by_value() {
+        // Arrange
+        let dictionary = dictionary();
+        let mut borrowed = 0;
+
+        // Act
+        for (_, value) in &dictionary {
+            borrowed += value;
+        }
+        let mut owned = dictionary.into_iter().collect::<Vec<_>>();
+
+        // Assert
+        owned.sort();
+        assert_eq!(6, borrowed);
+        assert_eq!(vec![("one", 1), ("three", 3), ("two", 2)], owned);
+    }
+}
+
+#[cfg(test)]
+mod specs_for_dictionary_with_str_keys {
+    use super::Dictionary;
+    use super::DictionaryError;
+
+    #[test]
+    fn sut_returns_value_of_added_entry_searched_by_borrowed_key() {
+        // Arrange
+        let mut dictionary = Dictionary::<String, u32>::new();
+
+        // Act
+        dictionary.add("answer".to_string(), 42).unwrap();
+
+        // Assert
+        let actual = dictionary.search("answer").unwrap();
+        assert_eq!(&42, actual);
+    }
+
+    #[test]
+    fn sut_returns_not_found_error_displaying_key_if_key_does_not_exist() {
+        // Arrange
+        let dictionary = Dictionary::<String, u32>::new();
+
+        // Act
+        let actual = dictionary.search("answer").unwrap_err();
+
+        // Assert
+        assert_eq!(DictionaryError::NotFound("answer".to_string()), actual);
+        assert_eq!("the key 'answer' was not found", actual.to_string());
+    }
+
+    #[test]
+    fn sut_is_not_able_to_search_deleted_entry() {
+        // Arrange
+        let mut dictionary = Dictionary::from([("answer".to_string(), 42)]);
+
+        // Act
+        dictionary.delete("answer".to_string()).unwrap();
+
+        // Assert
+        let actual = dictionary.search("answer").unwrap_err();
+        assert_eq!(DictionaryError::NotFound("answer".to_string()), actual);
+    }
+}
+
+#[cfg(test)]
+mod specs_for_dictionary_with_u32_keys {
+    use super::Dictionary;
+    use super::DictionaryError;
+
+    #[test]
+    fn sut_returns_updated_value() {
+        // Arrange
+        let mut dictionary = Dictionary::from([(1u32, "one")]);
+
+        // Act
+        dictionary.update(1, "uno").unwrap();
+
+        // Assert
+        let actual = dictionary.search(&1).unwrap();
+        assert_eq!(&"uno", actual);
+    }
+
+    #[test]
+    fn sut_returns_already_exists_error_displaying_key_if_key_is_taken() {
+        // Arrange
+        let mut dictionary = Dictionary::from([(1u32, "one")]);
+
+        // Act
+        let actual = dictionary.add(1, "uno").unwrap_err();
+
+        // Assert
+        assert_eq!(DictionaryError::AlreadyExists(1), actual);
+        assert_eq!("the key '1' already exists", actual.to_string());
+    }
+
+    #[test]
+    fn sut_reports_each_conflicting_key_of_entries_added_at_once() {
+        // Arrange
+        let mut dictionary = Dictionary::from([(1u32, "one")]);
+
+        // Act
+        let actual = dictionary
+            .add_many([(1, "uno"), (2, "two"), (2, "dos")])
+            .unwrap_err();
+
+        // Assert
+        let expected = vec![
+            DictionaryError::AlreadyExists(1),
+            DictionaryError::AlreadyExists(2),
+        ];
+        assert_eq!(expected, actual);
+        assert_eq!(&"two", dictionary.search(&2).unwrap());
+    }
+}
+
+#[cfg(test)]
+mod specs_for_dictionary_with_struct_keys {
+    use std::fmt;
+
+    use super::Dictionary;
+    use super::DictionaryError;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Isbn {
+        prefix: u16,
+        number: u32,
+    }
+
+    impl fmt::Display for Isbn {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{}-{}", self.prefix, self.number)
+        }
+    }
+
+    const RUST_BOOK: Isbn = Isbn {
+        prefix: 978,
+        number: 1718503106,
+    };
+
+    #[test]
+    fn sut_returns_value_of_added_entry() {
+        // Arrange
+        let mut dictionary = Dictionary::new();
+
+        // Act
+        dictionary
+            .add(RUST_BOOK, "The Rust Programming Language")
+            .unwrap();
+
+        // Assert
+        let actual = dictionary.search(&RUST_BOOK).unwrap();
+        assert_eq!(&"The Rust Programming Language", actual);
+    }
+
+    #[test]
+    fn sut_returns_not_found_error_displaying_key_if_updated_key_does_not_exist() {
+        // Arrange
+        let mut dictionary = Dictionary::new();
+
+        // Act
+        let actual = dictionary.update(RUST_BOOK, "Rust").unwrap_err();
+
+        // Assert
+        assert_eq!(DictionaryError::NotFound(RUST_BOOK), actual);
+        assert_eq!("the key '978-1718503106' was not found", actual.to_string());
+    }
+}
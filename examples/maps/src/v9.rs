@@ -0,0 +1,125 @@
+use std::borrow::Borrow;
+use std::hash::Hash;
+use std::sync::RwLock;
+
+pub use super::v8::Dictionary;
+pub use super::v8::DictionaryError;
+
+/// `ConcurrentDictionary` is a `Dictionary` shared by many tasks at once.
+/// Searches share the lock with each other, while changes take it alone.
+#[derive(Debug)]
+pub struct ConcurrentDictionary<K = String, V = String> {
+    dictionary: RwLock<Dictionary<K, V>>,
+}
+
+impl<K, V> Default for ConcurrentDictionary<K, V> {
+    fn default() -> Self {
+        ConcurrentDictionary {
+            dictionary: RwLock::new(Dictionary::default()),
+        }
+    }
+}
+
+impl<K: Eq + Hash, V> ConcurrentDictionary<K, V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Searches the value of `key`, returning a copy of it, as the value
+    /// itself cannot outlive the lock.
+    pub fn search<Q>(&self, key: &Q) -> Result<V, DictionaryError<K>>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ToOwned<Owned = K> + ?Sized,
+        V: Clone,
+    {
+        self.dictionary.read().unwrap().search(key).cloned()
+    }
+
+    pub fn add(&self, key: K, value: V) -> Result<(), DictionaryError<K>> {
+        self.dictionary.write().unwrap().add(key, value)
+    }
+
+    pub fn update(&self, key: K, value: V) -> Result<(), DictionaryError<K>> {
+        self.dictionary.write().unwrap().update(key, value)
+    }
+
+    pub fn delete(&self, key: K) -> Result<(), DictionaryError<K>> {
+        self.dictionary.write().unwrap().delete(key)
+    }
+}
+
+impl<K, V> From<Dictionary<K, V>> for ConcurrentDictionary<K, V> {
+    fn from(dictionary: Dictionary<K, V>) -> Self {
+        ConcurrentDictionary {
+            dictionary: RwLock::new(dictionary),
+        }
+    }
+}
+
+#[cfg(test)]
+mod specs_for_concurrent_dictionary {
+    use std::sync::Arc;
+
+    use futures::future::join_all;
+
+    use super::ConcurrentDictionary;
+    use super::DictionaryError;
+
+    #[test]
+    fn sut_returns_copy_of_updated_value() {
+        // Arrange
+        let dictionary = ConcurrentDictionary::new();
+        dictionary
+            .add("test".to_string(), "value1".to_string())
+            .unwrap();
+
+        // Act
+        dictionary
+            .update("test".to_string(), "value2".to_string())
+            .unwrap();
+
+        // Assert
+        let actual = dictionary.search("test").unwrap();
+        assert_eq!("value2", actual);
+    }
+
+    #[test]
+    fn sut_returns_not_found_error_if_deleted_key_does_not_exist() {
+        // Arrange
+        let dictionary = ConcurrentDictionary::<String, String>::new();
+
+        // Act
+        let actual = dictionary.delete("test".to_string()).unwrap_err();
+
+        // Assert
+        assert_eq!(DictionaryError::NotFound("test".to_string()), actual);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn sut_loses_no_entry_changed_by_many_tasks_at_once() {
+        // Arrange
+        let tasks = 500;
+        let dictionary = Arc::new(ConcurrentDictionary::<u32, u32>::new());
+
+        // Act
+        let handles = (0..tasks)
+            .map(|key| {
+                let dictionary = Arc::clone(&dictionary);
+                tokio::spawn(async move {
+                    dictionary.add(key, key).unwrap();
+                    tokio::task::yield_now().await;
+                    dictionary.update(key, key * 2).unwrap();
+                    dictionary.search(&key).unwrap()
+                })
+            })
+            .collect::<Vec<_>>();
+        let searched = join_all(handles).await;
+
+        // Assert
+        for (key, value) in (0..tasks).zip(searched) {
+            assert_eq!(key * 2, value.unwrap());
+            assert_eq!(Ok(key * 2), dictionary.search(&key));
+        }
+    }
+}
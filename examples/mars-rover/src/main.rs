@@ -0,0 +1,19 @@
+use std::io::stdin;
+use std::io::stdout;
+
+use mars_rover::v1::Direction;
+use mars_rover::v1::Grid;
+use mars_rover::v1::Position;
+use mars_rover::v1::Rover;
+use mars_rover::v1::mission::LineCommandSource;
+use mars_rover::v1::mission::Mission;
+use mars_rover::v1::mission::WriterReporter;
+
+fn main() {
+    let grid = Grid::new(10, 10).with_obstacles([Position { x: 2, y: 2 }, Position { x: 7, y: 5 }]);
+    let mission = Mission::new(grid, Rover::new(0, 0, Direction::North));
+    mission.run(
+        &mut LineCommandSource::new(stdin().lock()),
+        &mut WriterReporter::new(stdout()),
+    );
+}
@@ -0,0 +1,342 @@
+pub mod mission;
+
+use std::collections::HashSet;
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    pub fn left(self) -> Self {
+        match self {
+            Direction::North => Direction::West,
+            Direction::West => Direction::South,
+            Direction::South => Direction::East,
+            Direction::East => Direction::North,
+        }
+    }
+
+    pub fn right(self) -> Self {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let direction = match self {
+            Direction::North => "N",
+            Direction::East => "E",
+            Direction::South => "S",
+            Direction::West => "W",
+        };
+        f.write_str(direction)
+    }
+}
+
+/// `Position` is a cell of the grid. `y` grows to the north and `x` to the
+/// east.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Position {
+    pub x: usize,
+    pub y: usize,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rover {
+    pub position: Position,
+    pub direction: Direction,
+}
+
+/// Writes the rover as `x:y:D`, as the kata reports it.
+impl fmt::Display for Rover {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Position { x, y } = self.position;
+        write!(f, "{x}:{y}:{}", self.direction)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Command {
+    Left,
+    Right,
+    Move,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+#[error("unknown command {character:?} at position {position}")]
+pub struct ParseError {
+    pub position: usize,
+    pub character: char,
+}
+
+/// Reads commands such as `"LMMR"`, where `L` and `R` turn the rover and
+/// `M` moves it forward. Positions in errors are counted from 0.
+pub fn parse_commands(input: &str) -> Result<Vec<Command>, ParseError> {
+    input
+        .chars()
+        .enumerate()
+        .map(|(position, character)| match character {
+            'L' => Ok(Command::Left),
+            'R' => Ok(Command::Right),
+            'M' => Ok(Command::Move),
+            _ => Err(ParseError {
+                position,
+                character,
+            }),
+        })
+        .collect()
+}
+
+/// `Grid` is the plateau the rover drives on. It wraps around at its edges,
+/// and the rover cannot drive onto its obstacles.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid {
+    width: usize,
+    height: usize,
+    obstacles: HashSet<Position>,
+}
+
+impl Grid {
+    /// # Panics
+    ///
+    /// Panics if `width` or `height` is zero.
+    pub fn new(width: usize, height: usize) -> Self {
+        assert!(width > 0 && height > 0, "grid must not be empty");
+        Grid {
+            width,
+            height,
+            obstacles: HashSet::new(),
+        }
+    }
+
+    pub fn with_obstacles(self, obstacles: impl IntoIterator<Item = Position>) -> Self {
+        Grid {
+            obstacles: obstacles.into_iter().collect(),
+            ..self
+        }
+    }
+
+    /// Returns the cell next to `position` in `direction`, wrapping around
+    /// the edges.
+    fn next(&self, Position { x, y }: Position, direction: Direction) -> Position {
+        match direction {
+            Direction::North => Position {
+                x,
+                y: (y + 1) % self.height,
+            },
+            Direction::East => Position {
+                x: (x + 1) % self.width,
+                y,
+            },
+            Direction::South => Position {
+                x,
+                y: (y + self.height - 1) % self.height,
+            },
+            Direction::West => Position {
+                x: (x + self.width - 1) % self.width,
+                y,
+            },
+        }
+    }
+}
+
+/// `Outcome` is where the rover ended up after its commands.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    /// Every command was carried out.
+    Completed(Rover),
+    /// The rover stopped in front of an obstacle, leaving the remaining
+    /// commands undone.
+    Blocked { rover: Rover, obstacle: Position },
+}
+
+impl Rover {
+    pub fn new(x: usize, y: usize, direction: Direction) -> Self {
+        Rover {
+            position: Position { x, y },
+            direction,
+        }
+    }
+
+    pub fn execute(self, commands: &[Command], grid: &Grid) -> Outcome {
+        let mut rover = self;
+        for command in commands {
+            match command {
+                Command::Left => rover.direction = rover.direction.left(),
+                Command::Right => rover.direction = rover.direction.right(),
+                Command::Move => {
+                    let next = grid.next(rover.position, rover.direction);
+                    if grid.obstacles.contains(&next) {
+                        return Outcome::Blocked {
+                            rover,
+                            obstacle: next,
+                        };
+                    }
+                    rover.position = next;
+                }
+            }
+        }
+        Outcome::Completed(rover)
+    }
+}
+
+#[cfg(test)]
+mod specs_for_parse_commands {
+    use super::Command;
+    use super::Command::*;
+    use super::ParseError;
+    use super::parse_commands;
+
+    #[rstest::rstest]
+    #[case::empty("", vec![])]
+    #[case::left("L", vec![Left])]
+    #[case::right("R", vec![Right])]
+    #[case::move_("M", vec![Move])]
+    #[case::mixed("LMRMM", vec![Left, Move, Right, Move, Move])]
+    fn sut_reads_each_command(#[case] input: &str, #[case] expected: Vec<Command>) {
+        assert_eq!(Ok(expected), parse_commands(input));
+    }
+
+    #[rstest::rstest]
+    #[case::lowercase("lm", 0, 'l')]
+    #[case::unknown_letter("MMX", 2, 'X')]
+    #[case::whitespace("M M", 1, ' ')]
+    fn sut_points_at_unknown_command(
+        #[case] input: &str,
+        #[case] position: usize,
+        #[case] character: char,
+    ) {
+        assert_eq!(
+            Err(ParseError {
+                position,
+                character
+            }),
+            parse_commands(input)
+        );
+    }
+}
+
+#[cfg(test)]
+mod specs_for_rover {
+    use super::Direction;
+    use super::Direction::*;
+    use super::Grid;
+    use super::Outcome;
+    use super::Position;
+    use super::Rover;
+    use super::parse_commands;
+
+    fn execute(rover: Rover, commands: &str, grid: &Grid) -> Outcome {
+        rover.execute(&parse_commands(commands).unwrap(), grid)
+    }
+
+    #[rstest::rstest]
+    #[case::left_from_north(North, "L", West)]
+    #[case::left_from_west(West, "L", South)]
+    #[case::left_from_south(South, "L", East)]
+    #[case::left_from_east(East, "L", North)]
+    #[case::right_from_north(North, "R", East)]
+    #[case::right_from_east(East, "R", South)]
+    #[case::right_from_south(South, "R", West)]
+    #[case::right_from_west(West, "R", North)]
+    #[case::full_turn(North, "RRRR", North)]
+    fn sut_turns_without_moving(
+        #[case] facing: Direction,
+        #[case] commands: &str,
+        #[case] expected: Direction,
+    ) {
+        // Act
+        let actual = execute(Rover::new(2, 2, facing), commands, &Grid::new(5, 5));
+
+        // Assert
+        assert_eq!(Outcome::Completed(Rover::new(2, 2, expected)), actual);
+    }
+
+    #[rstest::rstest]
+    #[case::north(North, (2, 3))]
+    #[case::east(East, (3, 2))]
+    #[case::south(South, (2, 1))]
+    #[case::west(West, (1, 2))]
+    fn sut_moves_forward_in_direction_it_faces(
+        #[case] facing: Direction,
+        #[case] (x, y): (usize, usize),
+    ) {
+        // Act
+        let actual = execute(Rover::new(2, 2, facing), "M", &Grid::new(5, 5));
+
+        // Assert
+        assert_eq!(Outcome::Completed(Rover::new(x, y, facing)), actual);
+    }
+
+    #[rstest::rstest]
+    #[case::north_edge(Rover::new(1, 4, North), Rover::new(1, 0, North))]
+    #[case::east_edge(Rover::new(4, 1, East), Rover::new(0, 1, East))]
+    #[case::south_edge(Rover::new(1, 0, South), Rover::new(1, 4, South))]
+    #[case::west_edge(Rover::new(0, 1, West), Rover::new(4, 1, West))]
+    fn sut_wraps_around_edges_of_grid(#[case] rover: Rover, #[case] expected: Rover) {
+        assert_eq!(
+            Outcome::Completed(expected),
+            execute(rover, "M", &Grid::new(5, 5))
+        );
+    }
+
+    #[rstest::rstest]
+    #[case::kata_example("MMRMMLM", Rover::new(2, 3, North))]
+    #[case::around_the_grid("MMMMMMMMMM", Rover::new(0, 0, North))]
+    #[case::square("MRMRMRMR", Rover::new(0, 0, North))]
+    fn sut_carries_out_commands_in_order(#[case] commands: &str, #[case] expected: Rover) {
+        assert_eq!(
+            Outcome::Completed(expected),
+            execute(Rover::new(0, 0, North), commands, &Grid::new(10, 10))
+        );
+    }
+
+    #[test]
+    fn sut_stops_in_front_of_obstacle() {
+        // Arrange
+        let grid = Grid::new(10, 10).with_obstacles([Position { x: 0, y: 3 }]);
+
+        // Act
+        let actual = execute(Rover::new(0, 0, North), "MMMMRM", &grid);
+
+        // Assert
+        let expected = Outcome::Blocked {
+            rover: Rover::new(0, 2, North),
+            obstacle: Position { x: 0, y: 3 },
+        };
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn sut_detects_obstacle_beyond_edge() {
+        // Arrange
+        let grid = Grid::new(10, 10).with_obstacles([Position { x: 9, y: 0 }]);
+
+        // Act
+        let actual = execute(Rover::new(0, 0, West), "M", &grid);
+
+        // Assert
+        let expected = Outcome::Blocked {
+            rover: Rover::new(0, 0, West),
+            obstacle: Position { x: 9, y: 0 },
+        };
+        assert_eq!(expected, actual);
+    }
+
+    #[rstest::rstest]
+    #[case::north(Rover::new(2, 3, North), "2:3:N")]
+    #[case::west(Rover::new(0, 9, West), "0:9:W")]
+    fn sut_writes_itself_as_kata_reports_it(#[case] rover: Rover, #[case] expected: &str) {
+        assert_eq!(expected, rover.to_string());
+    }
+}
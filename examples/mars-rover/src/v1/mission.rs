@@ -0,0 +1,218 @@
+use std::fmt;
+use std::io::BufRead;
+use std::io::Write;
+
+use super::Grid;
+use super::Outcome;
+use super::ParseError;
+use super::Rover;
+use super::parse_commands;
+
+/// `CommandSource` hands out the commands for the rover, a batch at a time,
+/// such as a line of `"LMMR"`. `None` ends the mission.
+pub trait CommandSource {
+    fn next_batch(&mut self) -> Option<String>;
+}
+
+/// `Reporter` is told where the rover is after every batch.
+pub trait Reporter {
+    fn report(&mut self, report: &Report);
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Report {
+    /// The rover carried out every command of the batch.
+    Arrived(Rover),
+    /// The rover stopped in front of an obstacle.
+    Blocked(Rover),
+    /// The batch had an unknown command, so none of it was carried out.
+    Rejected(ParseError),
+}
+
+/// Writes reports as the kata does: `2:3:N`, or `O:2:3:N` when blocked.
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Report::Arrived(rover) => write!(f, "{rover}"),
+            Report::Blocked(rover) => write!(f, "O:{rover}"),
+            Report::Rejected(error) => write!(f, "error: {error}"),
+        }
+    }
+}
+
+/// `Mission` drives a rover with the commands of a source, telling a
+/// reporter where it is after each batch. A blocked rover stays where it
+/// stopped, and takes the next batch from there.
+pub struct Mission {
+    grid: Grid,
+    rover: Rover,
+}
+
+impl Mission {
+    pub fn new(grid: Grid, rover: Rover) -> Self {
+        Mission { grid, rover }
+    }
+
+    /// Runs until the source runs out of commands, and returns where the
+    /// rover ended up.
+    pub fn run(mut self, source: &mut impl CommandSource, reporter: &mut impl Reporter) -> Rover {
+        while let Some(batch) = source.next_batch() {
+            let report = match parse_commands(batch.trim()) {
+                Err(error) => Report::Rejected(error),
+                Ok(commands) => match self.rover.execute(&commands, &self.grid) {
+                    Outcome::Completed(rover) => {
+                        self.rover = rover;
+                        Report::Arrived(rover)
+                    }
+                    Outcome::Blocked { rover, .. } => {
+                        self.rover = rover;
+                        Report::Blocked(rover)
+                    }
+                },
+            };
+            reporter.report(&report);
+        }
+        self.rover
+    }
+}
+
+/// `LineCommandSource` reads a batch per line, such as from stdin.
+pub struct LineCommandSource<R> {
+    reader: R,
+}
+
+impl<R: BufRead> LineCommandSource<R> {
+    pub fn new(reader: R) -> Self {
+        LineCommandSource { reader }
+    }
+}
+
+impl<R: BufRead> CommandSource for LineCommandSource<R> {
+    fn next_batch(&mut self) -> Option<String> {
+        let mut line = String::new();
+        match self.reader.read_line(&mut line) {
+            Ok(0) | Err(_) => None,
+            Ok(_) => Some(line),
+        }
+    }
+}
+
+/// `WriterReporter` writes a line per report.
+pub struct WriterReporter<W> {
+    writer: W,
+}
+
+impl<W: Write> WriterReporter<W> {
+    pub fn new(writer: W) -> Self {
+        WriterReporter { writer }
+    }
+}
+
+impl<W: Write> Reporter for WriterReporter<W> {
+    fn report(&mut self, report: &Report) {
+        // A report that cannot be written is lost, but the rover carries on.
+        let _ = writeln!(self.writer, "{report}");
+    }
+}
+
+#[cfg(test)]
+mod specs_for_mission {
+    use std::collections::VecDeque;
+    use std::io::Cursor;
+
+    use super::CommandSource;
+    use super::LineCommandSource;
+    use super::Mission;
+    use super::Report;
+    use super::Reporter;
+    use super::WriterReporter;
+    use crate::v1::Direction::*;
+    use crate::v1::Grid;
+    use crate::v1::ParseError;
+    use crate::v1::Position;
+    use crate::v1::Rover;
+
+    struct ScriptedCommandSource {
+        batches: VecDeque<&'static str>,
+    }
+
+    impl ScriptedCommandSource {
+        fn new(batches: &[&'static str]) -> Self {
+            ScriptedCommandSource {
+                batches: batches.iter().copied().collect(),
+            }
+        }
+    }
+
+    impl CommandSource for ScriptedCommandSource {
+        fn next_batch(&mut self) -> Option<String> {
+            self.batches.pop_front().map(str::to_string)
+        }
+    }
+
+    #[derive(Default)]
+    struct SpyReporter {
+        reports: Vec<Report>,
+    }
+
+    impl Reporter for SpyReporter {
+        fn report(&mut self, report: &Report) {
+            self.reports.push(*report);
+        }
+    }
+
+    fn mission() -> Mission {
+        let grid = Grid::new(10, 10).with_obstacles([Position { x: 2, y: 2 }]);
+        Mission::new(grid, Rover::new(0, 0, North))
+    }
+
+    #[test]
+    fn sut_reports_after_every_batch() {
+        // Arrange
+        let mut source = ScriptedCommandSource::new(&["MM", "RMM", "X", "LM"]);
+        let mut reporter = SpyReporter::default();
+
+        // Act
+        let actual = mission().run(&mut source, &mut reporter);
+
+        // Assert
+        let expected = vec![
+            Report::Arrived(Rover::new(0, 2, North)),
+            Report::Blocked(Rover::new(1, 2, East)),
+            Report::Rejected(ParseError {
+                position: 0,
+                character: 'X',
+            }),
+            Report::Arrived(Rover::new(1, 3, North)),
+        ];
+        assert_eq!(expected, reporter.reports);
+        assert_eq!(Rover::new(1, 3, North), actual);
+    }
+
+    #[test]
+    fn sut_reports_nothing_without_commands() {
+        // Arrange
+        let mut reporter = SpyReporter::default();
+
+        // Act
+        let actual = mission().run(&mut ScriptedCommandSource::new(&[]), &mut reporter);
+
+        // Assert
+        assert!(reporter.reports.is_empty());
+        assert_eq!(Rover::new(0, 0, North), actual);
+    }
+
+    #[test]
+    fn sut_reads_lines_and_writes_reports_as_kata_does() {
+        // Arrange
+        let mut source = LineCommandSource::new(Cursor::new("MM\nRMM\nMZ\n"));
+        let mut output = Vec::new();
+
+        // Act
+        mission().run(&mut source, &mut WriterReporter::new(&mut output));
+
+        // Assert
+        let expected = "0:2:N\nO:1:2:E\nerror: unknown command 'Z' at position 1\n";
+        assert_eq!(expected, String::from_utf8(output).unwrap());
+    }
+}
@@ -0,0 +1,373 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+/// Wraps `f` so that it is called once per argument, answering later calls
+/// with the same argument from a cache. `f` has to be pure, as its side
+/// effects only happen on the first call.
+pub fn memoize<A, R, F: Fn(A) -> R>(f: F) -> Memoized<F, A, R> {
+    Memoized {
+        f,
+        cache: Mutex::new(Cache::new()),
+    }
+}
+
+/// Wraps the async `f` as `memoize` does. Calls with the same argument that
+/// start before the first one finished are not held back, so each of them
+/// calls `f`.
+pub fn memoize_async<A, R, F, Fut>(f: F) -> MemoizedAsync<F, A, R>
+where
+    F: Fn(A) -> Fut,
+    Fut: Future<Output = R>,
+{
+    MemoizedAsync {
+        f,
+        cache: Mutex::new(Cache::new()),
+    }
+}
+
+pub struct Memoized<F, A, R> {
+    f: F,
+    cache: Mutex<Cache<A, R>>,
+}
+
+impl<F, A, R> Memoized<F, A, R>
+where
+    F: Fn(A) -> R,
+    A: Eq + Hash + Clone,
+    R: Clone,
+{
+    /// Forgets a result once `ttl` passed since it was cached.
+    pub fn with_ttl(self, ttl: Duration) -> Self {
+        Memoized {
+            cache: Mutex::new(self.cache.into_inner().unwrap().with_ttl(ttl)),
+            ..self
+        }
+    }
+
+    /// Keeps at most `capacity` results, forgetting the least recently used
+    /// one to make room.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn with_capacity(self, capacity: usize) -> Self {
+        Memoized {
+            cache: Mutex::new(self.cache.into_inner().unwrap().with_capacity(capacity)),
+            ..self
+        }
+    }
+
+    pub fn call(&self, arg: A) -> R {
+        if let Some(result) = self.cache.lock().unwrap().get(&arg) {
+            return result;
+        }
+        let result = (self.f)(arg.clone());
+        self.cache.lock().unwrap().insert(arg, result.clone());
+        result
+    }
+}
+
+pub struct MemoizedAsync<F, A, R> {
+    f: F,
+    cache: Mutex<Cache<A, R>>,
+}
+
+impl<F, Fut, A, R> MemoizedAsync<F, A, R>
+where
+    F: Fn(A) -> Fut,
+    Fut: Future<Output = R>,
+    A: Eq + Hash + Clone,
+    R: Clone,
+{
+    /// Forgets a result once `ttl` passed since it was cached.
+    pub fn with_ttl(self, ttl: Duration) -> Self {
+        MemoizedAsync {
+            cache: Mutex::new(self.cache.into_inner().unwrap().with_ttl(ttl)),
+            ..self
+        }
+    }
+
+    /// Keeps at most `capacity` results, forgetting the least recently used
+    /// one to make room.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn with_capacity(self, capacity: usize) -> Self {
+        MemoizedAsync {
+            cache: Mutex::new(self.cache.into_inner().unwrap().with_capacity(capacity)),
+            ..self
+        }
+    }
+
+    pub async fn call(&self, arg: A) -> R {
+        if let Some(result) = self.cache.lock().unwrap().get(&arg) {
+            return result;
+        }
+        let result = (self.f)(arg.clone()).await;
+        self.cache.lock().unwrap().insert(arg, result.clone());
+        result
+    }
+}
+
+/// `Cache` keeps results by argument. Time is read from the tokio timer, so
+/// tests can pause it to expire results.
+struct Cache<A, R> {
+    entries: HashMap<A, Entry<R>>,
+    ttl: Option<Duration>,
+    capacity: Option<usize>,
+    /// Counts uses of entries, to tell which one was used least recently.
+    uses: u64,
+}
+
+struct Entry<R> {
+    result: R,
+    cached: Instant,
+    used: u64,
+}
+
+impl<A, R> Cache<A, R> {
+    fn new() -> Self {
+        Cache {
+            entries: HashMap::new(),
+            ttl: None,
+            capacity: None,
+            uses: 0,
+        }
+    }
+}
+
+impl<A: Eq + Hash + Clone, R: Clone> Cache<A, R> {
+    fn with_ttl(self, ttl: Duration) -> Self {
+        Cache {
+            ttl: Some(ttl),
+            ..self
+        }
+    }
+
+    fn with_capacity(self, capacity: usize) -> Self {
+        assert!(capacity > 0, "capacity must not be zero");
+        Cache {
+            capacity: Some(capacity),
+            ..self
+        }
+    }
+
+    fn get(&mut self, arg: &A) -> Option<R> {
+        let entry = self.entries.get_mut(arg)?;
+        if self.ttl.is_some_and(|ttl| entry.cached.elapsed() >= ttl) {
+            self.entries.remove(arg);
+            return None;
+        }
+        self.uses += 1;
+        entry.used = self.uses;
+        Some(entry.result.clone())
+    }
+
+    fn insert(&mut self, arg: A, result: R) {
+        if let Some(capacity) = self.capacity
+            && self.entries.len() >= capacity
+            && !self.entries.contains_key(&arg)
+        {
+            // A linear scan is enough for the small caches of the examples.
+            let least_recently_used = self
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.used)
+                .map(|(arg, _)| arg.clone());
+            if let Some(least_recently_used) = least_recently_used {
+                self.entries.remove(&least_recently_used);
+            }
+        }
+        self.uses += 1;
+        let entry = Entry {
+            result,
+            cached: Instant::now(),
+            used: self.uses,
+        };
+        self.entries.insert(arg, entry);
+    }
+}
+
+#[cfg(test)]
+mod specs_for_memoize {
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+    use std::time::Duration;
+
+    use roman::v10::convert_to_roman;
+
+    use super::memoize;
+
+    /// Returns `convert_to_roman`, counting its calls in `calls`.
+    fn spy(calls: &AtomicUsize) -> impl Fn(usize) -> String {
+        move |arabic| {
+            calls.fetch_add(1, Ordering::SeqCst);
+            convert_to_roman(arabic)
+        }
+    }
+
+    #[test]
+    fn sut_converts_same_number_only_once() {
+        // Arrange
+        let calls = AtomicUsize::new(0);
+        let sut = memoize(spy(&calls));
+
+        // Act
+        let actual = (0..3).map(|_| sut.call(1994)).collect::<Vec<_>>();
+
+        // Assert
+        assert_eq!(vec!["MCMXCIV"; 3], actual);
+        assert_eq!(1, calls.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn sut_converts_each_different_number() {
+        // Arrange
+        let calls = AtomicUsize::new(0);
+        let sut = memoize(spy(&calls));
+
+        // Act
+        let actual = [4, 9, 4].map(|arabic| sut.call(arabic));
+
+        // Assert
+        assert_eq!(["IV", "IX", "IV"], actual);
+        assert_eq!(2, calls.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn sut_converts_number_again_once_ttl_passed() {
+        // Arrange
+        let calls = AtomicUsize::new(0);
+        let sut = memoize(spy(&calls)).with_ttl(Duration::from_secs(60));
+        sut.call(1984);
+
+        // Act
+        tokio::time::advance(Duration::from_secs(59)).await;
+        sut.call(1984);
+        tokio::time::advance(Duration::from_secs(1)).await;
+        sut.call(1984);
+
+        // Assert
+        assert_eq!(2, calls.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn sut_forgets_least_recently_used_number_beyond_capacity() {
+        // Arrange
+        let calls = AtomicUsize::new(0);
+        let sut = memoize(spy(&calls)).with_capacity(2);
+        sut.call(1);
+        sut.call(2);
+        sut.call(1);
+
+        // Act
+        sut.call(3);
+        sut.call(1);
+        sut.call(2);
+
+        // Assert
+        assert_eq!(4, calls.load(Ordering::SeqCst));
+    }
+}
+
+#[cfg(test)]
+mod specs_for_memoize_async {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    use concurrency::v5::WebsiteChecker;
+
+    use super::memoize_async;
+
+    /// `SpyWebsiteChecker` reports every website up, counting the checks of
+    /// each website.
+    #[derive(Clone, Default)]
+    struct SpyWebsiteChecker {
+        checks: Arc<Mutex<HashMap<String, usize>>>,
+    }
+
+    impl SpyWebsiteChecker {
+        fn checks(&self, url: &str) -> usize {
+            self.checks.lock().unwrap().get(url).copied().unwrap_or(0)
+        }
+    }
+
+    impl WebsiteChecker for SpyWebsiteChecker {
+        async fn check(&self, url: String) -> bool {
+            *self.checks.lock().unwrap().entry(url).or_default() += 1;
+            true
+        }
+    }
+
+    #[tokio::test]
+    async fn sut_checks_same_website_only_once() {
+        // Arrange
+        let checker = SpyWebsiteChecker::default();
+        let spy = checker.clone();
+        let sut = memoize_async(move |url| {
+            let checker = checker.clone();
+            async move { checker.check(url).await }
+        });
+
+        // Act
+        for _ in 0..3 {
+            assert!(sut.call("http://google.com".to_string()).await);
+        }
+        sut.call("http://blog.gypsydave5.com".to_string()).await;
+
+        // Assert
+        assert_eq!(1, spy.checks("http://google.com"));
+        assert_eq!(1, spy.checks("http://blog.gypsydave5.com"));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn sut_checks_website_again_once_ttl_passed() {
+        // Arrange
+        let checker = SpyWebsiteChecker::default();
+        let spy = checker.clone();
+        let sut = memoize_async(move |url| {
+            let checker = checker.clone();
+            async move { checker.check(url).await }
+        })
+        .with_ttl(Duration::from_secs(30));
+        sut.call("http://google.com".to_string()).await;
+
+        // Act
+        tokio::time::advance(Duration::from_secs(30)).await;
+        sut.call("http://google.com".to_string()).await;
+
+        // Assert
+        assert_eq!(2, spy.checks("http://google.com"));
+    }
+
+    #[tokio::test]
+    async fn sut_forgets_least_recently_checked_website_beyond_capacity() {
+        // Arrange
+        let checker = SpyWebsiteChecker::default();
+        let spy = checker.clone();
+        let sut = memoize_async(move |url| {
+            let checker = checker.clone();
+            async move { checker.check(url).await }
+        })
+        .with_capacity(1);
+
+        // Act
+        for url in [
+            "http://google.com",
+            "http://blog.gypsydave5.com",
+            "http://google.com",
+        ] {
+            sut.call(url.to_string()).await;
+        }
+
+        // Assert
+        assert_eq!(2, spy.checks("http://google.com"));
+    }
+}
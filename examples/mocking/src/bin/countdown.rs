@@ -0,0 +1,53 @@
+use std::io::stderr;
+use std::io::stdout;
+use std::thread::sleep;
+use std::time::Duration;
+
+use clap::Parser;
+use logger::TextLogger;
+use mocking::v6::ConfigurableSleeper;
+use mocking::v6::Countdown;
+use mocking::v6::CountdownError;
+
+/// Counts down, a second per number, to a final word.
+#[derive(Debug, Parser)]
+#[command(name = "countdown")]
+struct Cli {
+    /// The number to count from.
+    #[arg(long, default_value_t = 3)]
+    start: usize,
+
+    /// The word written once done counting.
+    #[arg(long, default_value = "Go!")]
+    final_word: String,
+
+    /// How each number is written, `{n}` standing for the number.
+    #[arg(long, default_value = "{n}\n")]
+    step_format: String,
+
+    /// Counts up from 1 to the number instead.
+    #[arg(long)]
+    up: bool,
+}
+
+fn main() -> Result<(), CountdownError> {
+    let cli = Cli::parse();
+    let step_format = cli.step_format;
+    let mut countdown = Countdown::new()
+        .with_start(cli.start)
+        .with_final_word(cli.final_word)
+        .with_formatter(move |i| step_format.replace("{n}", &i.to_string()));
+    if cli.up {
+        countdown = countdown.counting_up();
+    }
+    countdown.run(
+        &mut stdout(),
+        &ConfigurableSleeper::new(
+            Duration::from_secs(1),
+            Box::new(|duration| {
+                sleep(duration);
+            }),
+        ),
+        &TextLogger::new(stderr()),
+    )
+}
@@ -27,27 +27,17 @@ pub fn countdown(out: &mut dyn Write, sleeper: &dyn Sleeper) {
 
 #[cfg(test)]
 mod specs_for_countdown {
-    use std::cell::RefCell;
     use std::io::stdout;
 
+    use test_helpers::CallRecorder;
+    use test_helpers::SpySleeper;
+
     use super::Sleeper;
     use super::countdown;
 
-    struct SleeprSpy {
-        calls: RefCell<usize>,
-    }
-
-    impl SleeprSpy {
-        fn new() -> Self {
-            SleeprSpy {
-                calls: RefCell::new(0),
-            }
-        }
-    }
-
-    impl Sleeper for SleeprSpy {
+    impl Sleeper for SpySleeper<&'static str> {
         fn sleep(&self) {
-            *self.calls.borrow_mut() += 1;
+            SpySleeper::sleep(self);
         }
     }
 
@@ -55,7 +45,7 @@ mod specs_for_countdown {
     fn sut_writes_3_2_1_go() {
         // Arrange
         let mut buffer = Vec::new();
-        let sleeper_dummy = SleeprSpy::new();
+        let sleeper_dummy = SpySleeper::new(&CallRecorder::new(), "sleep");
 
         // Act
         countdown(&mut buffer, &sleeper_dummy);
@@ -69,12 +59,13 @@ mod specs_for_countdown {
     #[test]
     fn sut_calls_sleep_3_times() {
         // Arrange
-        let sleeper_spy = SleeprSpy::new();
+        let recorder = CallRecorder::new();
+        let sleeper_spy = SpySleeper::new(&recorder, "sleep");
 
         // Act
         countdown(&mut stdout(), &sleeper_spy);
 
         // Assert
-        assert_eq!(*sleeper_spy.calls.borrow(), 3);
+        assert_eq!(3, recorder.count());
     }
 }
@@ -27,65 +27,26 @@ pub fn countdown(out: &mut dyn Write, sleeper: &dyn Sleeper) {
 
 #[cfg(test)]
 mod specs_for_countdown {
-    use std::cell::RefCell;
-    use std::io::Write;
     use std::io::stdout;
-    use std::thread::sleep;
-    use std::time::Duration;
-    use std::time::SystemTime;
-    use std::time::UNIX_EPOCH;
+
+    use test_helpers::CallRecorder;
+    use test_helpers::SpySleeper;
+    use test_helpers::SpyWriter;
 
     use super::Sleeper;
     use super::countdown;
 
-    struct CountdownOperationsSpy {
-        sleep_command: &'static str,
-        write_command: &'static str,
-        calls: RefCell<Vec<(u128, &'static str)>>,
-    }
-
-    impl CountdownOperationsSpy {
-        fn new() -> Self {
-            CountdownOperationsSpy {
-                sleep_command: "sleep",
-                write_command: "write",
-                calls: RefCell::new(Vec::new()),
-            }
-        }
-    }
-
-    impl Sleeper for CountdownOperationsSpy {
+    impl Sleeper for SpySleeper<&'static str> {
         fn sleep(&self) {
-            let mut calls = self.calls.borrow_mut();
-            calls.push((get_current_timestamp(), self.sleep_command));
-            sleep(Duration::from_millis(1));
-        }
-    }
-
-    impl Write for CountdownOperationsSpy {
-        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
-            let mut calls = self.calls.borrow_mut();
-            calls.push((get_current_timestamp(), self.write_command));
-            Ok(buf.len())
-        }
-
-        fn flush(&mut self) -> std::io::Result<()> {
-            Ok(())
+            SpySleeper::sleep(self);
         }
     }
 
-    fn get_current_timestamp() -> u128 {
-        SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_nanos()
-    }
-
     #[test]
     fn sut_writes_3_2_1_go() {
         // Arrange
         let mut buffer = Vec::new();
-        let sleeper_dummy = CountdownOperationsSpy::new();
+        let sleeper_dummy = SpySleeper::new(&CallRecorder::new(), "sleep");
 
         // Act
         countdown(&mut buffer, &sleeper_dummy);
@@ -99,36 +60,30 @@ mod specs_for_countdown {
     #[test]
     fn sut_calls_sleep_3_times() {
         // Arrange
-        let sleeper_spy = CountdownOperationsSpy::new();
+        let recorder = CallRecorder::new();
+        let sleeper_spy = SpySleeper::new(&recorder, "sleep");
 
         // Act
         countdown(&mut stdout(), &sleeper_spy);
 
         // Assert
-        assert_eq!(sleeper_spy.calls.borrow().len(), 3);
+        assert_eq!(3, recorder.count());
     }
 
     #[test]
     fn sut_sleeps_after_writing() {
         // Arrange
-        let sleeper_spy = CountdownOperationsSpy::new();
-        let mut writer_spy = CountdownOperationsSpy::new();
+        let recorder = CallRecorder::new();
+        let sleeper_spy = SpySleeper::new(&recorder, "sleep");
+        let mut writer_spy = SpyWriter::new(&recorder, "write");
 
         // Act
         countdown(&mut writer_spy, &sleeper_spy);
 
         // Assert
-        let merge_operations = |spy_1: CountdownOperationsSpy, spy_2: CountdownOperationsSpy| {
-            let mut operations = spy_1.calls.borrow_mut().clone();
-            operations.extend(spy_2.calls.borrow_mut().clone());
-            operations.sort_by_key(|key| key.0);
-            operations
-        };
-        let operations = merge_operations(writer_spy, sleeper_spy);
-        let actual: Vec<&str> = operations.into_iter().map(|(_, command)| command).collect();
         let expected = vec![
             "write", "sleep", "write", "sleep", "write", "sleep", "write",
         ];
-        assert_eq!(expected, actual);
+        assert_eq!(expected, recorder.calls());
     }
 }
@@ -0,0 +1,332 @@
+use std::io;
+use std::io::Write;
+
+use logger::Logger;
+
+pub use super::v5::ConfigurableSleeper;
+pub use super::v5::Sleeper;
+
+const COUNTDOWN_START: usize = 3;
+const FINAL_WORD: &str = "Go!";
+
+#[derive(Debug, thiserror::Error)]
+pub enum CountdownError {
+    #[error("failed to write countdown: {0}")]
+    Write(#[from] io::Error),
+}
+
+/// `Countdown` tells how to count: from which number, with which final
+/// word, how each number is written, and whether to count up to the number
+/// instead. It counts down from 3 to "Go!", a number per line, unless told
+/// otherwise.
+pub struct Countdown {
+    start: usize,
+    final_word: String,
+    format_step: Box<dyn Fn(usize) -> String>,
+    counting_up: bool,
+}
+
+impl Countdown {
+    pub fn new() -> Self {
+        Countdown {
+            start: COUNTDOWN_START,
+            final_word: FINAL_WORD.to_string(),
+            format_step: Box::new(|i| format!("{}\n", i)),
+            counting_up: false,
+        }
+    }
+
+    pub fn with_start(self, start: usize) -> Self {
+        Countdown { start, ..self }
+    }
+
+    pub fn with_final_word(self, final_word: impl Into<String>) -> Self {
+        Countdown {
+            final_word: final_word.into(),
+            ..self
+        }
+    }
+
+    /// Writes each number as `format_step` returns it.
+    pub fn with_formatter(self, format_step: impl Fn(usize) -> String + 'static) -> Self {
+        Countdown {
+            format_step: Box::new(format_step),
+            ..self
+        }
+    }
+
+    /// Counts up from 1 to the starting number instead.
+    pub fn counting_up(self) -> Self {
+        Countdown {
+            counting_up: true,
+            ..self
+        }
+    }
+
+    /// Writes every number, sleeping after each, then the final word. Logs
+    /// once it is done, or why it could not write.
+    pub fn run(
+        &self,
+        out: &mut dyn Write,
+        sleeper: &dyn Sleeper,
+        logger: &dyn Logger,
+    ) -> Result<(), CountdownError> {
+        match self.count(out, sleeper) {
+            Ok(()) if self.counting_up => {
+                logger.info("counted up", &[("to", &self.start)]);
+                Ok(())
+            }
+            Ok(()) => {
+                logger.info("counted down", &[("from", &self.start)]);
+                Ok(())
+            }
+            Err(error) => {
+                logger.error("countdown interrupted", &[("error", &error)]);
+                Err(error)
+            }
+        }
+    }
+
+    fn count(&self, out: &mut dyn Write, sleeper: &dyn Sleeper) -> Result<(), CountdownError> {
+        let steps: Box<dyn Iterator<Item = usize>> = if self.counting_up {
+            Box::new(1..=self.start)
+        } else {
+            Box::new((1..=self.start).rev())
+        };
+        for i in steps {
+            out.write_all((self.format_step)(i).as_bytes())?;
+            sleeper.sleep();
+        }
+        out.write_all(self.final_word.as_bytes())?;
+        Ok(())
+    }
+}
+
+impl Default for Countdown {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Counts down from 3 to "Go!" like `v5::countdown`, returning the error of
+/// the writer instead of panicking on it. Logs once it is done, or why it
+/// could not write.
+pub fn countdown(
+    out: &mut dyn Write,
+    sleeper: &dyn Sleeper,
+    logger: &dyn Logger,
+) -> Result<(), CountdownError> {
+    Countdown::new().run(out, sleeper, logger)
+}
+
+#[cfg(test)]
+mod specs_for_countdown {
+    use std::io;
+    use std::io::Write;
+    use std::io::stdout;
+
+    use assertions::assert_err_matches;
+    use assertions::assert_ordered_calls;
+    use logger::CaptureLogger;
+    use logger::Fields;
+    use logger::Level;
+    use logger::Record;
+    use test_helpers::CallRecorder;
+    use test_helpers::SpySleeper;
+    use test_helpers::SpyWriter;
+
+    use super::CountdownError;
+    use super::countdown;
+    use crate::v5::MockSleeper;
+
+    #[test]
+    fn sut_writes_3_2_1_go() {
+        // Arrange
+        let mut buffer = Vec::new();
+        let sleeper_dummy = SpySleeper::new(&CallRecorder::new(), "sleep");
+
+        // Act
+        countdown(&mut buffer, &sleeper_dummy, &CaptureLogger::new()).unwrap();
+
+        // Assert
+        let actual = String::from_utf8(buffer).unwrap();
+        let expected = "3\n2\n1\nGo!";
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn sut_calls_sleep_3_times() {
+        // Arrange
+        let recorder = CallRecorder::new();
+        let sleeper_spy = SpySleeper::new(&recorder, "sleep");
+
+        // Act
+        countdown(&mut stdout(), &sleeper_spy, &CaptureLogger::new()).unwrap();
+
+        // Assert
+        assert_eq!(3, recorder.count());
+    }
+
+    #[test]
+    fn sut_calls_sleep_3_times_as_mock_expects() {
+        // Arrange
+        let mut sleeper_mock = MockSleeper::new();
+        sleeper_mock.expect_sleep().times(3).return_const(());
+
+        // Act
+        countdown(&mut Vec::new(), &sleeper_mock, &CaptureLogger::new()).unwrap();
+
+        // Assert
+        sleeper_mock.checkpoint();
+    }
+
+    #[test]
+    fn sut_sleeps_after_writing() {
+        // Arrange
+        let recorder = CallRecorder::new();
+        let sleeper_spy = SpySleeper::new(&recorder, "sleep");
+        let mut writer_spy = SpyWriter::new(&recorder, "write");
+
+        // Act
+        countdown(&mut writer_spy, &sleeper_spy, &CaptureLogger::new()).unwrap();
+
+        // Assert
+        assert_ordered_calls!(
+            recorder.calls(),
+            [
+                "write", "sleep", "write", "sleep", "write", "sleep", "write"
+            ]
+        );
+    }
+
+    #[test]
+    fn sut_returns_write_error_with_source_if_writer_fails() {
+        // Arrange
+        let recorder = CallRecorder::new();
+        let sleeper_spy = SpySleeper::new(&recorder, "sleep");
+
+        // Act
+        let actual = countdown(&mut BrokenWriter, &sleeper_spy, &CaptureLogger::new());
+
+        // Assert
+        assert_err_matches!(
+            actual,
+            CountdownError::Write(ref error) if error.kind() == io::ErrorKind::BrokenPipe
+        );
+        assert_eq!(0, recorder.count());
+    }
+
+    #[test]
+    fn sut_logs_it_counted_down_from_3() {
+        // Arrange
+        let sleeper_dummy = SpySleeper::new(&CallRecorder::new(), "sleep");
+        let logger = CaptureLogger::new();
+
+        // Act
+        countdown(&mut Vec::new(), &sleeper_dummy, &logger).unwrap();
+
+        // Assert
+        let expected = vec![Record {
+            level: Level::Info,
+            message: "counted down".to_string(),
+            fields: Fields::from([("from".to_string(), "3".to_string())]),
+        }];
+        assert_eq!(expected, logger.records());
+    }
+
+    #[test]
+    fn sut_logs_error_if_writer_fails() {
+        // Arrange
+        let sleeper_dummy = SpySleeper::new(&CallRecorder::new(), "sleep");
+        let logger = CaptureLogger::new();
+
+        // Act
+        let _ = countdown(&mut BrokenWriter, &sleeper_dummy, &logger);
+
+        // Assert
+        let actual = logger.records();
+        assert_eq!(1, actual.len());
+        assert_eq!(Level::Error, actual[0].level);
+        assert_eq!("countdown interrupted", actual[0].message);
+        assert!(actual[0].fields["error"].starts_with("failed to write countdown"));
+    }
+
+    struct BrokenWriter;
+
+    impl Write for BrokenWriter {
+        fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+            Err(io::ErrorKind::BrokenPipe.into())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod specs_for_countdown_builder {
+    use logger::CaptureLogger;
+    use logger::Fields;
+    use test_helpers::CallRecorder;
+    use test_helpers::SpySleeper;
+
+    use super::Countdown;
+
+    fn run(sut: Countdown) -> String {
+        let mut buffer = Vec::new();
+        let sleeper_dummy = SpySleeper::new(&CallRecorder::new(), "sleep");
+        sut.run(&mut buffer, &sleeper_dummy, &CaptureLogger::new())
+            .unwrap();
+        String::from_utf8(buffer).unwrap()
+    }
+
+    #[rstest::rstest]
+    #[case::default(Countdown::new(), "3\n2\n1\nGo!")]
+    #[case::start(Countdown::new().with_start(5), "5\n4\n3\n2\n1\nGo!")]
+    #[case::final_word(Countdown::new().with_final_word("Liftoff!"), "3\n2\n1\nLiftoff!")]
+    #[case::formatter(Countdown::new().with_formatter(|i| format!("{i}... ")), "3... 2... 1... Go!")]
+    #[case::counting_up(Countdown::new().counting_up(), "1\n2\n3\nGo!")]
+    #[case::nothing_to_count(Countdown::new().with_start(0), "Go!")]
+    fn sut_writes_countdown_as_configured(#[case] sut: Countdown, #[case] expected: &str) {
+        // Act
+        let actual = run(sut);
+
+        // Assert
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn sut_sleeps_once_per_number() {
+        // Arrange
+        let recorder = CallRecorder::new();
+        let sleeper_spy = SpySleeper::new(&recorder, "sleep");
+        let sut = Countdown::new().with_start(10);
+
+        // Act
+        sut.run(&mut Vec::new(), &sleeper_spy, &CaptureLogger::new())
+            .unwrap();
+
+        // Assert
+        assert_eq!(10, recorder.count());
+    }
+
+    #[test]
+    fn sut_logs_it_counted_up_to_start() {
+        // Arrange
+        let sleeper_dummy = SpySleeper::new(&CallRecorder::new(), "sleep");
+        let logger = CaptureLogger::new();
+        let sut = Countdown::new().with_start(2).counting_up();
+
+        // Act
+        sut.run(&mut Vec::new(), &sleeper_dummy, &logger).unwrap();
+
+        // Assert
+        let actual = logger.records();
+        assert_eq!("counted up", actual[0].message);
+        assert_eq!(
+            Fields::from([("to".to_string(), "2".to_string())]),
+            actual[0].fields
+        );
+    }
+}
@@ -0,0 +1,311 @@
+use std::io::Write;
+use std::time::Duration;
+
+use cancellation::Scope;
+use logger::Logger;
+
+pub use super::v6::ConfigurableSleeper;
+pub use super::v6::Countdown;
+pub use super::v6::CountdownError;
+pub use super::v6::Sleeper;
+
+const COUNTDOWN_START: usize = 3;
+const FINAL_WORD: &str = "Go!";
+
+/// `AsyncSleeper` pauses the task rather than the thread, so that other
+/// tasks go on while the countdown waits.
+pub trait AsyncSleeper: Sync {
+    fn sleep(&self) -> impl Future<Output = ()> + Send;
+}
+
+/// `TokioSleeper` sleeps on the tokio timer, which specs can pause and
+/// move forward.
+#[derive(Clone, Copy, Debug)]
+pub struct TokioSleeper {
+    duration: Duration,
+}
+
+impl TokioSleeper {
+    pub fn new(duration: Duration) -> Self {
+        TokioSleeper { duration }
+    }
+}
+
+impl AsyncSleeper for TokioSleeper {
+    fn sleep(&self) -> impl Future<Output = ()> + Send {
+        tokio::time::sleep(self.duration)
+    }
+}
+
+/// Counts down from 3 to "Go!" like `v6::countdown`, awaiting the sleeper
+/// after every number.
+pub async fn countdown(
+    out: &mut (dyn Write + Send),
+    sleeper: &impl AsyncSleeper,
+    logger: &dyn Logger,
+) -> Result<(), CountdownError> {
+    match count(out, sleeper).await {
+        Ok(()) => {
+            logger.info("counted down", &[("from", &COUNTDOWN_START)]);
+            Ok(())
+        }
+        Err(error) => {
+            logger.error("countdown interrupted", &[("error", &error)]);
+            Err(error)
+        }
+    }
+}
+
+async fn count(
+    out: &mut (dyn Write + Send),
+    sleeper: &impl AsyncSleeper,
+) -> Result<(), CountdownError> {
+    for i in (1..=COUNTDOWN_START).rev() {
+        out.write_all(format!("{}\n", i).as_bytes())?;
+        sleeper.sleep().await;
+    }
+    out.write_all(FINAL_WORD.as_bytes())?;
+    Ok(())
+}
+
+/// Counts down like `countdown` until the scope is cancelled, and returns
+/// how many steps it wrote, "Go!" included. Cancelling the scope while the
+/// countdown sleeps stops it at once, without writing anything more.
+pub async fn countdown_until_cancelled(
+    out: &mut (dyn Write + Send),
+    sleeper: &impl AsyncSleeper,
+    scope: &Scope,
+    logger: &dyn Logger,
+) -> Result<usize, CountdownError> {
+    match count_until_cancelled(out, sleeper, scope).await {
+        Ok(steps) if scope.is_cancelled() => {
+            logger.info("countdown cancelled", &[("steps", &steps)]);
+            Ok(steps)
+        }
+        Ok(steps) => {
+            logger.info("counted down", &[("from", &COUNTDOWN_START)]);
+            Ok(steps)
+        }
+        Err(error) => {
+            logger.error("countdown interrupted", &[("error", &error)]);
+            Err(error)
+        }
+    }
+}
+
+async fn count_until_cancelled(
+    out: &mut (dyn Write + Send),
+    sleeper: &impl AsyncSleeper,
+    scope: &Scope,
+) -> Result<usize, CountdownError> {
+    let mut steps = 0;
+    for i in (1..=COUNTDOWN_START).rev() {
+        if scope.is_cancelled() {
+            return Ok(steps);
+        }
+        out.write_all(format!("{}\n", i).as_bytes())?;
+        steps += 1;
+        if scope.run(sleeper.sleep()).await.is_err() {
+            return Ok(steps);
+        }
+    }
+    if scope.is_cancelled() {
+        return Ok(steps);
+    }
+    out.write_all(FINAL_WORD.as_bytes())?;
+    Ok(steps + 1)
+}
+
+#[cfg(test)]
+mod specs_for_countdown {
+    use std::time::Duration;
+
+    use assertions::assert_ordered_calls;
+    use logger::CaptureLogger;
+    use test_helpers::CallRecorder;
+    use test_helpers::SpyWriter;
+    use tokio::time::Instant;
+    use tokio_test::assert_pending;
+    use tokio_test::task;
+
+    use super::AsyncSleeper;
+    use super::TokioSleeper;
+    use super::countdown;
+
+    /// `SpyAsyncSleeper` records a "sleep" call whenever it is awaited,
+    /// without sleeping.
+    struct SpyAsyncSleeper(CallRecorder<&'static str>);
+
+    impl AsyncSleeper for SpyAsyncSleeper {
+        async fn sleep(&self) {
+            self.0.record("sleep");
+        }
+    }
+
+    #[tokio::test]
+    async fn sut_sleeps_after_writing() {
+        // Arrange
+        let recorder = CallRecorder::new();
+        let sleeper_spy = SpyAsyncSleeper(recorder.clone());
+        let mut writer_spy = SpyWriter::new(&recorder, "write");
+
+        // Act
+        countdown(&mut writer_spy, &sleeper_spy, &CaptureLogger::new())
+            .await
+            .unwrap();
+
+        // Assert
+        assert_ordered_calls!(
+            recorder.calls(),
+            [
+                "write", "sleep", "write", "sleep", "write", "sleep", "write"
+            ]
+        );
+        assert_eq!("3\n2\n1\nGo!", writer_spy.written());
+    }
+
+    #[tokio::test]
+    async fn sut_writes_next_number_only_once_its_second_has_passed() {
+        // Arrange
+        tokio::time::pause();
+        let recorder = CallRecorder::new();
+        let mut writer_spy = SpyWriter::new(&recorder, "write");
+        let sleeper = TokioSleeper::new(Duration::from_secs(1));
+        let logger = CaptureLogger::new();
+        let mut counting = task::spawn(countdown(&mut writer_spy, &sleeper, &logger));
+        assert_pending!(counting.poll());
+
+        // Act
+        tokio::time::advance(Duration::from_millis(500)).await;
+        assert_pending!(counting.poll());
+        let half_a_second = recorder.count();
+        tokio::time::advance(Duration::from_secs(1)).await;
+        assert_pending!(counting.poll());
+        let a_second_and_a_half = recorder.count();
+
+        // Assert
+        assert_eq!(1, half_a_second);
+        assert_eq!(2, a_second_and_a_half);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn sut_takes_a_second_per_number() {
+        // Arrange
+        let sleeper = TokioSleeper::new(Duration::from_secs(1));
+        let start = Instant::now();
+
+        // Act
+        countdown(&mut Vec::new(), &sleeper, &CaptureLogger::new())
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(Duration::from_secs(3), start.elapsed());
+    }
+}
+
+#[cfg(test)]
+mod specs_for_countdown_until_cancelled {
+    use std::time::Duration;
+
+    use cancellation::Scope;
+    use logger::CaptureLogger;
+    use tokio_test::assert_pending;
+    use tokio_test::assert_ready;
+    use tokio_test::task;
+
+    use super::AsyncSleeper;
+    use super::TokioSleeper;
+    use super::countdown_until_cancelled;
+
+    /// `CancellingSleeper` cancels the scope the first time the countdown
+    /// sleeps, that is right after its first write.
+    struct CancellingSleeper(Scope);
+
+    impl AsyncSleeper for CancellingSleeper {
+        async fn sleep(&self) {
+            self.0.cancel();
+        }
+    }
+
+    struct NoSleeper;
+
+    impl AsyncSleeper for NoSleeper {
+        async fn sleep(&self) {}
+    }
+
+    #[tokio::test]
+    async fn sut_stops_after_first_write_when_cancelled_after_it() {
+        // Arrange
+        let scope = Scope::new();
+        let sleeper = CancellingSleeper(scope.clone());
+        let logger = CaptureLogger::new();
+        let mut out = Vec::new();
+
+        // Act
+        let steps = countdown_until_cancelled(&mut out, &sleeper, &scope, &logger)
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(1, steps);
+        assert_eq!("3\n", String::from_utf8(out).unwrap());
+        assert_eq!(vec!["countdown cancelled"], logger.messages());
+    }
+
+    #[tokio::test]
+    async fn sut_writes_nothing_when_cancelled_before_it_starts() {
+        // Arrange
+        let scope = Scope::new();
+        scope.cancel();
+        let mut out = Vec::new();
+
+        // Act
+        let steps = countdown_until_cancelled(&mut out, &NoSleeper, &scope, &CaptureLogger::new())
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(0, steps);
+        assert!(out.is_empty());
+    }
+
+    #[tokio::test]
+    async fn sut_counts_every_step_when_never_cancelled() {
+        // Arrange
+        let scope = Scope::new();
+        let mut out = Vec::new();
+
+        // Act
+        let steps = countdown_until_cancelled(&mut out, &NoSleeper, &scope, &CaptureLogger::new())
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(4, steps);
+        assert_eq!("3\n2\n1\nGo!", String::from_utf8(out).unwrap());
+    }
+
+    #[tokio::test]
+    async fn sut_stops_at_once_when_cancelled_while_sleeping() {
+        // Arrange
+        tokio::time::pause();
+        let scope = Scope::new();
+        let sleeper = TokioSleeper::new(Duration::from_secs(1));
+        let logger = CaptureLogger::new();
+        let mut out = Vec::new();
+        let mut counting = task::spawn(countdown_until_cancelled(
+            &mut out, &sleeper, &scope, &logger,
+        ));
+        assert_pending!(counting.poll());
+
+        // Act
+        scope.cancel();
+        let steps = assert_ready!(counting.poll()).unwrap();
+
+        // Assert
+        drop(counting);
+        assert_eq!(1, steps);
+        assert_eq!("3\n", String::from_utf8(out).unwrap());
+    }
+}
@@ -0,0 +1,21 @@
+use std::error::Error;
+use std::io::stdin;
+use std::io::stdout;
+use std::path::Path;
+
+use http_server::v1::file_system::FileSystemPlayerStore;
+use poker::v2::Cli;
+use poker::v2::TexasHoldem;
+use poker::v2::WriterBlindAlerter;
+
+const DATABASE_FILE: &str = "game.db.json";
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let store = FileSystemPlayerStore::from_path(Path::new(DATABASE_FILE))?;
+    let alerter = WriterBlindAlerter::new(stdout());
+    let game = TexasHoldem::new(&alerter, &store);
+    println!("Let's play poker");
+    println!("Type {{Name}} wins to record a win");
+    Cli::new(&mut stdin().lock(), &mut stdout(), &game).play_poker()?;
+    Ok(())
+}
@@ -0,0 +1,183 @@
+use std::io::BufRead;
+use std::io::Write;
+
+use http_server::v1::PlayerStore;
+use http_server::v1::StoreError;
+use http_server::v1::game::BLIND_UNIT;
+use http_server::v1::game::blind_schedule;
+
+pub const PLAYER_PROMPT: &str = "Please enter the number of players: ";
+pub const BAD_PLAYER_INPUT: &str =
+    "Bad value received for number of players, please try again with a number\n";
+pub const BAD_WINNER_INPUT: &str = "Bad value received for winner, expected '{Name} wins'\n";
+
+const WINS_SUFFIX: &str = " wins";
+
+#[derive(Debug, thiserror::Error)]
+pub enum CliError {
+    #[error("failed to talk to the players: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error(transparent)]
+    Store(#[from] StoreError),
+}
+
+/// `Cli` plays a game of poker on a terminal: it asks for the number of
+/// players, prints when the blinds go up and records the winner.
+pub struct Cli<'a> {
+    store: &'a dyn PlayerStore,
+    input: &'a mut dyn BufRead,
+    output: &'a mut dyn Write,
+}
+
+impl<'a> Cli<'a> {
+    pub fn new(
+        store: &'a dyn PlayerStore,
+        input: &'a mut dyn BufRead,
+        output: &'a mut dyn Write,
+    ) -> Self {
+        Cli {
+            store,
+            input,
+            output,
+        }
+    }
+
+    /// Plays one game. Input that makes no sense ends the game with a
+    /// message rather than an error, so that the players can start over.
+    pub fn play_poker(&mut self) -> Result<(), CliError> {
+        self.output.write_all(PLAYER_PROMPT.as_bytes())?;
+        let Ok(players) = self.read_line()?.trim().parse::<u32>() else {
+            self.output.write_all(BAD_PLAYER_INPUT.as_bytes())?;
+            return Ok(());
+        };
+
+        self.print_blind_schedule(players)?;
+
+        let line = self.read_line()?;
+        match extract_winner(&line) {
+            Some(winner) => self.store.record_win(winner)?,
+            None => self.output.write_all(BAD_WINNER_INPUT.as_bytes())?,
+        }
+        Ok(())
+    }
+
+    fn print_blind_schedule(&mut self, players: u32) -> Result<(), CliError> {
+        writeln!(self.output, "Blind schedule:")?;
+        for (at, amount) in blind_schedule(players, BLIND_UNIT) {
+            let minutes = at.as_secs() / BLIND_UNIT.as_secs();
+            writeln!(self.output, "{:>4} min: {}", minutes, amount)?;
+        }
+        Ok(())
+    }
+
+    fn read_line(&mut self) -> Result<String, CliError> {
+        let mut line = String::new();
+        self.input.read_line(&mut line)?;
+        Ok(line)
+    }
+}
+
+/// Returns the name in a line such as `Chris wins`.
+fn extract_winner(line: &str) -> Option<&str> {
+    line.trim()
+        .strip_suffix(WINS_SUFFIX)
+        .map(str::trim)
+        .filter(|winner| !winner.is_empty())
+}
+
+#[cfg(test)]
+mod specs_for_cli {
+    use http_server::v1::PlayerStore;
+    use http_server::v1::in_memory::InMemoryPlayerStore;
+
+    use super::BAD_PLAYER_INPUT;
+    use super::BAD_WINNER_INPUT;
+    use super::Cli;
+    use super::PLAYER_PROMPT;
+
+    /// Plays a game on the input and returns what was printed.
+    fn play(store: &InMemoryPlayerStore, input: &str) -> String {
+        let mut input = input.as_bytes();
+        let mut output = vec![];
+        Cli::new(store, &mut input, &mut output)
+            .play_poker()
+            .unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[rstest::rstest]
+    #[case("5\nChris wins\n", "Chris")]
+    #[case("5\nCleo wins\n", "Cleo")]
+    #[case("5\n  Mary Ann wins  \n", "Mary Ann")]
+    fn sut_records_winner_from_input(#[case] input: &str, #[case] winner: &str) {
+        // Arrange
+        let store = InMemoryPlayerStore::new();
+
+        // Act
+        play(&store, input);
+
+        // Assert
+        assert_eq!(Some(1), store.get_player_score(winner));
+    }
+
+    #[test]
+    fn sut_prompts_for_players_and_prints_blind_schedule() {
+        // Arrange
+        let store = InMemoryPlayerStore::new();
+
+        // Act
+        let actual = play(&store, "7\nChris wins\n");
+
+        // Assert
+        let expected = [
+            PLAYER_PROMPT,
+            "Blind schedule:\n",
+            "   0 min: 100\n",
+            "  12 min: 200\n",
+            "  24 min: 300\n",
+            "  36 min: 400\n",
+            "  48 min: 500\n",
+            "  60 min: 600\n",
+            "  72 min: 800\n",
+            "  84 min: 1000\n",
+            "  96 min: 2000\n",
+            " 108 min: 4000\n",
+            " 120 min: 8000\n",
+        ]
+        .concat();
+        assert_eq!(expected, actual);
+    }
+
+    #[rstest::rstest]
+    #[case("Pies\nChris wins\n")]
+    #[case("\n")]
+    #[case("")]
+    fn sut_rejects_number_of_players_that_is_not_a_number(#[case] input: &str) {
+        // Arrange
+        let store = InMemoryPlayerStore::new();
+
+        // Act
+        let actual = play(&store, input);
+
+        // Assert
+        assert_eq!(format!("{}{}", PLAYER_PROMPT, BAD_PLAYER_INPUT), actual);
+        assert!(store.get_league().is_empty());
+    }
+
+    #[rstest::rstest]
+    #[case("5\nLloyd is a killer\n")]
+    #[case("5\n wins\n")]
+    #[case("5\n")]
+    fn sut_rejects_line_that_does_not_name_winner(#[case] input: &str) {
+        // Arrange
+        let store = InMemoryPlayerStore::new();
+
+        // Act
+        let actual = play(&store, input);
+
+        // Assert
+        assert!(actual.ends_with(BAD_WINNER_INPUT));
+        assert!(store.get_league().is_empty());
+    }
+}
@@ -0,0 +1,409 @@
+use std::io::BufRead;
+use std::io::Write;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+use http_server::v1::PlayerStore;
+use http_server::v1::StoreError;
+use http_server::v1::game::BLIND_UNIT;
+use http_server::v1::game::blind_schedule;
+use virtual_clock::Clock;
+use virtual_clock::SystemClock;
+
+pub const PLAYER_PROMPT: &str = "Please enter the number of players: ";
+pub const BAD_PLAYER_INPUT: &str =
+    "Bad value received for number of players, please try again with a number\n";
+pub const BAD_WINNER_INPUT: &str = "Bad value received for winner, expected '{Name} wins'\n";
+
+const WINS_SUFFIX: &str = " wins";
+
+#[derive(Debug, thiserror::Error)]
+pub enum CliError {
+    #[error("failed to talk to the players: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error(transparent)]
+    Store(#[from] StoreError),
+}
+
+/// `BlindAlerter` tells the players when the blind goes up.
+#[cfg_attr(test, mockall::automock)]
+pub trait BlindAlerter {
+    /// Arranges for the players to be told the blind is `amount` once
+    /// `duration` has passed from now. It returns without waiting.
+    fn schedule_alert_at(&self, duration: Duration, amount: u32);
+}
+
+/// `WriterBlindAlerter` writes `Blind is now {amount}` to the output when an
+/// alert is due by its clock. Every alert waits on a thread of its own.
+pub struct WriterBlindAlerter<W, C = SystemClock> {
+    output: Arc<Mutex<W>>,
+    clock: C,
+}
+
+impl<W> WriterBlindAlerter<W> {
+    pub fn new(output: W) -> Self {
+        Self::with_clock(output, SystemClock)
+    }
+}
+
+impl<W, C> WriterBlindAlerter<W, C> {
+    pub fn with_clock(output: W, clock: C) -> Self {
+        WriterBlindAlerter {
+            output: Arc::new(Mutex::new(output)),
+            clock,
+        }
+    }
+}
+
+impl<W: Write + Send + 'static, C: Clock> BlindAlerter for WriterBlindAlerter<W, C> {
+    fn schedule_alert_at(&self, duration: Duration, amount: u32) {
+        let output = self.output.clone();
+        let due = self.clock.sleep(duration);
+        thread::spawn(move || {
+            virtual_clock::block_on(due);
+            // Nobody is left to tell if the output is gone.
+            writeln!(output.lock().unwrap(), "Blind is now {}", amount).ok();
+        });
+    }
+}
+
+/// `Game` is a game of poker from its start to its winner.
+pub trait Game {
+    fn start(&self, players: u32);
+    fn finish(&self, winner: &str) -> Result<(), StoreError>;
+}
+
+/// `TexasHoldem` raises the blinds on the schedule for the table and records
+/// the winner in the league.
+pub struct TexasHoldem<'a> {
+    alerter: &'a dyn BlindAlerter,
+    store: &'a dyn PlayerStore,
+}
+
+impl<'a> TexasHoldem<'a> {
+    pub fn new(alerter: &'a dyn BlindAlerter, store: &'a dyn PlayerStore) -> Self {
+        TexasHoldem { alerter, store }
+    }
+}
+
+impl Game for TexasHoldem<'_> {
+    fn start(&self, players: u32) {
+        for (at, amount) in blind_schedule(players, BLIND_UNIT) {
+            self.alerter.schedule_alert_at(at, amount);
+        }
+    }
+
+    fn finish(&self, winner: &str) -> Result<(), StoreError> {
+        self.store.record_win(winner)
+    }
+}
+
+/// `Cli` plays a game of poker on a terminal: it asks for the number of
+/// players, starts the game and records the winner once the players name
+/// them.
+pub struct Cli<'a> {
+    input: &'a mut dyn BufRead,
+    output: &'a mut dyn Write,
+    game: &'a dyn Game,
+}
+
+impl<'a> Cli<'a> {
+    pub fn new(input: &'a mut dyn BufRead, output: &'a mut dyn Write, game: &'a dyn Game) -> Self {
+        Cli {
+            input,
+            output,
+            game,
+        }
+    }
+
+    /// Plays one game. Input that makes no sense ends the game with a
+    /// message rather than an error, so that the players can start over.
+    pub fn play_poker(&mut self) -> Result<(), CliError> {
+        self.output.write_all(PLAYER_PROMPT.as_bytes())?;
+        let Ok(players) = self.read_line()?.trim().parse::<u32>() else {
+            self.output.write_all(BAD_PLAYER_INPUT.as_bytes())?;
+            return Ok(());
+        };
+
+        self.game.start(players);
+
+        let line = self.read_line()?;
+        match extract_winner(&line) {
+            Some(winner) => self.game.finish(winner)?,
+            None => self.output.write_all(BAD_WINNER_INPUT.as_bytes())?,
+        }
+        Ok(())
+    }
+
+    fn read_line(&mut self) -> Result<String, CliError> {
+        let mut line = String::new();
+        self.input.read_line(&mut line)?;
+        Ok(line)
+    }
+}
+
+/// Returns the name in a line such as `Chris wins`.
+fn extract_winner(line: &str) -> Option<&str> {
+    line.trim()
+        .strip_suffix(WINS_SUFFIX)
+        .map(str::trim)
+        .filter(|winner| !winner.is_empty())
+}
+
+#[cfg(test)]
+mod specs_for_writer_blind_alerter {
+    use std::io::Write;
+    use std::sync::Arc;
+    use std::sync::Mutex;
+    use std::time::Duration;
+    use std::time::Instant;
+
+    use virtual_clock::TestClock;
+
+    use super::BlindAlerter;
+    use super::WriterBlindAlerter;
+
+    /// `SharedBuffer` lets the spec read what the alerter's threads wrote.
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl SharedBuffer {
+        fn wait_for_lines(&self, lines: usize) -> String {
+            let deadline = Instant::now() + Duration::from_secs(5);
+            loop {
+                let content = String::from_utf8(self.0.lock().unwrap().clone()).unwrap();
+                if content.lines().count() >= lines || Instant::now() > deadline {
+                    return content;
+                }
+                std::thread::sleep(Duration::from_millis(1));
+            }
+        }
+    }
+
+    #[test]
+    fn sut_writes_alerts_in_order_they_are_due() {
+        // Arrange
+        let buffer = SharedBuffer::default();
+        let clock = TestClock::new();
+        let sut = WriterBlindAlerter::with_clock(buffer.clone(), clock.clone());
+        sut.schedule_alert_at(Duration::from_secs(600), 200);
+        sut.schedule_alert_at(Duration::ZERO, 100);
+
+        // Act
+        let first = buffer.wait_for_lines(1);
+        clock.advance(Duration::from_secs(600));
+        let second = buffer.wait_for_lines(2);
+
+        // Assert
+        assert_eq!("Blind is now 100\n", first);
+        assert_eq!("Blind is now 100\nBlind is now 200\n", second);
+    }
+
+    #[test]
+    fn sut_returns_before_alert_is_due() {
+        // Arrange
+        let buffer = SharedBuffer::default();
+        let sut = WriterBlindAlerter::with_clock(buffer.clone(), TestClock::new());
+
+        // Act
+        sut.schedule_alert_at(Duration::from_secs(60), 100);
+
+        // Assert
+        assert!(buffer.0.lock().unwrap().is_empty());
+    }
+}
+
+#[cfg(test)]
+mod specs_for_texas_holdem {
+    use std::cell::RefCell;
+    use std::time::Duration;
+
+    use http_server::v1::PlayerStore;
+    use http_server::v1::in_memory::InMemoryPlayerStore;
+    use mockall::Sequence;
+    use mockall::predicate::eq;
+
+    use super::BlindAlerter;
+    use super::Game;
+    use super::MockBlindAlerter;
+    use super::TexasHoldem;
+
+    #[derive(Default)]
+    struct BlindAlerterSpy {
+        alerts: RefCell<Vec<(Duration, u32)>>,
+    }
+
+    impl BlindAlerter for BlindAlerterSpy {
+        fn schedule_alert_at(&self, duration: Duration, amount: u32) {
+            self.alerts.borrow_mut().push((duration, amount));
+        }
+    }
+
+    fn minutes(minutes: u64) -> Duration {
+        Duration::from_secs(minutes * 60)
+    }
+
+    #[rstest::rstest]
+    #[case(5, vec![(0, 100), (10, 200), (20, 300), (30, 400), (40, 500), (50, 600),
+        (60, 800), (70, 1000), (80, 2000), (90, 4000), (100, 8000)])]
+    #[case(7, vec![(0, 100), (12, 200), (24, 300), (36, 400)])]
+    fn sut_schedules_blinds_for_number_of_players(
+        #[case] players: u32,
+        #[case] expected: Vec<(u64, u32)>,
+    ) {
+        // Arrange
+        let alerter = BlindAlerterSpy::default();
+        let store = InMemoryPlayerStore::new();
+        let sut = TexasHoldem::new(&alerter, &store);
+
+        // Act
+        sut.start(players);
+
+        // Assert
+        let actual = alerter.alerts.borrow();
+        for (i, (at, amount)) in expected.into_iter().enumerate() {
+            assert_eq!((minutes(at), amount), actual[i], "alert #{}", i);
+        }
+    }
+
+    #[test]
+    fn sut_schedules_blinds_in_order_as_mock_expects() {
+        // Arrange
+        let mut alerter_mock = MockBlindAlerter::new();
+        let mut sequence = Sequence::new();
+        for (at, amount) in [(0, 100), (12, 200), (24, 300), (36, 400)] {
+            alerter_mock
+                .expect_schedule_alert_at()
+                .with(eq(minutes(at)), eq(amount))
+                .times(1)
+                .in_sequence(&mut sequence)
+                .return_const(());
+        }
+        alerter_mock.expect_schedule_alert_at().return_const(());
+        let store = InMemoryPlayerStore::new();
+        let sut = TexasHoldem::new(&alerter_mock, &store);
+
+        // Act
+        sut.start(7);
+
+        // Assert
+        alerter_mock.checkpoint();
+    }
+
+    #[test]
+    fn sut_records_winner_on_finish() {
+        // Arrange
+        let alerter = BlindAlerterSpy::default();
+        let store = InMemoryPlayerStore::new();
+        let sut = TexasHoldem::new(&alerter, &store);
+
+        // Act
+        sut.finish("Ruth").unwrap();
+
+        // Assert
+        assert_eq!(Some(1), store.get_player_score("Ruth"));
+    }
+}
+
+#[cfg(test)]
+mod specs_for_cli {
+    use std::cell::RefCell;
+
+    use http_server::v1::StoreError;
+
+    use super::BAD_PLAYER_INPUT;
+    use super::BAD_WINNER_INPUT;
+    use super::Cli;
+    use super::Game;
+    use super::PLAYER_PROMPT;
+
+    #[derive(Default)]
+    struct GameSpy {
+        started_with: RefCell<Option<u32>>,
+        finished_with: RefCell<Option<String>>,
+    }
+
+    impl Game for GameSpy {
+        fn start(&self, players: u32) {
+            *self.started_with.borrow_mut() = Some(players);
+        }
+
+        fn finish(&self, winner: &str) -> Result<(), StoreError> {
+            *self.finished_with.borrow_mut() = Some(winner.to_string());
+            Ok(())
+        }
+    }
+
+    /// Plays a game on the input and returns what was printed.
+    fn play(game: &GameSpy, input: &str) -> String {
+        let mut input = input.as_bytes();
+        let mut output = vec![];
+        Cli::new(&mut input, &mut output, game)
+            .play_poker()
+            .unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[rstest::rstest]
+    #[case("3\nChris wins\n", 3, "Chris")]
+    #[case("8\n  Mary Ann wins  \n", 8, "Mary Ann")]
+    fn sut_starts_game_and_finishes_it_with_winner(
+        #[case] input: &str,
+        #[case] players: u32,
+        #[case] winner: &str,
+    ) {
+        // Arrange
+        let game = GameSpy::default();
+
+        // Act
+        let actual = play(&game, input);
+
+        // Assert
+        assert_eq!(PLAYER_PROMPT, actual);
+        assert_eq!(Some(players), *game.started_with.borrow());
+        assert_eq!(Some(winner), game.finished_with.borrow().as_deref());
+    }
+
+    #[rstest::rstest]
+    #[case("Pies\nChris wins\n")]
+    #[case("")]
+    fn sut_does_not_start_game_if_number_of_players_is_not_a_number(#[case] input: &str) {
+        // Arrange
+        let game = GameSpy::default();
+
+        // Act
+        let actual = play(&game, input);
+
+        // Assert
+        assert_eq!(format!("{}{}", PLAYER_PROMPT, BAD_PLAYER_INPUT), actual);
+        assert_eq!(None, *game.started_with.borrow());
+        assert_eq!(None, *game.finished_with.borrow());
+    }
+
+    #[test]
+    fn sut_does_not_finish_game_if_line_names_no_winner() {
+        // Arrange
+        let game = GameSpy::default();
+
+        // Act
+        let actual = play(&game, "3\nLloyd is a killer\n");
+
+        // Assert
+        assert_eq!(format!("{}{}", PLAYER_PROMPT, BAD_WINNER_INPUT), actual);
+        assert_eq!(Some(3), *game.started_with.borrow());
+        assert_eq!(None, *game.finished_with.borrow());
+    }
+}
@@ -0,0 +1,357 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::Weak;
+
+use tokio::sync::Notify;
+
+/// How many messages a subscriber buffers unless told otherwise.
+pub const DEFAULT_CAPACITY: usize = 16;
+
+/// `Overflow` tells which message a full subscriber loses when another one
+/// is published.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Overflow {
+    /// The oldest buffered message makes room, so the subscriber catches up
+    /// on the latest messages.
+    DropOldest,
+    /// The new message is dropped, so the subscriber keeps the messages it
+    /// fell behind on.
+    DropNewest,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+#[error("bus is closed")]
+pub struct Closed;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum RecvError {
+    /// The subscriber fell so far behind that this many messages were
+    /// dropped. The next receive goes on with the messages that are left.
+    #[error("subscriber lagged behind by {0} messages")]
+    Lagged(u64),
+
+    /// The bus is closed and every buffered message has been received.
+    #[error("bus is closed")]
+    Closed,
+}
+
+/// `Mailbox` buffers the messages of one subscriber.
+struct Mailbox<T> {
+    state: Mutex<MailboxState<T>>,
+    arrived: Notify,
+}
+
+struct MailboxState<T> {
+    messages: VecDeque<T>,
+    dropped: u64,
+    closed: bool,
+}
+
+/// `Bus` hands a clone of every published message to every subscriber.
+///
+/// Each subscriber has a buffer of its own, so a slow subscriber loses
+/// messages, as its `Overflow` tells, rather than holding back the publisher
+/// or the other subscribers.
+pub struct Bus<T> {
+    /// The mailbox of every subscriber, or `None` once the bus is closed.
+    subscribers: Mutex<Option<Vec<Weak<Mailbox<T>>>>>,
+    capacity: usize,
+    overflow: Overflow,
+}
+
+impl<T: Clone> Bus<T> {
+    pub fn new() -> Self {
+        Self::with_capacity(DEFAULT_CAPACITY, Overflow::DropOldest)
+    }
+
+    /// Creates a bus whose subscribers buffer `capacity` messages each.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is 0.
+    pub fn with_capacity(capacity: usize, overflow: Overflow) -> Self {
+        assert!(capacity > 0, "capacity must be at least 1");
+        Bus {
+            subscribers: Mutex::new(Some(vec![])),
+            capacity,
+            overflow,
+        }
+    }
+
+    /// Subscribes to the messages published from now on. Subscribing to a
+    /// closed bus returns a receiver that is closed already.
+    pub fn subscribe(&self) -> Receiver<T> {
+        let mut subscribers = self.subscribers.lock().unwrap();
+        let mailbox = Arc::new(Mailbox {
+            state: Mutex::new(MailboxState {
+                messages: VecDeque::with_capacity(self.capacity),
+                dropped: 0,
+                closed: subscribers.is_none(),
+            }),
+            arrived: Notify::new(),
+        });
+        if let Some(subscribers) = subscribers.as_mut() {
+            subscribers.push(Arc::downgrade(&mailbox));
+        }
+        Receiver { mailbox }
+    }
+
+    /// Publishes the message to every subscriber. Returns how many
+    /// subscribers there were; a dropped receiver no longer counts.
+    pub fn publish(&self, message: T) -> Result<usize, Closed> {
+        let mut subscribers = self.subscribers.lock().unwrap();
+        let subscribers = subscribers.as_mut().ok_or(Closed)?;
+        subscribers.retain(|subscriber| subscriber.strong_count() > 0);
+        for mailbox in subscribers.iter().filter_map(Weak::upgrade) {
+            let mut state = mailbox.state.lock().unwrap();
+            if state.messages.len() < self.capacity {
+                state.messages.push_back(message.clone());
+            } else {
+                state.dropped += 1;
+                if self.overflow == Overflow::DropOldest {
+                    state.messages.pop_front();
+                    state.messages.push_back(message.clone());
+                }
+            }
+            mailbox.arrived.notify_one();
+        }
+        Ok(subscribers.len())
+    }
+
+    /// Closes the bus. Subscribers still receive the messages they buffered
+    /// before they are told the bus is closed.
+    pub fn close(&self) {
+        close(self.subscribers.lock().unwrap().take());
+    }
+}
+
+impl<T: Clone> Default for Bus<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for Bus<T> {
+    /// Dropping the bus closes it, as no message can be published anymore.
+    fn drop(&mut self) {
+        close(self.subscribers.get_mut().unwrap().take());
+    }
+}
+
+fn close<T>(subscribers: Option<Vec<Weak<Mailbox<T>>>>) {
+    for mailbox in subscribers
+        .into_iter()
+        .flatten()
+        .filter_map(|subscriber| subscriber.upgrade())
+    {
+        mailbox.state.lock().unwrap().closed = true;
+        mailbox.arrived.notify_one();
+    }
+}
+
+/// `Receiver` receives the messages of one subscription. Dropping it
+/// unsubscribes.
+pub struct Receiver<T> {
+    mailbox: Arc<Mailbox<T>>,
+}
+
+impl<T> Receiver<T> {
+    /// Waits for the next message.
+    ///
+    /// If messages were dropped since the last receive, `Lagged` tells how
+    /// many first.
+    pub async fn recv(&mut self) -> Result<T, RecvError> {
+        loop {
+            if let Some(received) = self.poll() {
+                return received;
+            }
+            // Each mailbox has a single receiver, so a notification sent
+            // before waiting starts is kept for it rather than lost.
+            self.mailbox.arrived.notified().await;
+        }
+    }
+
+    /// Receives the next message if there is one, without waiting.
+    pub fn try_recv(&mut self) -> Option<Result<T, RecvError>> {
+        self.poll()
+    }
+
+    fn poll(&self) -> Option<Result<T, RecvError>> {
+        let mut state = self.mailbox.state.lock().unwrap();
+        if state.dropped > 0 {
+            return Some(Err(RecvError::Lagged(std::mem::take(&mut state.dropped))));
+        }
+        match state.messages.pop_front() {
+            Some(message) => Some(Ok(message)),
+            None if state.closed => Some(Err(RecvError::Closed)),
+            None => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod specs_for_bus {
+    use std::time::Duration;
+
+    use super::Bus;
+    use super::Closed;
+    use super::Overflow;
+    use super::Receiver;
+    use super::RecvError;
+
+    /// Receives whatever is buffered, without waiting, up to `Closed`.
+    fn drain(receiver: &mut Receiver<u32>) -> Vec<Result<u32, RecvError>> {
+        let mut received = vec![];
+        while let Some(next) = receiver.try_recv() {
+            received.push(next);
+            if next == Err(RecvError::Closed) {
+                break;
+            }
+        }
+        received
+    }
+
+    #[tokio::test]
+    async fn sut_delivers_every_message_to_every_subscriber_in_order() {
+        // Arrange
+        let sut = Bus::new();
+        let mut receivers = [sut.subscribe(), sut.subscribe()];
+
+        // Act
+        for message in 1..=3 {
+            sut.publish(message).unwrap();
+        }
+
+        // Assert
+        for receiver in &mut receivers {
+            assert_eq!(Ok(1), receiver.recv().await);
+            assert_eq!(Ok(2), receiver.recv().await);
+            assert_eq!(Ok(3), receiver.recv().await);
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn sut_wakes_subscriber_waiting_for_message() {
+        // Arrange
+        let sut = Bus::new();
+        let mut receiver = sut.subscribe();
+        let waiting = tokio::spawn(async move { receiver.recv().await });
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        // Act
+        sut.publish(7).unwrap();
+
+        // Assert
+        assert_eq!(Ok(7), waiting.await.unwrap());
+    }
+
+    #[test]
+    fn sut_delivers_to_late_subscriber_only_messages_published_after() {
+        // Arrange
+        let sut = Bus::new();
+        sut.publish(1).unwrap();
+
+        // Act
+        let mut late = sut.subscribe();
+        sut.publish(2).unwrap();
+
+        // Assert
+        assert_eq!(vec![Ok(2)], drain(&mut late));
+    }
+
+    #[rstest::rstest]
+    #[case::drop_oldest(Overflow::DropOldest, vec![Err(RecvError::Lagged(2)), Ok(3), Ok(4)])]
+    #[case::drop_newest(Overflow::DropNewest, vec![Err(RecvError::Lagged(2)), Ok(1), Ok(2)])]
+    fn sut_tells_slow_subscriber_how_many_messages_it_lost(
+        #[case] overflow: Overflow,
+        #[case] expected: Vec<Result<u32, RecvError>>,
+    ) {
+        // Arrange
+        let sut = Bus::with_capacity(2, overflow);
+        let mut slow = sut.subscribe();
+
+        // Act
+        for message in 1..=4 {
+            sut.publish(message).unwrap();
+        }
+
+        // Assert
+        assert_eq!(expected, drain(&mut slow));
+    }
+
+    #[test]
+    fn sut_does_not_let_slow_subscriber_hold_back_others() {
+        // Arrange
+        let sut = Bus::with_capacity(1, Overflow::DropOldest);
+        let _slow = sut.subscribe();
+        let mut fast = sut.subscribe();
+
+        // Act
+        let mut actual = vec![];
+        for message in 1..=3 {
+            sut.publish(message).unwrap();
+            actual.extend(drain(&mut fast));
+        }
+
+        // Assert
+        assert_eq!(vec![Ok(1), Ok(2), Ok(3)], actual);
+    }
+
+    #[test]
+    fn sut_counts_only_subscribers_still_receiving() {
+        // Arrange
+        let sut = Bus::new();
+        let _kept = sut.subscribe();
+        drop(sut.subscribe());
+
+        // Act
+        let actual = sut.publish(1);
+
+        // Assert
+        assert_eq!(Ok(1), actual);
+    }
+
+    #[test]
+    fn sut_lets_subscribers_receive_buffered_messages_after_close() {
+        // Arrange
+        let sut = Bus::new();
+        let mut receiver = sut.subscribe();
+        sut.publish(1).unwrap();
+
+        // Act
+        sut.close();
+
+        // Assert
+        assert_eq!(vec![Ok(1), Err(RecvError::Closed)], drain(&mut receiver));
+        assert_eq!(Err(Closed), sut.publish(2));
+    }
+
+    #[test]
+    fn sut_returns_closed_receiver_to_subscription_after_close() {
+        // Arrange
+        let sut = Bus::<u32>::new();
+        sut.close();
+
+        // Act
+        let mut actual = sut.subscribe();
+
+        // Assert
+        assert_eq!(Some(Err(RecvError::Closed)), actual.try_recv());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn sut_closes_when_dropped() {
+        // Arrange
+        let sut = Bus::<u32>::new();
+        let mut receiver = sut.subscribe();
+        let waiting = tokio::spawn(async move { receiver.recv().await });
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        // Act
+        drop(sut);
+
+        // Assert
+        assert_eq!(Err(RecvError::Closed), waiting.await.unwrap());
+    }
+}
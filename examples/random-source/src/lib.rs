@@ -0,0 +1,144 @@
+//! A source of random numbers the examples can be handed instead of
+//! reaching for one themselves, so that specs decide what comes out.
+//!
+//! - `ThreadRandom` draws from the thread's generator, seeded by the OS.
+//! - `SeededRandom` draws the same numbers for the same seed, every run.
+//!
+//! `RandomSource::rng` adapts a source to `rand::RngCore`, for libraries
+//! such as `fake` that take a generator.
+
+use std::sync::Mutex;
+
+use rand::RngCore;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+/// `RandomSource` hands out random numbers.
+pub trait RandomSource: Send + Sync {
+    fn next_u64(&self) -> u64;
+
+    /// Returns a number between 0, inclusive, and 1, exclusive.
+    fn next_f64(&self) -> f64 {
+        // The 53 high bits fill the mantissa of a double exactly.
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Borrows the source as a `rand` generator.
+    fn rng(&self) -> SourceRng<'_, Self> {
+        SourceRng(self)
+    }
+}
+
+impl<S: RandomSource + ?Sized> RandomSource for &S {
+    fn next_u64(&self) -> u64 {
+        (**self).next_u64()
+    }
+}
+
+/// `ThreadRandom` draws from the generator of the calling thread, so its
+/// numbers differ between runs.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ThreadRandom;
+
+impl RandomSource for ThreadRandom {
+    fn next_u64(&self) -> u64 {
+        rand::rng().next_u64()
+    }
+}
+
+/// `SeededRandom` draws the same numbers in the same order for the same
+/// seed, so a spec pinning the seed sees the same run every time.
+#[derive(Debug)]
+pub struct SeededRandom {
+    rng: Mutex<StdRng>,
+}
+
+impl SeededRandom {
+    pub fn new(seed: u64) -> Self {
+        SeededRandom {
+            rng: Mutex::new(StdRng::seed_from_u64(seed)),
+        }
+    }
+}
+
+impl RandomSource for SeededRandom {
+    fn next_u64(&self) -> u64 {
+        self.rng.lock().unwrap().next_u64()
+    }
+}
+
+/// `SourceRng` is a `RandomSource` seen as a `rand` generator.
+#[derive(Debug)]
+pub struct SourceRng<'a, S: ?Sized>(&'a S);
+
+impl<S: RandomSource + ?Sized> RngCore for SourceRng<'_, S> {
+    fn next_u32(&mut self) -> u32 {
+        (self.0.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        for chunk in dst.chunks_mut(8) {
+            let bytes = self.0.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod specs_for_random_source {
+    use rand::Rng;
+
+    use super::RandomSource;
+    use super::SeededRandom;
+    use super::ThreadRandom;
+
+    #[test]
+    fn sut_draws_same_numbers_for_same_seed() {
+        // Arrange
+        let (one, other) = (SeededRandom::new(42), SeededRandom::new(42));
+
+        // Act
+        let actual = (0..10).map(|_| one.next_u64()).collect::<Vec<_>>();
+
+        // Assert
+        let expected = (0..10).map(|_| other.next_u64()).collect::<Vec<_>>();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn sut_draws_different_numbers_for_different_seeds() {
+        // Act
+        let actual = SeededRandom::new(1).next_u64();
+
+        // Assert
+        assert_ne!(SeededRandom::new(2).next_u64(), actual);
+    }
+
+    #[test]
+    fn sut_draws_fractions_between_0_and_1() {
+        // Arrange
+        let sut = ThreadRandom;
+
+        // Act
+        let actual = (0..1000).map(|_| sut.next_f64()).collect::<Vec<_>>();
+
+        // Assert
+        assert!(actual.iter().all(|n| (0.0..1.0).contains(n)));
+    }
+
+    #[test]
+    fn sut_drives_rand_generator_from_its_numbers() {
+        // Arrange
+        let sut = SeededRandom::new(42);
+
+        // Act
+        let actual: u32 = sut.rng().random_range(0..100);
+
+        // Assert
+        assert_eq!(SeededRandom::new(42).rng().random_range(0..100), actual);
+    }
+}
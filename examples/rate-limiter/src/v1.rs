@@ -0,0 +1,32 @@
+use std::time::Duration;
+
+pub use virtual_clock::Clock;
+pub use virtual_clock::TokioClock;
+
+#[cfg(test)]
+mod contract;
+pub mod fixed_window;
+pub mod token_bucket;
+
+/// `RateLimited` is returned when no permit is left, with how long until
+/// the next one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+#[error("rate limited, next permit in {0:?}")]
+pub struct RateLimited(pub Duration);
+
+/// `RateLimiter` hands out permits at a limited rate. Limiters take `&self`
+/// so that one can be shared by every task it limits.
+pub trait RateLimiter: Send + Sync {
+    /// Takes a permit if one is available now.
+    fn try_acquire(&self) -> Result<(), RateLimited>;
+
+    /// Waits until a permit is available and takes it. Waiting is done on
+    /// the tokio timer, so tests can pause it.
+    fn acquire_async(&self) -> impl Future<Output = ()> + Send {
+        async move {
+            while let Err(RateLimited(wait)) = self.try_acquire() {
+                tokio::time::sleep(wait).await;
+            }
+        }
+    }
+}
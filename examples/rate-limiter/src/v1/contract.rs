@@ -0,0 +1,83 @@
+//! Specs every rate limiter has to meet, whatever algorithm it follows.
+//!
+//! `rate_limiter_contract!` expands to a test module running the specs against
+//! the limiter the expression creates from `clock`. The limiter must grant
+//! 3 permits at once, and nothing more until some time passed.
+
+macro_rules! rate_limiter_contract {
+    (|$clock:ident| $limiter:expr) => {
+        mod specs_for_rate_limiter_contract {
+            use std::sync::Arc;
+
+            use futures::future::join_all;
+            use tokio::time::Instant;
+            use virtual_clock::TestClock;
+
+            #[allow(unused_imports)]
+            use super::*;
+            use $crate::v1::RateLimited;
+            use $crate::v1::RateLimiter;
+            use $crate::v1::TokioClock;
+
+            #[test]
+            fn sut_grants_3_permits_and_then_tells_how_long_to_wait() {
+                // Arrange
+                let $clock = TestClock::new();
+                let sut = $limiter;
+
+                // Act
+                let actual = (0..4).map(|_| sut.try_acquire()).collect::<Vec<_>>();
+
+                // Assert
+                assert_eq!(vec![Ok(()), Ok(()), Ok(())], actual[..3]);
+                let Err(RateLimited(wait)) = actual[3] else {
+                    panic!("expected 4th permit to be refused");
+                };
+                assert!(!wait.is_zero());
+            }
+
+            #[tokio::test(flavor = "multi_thread")]
+            async fn sut_grants_3_permits_however_many_tasks_ask_at_once() {
+                // Arrange
+                let $clock = TestClock::new();
+                let sut = Arc::new($limiter);
+
+                // Act
+                let handles = (0..100)
+                    .map(|_| {
+                        let sut = Arc::clone(&sut);
+                        tokio::spawn(async move { sut.try_acquire().is_ok() })
+                    })
+                    .collect::<Vec<_>>();
+                let granted = join_all(handles)
+                    .await
+                    .into_iter()
+                    .filter(|granted| *granted.as_ref().unwrap())
+                    .count();
+
+                // Assert
+                assert_eq!(3, granted);
+            }
+
+            #[tokio::test(start_paused = true)]
+            async fn sut_acquire_async_waits_as_long_as_try_acquire_tells() {
+                // Arrange
+                let $clock = TokioClock;
+                let sut = $limiter;
+                for _ in 0..3 {
+                    sut.acquire_async().await;
+                }
+                let RateLimited(wait) = sut.try_acquire().unwrap_err();
+                let start = Instant::now();
+
+                // Act
+                sut.acquire_async().await;
+
+                // Assert
+                assert_eq!(wait, start.elapsed());
+            }
+        }
+    };
+}
+
+pub(crate) use rate_limiter_contract;
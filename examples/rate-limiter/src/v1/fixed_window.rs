@@ -0,0 +1,149 @@
+use std::num::NonZeroU32;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+
+use super::Clock;
+use super::RateLimited;
+use super::RateLimiter;
+use super::TokioClock;
+
+/// `FixedWindow` allows `limit` permits in every window of time. The count
+/// starts over at the start of each window, so up to twice the limit can be
+/// taken around the boundary between two windows.
+#[derive(Debug)]
+pub struct FixedWindow<C = TokioClock> {
+    limit: u32,
+    window: Duration,
+    clock: C,
+    state: Mutex<Window>,
+}
+
+#[derive(Debug)]
+struct Window {
+    started: Instant,
+    taken: u32,
+}
+
+impl FixedWindow {
+    /// Creates a limiter whose first window starts now.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is zero.
+    pub fn new(limit: NonZeroU32, window: Duration) -> Self {
+        Self::with_clock(limit, window, TokioClock)
+    }
+}
+
+impl<C: Clock> FixedWindow<C> {
+    /// Creates a limiter that tells the time by `clock`, whose first window starts now.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is zero.
+    pub fn with_clock(limit: NonZeroU32, window: Duration, clock: C) -> Self {
+        assert!(!window.is_zero(), "window must not be zero");
+        let started = clock.now();
+        FixedWindow {
+            limit: limit.get(),
+            window,
+            clock,
+            state: Mutex::new(Window { started, taken: 0 }),
+        }
+    }
+}
+
+impl<C: Clock> RateLimiter for FixedWindow<C> {
+    fn try_acquire(&self) -> Result<(), RateLimited> {
+        let now = self.clock.now();
+        let mut current = self.state.lock().unwrap();
+        let elapsed = now.saturating_duration_since(current.started);
+        if elapsed >= self.window {
+            // Windows stay aligned to the first one, however many passed.
+            let into_window = elapsed.as_nanos() % self.window.as_nanos();
+            current.started = now - Duration::from_nanos_u128(into_window);
+            current.taken = 0;
+        }
+        if current.taken >= self.limit {
+            let next = current.started + self.window;
+            return Err(RateLimited(next.saturating_duration_since(now)));
+        }
+        current.taken += 1;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod specs_for_fixed_window {
+    use std::num::NonZeroU32;
+    use std::time::Duration;
+
+    use virtual_clock::TestClock;
+
+    use super::FixedWindow;
+    use crate::v1::RateLimited;
+    use crate::v1::RateLimiter;
+    use crate::v1::contract::rate_limiter_contract;
+
+    const LIMIT: NonZeroU32 = NonZeroU32::new(2).unwrap();
+    const WINDOW: Duration = Duration::from_millis(300);
+
+    rate_limiter_contract!(|clock| FixedWindow::with_clock(
+        NonZeroU32::new(3).unwrap(),
+        WINDOW,
+        clock
+    ));
+
+    #[test]
+    fn sut_starts_count_over_in_next_window() {
+        // Arrange
+        let clock = TestClock::new();
+        let sut = FixedWindow::with_clock(LIMIT, WINDOW, clock.clone());
+        sut.try_acquire().unwrap();
+        sut.try_acquire().unwrap();
+
+        // Act
+        clock.advance(WINDOW);
+        let actual = (0..3).map(|_| sut.try_acquire()).collect::<Vec<_>>();
+
+        // Assert
+        assert_eq!(vec![Ok(()), Ok(()), Err(RateLimited(WINDOW))], actual);
+    }
+
+    #[test]
+    fn sut_starts_count_over_after_more_windows_than_a_u32_holds() {
+        // Arrange
+        let window = Duration::from_nanos(2);
+        let clock = TestClock::new();
+        let sut = FixedWindow::with_clock(NonZeroU32::MIN, window, clock.clone());
+
+        // Act
+        clock.advance(window * u32::MAX + window + window / 2);
+        let actual = (0..2).map(|_| sut.try_acquire()).collect::<Vec<_>>();
+
+        // Assert
+        assert_eq!(vec![Ok(()), Err(RateLimited(window / 2))], actual);
+    }
+
+    #[test]
+    #[should_panic(expected = "window must not be zero")]
+    fn sut_rejects_zero_window() {
+        FixedWindow::with_clock(LIMIT, Duration::ZERO, TestClock::new());
+    }
+
+    #[test]
+    fn sut_waits_for_end_of_window_rather_than_interval() {
+        // Arrange
+        let clock = TestClock::new();
+        let sut = FixedWindow::with_clock(NonZeroU32::MIN, WINDOW, clock.clone());
+        clock.advance(WINDOW * 5 / 2);
+        sut.try_acquire().unwrap();
+
+        // Act
+        let actual = sut.try_acquire();
+
+        // Assert
+        assert_eq!(Err(RateLimited(WINDOW / 2)), actual);
+    }
+}
@@ -0,0 +1,173 @@
+use std::num::NonZeroU32;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+
+use super::Clock;
+use super::RateLimited;
+use super::RateLimiter;
+use super::TokioClock;
+
+/// `TokenBucket` holds up to `capacity` permits and gains one back every
+/// `interval`, so it allows bursts up to its capacity and a steady rate of
+/// one permit per interval after that.
+#[derive(Debug)]
+pub struct TokenBucket<C = TokioClock> {
+    capacity: u32,
+    interval: Duration,
+    clock: C,
+    state: Mutex<Bucket>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: u32,
+    /// When the last token was added. Time since then that does not make a
+    /// whole interval counts towards the next token.
+    refilled: Instant,
+}
+
+impl TokenBucket {
+    /// Creates a full bucket.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `interval` is zero.
+    pub fn new(capacity: NonZeroU32, interval: Duration) -> Self {
+        Self::with_clock(capacity, interval, TokioClock)
+    }
+}
+
+impl<C: Clock> TokenBucket<C> {
+    /// Creates a full bucket that tells the time by `clock`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `interval` is zero.
+    pub fn with_clock(capacity: NonZeroU32, interval: Duration, clock: C) -> Self {
+        assert!(!interval.is_zero(), "interval must not be zero");
+        let capacity = capacity.get();
+        let refilled = clock.now();
+        TokenBucket {
+            capacity,
+            interval,
+            clock,
+            state: Mutex::new(Bucket {
+                tokens: capacity,
+                refilled,
+            }),
+        }
+    }
+}
+
+impl<C: Clock> RateLimiter for TokenBucket<C> {
+    fn try_acquire(&self) -> Result<(), RateLimited> {
+        let now = self.clock.now();
+        let mut bucket = self.state.lock().unwrap();
+        let elapsed = now.saturating_duration_since(bucket.refilled);
+        // However long the bucket stood idle, it cannot earn more than full.
+        let earned =
+            u32::try_from(elapsed.as_nanos() / self.interval.as_nanos()).unwrap_or(u32::MAX);
+        if earned > 0 {
+            bucket.tokens = bucket.tokens.saturating_add(earned).min(self.capacity);
+            bucket.refilled = if bucket.tokens == self.capacity {
+                now
+            } else {
+                bucket.refilled + self.interval * earned
+            };
+        }
+        if bucket.tokens == 0 {
+            let next = bucket.refilled + self.interval;
+            return Err(RateLimited(next.saturating_duration_since(now)));
+        }
+        bucket.tokens -= 1;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod specs_for_token_bucket {
+    use std::num::NonZeroU32;
+    use std::time::Duration;
+
+    use virtual_clock::TestClock;
+
+    use super::TokenBucket;
+    use crate::v1::RateLimited;
+    use crate::v1::RateLimiter;
+    use crate::v1::contract::rate_limiter_contract;
+
+    const CAPACITY: NonZeroU32 = NonZeroU32::new(3).unwrap();
+    const INTERVAL: Duration = Duration::from_millis(100);
+
+    rate_limiter_contract!(|clock| TokenBucket::with_clock(CAPACITY, INTERVAL, clock));
+
+    #[test]
+    fn sut_gains_one_permit_back_every_interval() {
+        // Arrange
+        let clock = TestClock::new();
+        let sut = TokenBucket::with_clock(CAPACITY, INTERVAL, clock.clone());
+        for _ in 0..3 {
+            sut.try_acquire().unwrap();
+        }
+
+        // Act
+        clock.advance(INTERVAL * 2);
+
+        // Assert
+        assert_eq!(Ok(()), sut.try_acquire());
+        assert_eq!(Ok(()), sut.try_acquire());
+        assert_eq!(Err(RateLimited(INTERVAL)), sut.try_acquire());
+    }
+
+    #[test]
+    fn sut_keeps_time_towards_next_permit() {
+        // Arrange
+        let clock = TestClock::new();
+        let sut = TokenBucket::with_clock(NonZeroU32::MIN, INTERVAL, clock.clone());
+        sut.try_acquire().unwrap();
+
+        // Act
+        clock.advance(INTERVAL / 2);
+        let actual = sut.try_acquire();
+
+        // Assert
+        assert_eq!(Err(RateLimited(INTERVAL / 2)), actual);
+    }
+
+    #[test]
+    fn sut_refills_after_more_intervals_than_a_u32_holds() {
+        // Arrange
+        let interval = Duration::from_nanos(1);
+        let clock = TestClock::new();
+        let sut = TokenBucket::with_clock(NonZeroU32::MIN, interval, clock.clone());
+        sut.try_acquire().unwrap();
+
+        // Act
+        clock.advance(interval * u32::MAX + interval);
+        let actual = sut.try_acquire();
+
+        // Assert
+        assert_eq!(Ok(()), actual);
+    }
+
+    #[test]
+    #[should_panic(expected = "interval must not be zero")]
+    fn sut_rejects_zero_interval() {
+        TokenBucket::with_clock(CAPACITY, Duration::ZERO, TestClock::new());
+    }
+
+    #[test]
+    fn sut_does_not_hold_more_than_capacity() {
+        // Arrange
+        let clock = TestClock::new();
+        let sut = TokenBucket::with_clock(NonZeroU32::new(2).unwrap(), INTERVAL, clock.clone());
+
+        // Act
+        clock.advance(INTERVAL * 10);
+        let actual = (0..3).map(|_| sut.try_acquire()).collect::<Vec<_>>();
+
+        // Assert
+        assert_eq!(vec![Ok(()), Ok(()), Err(RateLimited(INTERVAL))], actual);
+    }
+}
@@ -0,0 +1,397 @@
+use serde::Serialize;
+use serde::Serializer;
+use serde::ser;
+
+/// `WalkError` is an error the value raised while it was walked.
+#[derive(Debug, PartialEq, thiserror::Error)]
+#[error("{0}")]
+pub struct WalkError(String);
+
+impl ser::Error for WalkError {
+    fn custom<T: std::fmt::Display>(message: T) -> Self {
+        WalkError(message.to_string())
+    }
+}
+
+/// Calls `f` with every string in the value, however deep it is nested in
+/// structs, enums, sequences, options and maps.
+///
+/// Only values are visited: field names, variant names and map keys are not.
+/// The value is taken apart through its `Serialize` implementation, which is
+/// the closest Rust has to reflection.
+pub fn walk(value: &impl Serialize, f: impl FnMut(&str)) -> Result<(), WalkError> {
+    value.serialize(&mut Walker { f })
+}
+
+/// `Walker` is a serializer that produces nothing but hands every string it
+/// is given to `f`.
+struct Walker<F> {
+    f: F,
+}
+
+impl<F: FnMut(&str)> Serializer for &mut Walker<F> {
+    type Ok = ();
+    type Error = WalkError;
+    type SerializeSeq = Self;
+    type SerializeTuple = Self;
+    type SerializeTupleStruct = Self;
+    type SerializeTupleVariant = Self;
+    type SerializeMap = Self;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = Self;
+
+    fn serialize_str(self, value: &str) -> Result<(), WalkError> {
+        (self.f)(value);
+        Ok(())
+    }
+
+    fn serialize_bool(self, _: bool) -> Result<(), WalkError> {
+        Ok(())
+    }
+
+    fn serialize_i8(self, _: i8) -> Result<(), WalkError> {
+        Ok(())
+    }
+
+    fn serialize_i16(self, _: i16) -> Result<(), WalkError> {
+        Ok(())
+    }
+
+    fn serialize_i32(self, _: i32) -> Result<(), WalkError> {
+        Ok(())
+    }
+
+    fn serialize_i64(self, _: i64) -> Result<(), WalkError> {
+        Ok(())
+    }
+
+    fn serialize_u8(self, _: u8) -> Result<(), WalkError> {
+        Ok(())
+    }
+
+    fn serialize_u16(self, _: u16) -> Result<(), WalkError> {
+        Ok(())
+    }
+
+    fn serialize_u32(self, _: u32) -> Result<(), WalkError> {
+        Ok(())
+    }
+
+    fn serialize_u64(self, _: u64) -> Result<(), WalkError> {
+        Ok(())
+    }
+
+    fn serialize_f32(self, _: f32) -> Result<(), WalkError> {
+        Ok(())
+    }
+
+    fn serialize_f64(self, _: f64) -> Result<(), WalkError> {
+        Ok(())
+    }
+
+    fn serialize_char(self, _: char) -> Result<(), WalkError> {
+        Ok(())
+    }
+
+    fn serialize_bytes(self, _: &[u8]) -> Result<(), WalkError> {
+        Ok(())
+    }
+
+    fn serialize_none(self) -> Result<(), WalkError> {
+        Ok(())
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<(), WalkError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<(), WalkError> {
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<(), WalkError> {
+        Ok(())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+    ) -> Result<(), WalkError> {
+        Ok(())
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<(), WalkError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        value: &T,
+    ) -> Result<(), WalkError> {
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self, WalkError> {
+        Ok(self)
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Self, WalkError> {
+        Ok(self)
+    }
+
+    fn serialize_tuple_struct(self, _: &'static str, _: usize) -> Result<Self, WalkError> {
+        Ok(self)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self, WalkError> {
+        Ok(self)
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Self, WalkError> {
+        Ok(self)
+    }
+
+    fn serialize_struct(self, _: &'static str, _: usize) -> Result<Self, WalkError> {
+        Ok(self)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self, WalkError> {
+        Ok(self)
+    }
+}
+
+impl<F: FnMut(&str)> ser::SerializeSeq for &mut Walker<F> {
+    type Ok = ();
+    type Error = WalkError;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), WalkError> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), WalkError> {
+        Ok(())
+    }
+}
+
+impl<F: FnMut(&str)> ser::SerializeTuple for &mut Walker<F> {
+    type Ok = ();
+    type Error = WalkError;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), WalkError> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), WalkError> {
+        Ok(())
+    }
+}
+
+impl<F: FnMut(&str)> ser::SerializeTupleStruct for &mut Walker<F> {
+    type Ok = ();
+    type Error = WalkError;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), WalkError> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), WalkError> {
+        Ok(())
+    }
+}
+
+impl<F: FnMut(&str)> ser::SerializeTupleVariant for &mut Walker<F> {
+    type Ok = ();
+    type Error = WalkError;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), WalkError> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), WalkError> {
+        Ok(())
+    }
+}
+
+impl<F: FnMut(&str)> ser::SerializeMap for &mut Walker<F> {
+    type Ok = ();
+    type Error = WalkError;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, _: &T) -> Result<(), WalkError> {
+        Ok(())
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), WalkError> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), WalkError> {
+        Ok(())
+    }
+}
+
+impl<F: FnMut(&str)> ser::SerializeStruct for &mut Walker<F> {
+    type Ok = ();
+    type Error = WalkError;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<(), WalkError> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), WalkError> {
+        Ok(())
+    }
+}
+
+impl<F: FnMut(&str)> ser::SerializeStructVariant for &mut Walker<F> {
+    type Ok = ();
+    type Error = WalkError;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<(), WalkError> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), WalkError> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod specs_for_walk {
+    use std::collections::BTreeMap;
+    use std::collections::HashMap;
+
+    use serde::Serialize;
+    use serde::Serializer;
+
+    use super::WalkError;
+    use super::walk;
+
+    #[derive(Serialize)]
+    struct Person {
+        name: String,
+        profile: Profile,
+    }
+
+    #[derive(Serialize)]
+    struct Profile {
+        age: u32,
+        city: String,
+    }
+
+    #[derive(Serialize)]
+    struct Nickname(&'static str);
+
+    #[derive(Serialize)]
+    enum Contact {
+        Unknown,
+        Email(String),
+        Phone { country: u16, number: String },
+    }
+
+    /// `Broken` fails to serialize, as a value with a custom implementation
+    /// may.
+    struct Broken;
+
+    impl Serialize for Broken {
+        fn serialize<S: Serializer>(&self, _: S) -> Result<S::Ok, S::Error> {
+            Err(serde::ser::Error::custom("cannot serialize"))
+        }
+    }
+
+    fn visited(value: &impl Serialize) -> Vec<String> {
+        let mut visited = vec![];
+        walk(value, |s| visited.push(s.to_string())).unwrap();
+        visited
+    }
+
+    fn person(name: &str, city: &str) -> Person {
+        Person {
+            name: name.to_string(),
+            profile: Profile {
+                age: 33,
+                city: city.to_string(),
+            },
+        }
+    }
+
+    #[rstest::rstest]
+    #[case::string(visited(&"Chris"), vec!["Chris"])]
+    #[case::nested_struct(visited(&person("Chris", "London")), vec!["Chris", "London"])]
+    #[case::non_string_fields(visited(&(33, true, 'c', 1.5)), vec![])]
+    #[case::reference(visited(&&&person("Chris", "London")), vec!["Chris", "London"])]
+    #[case::newtype(visited(&Nickname("Chrissy")), vec!["Chrissy"])]
+    #[case::vector(
+        visited(&vec![person("Chris", "London"), person("Cleo", "Reykjavík")]),
+        vec!["Chris", "London", "Cleo", "Reykjavík"],
+    )]
+    #[case::array(visited(&["Chris", "Cleo"]), vec!["Chris", "Cleo"])]
+    #[case::some(visited(&Some("Chris")), vec!["Chris"])]
+    #[case::none(visited(&None::<String>), vec![])]
+    #[case::map_values(
+        visited(&BTreeMap::from([("Cow", "Moo"), ("Sheep", "Baa")])),
+        vec!["Moo", "Baa"],
+    )]
+    #[case::unit_variant(visited(&Contact::Unknown), vec![])]
+    #[case::newtype_variant(visited(&Contact::Email("c@example.com".to_string())), vec!["c@example.com"])]
+    #[case::struct_variant(
+        visited(&Contact::Phone { country: 44, number: "555".to_string() }),
+        vec!["555"],
+    )]
+    fn sut_visits_every_string_value(#[case] actual: Vec<String>, #[case] expected: Vec<&str>) {
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn sut_visits_every_value_of_unordered_map() {
+        // Arrange
+        let animals = HashMap::from([("Cow", "Moo"), ("Sheep", "Baa")]);
+
+        // Act
+        let mut actual = visited(&animals);
+
+        // Assert
+        actual.sort();
+        assert_eq!(vec!["Baa", "Moo"], actual);
+    }
+
+    #[test]
+    fn sut_returns_error_the_value_raises() {
+        // Arrange
+        let value = (person("Chris", "London"), Broken);
+
+        // Act
+        let actual = walk(&value, |_| {});
+
+        // Assert
+        assert_eq!(Err(WalkError("cannot serialize".to_string())), actual);
+    }
+}
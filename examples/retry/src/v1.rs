@@ -0,0 +1,401 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use random_source::RandomSource;
+use random_source::ThreadRandom;
+
+/// `Policy` decides whether a failed attempt is tried again, and after how
+/// long.
+pub trait Policy<E> {
+    /// Returns the wait before the next attempt, or `None` to give up.
+    /// Attempts are counted from 1, so the first failure is attempt 1.
+    fn delay(&self, attempt: u32, error: &E) -> Option<Duration>;
+}
+
+/// `RetryPredicate` tells which errors are worth retrying. A closure taking
+/// the error is one.
+pub trait RetryPredicate<E> {
+    fn should_retry(&self, error: &E) -> bool;
+}
+
+impl<E, F: Fn(&E) -> bool> RetryPredicate<E> for F {
+    fn should_retry(&self, error: &E) -> bool {
+        self(error)
+    }
+}
+
+/// `Fixed` waits the same delay before each of up to `max_attempts`
+/// attempts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Fixed {
+    pub delay: Duration,
+    pub max_attempts: u32,
+}
+
+impl<E> Policy<E> for Fixed {
+    fn delay(&self, attempt: u32, _: &E) -> Option<Duration> {
+        (attempt < self.max_attempts).then_some(self.delay)
+    }
+}
+
+/// `Exponential` doubles the delay after every attempt, from `initial` up to
+/// `max_delay`, for up to `max_attempts` attempts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Exponential {
+    pub initial: Duration,
+    pub max_delay: Duration,
+    pub max_attempts: u32,
+}
+
+impl<E> Policy<E> for Exponential {
+    fn delay(&self, attempt: u32, _: &E) -> Option<Duration> {
+        if attempt >= self.max_attempts {
+            return None;
+        }
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        Some(self.initial.saturating_mul(factor).min(self.max_delay))
+    }
+}
+
+/// `Jitter` waits a random part of the delay of another policy, so that
+/// clients failing together do not all retry at the same moment.
+#[derive(Clone)]
+pub struct Jitter<P> {
+    policy: P,
+    random: Arc<dyn Fn() -> f64 + Send + Sync>,
+}
+
+impl<P> Jitter<P> {
+    pub fn new(policy: P) -> Self {
+        Self::with_source(policy, ThreadRandom)
+    }
+
+    /// Creates the policy drawing the part of the delay to wait from
+    /// `source`, so that a seeded source gives the same delays every run.
+    pub fn with_source(policy: P, source: impl RandomSource + 'static) -> Self {
+        Self::with_random(policy, move || source.next_f64())
+    }
+
+    /// Creates the policy with `random` picking the part of the delay to
+    /// wait, between 0 and 1.
+    pub fn with_random(policy: P, random: impl Fn() -> f64 + Send + Sync + 'static) -> Self {
+        Jitter {
+            policy,
+            random: Arc::new(random),
+        }
+    }
+}
+
+impl<E, P: Policy<E>> Policy<E> for Jitter<P> {
+    fn delay(&self, attempt: u32, error: &E) -> Option<Duration> {
+        let delay = self.policy.delay(attempt, error)?;
+        Some(delay.mul_f64((self.random)().clamp(0.0, 1.0)))
+    }
+}
+
+/// `Selective` retries with another policy only the errors its predicate
+/// accepts, giving up on any other error at once.
+#[derive(Clone, Copy, Debug)]
+pub struct Selective<P, R> {
+    policy: P,
+    predicate: R,
+}
+
+impl<P, R> Selective<P, R> {
+    pub fn new(policy: P, predicate: R) -> Self {
+        Selective { policy, predicate }
+    }
+}
+
+impl<E, P: Policy<E>, R: RetryPredicate<E>> Policy<E> for Selective<P, R> {
+    fn delay(&self, attempt: u32, error: &E) -> Option<Duration> {
+        if !self.predicate.should_retry(error) {
+            return None;
+        }
+        self.policy.delay(attempt, error)
+    }
+}
+
+/// Calls `op` until it succeeds or the policy gives up, sleeping the thread
+/// between attempts. Returns the last error if every attempt failed.
+pub fn retry<T, E>(policy: &impl Policy<E>, mut op: impl FnMut() -> Result<T, E>) -> Result<T, E> {
+    let mut attempt = 1;
+    loop {
+        let error = match op() {
+            Ok(value) => return Ok(value),
+            Err(error) => error,
+        };
+        let Some(delay) = policy.delay(attempt, &error) else {
+            return Err(error);
+        };
+        std::thread::sleep(delay);
+        attempt += 1;
+    }
+}
+
+/// Awaits the future `op` returns until one succeeds or the policy gives up,
+/// sleeping the task between attempts. Returns the last error if every
+/// attempt failed.
+pub async fn retry_async<T, E, F: Future<Output = Result<T, E>>>(
+    policy: &impl Policy<E>,
+    op: impl FnMut() -> F,
+) -> Result<T, E> {
+    retry_async_with_sleep(policy, tokio::time::sleep, op).await
+}
+
+/// Awaits the future `op` returns like `retry_async`, but waits between
+/// attempts on the future `sleep` returns, so that a virtual clock can
+/// stand in for the Tokio timer.
+pub async fn retry_async_with_sleep<T, E, F, S>(
+    policy: &impl Policy<E>,
+    sleep: impl Fn(Duration) -> S,
+    mut op: impl FnMut() -> F,
+) -> Result<T, E>
+where
+    F: Future<Output = Result<T, E>>,
+    S: Future<Output = ()>,
+{
+    let mut attempt = 1;
+    loop {
+        let error = match op().await {
+            Ok(value) => return Ok(value),
+            Err(error) => error,
+        };
+        let Some(delay) = policy.delay(attempt, &error) else {
+            return Err(error);
+        };
+        sleep(delay).await;
+        attempt += 1;
+    }
+}
+
+#[cfg(test)]
+mod specs_for_policies {
+    use std::time::Duration;
+
+    use random_source::SeededRandom;
+
+    use super::Exponential;
+    use super::Fixed;
+    use super::Jitter;
+    use super::Policy;
+    use super::Selective;
+
+    const FIXED: Fixed = Fixed {
+        delay: Duration::from_millis(100),
+        max_attempts: 3,
+    };
+
+    const EXPONENTIAL: Exponential = Exponential {
+        initial: Duration::from_millis(100),
+        max_delay: Duration::from_millis(300),
+        max_attempts: 4,
+    };
+
+    fn delays(policy: &impl Policy<()>) -> Vec<Option<u128>> {
+        (1..=4)
+            .map(|attempt| policy.delay(attempt, &()).map(|delay| delay.as_millis()))
+            .collect()
+    }
+
+    #[rstest::rstest]
+    #[case::fixed(delays(&FIXED), vec![Some(100), Some(100), None, None])]
+    #[case::exponential(delays(&EXPONENTIAL), vec![Some(100), Some(200), Some(300), None])]
+    #[case::jitter(
+        delays(&Jitter::with_random(EXPONENTIAL, || 0.5)),
+        vec![Some(50), Some(100), Some(150), None],
+    )]
+    fn sut_waits_longer_or_gives_up_as_policy_tells(
+        #[case] actual: Vec<Option<u128>>,
+        #[case] expected: Vec<Option<u128>>,
+    ) {
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn sut_waits_at_most_delay_of_policy_with_random_jitter() {
+        // Arrange
+        let sut = Jitter::new(FIXED);
+
+        // Act
+        let actual = (0..100)
+            .map(|_| Policy::<()>::delay(&sut, 1, &()).unwrap())
+            .collect::<Vec<_>>();
+
+        // Assert
+        assert!(actual.iter().all(|delay| *delay <= FIXED.delay));
+        assert!(actual.iter().any(|delay| *delay != actual[0]));
+    }
+
+    #[test]
+    fn sut_waits_same_delays_with_jitter_from_same_seed() {
+        // Arrange
+        let sut = Jitter::with_source(EXPONENTIAL, SeededRandom::new(42));
+
+        // Act
+        let actual = delays(&sut);
+
+        // Assert
+        let expected = delays(&Jitter::with_source(EXPONENTIAL, SeededRandom::new(42)));
+        assert_eq!(expected, actual);
+    }
+
+    #[rstest::rstest]
+    #[case::retried_error("timed out", Some(Duration::from_millis(100)))]
+    #[case::other_error("not found", None)]
+    fn sut_retries_only_errors_predicate_accepts(
+        #[case] error: &str,
+        #[case] expected: Option<Duration>,
+    ) {
+        // Arrange
+        let sut = Selective::new(FIXED, |error: &&str| *error == "timed out");
+
+        // Act
+        let actual = sut.delay(1, &error);
+
+        // Assert
+        assert_eq!(expected, actual);
+    }
+}
+
+#[cfg(test)]
+mod specs_for_retry {
+    use std::cell::Cell;
+    use std::time::Duration;
+
+    use super::Fixed;
+    use super::Selective;
+    use super::retry;
+
+    const IMMEDIATELY: Fixed = Fixed {
+        delay: Duration::ZERO,
+        max_attempts: 3,
+    };
+
+    /// Returns an operation failing its first `failures` calls, and the
+    /// number of calls made.
+    fn flaky(failures: u32, calls: &Cell<u32>) -> impl FnMut() -> Result<u32, String> {
+        move || {
+            calls.set(calls.get() + 1);
+            if calls.get() <= failures {
+                return Err(format!("call {} failed", calls.get()));
+            }
+            Ok(calls.get())
+        }
+    }
+
+    #[test]
+    fn sut_returns_value_of_first_successful_attempt() {
+        // Arrange
+        let calls = Cell::new(0);
+
+        // Act
+        let actual = retry(&IMMEDIATELY, flaky(2, &calls));
+
+        // Assert
+        assert_eq!(Ok(3), actual);
+    }
+
+    #[test]
+    fn sut_returns_last_error_once_policy_gives_up() {
+        // Arrange
+        let calls = Cell::new(0);
+
+        // Act
+        let actual = retry(&IMMEDIATELY, flaky(5, &calls));
+
+        // Assert
+        assert_eq!(Err("call 3 failed".to_string()), actual);
+        assert_eq!(3, calls.get());
+    }
+
+    #[test]
+    fn sut_does_not_retry_error_predicate_rejects() {
+        // Arrange
+        let calls = Cell::new(0);
+        let policy = Selective::new(IMMEDIATELY, |error: &String| error.contains("timed out"));
+
+        // Act
+        let actual = retry(&policy, flaky(5, &calls));
+
+        // Assert
+        assert_eq!(Err("call 1 failed".to_string()), actual);
+        assert_eq!(1, calls.get());
+    }
+}
+
+#[cfg(test)]
+mod specs_for_retry_async {
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    use tokio::time::Instant;
+
+    use super::Exponential;
+    use super::Fixed;
+    use super::retry_async;
+    use super::retry_async_with_sleep;
+
+    #[tokio::test(start_paused = true)]
+    async fn sut_sleeps_between_attempts_as_policy_tells() {
+        // Arrange
+        let policy = Exponential {
+            initial: Duration::from_millis(100),
+            max_delay: Duration::from_secs(1),
+            max_attempts: 3,
+        };
+        let attempts = Mutex::new(vec![]);
+
+        // Act
+        let actual = retry_async(&policy, || async {
+            let mut attempts = attempts.lock().unwrap();
+            attempts.push(Instant::now());
+            if attempts.len() < 3 {
+                Err(())
+            } else {
+                Ok("done")
+            }
+        })
+        .await;
+
+        // Assert
+        assert_eq!(Ok("done"), actual);
+        let attempts = attempts.lock().unwrap();
+        let waits = attempts
+            .windows(2)
+            .map(|pair| pair[1] - pair[0])
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![Duration::from_millis(100), Duration::from_millis(200)],
+            waits
+        );
+    }
+
+    #[tokio::test]
+    async fn sut_waits_on_future_sleep_returns() {
+        // Arrange
+        let policy = Fixed {
+            delay: Duration::from_secs(60),
+            max_attempts: 3,
+        };
+        let slept = Mutex::new(vec![]);
+
+        // Act
+        let actual = retry_async_with_sleep(
+            &policy,
+            |delay| {
+                slept.lock().unwrap().push(delay);
+                async {}
+            },
+            || async { Err::<(), _>("down") },
+        )
+        .await;
+
+        // Assert
+        assert_eq!(Err("down"), actual);
+        assert_eq!(
+            vec![Duration::from_secs(60), Duration::from_secs(60)],
+            *slept.lock().unwrap()
+        );
+    }
+}
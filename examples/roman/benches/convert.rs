@@ -0,0 +1,51 @@
+use criterion::BenchmarkId;
+use criterion::Criterion;
+use criterion::black_box;
+use criterion::criterion_group;
+use criterion::criterion_main;
+
+use roman::v10;
+use roman::v11;
+
+const ARABICS: [usize; 3] = [4, 1984, 3888];
+
+type ToRoman = fn(usize) -> String;
+type ToArabic = fn(&str) -> usize;
+
+/// Compares subtracting numerals one at a time with looking up each digit.
+pub fn bench_convert_to_roman(c: &mut Criterion) {
+    let strategies: [(&str, ToRoman); 2] = [
+        ("subtract", v10::convert_to_roman),
+        ("lookup", v11::convert_to_roman),
+    ];
+    let mut group = c.benchmark_group("convert to roman");
+    for arabic in ARABICS {
+        for (name, strategy) in strategies {
+            group.bench_with_input(BenchmarkId::new(name, arabic), &arabic, |b, &arabic| {
+                b.iter(|| strategy(black_box(arabic)))
+            });
+        }
+    }
+    group.finish();
+}
+
+/// Compares stripping numerals one at a time with a single pass.
+pub fn bench_convert_to_arabic(c: &mut Criterion) {
+    let strategies: [(&str, ToArabic); 2] = [
+        ("strip", v10::convert_to_arabic),
+        ("single pass", v11::convert_to_arabic),
+    ];
+    let mut group = c.benchmark_group("convert to arabic");
+    for arabic in ARABICS {
+        let roman = v10::convert_to_roman(arabic);
+        for (name, strategy) in strategies {
+            group.bench_with_input(BenchmarkId::new(name, &roman), &roman, |b, roman| {
+                b.iter(|| strategy(black_box(roman)))
+            });
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_convert_to_roman, bench_convert_to_arabic);
+criterion_main!(benches);
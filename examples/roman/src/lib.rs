@@ -1,5 +1,16 @@
+//! The conversions only need `alloc` for the `String`s they build, so
+//! without the default `std` feature the crate is `no_std`.
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+
 pub mod v1;
 pub mod v10;
+pub mod v11;
 pub mod v2;
 pub mod v3;
 pub mod v4;
@@ -0,0 +1,21 @@
+//! Generators of roman numerals for property tests, shared with the crates
+//! depending on this one through the `testing` feature.
+
+use alloc::string::String;
+
+use proptest::strategy::Strategy;
+
+use crate::v11::convert_to_roman;
+
+/// The largest number written with the numerals up to `M`.
+pub const MAX_ARABIC: usize = 3999;
+
+/// Generates the numbers that can be written in roman numerals, 1 to 3999.
+pub fn arabic() -> impl Strategy<Value = usize> {
+    1..=MAX_ARABIC
+}
+
+/// Generates a number along with how it is written in roman numerals.
+pub fn roman_numeral() -> impl Strategy<Value = (usize, String)> {
+    arabic().prop_map(|arabic| (arabic, convert_to_roman(arabic)))
+}
@@ -1,3 +1,6 @@
+use alloc::string::String;
+use alloc::string::ToString;
+
 pub fn convert_to_roman(arabic: usize) -> String {
     "I".to_string()
 }
@@ -0,0 +1,86 @@
+use alloc::string::String;
+
+const THOUSANDS: [&str; 4] = ["", "M", "MM", "MMM"];
+const HUNDREDS: [&str; 10] = ["", "C", "CC", "CCC", "CD", "D", "DC", "DCC", "DCCC", "CM"];
+const TENS: [&str; 10] = ["", "X", "XX", "XXX", "XL", "L", "LX", "LXX", "LXXX", "XC"];
+const ONES: [&str; 10] = ["", "I", "II", "III", "IV", "V", "VI", "VII", "VIII", "IX"];
+
+/// Converts digit by digit, looking each one up in the table of its place,
+/// instead of subtracting numerals one at a time as `v10` does.
+pub fn convert_to_roman(arabic: usize) -> String {
+    [
+        THOUSANDS[arabic / 1000],
+        HUNDREDS[arabic / 100 % 10],
+        TENS[arabic / 10 % 10],
+        ONES[arabic % 10],
+    ]
+    .concat()
+}
+
+fn value_of(symbol: u8) -> usize {
+    match symbol {
+        b'I' => 1,
+        b'V' => 5,
+        b'X' => 10,
+        b'L' => 50,
+        b'C' => 100,
+        b'D' => 500,
+        b'M' => 1000,
+        _ => 0,
+    }
+}
+
+/// Converts in a single pass over the symbols from the right, subtracting a
+/// symbol that is smaller than the one after it and adding any other.
+pub fn convert_to_arabic(roman: &str) -> usize {
+    let mut arabic = 0;
+    let mut next = 0;
+    for value in roman.bytes().rev().map(value_of) {
+        if value < next {
+            arabic -= value;
+        } else {
+            arabic += value;
+        }
+        next = value;
+    }
+    arabic
+}
+
+#[cfg(test)]
+mod specs_for_convert {
+    use super::convert_to_arabic;
+    use super::convert_to_roman;
+    use crate::testing::roman_numeral;
+
+    #[rstest::rstest]
+    #[case(1, "I")]
+    #[case(4, "IV")]
+    #[case(9, "IX")]
+    #[case(14, "XIV")]
+    #[case(40, "XL")]
+    #[case(49, "XLIX")]
+    #[case(90, "XC")]
+    #[case(400, "CD")]
+    #[case(798, "DCCXCVIII")]
+    #[case(900, "CM")]
+    #[case(1006, "MVI")]
+    #[case(1984, "MCMLXXXIV")]
+    #[case(3999, "MMMCMXCIX")]
+    fn sut_converts_both_ways_correctly(#[case] arabic: usize, #[case] roman: &str) {
+        // Act
+        let actual_roman = convert_to_roman(arabic);
+        let actual_arabic = convert_to_arabic(roman);
+
+        // Assert
+        assert_eq!(roman, actual_roman);
+        assert_eq!(arabic, actual_arabic);
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn convert_agrees_with_v10((arabic, roman) in roman_numeral()) {
+            proptest::prop_assert_eq!(crate::v10::convert_to_roman(arabic), roman.clone());
+            proptest::prop_assert_eq!(arabic, convert_to_arabic(&roman));
+        }
+    }
+}
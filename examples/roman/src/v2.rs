@@ -1,3 +1,5 @@
+use alloc::string::String;
+
 pub fn convert_to_roman(arabic: usize) -> String {
     let mut roman = String::new();
     for _ in 0..arabic {
@@ -1,3 +1,5 @@
+use alloc::string::String;
+
 pub fn convert_to_roman(mut arabic: usize) -> String {
     let mut roman = String::new();
     while arabic > 0 {
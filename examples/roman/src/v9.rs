@@ -1,3 +1,5 @@
+use alloc::string::String;
+
 struct RomanNumeral(usize, &'static str);
 
 const ALL_ROMAN_NUMERALS: [RomanNumeral; 13] = [
@@ -0,0 +1,38 @@
+use std::env;
+use std::process::ExitCode;
+
+use route_planner::v1::a_star;
+use route_planner::v1::text;
+
+const MAP: &str = "
+# A few towns, with roads winding a little between them.
+place harbour 0 0
+place mill 4 3
+place market 8 0
+place castle 12 4
+place abbey 6 9
+road harbour mill 6
+road harbour market 9
+road mill market 5
+road mill abbey 7
+road market castle 6
+road abbey castle 9
+";
+
+fn main() -> ExitCode {
+    let mut args = env::args().skip(1);
+    let from = args.next().unwrap_or_else(|| "harbour".to_string());
+    let to = args.next().unwrap_or_else(|| "castle".to_string());
+
+    let graph = text::load(MAP).expect("map is well-formed");
+    match a_star::shortest_path(&graph, &from, &to) {
+        Ok(path) => {
+            println!("{} ({})", path.places.join(" -> "), path.length);
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("{error}");
+            ExitCode::FAILURE
+        }
+    }
+}
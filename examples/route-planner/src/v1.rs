@@ -0,0 +1,326 @@
+pub mod a_star;
+pub mod dijkstra;
+pub mod text;
+
+use std::collections::HashMap;
+
+/// `Graph` is a map of places joined by two-way roads. Every place sits at
+/// a point on a plane, and no road is shorter than the straight line
+/// between its ends, so that the straight line can guide A*.
+#[derive(Clone, Debug, Default)]
+pub struct Graph {
+    places: Vec<Place>,
+    ids: HashMap<String, usize>,
+    roads: Vec<Vec<Road>>,
+}
+
+#[derive(Clone, Debug)]
+struct Place {
+    name: String,
+    x: i64,
+    y: i64,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Road {
+    to: usize,
+    length: u64,
+}
+
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum GraphError {
+    #[error("place {0} is already on the map")]
+    DuplicatePlace(String),
+    #[error("place {0} is not on the map")]
+    UnknownPlace(String),
+    #[error("road from {from} to {to} is shorter than the straight line between them")]
+    ShorterThanStraightLine { from: String, to: String },
+}
+
+impl Graph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_place(&mut self, name: &str, x: i64, y: i64) -> Result<(), GraphError> {
+        if self.ids.contains_key(name) {
+            return Err(GraphError::DuplicatePlace(name.to_string()));
+        }
+        self.ids.insert(name.to_string(), self.places.len());
+        self.places.push(Place {
+            name: name.to_string(),
+            x,
+            y,
+        });
+        self.roads.push(vec![]);
+        Ok(())
+    }
+
+    /// Joins `from` and `to` with a road of `length`, both ways.
+    pub fn add_road(&mut self, from: &str, to: &str, length: u64) -> Result<(), GraphError> {
+        let from_id = self.id(from)?;
+        let to_id = self.id(to)?;
+        if length < self.straight_line(from_id, to_id) {
+            return Err(GraphError::ShorterThanStraightLine {
+                from: from.to_string(),
+                to: to.to_string(),
+            });
+        }
+        self.roads[from_id].push(Road { to: to_id, length });
+        self.roads[to_id].push(Road {
+            to: from_id,
+            length,
+        });
+        Ok(())
+    }
+
+    fn id(&self, name: &str) -> Result<usize, GraphError> {
+        self.ids
+            .get(name)
+            .copied()
+            .ok_or_else(|| GraphError::UnknownPlace(name.to_string()))
+    }
+
+    /// Returns the straight line between two places, rounded down so that
+    /// it never overestimates a route.
+    fn straight_line(&self, from: usize, to: usize) -> u64 {
+        let (from, to) = (&self.places[from], &self.places[to]);
+        let (dx, dy) = ((from.x - to.x) as f64, (from.y - to.y) as f64);
+        dx.hypot(dy).floor() as u64
+    }
+}
+
+/// `Path` is a route through the places from start to end, and how long
+/// it is.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Path {
+    pub places: Vec<String>,
+    pub length: u64,
+}
+
+/// `NoRoute` tells that no road leads from one place to the other,
+/// including when either of them is not on the map.
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+#[error("no route from {from} to {to}")]
+pub struct NoRoute {
+    pub from: String,
+    pub to: String,
+}
+
+impl NoRoute {
+    fn new(from: &str, to: &str) -> Self {
+        NoRoute {
+            from: from.to_string(),
+            to: to.to_string(),
+        }
+    }
+}
+
+/// Walks the places each search came from back from `to`.
+fn path(graph: &Graph, came_from: &[Option<usize>], to: usize, length: u64) -> Path {
+    let mut places = vec![graph.places[to].name.clone()];
+    let mut current = to;
+    while let Some(previous) = came_from[current] {
+        places.push(graph.places[previous].name.clone());
+        current = previous;
+    }
+    places.reverse();
+    Path { places, length }
+}
+
+#[cfg(test)]
+mod specs_for_graph {
+    use super::Graph;
+    use super::GraphError;
+
+    #[rstest::rstest]
+    #[case::along_straight_line(5, Ok(()))]
+    #[case::winding(9, Ok(()))]
+    #[case::shorter_than_straight_line(4, Err(GraphError::ShorterThanStraightLine {
+        from: "a".to_string(),
+        to: "b".to_string(),
+    }))]
+    fn sut_accepts_roads_no_shorter_than_straight_line(
+        #[case] length: u64,
+        #[case] expected: Result<(), GraphError>,
+    ) {
+        // Arrange
+        let mut sut = Graph::new();
+        sut.add_place("a", 0, 0).unwrap();
+        sut.add_place("b", 3, 4).unwrap();
+
+        // Act
+        let actual = sut.add_road("a", "b", length);
+
+        // Assert
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn sut_rejects_duplicate_place() {
+        // Arrange
+        let mut sut = Graph::new();
+        sut.add_place("a", 0, 0).unwrap();
+
+        // Act
+        let actual = sut.add_place("a", 1, 1);
+
+        // Assert
+        assert_eq!(Err(GraphError::DuplicatePlace("a".to_string())), actual);
+    }
+
+    #[test]
+    fn sut_rejects_road_to_unknown_place() {
+        // Arrange
+        let mut sut = Graph::new();
+        sut.add_place("a", 0, 0).unwrap();
+
+        // Act
+        let actual = sut.add_road("a", "b", 1);
+
+        // Assert
+        assert_eq!(Err(GraphError::UnknownPlace("b".to_string())), actual);
+    }
+}
+
+/// Specs both searches have to meet, with properties comparing them on
+/// random graphs.
+#[cfg(test)]
+mod specs_for_shortest_path {
+    use proptest::prelude::*;
+
+    use super::Graph;
+    use super::NoRoute;
+    use super::Path;
+    use super::a_star;
+    use super::dijkstra;
+    use super::text;
+
+    type ShortestPath = fn(&Graph, &str, &str) -> Result<Path, NoRoute>;
+
+    /// ```text
+    ///   b --- d
+    ///  /     / \
+    /// a --- c   e     f
+    /// ```
+    const MAP: &str = "
+        place a 0 0
+        place b 2 2
+        place c 4 0
+        place d 6 2
+        place e 8 0
+        place f 12 0
+        road a b 3
+        road a c 5
+        road b d 4
+        road c d 3
+        road d e 3
+    ";
+
+    fn path(places: &[&str], length: u64) -> Path {
+        Path {
+            places: places.iter().map(|place| place.to_string()).collect(),
+            length,
+        }
+    }
+
+    #[rstest::rstest]
+    #[case::same_place("a", "a", path(&["a"], 0))]
+    #[case::neighbours("a", "c", path(&["a", "c"], 5))]
+    #[case::shorter_of_two_ways("a", "d", path(&["a", "b", "d"], 7))]
+    #[case::backwards("e", "a", path(&["e", "d", "b", "a"], 10))]
+    fn sut_finds_shortest_path(
+        #[values(dijkstra::shortest_path, a_star::shortest_path)] sut: ShortestPath,
+        #[case] from: &str,
+        #[case] to: &str,
+        #[case] expected: Path,
+    ) {
+        // Arrange
+        let graph = text::load(MAP).unwrap();
+
+        // Act
+        let actual = sut(&graph, from, to);
+
+        // Assert
+        assert_eq!(Ok(expected), actual);
+    }
+
+    #[rstest::rstest]
+    #[case::unreachable("a", "f")]
+    #[case::unknown_start("x", "a")]
+    #[case::unknown_end("a", "x")]
+    fn sut_tells_there_is_no_route(
+        #[values(dijkstra::shortest_path, a_star::shortest_path)] sut: ShortestPath,
+        #[case] from: &str,
+        #[case] to: &str,
+    ) {
+        // Arrange
+        let graph = text::load(MAP).unwrap();
+
+        // Act
+        let actual = sut(&graph, from, to);
+
+        // Assert
+        assert_eq!(Err(NoRoute::new(from, to)), actual);
+    }
+
+    /// Generates graphs of up to 8 places, each road up to 10 longer than
+    /// the straight line.
+    fn graphs() -> impl Strategy<Value = Graph> {
+        (2..8usize)
+            .prop_flat_map(|n| {
+                (
+                    prop::collection::vec((0..20i64, 0..20i64), n),
+                    prop::collection::vec((0..n, 0..n, 0..10u64), 0..n * 2),
+                )
+            })
+            .prop_map(|(places, roads)| {
+                let mut graph = Graph::new();
+                for (i, (x, y)) in places.into_iter().enumerate() {
+                    graph.add_place(&i.to_string(), x, y).unwrap();
+                }
+                for (from, to, detour) in roads {
+                    let length = graph.straight_line(from, to) + 1 + detour;
+                    graph
+                        .add_road(&from.to_string(), &to.to_string(), length)
+                        .unwrap();
+                }
+                graph
+            })
+    }
+
+    fn length_along(graph: &Graph, places: &[String]) -> Option<u64> {
+        places.windows(2).try_fold(0, |length, pair| {
+            let (from, to) = (graph.ids[&pair[0]], graph.ids[&pair[1]]);
+            let road = graph.roads[from].iter().filter(|road| road.to == to);
+            Some(length + road.map(|road| road.length).min()?)
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn a_star_finds_path_as_short_as_dijkstra(graph in graphs(), from in 0..8usize, to in 0..8usize) {
+            let (from, to) = (from.to_string(), to.to_string());
+            let expected = dijkstra::shortest_path(&graph, &from, &to).map(|path| path.length);
+            let actual = a_star::shortest_path(&graph, &from, &to).map(|path| path.length);
+            prop_assert_eq!(expected, actual);
+        }
+
+        #[test]
+        fn path_is_as_long_as_its_roads(
+            graph in graphs(),
+            from in 0..8usize,
+            to in 0..8usize,
+            sut in prop::sample::select(vec![
+                dijkstra::shortest_path as ShortestPath,
+                a_star::shortest_path,
+            ]),
+        ) {
+            if let Ok(path) = sut(&graph, &from.to_string(), &to.to_string()) {
+                prop_assert_eq!(Some(path.length), length_along(&graph, &path.places));
+                prop_assert_eq!(Some(&from.to_string()), path.places.first());
+                prop_assert_eq!(Some(&to.to_string()), path.places.last());
+            }
+        }
+    }
+}
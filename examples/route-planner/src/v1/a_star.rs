@@ -0,0 +1,41 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use super::Graph;
+use super::NoRoute;
+use super::Path;
+use super::path;
+
+/// Finds the shortest path with A*, which tries the places first whose
+/// distance from `from` plus the straight line to `to` is the least. As
+/// the straight line never overestimates, the first path to reach `to` is
+/// a shortest one.
+pub fn shortest_path(graph: &Graph, from: &str, to: &str) -> Result<Path, NoRoute> {
+    let (Ok(start), Ok(end)) = (graph.id(from), graph.id(to)) else {
+        return Err(NoRoute::new(from, to));
+    };
+    let estimate = |place: usize, distance: u64| distance + graph.straight_line(place, end);
+
+    let mut distances = vec![u64::MAX; graph.places.len()];
+    let mut came_from = vec![None; graph.places.len()];
+    let mut frontier = BinaryHeap::from([Reverse((estimate(start, 0), 0, start))]);
+    distances[start] = 0;
+
+    while let Some(Reverse((_, distance, place))) = frontier.pop() {
+        if place == end {
+            return Ok(path(graph, &came_from, end, distance));
+        }
+        if distance > distances[place] {
+            continue;
+        }
+        for road in &graph.roads[place] {
+            let next = distance + road.length;
+            if next < distances[road.to] {
+                distances[road.to] = next;
+                came_from[road.to] = Some(place);
+                frontier.push(Reverse((estimate(road.to, next), next, road.to)));
+            }
+        }
+    }
+    Err(NoRoute::new(from, to))
+}
@@ -0,0 +1,102 @@
+use super::Graph;
+use super::GraphError;
+
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum LoadError {
+    #[error("line {line}: expected `place <name> <x> <y>` or `road <from> <to> <length>`")]
+    Syntax { line: usize },
+    #[error("line {line}: {source}")]
+    Graph {
+        line: usize,
+        #[source]
+        source: GraphError,
+    },
+}
+
+/// Loads a graph from text with a place or a road per line:
+///
+/// ```text
+/// # Places come before the roads joining them.
+/// place home 0 0
+/// place work 3 4
+/// road home work 6
+/// ```
+///
+/// Blank lines and lines starting with `#` are skipped. Lines in errors are
+/// counted from 1.
+pub fn load(text: &str) -> Result<Graph, LoadError> {
+    let mut graph = Graph::new();
+    for (i, line) in text.lines().enumerate() {
+        let line_number = i + 1;
+        let fields = line.split_whitespace().collect::<Vec<_>>();
+        let result = match fields[..] {
+            [] => continue,
+            [comment, ..] if comment.starts_with('#') => continue,
+            ["place", name, x, y] => match (x.parse(), y.parse()) {
+                (Ok(x), Ok(y)) => graph.add_place(name, x, y),
+                _ => return Err(LoadError::Syntax { line: line_number }),
+            },
+            ["road", from, to, length] => match length.parse() {
+                Ok(length) => graph.add_road(from, to, length),
+                Err(_) => return Err(LoadError::Syntax { line: line_number }),
+            },
+            _ => return Err(LoadError::Syntax { line: line_number }),
+        };
+        result.map_err(|source| LoadError::Graph {
+            line: line_number,
+            source,
+        })?;
+    }
+    Ok(graph)
+}
+
+#[cfg(test)]
+mod specs_for_load {
+    use super::LoadError;
+    use super::load;
+    use crate::v1::GraphError;
+    use crate::v1::Path;
+    use crate::v1::dijkstra;
+
+    #[test]
+    fn sut_loads_places_and_roads() {
+        // Arrange
+        let text = "# Commute\nplace home 0 0\n\nplace work 3 4\nroad home work 6\n";
+
+        // Act
+        let actual = load(text).unwrap();
+
+        // Assert
+        let expected = Path {
+            places: vec!["home".to_string(), "work".to_string()],
+            length: 6,
+        };
+        assert_eq!(
+            Ok(expected),
+            dijkstra::shortest_path(&actual, "home", "work")
+        );
+    }
+
+    #[rstest::rstest]
+    #[case::unknown_keyword("place a 0 0\nstreet a a 1", 2)]
+    #[case::missing_field("place a 0", 1)]
+    #[case::extra_field("place a 0 0 0", 1)]
+    #[case::coordinate_not_a_number("place a x 0", 1)]
+    #[case::negative_length("place a 0 0\nroad a a -1", 2)]
+    fn sut_points_at_malformed_line(#[case] text: &str, #[case] line: usize) {
+        assert_eq!(Err(LoadError::Syntax { line }), load(text).map(|_| ()));
+    }
+
+    #[test]
+    fn sut_points_at_line_the_graph_rejects() {
+        // Act
+        let actual = load("place a 0 0\n# b comes too late\nroad a b 1\nplace b 1 0");
+
+        // Assert
+        let expected = LoadError::Graph {
+            line: 3,
+            source: GraphError::UnknownPlace("b".to_string()),
+        };
+        assert_eq!(Err(expected), actual.map(|_| ()));
+    }
+}
@@ -0,0 +1,136 @@
+//! Servers the examples' specs talk HTTP to, each arranged in one line.
+//!
+//! - `delayed_server` answers every request after a delay.
+//! - `status_server` answers every request with a status.
+//! - `flaky_server` fails the first requests, then answers the rest.
+//! - `slow_body_server` sends the headers at once and the body slowly.
+//! - `unreachable_url` is a URL nothing listens on.
+//!
+//! Every server answers `GET` on any path.
+
+mod slow_body;
+
+use std::net::TcpListener;
+use std::time::Duration;
+
+use wiremock::Mock;
+use wiremock::MockServer;
+use wiremock::ResponseTemplate;
+use wiremock::matchers::method;
+
+pub use slow_body::SlowBodyServer;
+pub use slow_body::slow_body_server;
+
+/// Starts a server answering 200 after `delay`.
+pub async fn delayed_server(delay: Duration) -> MockServer {
+    serve(ResponseTemplate::new(200).set_delay(delay)).await
+}
+
+/// Starts a server answering `status` at once.
+pub async fn status_server(status: u16) -> MockServer {
+    serve(ResponseTemplate::new(status)).await
+}
+
+/// Starts a server answering its first `failures` requests with `failure`,
+/// and 200 at once to the ones after. A `failure` with a delay longer than
+/// the client's timeout makes those requests time out.
+pub async fn flaky_server(failures: u64, failure: ResponseTemplate) -> MockServer {
+    let server = MockServer::start().await;
+    if failures > 0 {
+        Mock::given(method("GET"))
+            .respond_with(failure)
+            .up_to_n_times(failures)
+            .with_priority(1)
+            .mount(&server)
+            .await;
+    }
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&server)
+        .await;
+    server
+}
+
+/// Returns a URL on a local port nothing listens on, so connecting to it is
+/// refused at once instead of waiting on DNS or a timeout.
+pub fn unreachable_url() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    format!("http://{}", address)
+}
+
+async fn serve(response: ResponseTemplate) -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(response)
+        .mount(&server)
+        .await;
+    server
+}
+
+#[cfg(test)]
+mod specs_for_scenarios {
+    use std::time::Duration;
+    use std::time::Instant;
+
+    use wiremock::ResponseTemplate;
+
+    use super::delayed_server;
+    use super::flaky_server;
+    use super::status_server;
+    use super::unreachable_url;
+
+    async fn status_of(url: &str) -> u16 {
+        reqwest::get(url).await.unwrap().status().as_u16()
+    }
+
+    #[tokio::test]
+    async fn sut_answers_after_delay() {
+        // Arrange
+        let server = delayed_server(Duration::from_millis(50)).await;
+
+        // Act
+        let start = Instant::now();
+        let actual = status_of(&server.uri()).await;
+
+        // Assert
+        assert_eq!(200, actual);
+        assert!(start.elapsed() >= Duration::from_millis(50));
+    }
+
+    #[tokio::test]
+    async fn sut_answers_with_status() {
+        // Arrange
+        let server = status_server(503).await;
+
+        // Act
+        let actual = status_of(&server.uri()).await;
+
+        // Assert
+        assert_eq!(503, actual);
+    }
+
+    #[tokio::test]
+    async fn sut_fails_first_requests_then_answers() {
+        // Arrange
+        let server = flaky_server(2, ResponseTemplate::new(500)).await;
+
+        // Act
+        let mut actual = vec![];
+        for _ in 0..3 {
+            actual.push(status_of(&server.uri()).await);
+        }
+
+        // Assert
+        assert_eq!(vec![500, 500, 200], actual);
+    }
+
+    #[tokio::test]
+    async fn sut_refuses_connection_to_unreachable_url() {
+        // Act
+        let actual = reqwest::get(unreachable_url()).await.unwrap_err();
+
+        // Assert
+        assert!(actual.is_connect());
+    }
+}
@@ -0,0 +1,98 @@
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use tokio::io::AsyncReadExt;
+use tokio::io::AsyncWriteExt;
+use tokio::net::TcpListener;
+use tokio::net::TcpStream;
+use tokio::task::JoinHandle;
+
+/// `SlowBodyServer` answers every request with 200 and its headers at once,
+/// then sends the body one byte at a time. It stops when dropped.
+///
+/// wiremock can only delay a whole response, so this server writes HTTP
+/// itself.
+pub struct SlowBodyServer {
+    address: SocketAddr,
+    task: JoinHandle<()>,
+}
+
+impl SlowBodyServer {
+    pub fn uri(&self) -> String {
+        format!("http://{}", self.address)
+    }
+}
+
+impl Drop for SlowBodyServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Starts a server sending `body` one byte every `delay`, after headers
+/// sent at once.
+pub async fn slow_body_server(body: &str, delay: Duration) -> SlowBodyServer {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    let body = body.as_bytes().to_vec();
+    let task = tokio::spawn(async move {
+        while let Ok((stream, _)) = listener.accept().await {
+            tokio::spawn(answer(stream, body.clone(), delay));
+        }
+    });
+    SlowBodyServer { address, task }
+}
+
+async fn answer(mut stream: TcpStream, body: Vec<u8>, delay: Duration) -> std::io::Result<()> {
+    read_request_head(&mut stream).await?;
+    let head = format!(
+        "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        body.len()
+    );
+    stream.write_all(head.as_bytes()).await?;
+    for byte in body {
+        tokio::time::sleep(delay).await;
+        stream.write_all(&[byte]).await?;
+    }
+    stream.shutdown().await
+}
+
+/// Reads up to the blank line ending the head of the request. The specs
+/// only send `GET`s, so there is no body to read after it.
+async fn read_request_head(stream: &mut TcpStream) -> std::io::Result<()> {
+    let mut head = Vec::new();
+    let mut buffer = [0; 1024];
+    while !head.ends_with(b"\r\n\r\n") {
+        let read = stream.read(&mut buffer).await?;
+        if read == 0 {
+            break;
+        }
+        head.extend_from_slice(&buffer[..read]);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod specs_for_slow_body_server {
+    use std::time::Duration;
+    use std::time::Instant;
+
+    use super::slow_body_server;
+
+    #[tokio::test]
+    async fn sut_sends_headers_at_once_and_body_slowly() {
+        // Arrange
+        let server = slow_body_server("abc", Duration::from_millis(30)).await;
+
+        // Act
+        let start = Instant::now();
+        let response = reqwest::get(server.uri()).await.unwrap();
+        let headers_after = start.elapsed();
+        let actual = response.text().await.unwrap();
+
+        // Assert
+        assert_eq!("abc", actual);
+        assert!(headers_after < Duration::from_millis(30));
+        assert!(start.elapsed() >= Duration::from_millis(90));
+    }
+}
@@ -26,19 +26,17 @@ async fn measure_response_duration(client: &Client, url: &str) -> Duration {
 mod specs_for_race {
     use std::time::Duration;
 
-    use wiremock::Mock;
-    use wiremock::MockServer;
-    use wiremock::ResponseTemplate;
-    use wiremock::matchers::method;
-    use wiremock::matchers::path;
+    use scenarios::delayed_server;
 
     use super::race;
 
     #[tokio::test]
     async fn sut_returns_fastest_url_correctly() {
         // Arrange
-        let slow_url = arrange_server(Some(Duration::from_millis(20))).await;
-        let fast_url = arrange_server(None).await;
+        let slow_server = delayed_server(Duration::from_millis(20)).await;
+        let slow_url = slow_server.uri();
+        let fast_server = delayed_server(Duration::ZERO).await;
+        let fast_url = fast_server.uri();
 
         // Act
         let actual = race(&slow_url, &fast_url).await.unwrap();
@@ -47,14 +45,4 @@ mod specs_for_race {
         let expected = &fast_url;
         assert_eq!(expected, actual);
     }
-
-    async fn arrange_server(delay: Option<Duration>) -> String {
-        let server = MockServer::start().await;
-        Mock::given(method("GET"))
-            .and(path("/"))
-            .respond_with(ResponseTemplate::new(200).set_delay(delay.unwrap_or_default()))
-            .mount(&server)
-            .await;
-        server.uri()
-    }
 }
@@ -41,11 +41,8 @@ async fn ping<'a>(client: &'a Client, url: &str, timeout: Option<Duration>) -> R
 mod specs_for_race {
     use std::time::Duration;
 
-    use wiremock::Mock;
-    use wiremock::MockServer;
-    use wiremock::ResponseTemplate;
-    use wiremock::matchers::method;
-    use wiremock::matchers::path;
+    use scenarios::delayed_server;
+    use scenarios::unreachable_url;
 
     use super::race;
     use super::race_with_configuration;
@@ -53,8 +50,10 @@ mod specs_for_race {
     #[tokio::test]
     async fn sut_returns_fastest_url_correctly() {
         // Arrange
-        let slow_url = arrange_server(Some(Duration::from_millis(20))).await;
-        let fast_url = arrange_server(None).await;
+        let slow_server = delayed_server(Duration::from_millis(20)).await;
+        let slow_url = slow_server.uri();
+        let fast_server = delayed_server(Duration::ZERO).await;
+        let fast_url = fast_server.uri();
 
         // Act
         let actual = race(&slow_url, &fast_url).await.unwrap();
@@ -67,8 +66,9 @@ mod specs_for_race {
     #[tokio::test]
     async fn sut_ignores_result_of_sending_failure_request() {
         // Arrange
-        let not_working_url = arrange_server_with_error();
-        let working_url = arrange_server(Some(Duration::from_millis(20))).await;
+        let not_working_url = unreachable_url();
+        let working_server = delayed_server(Duration::from_millis(20)).await;
+        let working_url = working_server.uri();
 
         // Act
         let actual = race(&not_working_url, &working_url).await.unwrap();
@@ -81,7 +81,7 @@ mod specs_for_race {
     #[tokio::test]
     async fn sut_returns_error_if_two_urls_are_failed_to_send_request() {
         // Arrange
-        let not_working_url = arrange_server_with_error();
+        let not_working_url = unreachable_url();
 
         // Act
         let actual = race(&not_working_url, &not_working_url).await.unwrap_err();
@@ -95,8 +95,10 @@ mod specs_for_race {
     async fn sut_returns_error_if_a_server_does_not_respond_within_timeout() {
         // Arrange
         let timeout = Some(Duration::from_millis(20));
-        let url_1 = arrange_server(Some(Duration::from_millis(22))).await;
-        let url_2 = arrange_server(Some(Duration::from_millis(22))).await;
+        let server_1 = delayed_server(Duration::from_millis(22)).await;
+        let url_1 = server_1.uri();
+        let server_2 = delayed_server(Duration::from_millis(22)).await;
+        let url_2 = server_2.uri();
 
         // Act
         let actual = race_with_configuration(&url_1, &url_2, timeout)
@@ -107,18 +109,4 @@ mod specs_for_race {
         let expected = "no successful response received";
         assert_eq!(expected, actual);
     }
-
-    async fn arrange_server(delay: Option<Duration>) -> String {
-        let server = MockServer::start().await;
-        Mock::given(method("GET"))
-            .and(path("/"))
-            .respond_with(ResponseTemplate::new(200).set_delay(delay.unwrap_or_default()))
-            .mount(&server)
-            .await;
-        server.uri()
-    }
-
-    fn arrange_server_with_error() -> String {
-        "http://non-existent.url".to_string()
-    }
 }
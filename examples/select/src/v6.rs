@@ -0,0 +1,336 @@
+use std::time::Duration;
+
+use cancellation::Scope;
+use futures::FutureExt;
+use futures::StreamExt;
+use futures::future::select_ok;
+use futures::stream::FuturesUnordered;
+use reqwest::Client;
+use retry::v1::Exponential;
+use retry::v1::Policy;
+use retry::v1::Selective;
+use retry::v1::retry_async;
+
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+pub(crate) const DEFAULT_BACKOFF: Exponential = Exponential {
+    initial: Duration::from_millis(50),
+    max_delay: Duration::from_secs(1),
+    max_attempts: 3,
+};
+
+/// Returns the policy `race` pings with: a URL that timed out or could not
+/// be connected to is pinged again, as the failure may not last.
+pub fn default_retry() -> impl Policy<reqwest::Error> {
+    Selective::new(DEFAULT_BACKOFF, |error: &reqwest::Error| {
+        error.is_timeout() || error.is_connect()
+    })
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RaceError {
+    /// Neither URL answered; the source is why the one failing last did not.
+    #[error("no successful response received: {0}")]
+    NoSuccessfulResponse(#[source] PingError),
+
+    /// The race was cancelled before either URL answered.
+    #[error("race was cancelled")]
+    Cancelled,
+
+    /// `race_all` was given no URL to race.
+    #[error("no URL to race")]
+    NoUrls,
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("failed to ping {url}: {source}")]
+pub struct PingError {
+    pub url: String,
+    #[source]
+    pub source: reqwest::Error,
+}
+
+pub async fn race<'a>(url_1: &'a str, url_2: &'a str) -> Result<&'a str, RaceError> {
+    race_with_configuration(url_1, url_2, Some(DEFAULT_TIMEOUT), &default_retry()).await
+}
+
+/// Races like `race` until the scope is cancelled, giving up on both pings
+/// then.
+pub async fn race_in<'a>(
+    url_1: &'a str,
+    url_2: &'a str,
+    scope: &Scope,
+) -> Result<&'a str, RaceError> {
+    scope
+        .run(race(url_1, url_2))
+        .await
+        .unwrap_or(Err(RaceError::Cancelled))
+}
+
+/// With the `tracing` feature, the race runs in a `race` span with both
+/// URLs, and the URL answering first is told in a `race.winner` event.
+#[cfg_attr(feature = "tracing", tracing::instrument(name = "race", skip(retry)))]
+pub async fn race_with_configuration<'a>(
+    url_1: &'a str,
+    url_2: &'a str,
+    timeout: Option<Duration>,
+    retry: &impl Policy<reqwest::Error>,
+) -> Result<&'a str, RaceError> {
+    let client = Client::new();
+
+    let pings = [url_1, url_2].map(|url| {
+        ping(&client, url, timeout, retry)
+            .map(move |result| result.map(|_| url))
+            .boxed_local()
+    });
+    let (url, _) = select_ok(pings)
+        .await
+        .map_err(RaceError::NoSuccessfulResponse)?;
+    won(url)
+}
+
+/// Races every URL like `race`, each ping giving up after `timeout`, and
+/// returns the first URL to answer. If none does, the error is why the one
+/// failing last did not.
+#[cfg_attr(feature = "tracing", tracing::instrument(name = "race_all"))]
+pub async fn race_all<'a>(urls: &[&'a str], timeout: Duration) -> Result<&'a str, RaceError> {
+    let client = Client::new();
+    let retry = default_retry();
+
+    let mut pings = urls
+        .iter()
+        .map(|&url| {
+            ping(&client, url, Some(timeout), &retry).map(move |result| result.map(|_| url))
+        })
+        .collect::<FuturesUnordered<_>>();
+    let mut last_error = None;
+    while let Some(result) = pings.next().await {
+        match result {
+            Ok(url) => return won(url),
+            Err(error) => last_error = Some(error),
+        }
+    }
+    Err(last_error.map_or(RaceError::NoUrls, RaceError::NoSuccessfulResponse))
+}
+
+fn won(url: &str) -> Result<&str, RaceError> {
+    #[cfg(feature = "tracing")]
+    tracing::info!(name: "race.winner", url, "answered first");
+    Ok(url)
+}
+
+async fn ping(
+    client: &Client,
+    url: &str,
+    timeout: Option<Duration>,
+    retry: &impl Policy<reqwest::Error>,
+) -> Result<(), PingError> {
+    retry_async(retry, || {
+        let mut request = client.get(url);
+        if let Some(timeout) = timeout {
+            request = request.timeout(timeout);
+        }
+        async move { request.send().await.map(|_| ()) }
+    })
+    .await
+    .map_err(|source| PingError {
+        url: url.to_string(),
+        source,
+    })
+}
+
+#[cfg(test)]
+mod specs_for_race {
+    use std::time::Duration;
+
+    use assertions::assert_err_matches;
+    use cancellation::Scope;
+    use retry::v1::Fixed;
+    use scenarios::delayed_server;
+    use scenarios::flaky_server;
+    use scenarios::unreachable_url;
+    use wiremock::MockServer;
+    use wiremock::ResponseTemplate;
+
+    use super::PingError;
+    use super::RaceError;
+    use super::race;
+    use super::race_all;
+    use super::race_in;
+    use super::race_with_configuration;
+
+    const TIMEOUT: Option<Duration> = Some(Duration::from_millis(20));
+
+    #[tokio::test]
+    async fn sut_returns_fastest_url_correctly() {
+        // Arrange
+        let slow_server = delayed_server(Duration::from_millis(20)).await;
+        let slow_url = slow_server.uri();
+        let fast_server = delayed_server(Duration::ZERO).await;
+        let fast_url = fast_server.uri();
+
+        // Act
+        let actual = race(&slow_url, &fast_url).await.unwrap();
+
+        // Assert
+        let expected = &fast_url;
+        assert_eq!(expected, actual);
+    }
+
+    #[tokio::test]
+    async fn sut_pings_again_url_that_timed_out() {
+        // Arrange
+        let server = arrange_flaky_server().await;
+        let flaky_url = server.uri();
+        let not_working_url = unreachable_url();
+        let retry = Fixed {
+            delay: Duration::ZERO,
+            max_attempts: 2,
+        };
+
+        // Act
+        let actual = race_with_configuration(&flaky_url, &not_working_url, TIMEOUT, &retry).await;
+
+        // Assert
+        assert_eq!(flaky_url, actual.unwrap());
+        assert_eq!(2, server.received_requests().await.unwrap().len());
+    }
+
+    #[tokio::test]
+    async fn sut_returns_error_if_server_times_out_on_last_attempt() {
+        // Arrange
+        let server = arrange_flaky_server().await;
+        let flaky_url = server.uri();
+        let not_working_url = unreachable_url();
+        let retry = Fixed {
+            delay: Duration::ZERO,
+            max_attempts: 1,
+        };
+
+        // Act
+        let actual = race_with_configuration(&flaky_url, &not_working_url, TIMEOUT, &retry).await;
+
+        // Assert
+        assert_err_matches!(
+            actual,
+            RaceError::NoSuccessfulResponse(PingError { ref source, .. }) if source.is_timeout() || source.is_connect()
+        );
+    }
+
+    #[tokio::test]
+    async fn sut_returns_cancelled_error_if_scope_is_cancelled_before_any_answer() {
+        // Arrange
+        let slow_server = delayed_server(Duration::from_secs(10)).await;
+        let slow_url = slow_server.uri();
+        let scope = Scope::new();
+        scope.cancel();
+
+        // Act
+        let actual = race_in(&slow_url, &slow_url, &scope).await;
+
+        // Assert
+        assert_err_matches!(actual, RaceError::Cancelled);
+    }
+
+    #[tokio::test]
+    async fn sut_returns_fastest_of_many_urls() {
+        // Arrange
+        let slowest_server = delayed_server(Duration::from_millis(60)).await;
+        let slow_server = delayed_server(Duration::from_millis(30)).await;
+        let fast_server = delayed_server(Duration::ZERO).await;
+        let not_working_url = unreachable_url();
+        let urls = [
+            slowest_server.uri(),
+            slow_server.uri(),
+            not_working_url,
+            fast_server.uri(),
+        ];
+        let urls = urls.iter().map(String::as_str).collect::<Vec<_>>();
+
+        // Act
+        let actual = race_all(&urls, Duration::from_secs(1)).await.unwrap();
+
+        // Assert
+        assert_eq!(fast_server.uri(), actual);
+    }
+
+    #[tokio::test]
+    async fn sut_returns_error_if_every_url_fails() {
+        // Arrange
+        let urls = [unreachable_url(), unreachable_url(), unreachable_url()];
+        let urls = urls.iter().map(String::as_str).collect::<Vec<_>>();
+
+        // Act
+        let actual = race_all(&urls, Duration::from_secs(1)).await;
+
+        // Assert
+        assert_err_matches!(
+            actual,
+            RaceError::NoSuccessfulResponse(PingError { ref source, .. }) if source.is_connect()
+        );
+    }
+
+    #[tokio::test]
+    async fn sut_returns_error_if_every_url_times_out() {
+        // Arrange
+        let servers = [
+            delayed_server(Duration::from_secs(1)).await,
+            delayed_server(Duration::from_secs(1)).await,
+            delayed_server(Duration::from_secs(1)).await,
+        ];
+        let urls = servers.iter().map(MockServer::uri).collect::<Vec<_>>();
+        let urls = urls.iter().map(String::as_str).collect::<Vec<_>>();
+
+        // Act
+        let actual = race_all(&urls, Duration::from_millis(20)).await;
+
+        // Assert
+        assert_err_matches!(
+            actual,
+            RaceError::NoSuccessfulResponse(PingError { ref source, .. }) if source.is_timeout()
+        );
+    }
+
+    #[tokio::test]
+    async fn sut_returns_error_if_given_no_url() {
+        // Act
+        let actual = race_all(&[], Duration::from_secs(1)).await;
+
+        // Assert
+        assert_err_matches!(actual, RaceError::NoUrls);
+    }
+
+    /// Arranges a server that takes too long to answer the first request,
+    /// and answers the ones after at once.
+    async fn arrange_flaky_server() -> MockServer {
+        flaky_server(
+            1,
+            ResponseTemplate::new(200).set_delay(Duration::from_millis(200)),
+        )
+        .await
+    }
+
+    #[cfg(feature = "tracing")]
+    #[tokio::test]
+    async fn sut_tells_winner_of_race_in_race_span() {
+        // Arrange
+        let slow_server = delayed_server(Duration::from_millis(20)).await;
+        let slow_url = slow_server.uri();
+        let fast_server = delayed_server(Duration::ZERO).await;
+        let fast_url = fast_server.uri();
+        let capture = test_helpers::TraceCapture::new();
+        let _guard = capture.set_default();
+
+        // Act
+        race(&slow_url, &fast_url).await.unwrap();
+
+        // Assert
+        let races = capture.spans("race");
+        assert_eq!(1, races.len());
+        assert_eq!(slow_url, races[0]["url_1"]);
+        assert_eq!(fast_url, races[0]["url_2"]);
+        let winners = capture.events("race.winner");
+        assert_eq!(1, winners.len());
+        assert_eq!(fast_url, winners[0]["url"]);
+    }
+}
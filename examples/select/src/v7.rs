@@ -0,0 +1,483 @@
+use std::time::Duration;
+use std::time::Instant;
+
+use cancellation::Scope;
+use futures::FutureExt;
+use futures::StreamExt;
+use futures::stream::FuturesUnordered;
+use reqwest::Client;
+use reqwest::StatusCode;
+use retry::v1::Exponential;
+use retry::v1::Policy;
+use retry::v1::Selective;
+use retry::v1::retry_async;
+
+pub use super::v6::PingError;
+pub use super::v6::RaceError;
+
+use super::v6::DEFAULT_BACKOFF;
+
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// `RaceConfig` tells how to race: how many times to ping a URL again
+/// after a timeout or a failed connection, how long each ping may take,
+/// and which URL to ping once every raced one failed. It pings a URL again
+/// twice, gives each ping 10 seconds and has no fallback, unless told
+/// otherwise.
+#[derive(Clone, Debug)]
+pub struct RaceConfig {
+    retries: u32,
+    timeout: Option<Duration>,
+    fallback: Option<String>,
+}
+
+impl RaceConfig {
+    pub fn new() -> Self {
+        RaceConfig {
+            retries: 2,
+            timeout: Some(DEFAULT_TIMEOUT),
+            fallback: None,
+        }
+    }
+
+    pub fn with_retries(self, retries: u32) -> Self {
+        RaceConfig { retries, ..self }
+    }
+
+    /// Gives up on a ping after `timeout`, then pings again if retries are
+    /// left.
+    pub fn with_timeout(self, timeout: Duration) -> Self {
+        RaceConfig {
+            timeout: Some(timeout),
+            ..self
+        }
+    }
+
+    /// Waits for every ping as long as it takes.
+    pub fn without_timeout(self) -> Self {
+        RaceConfig {
+            timeout: None,
+            ..self
+        }
+    }
+
+    /// Pings `url` once every raced URL failed, and returns it if it
+    /// answers.
+    pub fn with_fallback(self, url: impl Into<String>) -> Self {
+        RaceConfig {
+            fallback: Some(url.into()),
+            ..self
+        }
+    }
+
+    fn retry(&self) -> impl Policy<reqwest::Error> {
+        let backoff = Exponential {
+            max_attempts: self.retries.saturating_add(1),
+            ..DEFAULT_BACKOFF
+        };
+        Selective::new(backoff, |error: &reqwest::Error| {
+            error.is_timeout() || error.is_connect()
+        })
+    }
+}
+
+impl Default for RaceConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// `RaceOutcome` is the URL answering first, how long after the start of
+/// the race it answered, and the status it answered with.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RaceOutcome<'a> {
+    pub url: &'a str,
+    pub latency: Duration,
+    pub status: StatusCode,
+}
+
+/// Races both URLs with the default `RaceConfig`. With the `tracing`
+/// feature, the race runs in a `race` span like `race_with_configuration`.
+#[cfg_attr(feature = "tracing", tracing::instrument(name = "race"))]
+pub async fn race<'a>(url_1: &'a str, url_2: &'a str) -> Result<RaceOutcome<'a>, RaceError> {
+    first_to_answer(&[url_1, url_2], &Client::new(), &RaceConfig::new(), None).await
+}
+
+/// Races like `race` until the scope is cancelled, giving up on both pings
+/// then.
+pub async fn race_in<'a>(
+    url_1: &'a str,
+    url_2: &'a str,
+    scope: &Scope,
+) -> Result<RaceOutcome<'a>, RaceError> {
+    scope
+        .run(race(url_1, url_2))
+        .await
+        .unwrap_or(Err(RaceError::Cancelled))
+}
+
+/// Races like `race`, as `config` tells. With the `tracing` feature, the
+/// race runs in a `race` span with both URLs, and the outcome is told in a
+/// `race.winner` event.
+pub async fn race_with_configuration<'a>(
+    url_1: &'a str,
+    url_2: &'a str,
+    config: &'a RaceConfig,
+) -> Result<RaceOutcome<'a>, RaceError> {
+    race_with_client(url_1, url_2, &Client::new(), config).await
+}
+
+/// Races like `race_with_configuration`, pinging with `client`, so that
+/// its headers, proxies and TLS options apply to every ping and its
+/// connections are kept across races.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(name = "race", skip(client, config))
+)]
+pub async fn race_with_client<'a>(
+    url_1: &'a str,
+    url_2: &'a str,
+    client: &Client,
+    config: &'a RaceConfig,
+) -> Result<RaceOutcome<'a>, RaceError> {
+    first_to_answer(&[url_1, url_2], client, config, config.fallback.as_deref()).await
+}
+
+/// Races every URL like `race`, each ping giving up after `timeout`. If no
+/// URL answers, the error is why the one failing last did not.
+#[cfg_attr(feature = "tracing", tracing::instrument(name = "race_all"))]
+pub async fn race_all<'a>(
+    urls: &[&'a str],
+    timeout: Duration,
+) -> Result<RaceOutcome<'a>, RaceError> {
+    let config = RaceConfig::new().with_timeout(timeout);
+    first_to_answer(urls, &Client::new(), &config, None).await
+}
+
+/// Pings every URL of `urls` with `client`, then `fallback` if none
+/// answered, as `config` tells.
+async fn first_to_answer<'a>(
+    urls: &[&'a str],
+    client: &Client,
+    config: &RaceConfig,
+    fallback: Option<&'a str>,
+) -> Result<RaceOutcome<'a>, RaceError> {
+    let retry = config.retry();
+    let start = Instant::now();
+
+    let mut pings = urls
+        .iter()
+        .map(|&url| ping(client, url, config.timeout, &retry).map(move |result| (url, result)))
+        .collect::<FuturesUnordered<_>>();
+    let mut last_error = None;
+    while let Some((url, result)) = pings.next().await {
+        match result {
+            Ok(status) => {
+                return won(RaceOutcome {
+                    url,
+                    latency: start.elapsed(),
+                    status,
+                });
+            }
+            Err(error) => last_error = Some(error),
+        }
+    }
+    if let Some(url) = fallback {
+        let status = ping(client, url, config.timeout, &retry)
+            .await
+            .map_err(RaceError::NoSuccessfulResponse)?;
+        return won(RaceOutcome {
+            url,
+            latency: start.elapsed(),
+            status,
+        });
+    }
+    Err(last_error.map_or(RaceError::NoUrls, RaceError::NoSuccessfulResponse))
+}
+
+fn won(outcome: RaceOutcome<'_>) -> Result<RaceOutcome<'_>, RaceError> {
+    #[cfg(feature = "tracing")]
+    tracing::info!(
+        name: "race.winner",
+        url = outcome.url,
+        latency_ms = outcome.latency.as_millis() as u64,
+        status = outcome.status.as_u16(),
+        "answered first"
+    );
+    Ok(outcome)
+}
+
+async fn ping(
+    client: &Client,
+    url: &str,
+    timeout: Option<Duration>,
+    retry: &impl Policy<reqwest::Error>,
+) -> Result<StatusCode, PingError> {
+    retry_async(retry, || {
+        let mut request = client.get(url);
+        if let Some(timeout) = timeout {
+            request = request.timeout(timeout);
+        }
+        async move { request.send().await.map(|response| response.status()) }
+    })
+    .await
+    .map_err(|source| PingError {
+        url: url.to_string(),
+        source,
+    })
+}
+
+#[cfg(test)]
+mod specs_for_race {
+    use std::time::Duration;
+
+    use assertions::assert_err_matches;
+    use cancellation::Scope;
+    use reqwest::Client;
+    use reqwest::StatusCode;
+    use reqwest::header::HeaderMap;
+    use reqwest::header::HeaderValue;
+    use scenarios::delayed_server;
+    use scenarios::flaky_server;
+    use scenarios::status_server;
+    use scenarios::unreachable_url;
+    use wiremock::MockServer;
+    use wiremock::ResponseTemplate;
+
+    use super::PingError;
+    use super::RaceConfig;
+    use super::RaceError;
+    use super::race;
+    use super::race_all;
+    use super::race_in;
+    use super::race_with_client;
+    use super::race_with_configuration;
+
+    const TIMEOUT: Duration = Duration::from_millis(20);
+
+    #[tokio::test]
+    async fn sut_returns_fastest_url_with_its_status() {
+        // Arrange
+        let slow_server = delayed_server(Duration::from_millis(50)).await;
+        let slow_url = slow_server.uri();
+        let fast_server = status_server(204).await;
+        let fast_url = fast_server.uri();
+
+        // Act
+        let actual = race(&slow_url, &fast_url).await.unwrap();
+
+        // Assert
+        assert_eq!(fast_url, actual.url);
+        assert_eq!(StatusCode::NO_CONTENT, actual.status);
+    }
+
+    #[tokio::test]
+    async fn sut_measures_how_long_winner_took_to_answer() {
+        // Arrange
+        let server = delayed_server(Duration::from_millis(50)).await;
+        let url = server.uri();
+        let not_working_url = unreachable_url();
+
+        // Act
+        let actual = race(&url, &not_working_url).await.unwrap();
+
+        // Assert
+        assert_eq!(url, actual.url);
+        assert!(actual.latency >= Duration::from_millis(50));
+        assert!(actual.latency < Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    async fn sut_tells_status_of_winner_even_if_it_is_an_error() {
+        // Arrange
+        let server = status_server(503).await;
+        let url = server.uri();
+
+        // Act
+        let actual = race_all(&[&url], Duration::from_secs(1)).await.unwrap();
+
+        // Assert
+        assert_eq!(StatusCode::SERVICE_UNAVAILABLE, actual.status);
+    }
+
+    #[tokio::test]
+    async fn sut_returns_error_if_every_url_fails() {
+        // Arrange
+        let urls = [unreachable_url(), unreachable_url(), unreachable_url()];
+        let urls = urls.iter().map(String::as_str).collect::<Vec<_>>();
+
+        // Act
+        let actual = race_all(&urls, Duration::from_secs(1)).await;
+
+        // Assert
+        assert_err_matches!(
+            actual,
+            RaceError::NoSuccessfulResponse(PingError { ref source, .. }) if source.is_connect()
+        );
+    }
+
+    #[tokio::test]
+    async fn sut_returns_cancelled_error_if_scope_is_cancelled_before_any_answer() {
+        // Arrange
+        let slow_server = delayed_server(Duration::from_secs(10)).await;
+        let slow_url = slow_server.uri();
+        let scope = Scope::new();
+        scope.cancel();
+
+        // Act
+        let actual = race_in(&slow_url, &slow_url, &scope).await;
+
+        // Assert
+        assert_err_matches!(actual, RaceError::Cancelled);
+    }
+
+    #[tokio::test]
+    async fn sut_pings_url_again_as_many_times_as_configured() {
+        // Arrange
+        let server = arrange_flaky_server(2).await;
+        let flaky_url = server.uri();
+        let not_working_url = unreachable_url();
+        let config = RaceConfig::new().with_retries(2).with_timeout(TIMEOUT);
+
+        // Act
+        let actual = race_with_configuration(&flaky_url, &not_working_url, &config).await;
+
+        // Assert
+        assert_eq!(flaky_url, actual.unwrap().url);
+        assert_eq!(3, server.received_requests().await.unwrap().len());
+    }
+
+    #[tokio::test]
+    async fn sut_returns_error_if_url_times_out_once_retries_run_out() {
+        // Arrange
+        let server = arrange_flaky_server(2).await;
+        let flaky_url = server.uri();
+        let slow_server = delayed_server(Duration::from_secs(10)).await;
+        let slow_url = slow_server.uri();
+        let config = RaceConfig::new().with_retries(1).with_timeout(TIMEOUT);
+
+        // Act
+        let actual = race_with_configuration(&flaky_url, &slow_url, &config).await;
+
+        // Assert
+        assert_err_matches!(
+            actual,
+            RaceError::NoSuccessfulResponse(PingError { ref source, .. }) if source.is_timeout()
+        );
+        assert_eq!(2, server.received_requests().await.unwrap().len());
+    }
+
+    #[tokio::test]
+    async fn sut_returns_fallback_url_if_both_urls_fail() {
+        // Arrange
+        let fallback_server = delayed_server(Duration::ZERO).await;
+        let (url_1, url_2) = (unreachable_url(), unreachable_url());
+        let config = RaceConfig::new()
+            .with_retries(0)
+            .with_fallback(fallback_server.uri());
+
+        // Act
+        let actual = race_with_configuration(&url_1, &url_2, &config).await;
+
+        // Assert
+        assert_eq!(fallback_server.uri(), actual.unwrap().url);
+    }
+
+    #[tokio::test]
+    async fn sut_does_not_ping_fallback_url_if_a_url_answers() {
+        // Arrange
+        let server = delayed_server(Duration::ZERO).await;
+        let url = server.uri();
+        let not_working_url = unreachable_url();
+        let fallback_server = delayed_server(Duration::ZERO).await;
+        let config = RaceConfig::new()
+            .with_retries(0)
+            .with_fallback(fallback_server.uri());
+
+        // Act
+        let actual = race_with_configuration(&url, &not_working_url, &config).await;
+
+        // Assert
+        assert_eq!(url, actual.unwrap().url);
+        assert!(
+            fallback_server
+                .received_requests()
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn sut_returns_error_of_fallback_url_if_it_fails_too() {
+        // Arrange
+        let fallback_server = arrange_flaky_server(1).await;
+        let fallback_url = fallback_server.uri();
+        let (url_1, url_2) = (unreachable_url(), unreachable_url());
+        let config = RaceConfig::new()
+            .with_retries(0)
+            .with_timeout(TIMEOUT)
+            .with_fallback(&fallback_url);
+
+        // Act
+        let actual = race_with_configuration(&url_1, &url_2, &config).await;
+
+        // Assert
+        assert_err_matches!(
+            actual,
+            RaceError::NoSuccessfulResponse(PingError { ref url, ref source }) if *url == fallback_url && source.is_timeout()
+        );
+    }
+
+    #[tokio::test]
+    async fn sut_pings_with_headers_of_given_client() {
+        // Arrange
+        let server = delayed_server(Duration::ZERO).await;
+        let url = server.uri();
+        let not_working_url = unreachable_url();
+        let headers = HeaderMap::from_iter([(
+            "x-api-key".parse().unwrap(),
+            HeaderValue::from_static("secret"),
+        )]);
+        let client = Client::builder().default_headers(headers).build().unwrap();
+        let config = RaceConfig::new();
+
+        // Act
+        let actual = race_with_client(&url, &not_working_url, &client, &config).await;
+
+        // Assert
+        assert_eq!(url, actual.unwrap().url);
+        let requests = server.received_requests().await.unwrap();
+        assert_eq!("secret", requests[0].headers["x-api-key"]);
+    }
+
+    #[tokio::test]
+    async fn sut_races_again_with_same_client() {
+        // Arrange
+        let server = delayed_server(Duration::ZERO).await;
+        let url = server.uri();
+        let not_working_url = unreachable_url();
+        let client = Client::new();
+        let config = RaceConfig::new();
+
+        // Act
+        for _ in 0..3 {
+            race_with_client(&url, &not_working_url, &client, &config)
+                .await
+                .unwrap();
+        }
+
+        // Assert
+        assert_eq!(3, server.received_requests().await.unwrap().len());
+    }
+
+    /// Arranges a server that takes too long to answer its first `failures`
+    /// requests, and answers the ones after at once.
+    async fn arrange_flaky_server(failures: u64) -> MockServer {
+        flaky_server(
+            failures,
+            ResponseTemplate::new(200).set_delay(Duration::from_millis(200)),
+        )
+        .await
+    }
+}
@@ -0,0 +1,21 @@
+use std::error::Error;
+use std::path::Path;
+use std::sync::Arc;
+
+use shortener::v1::Shortener;
+use shortener::v1::base62::RandomCodes;
+use shortener::v1::file_system::FileSystemShortenerStore;
+use shortener::v1::router;
+
+const ADDRESS: &str = "127.0.0.1:5001";
+const DATABASE_FILE: &str = "links.db.json";
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    let store = FileSystemShortenerStore::from_path(Path::new(DATABASE_FILE))?;
+    let shortener = Shortener::new(Arc::new(store), Arc::new(RandomCodes::new()));
+    let listener = tokio::net::TcpListener::bind(ADDRESS).await?;
+    println!("listening on http://{}", ADDRESS);
+    axum::serve(listener, router(shortener)).await?;
+    Ok(())
+}
@@ -0,0 +1,422 @@
+use std::sync::Arc;
+
+use axum::Json;
+use axum::Router;
+use axum::extract::Path;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::http::Uri;
+use axum::response::IntoResponse;
+use axum::response::Redirect;
+use axum::response::Response;
+use axum::routing::get;
+use axum::routing::post;
+use serde::Deserialize;
+use serde::Serialize;
+
+pub mod base62;
+#[cfg(test)]
+mod contract;
+pub mod file_system;
+pub mod in_memory;
+
+/// How many codes `Shortener::shorten` tries before giving up on collisions.
+pub const ATTEMPTS: usize = 5;
+
+/// `ShortenerStore` keeps the URL every code stands for.
+///
+/// The methods take `&self` so that one store can be shared by every request;
+/// implementations synchronise access themselves.
+pub trait ShortenerStore: Send + Sync {
+    /// Returns the URL of the code, or `None` if the code is unknown.
+    fn get(&self, code: &str) -> Result<Option<String>, StoreError>;
+    /// Keeps the URL under the code. A code that is already taken is left as
+    /// it is and `StoreError::Collision` is returned.
+    fn insert(&self, code: &str, url: &str) -> Result<(), StoreError>;
+}
+
+/// `CodeGenerator` comes up with a code for the next URL to shorten.
+pub trait CodeGenerator: Send + Sync {
+    fn generate(&self) -> String;
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum StoreError {
+    #[error("code '{0}' is already taken")]
+    Collision(String),
+
+    #[error("failed to access links: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("links are corrupt: {0}")]
+    Corrupt(#[from] serde_json::Error),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ShortenError {
+    #[error("'{0}' is not an absolute http or https URL")]
+    InvalidUrl(String),
+
+    #[error("no free code found in {0} attempts")]
+    NoFreeCode(usize),
+
+    #[error(transparent)]
+    Store(#[from] StoreError),
+}
+
+/// `Shortener` hands out codes for URLs and looks them up again.
+#[derive(Clone)]
+pub struct Shortener {
+    store: Arc<dyn ShortenerStore>,
+    codes: Arc<dyn CodeGenerator>,
+}
+
+impl Shortener {
+    pub fn new(store: Arc<dyn ShortenerStore>, codes: Arc<dyn CodeGenerator>) -> Self {
+        Shortener { store, codes }
+    }
+
+    /// Returns a new code for the URL.
+    ///
+    /// A generated code that is already taken is replaced by another one, up
+    /// to `ATTEMPTS` codes in all.
+    pub fn shorten(&self, url: &str) -> Result<String, ShortenError> {
+        validate(url)?;
+        for _ in 0..ATTEMPTS {
+            let code = self.codes.generate();
+            match self.store.insert(&code, url) {
+                Ok(()) => return Ok(code),
+                Err(StoreError::Collision(_)) => continue,
+                Err(error) => return Err(error.into()),
+            }
+        }
+        Err(ShortenError::NoFreeCode(ATTEMPTS))
+    }
+
+    /// Returns the URL the code stands for, or `None` if it is unknown.
+    pub fn resolve(&self, code: &str) -> Result<Option<String>, StoreError> {
+        if !base62::is_code(code) {
+            return Ok(None);
+        }
+        self.store.get(code)
+    }
+}
+
+/// Accepts absolute `http` and `https` URLs, which are all a browser can be
+/// redirected to safely.
+fn validate(url: &str) -> Result<(), ShortenError> {
+    let invalid = || ShortenError::InvalidUrl(url.to_string());
+    let uri = url.parse::<Uri>().map_err(|_| invalid())?;
+    let web = matches!(uri.scheme_str(), Some("http" | "https"));
+    let host = uri.host().is_some_and(|host| !host.is_empty());
+    if web && host { Ok(()) } else { Err(invalid()) }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct ShortenRequest {
+    pub url: String,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct ShortenResponse {
+    pub code: String,
+}
+
+/// Builds the routes of the shortener:
+///
+/// - `POST /shorten` takes `{"url": ..}` and answers 201 with `{"code": ..}`
+/// - `GET /{code}` redirects to the URL of the code
+///
+/// An invalid URL is answered with 400 and an unknown code with 404. Running
+/// out of codes or failing to reach the store is answered with 500.
+pub fn router(shortener: Shortener) -> Router {
+    Router::new()
+        .route("/shorten", post(shorten))
+        .route("/{code}", get(redirect))
+        .with_state(shortener)
+}
+
+async fn shorten(
+    State(shortener): State<Shortener>,
+    Json(request): Json<ShortenRequest>,
+) -> Response {
+    match shortener.shorten(&request.url) {
+        Ok(code) => (StatusCode::CREATED, Json(ShortenResponse { code })).into_response(),
+        Err(error @ ShortenError::InvalidUrl(_)) => {
+            (StatusCode::BAD_REQUEST, error.to_string()).into_response()
+        }
+        Err(error) => (StatusCode::INTERNAL_SERVER_ERROR, error.to_string()).into_response(),
+    }
+}
+
+async fn redirect(State(shortener): State<Shortener>, Path(code): Path<String>) -> Response {
+    match shortener.resolve(&code) {
+        Ok(Some(url)) => Redirect::temporary(&url).into_response(),
+        Ok(None) => StatusCode::NOT_FOUND.into_response(),
+        Err(error) => (StatusCode::INTERNAL_SERVER_ERROR, error.to_string()).into_response(),
+    }
+}
+
+#[cfg(test)]
+mod specs_for_shortener {
+    use std::sync::Arc;
+    use std::sync::Mutex;
+
+    use assertions::assert_err_matches;
+
+    use super::ATTEMPTS;
+    use super::CodeGenerator;
+    use super::ShortenError;
+    use super::Shortener;
+    use super::ShortenerStore;
+    use super::StoreError;
+    use super::in_memory::InMemoryShortenerStore;
+
+    /// `ScriptedCodes` generates the codes it was given, in order.
+    struct ScriptedCodes {
+        codes: Mutex<Vec<&'static str>>,
+    }
+
+    impl ScriptedCodes {
+        fn new(codes: &[&'static str]) -> Arc<Self> {
+            let mut codes = codes.to_vec();
+            codes.reverse();
+            Arc::new(ScriptedCodes {
+                codes: Mutex::new(codes),
+            })
+        }
+    }
+
+    impl CodeGenerator for ScriptedCodes {
+        fn generate(&self) -> String {
+            self.codes
+                .lock()
+                .unwrap()
+                .pop()
+                .unwrap_or("last")
+                .to_string()
+        }
+    }
+
+    fn shortener(store: Arc<InMemoryShortenerStore>, codes: &[&'static str]) -> Shortener {
+        Shortener::new(store, ScriptedCodes::new(codes))
+    }
+
+    #[rstest::rstest]
+    #[case("https://quii.gitbook.io/learn-go-with-tests")]
+    #[case("http://localhost:8080/path?query=1#fragment")]
+    fn sut_stores_url_under_generated_code(#[case] url: &str) {
+        // Arrange
+        let store = Arc::new(InMemoryShortenerStore::new());
+        let sut = shortener(store.clone(), &["abc"]);
+
+        // Act
+        let actual = sut.shorten(url).unwrap();
+
+        // Assert
+        assert_eq!("abc", actual);
+        assert_eq!(Some(url.to_string()), store.get("abc").unwrap());
+    }
+
+    #[test]
+    fn sut_tries_next_code_if_generated_one_is_taken() {
+        // Arrange
+        let store = Arc::new(InMemoryShortenerStore::new());
+        store.insert("abc", "https://example.com/a").unwrap();
+        let sut = shortener(store.clone(), &["abc", "def"]);
+
+        // Act
+        let actual = sut.shorten("https://example.com/b").unwrap();
+
+        // Assert
+        assert_eq!("def", actual);
+        let expected = Some("https://example.com/a".to_string());
+        assert_eq!(expected, store.get("abc").unwrap());
+    }
+
+    #[test]
+    fn sut_gives_up_if_every_code_is_taken() {
+        // Arrange
+        let store = Arc::new(InMemoryShortenerStore::new());
+        store.insert("abc", "https://example.com/a").unwrap();
+        let sut = shortener(store, &["abc"; ATTEMPTS]);
+
+        // Act
+        let actual = sut.shorten("https://example.com/b");
+
+        // Assert
+        assert_err_matches!(actual, ShortenError::NoFreeCode(ATTEMPTS));
+    }
+
+    #[rstest::rstest]
+    #[case("")]
+    #[case("example.com")]
+    #[case("/relative/path")]
+    #[case("ftp://example.com/file")]
+    #[case("javascript:alert(1)")]
+    #[case("https://exa mple.com")]
+    fn sut_rejects_invalid_url(#[case] url: &str) {
+        // Arrange
+        let store = Arc::new(InMemoryShortenerStore::new());
+        let sut = shortener(store, &["abc"]);
+
+        // Act
+        let actual = sut.shorten(url);
+
+        // Assert
+        assert_err_matches!(actual, ShortenError::InvalidUrl(invalid) if invalid == url);
+    }
+
+    #[test]
+    fn sut_does_not_ask_store_for_code_it_could_not_have_generated() {
+        // Arrange
+        struct BrokenStore;
+
+        impl ShortenerStore for BrokenStore {
+            fn get(&self, _: &str) -> Result<Option<String>, StoreError> {
+                Err(StoreError::Io(std::io::Error::other("disk gone")))
+            }
+
+            fn insert(&self, _: &str, _: &str) -> Result<(), StoreError> {
+                Err(StoreError::Io(std::io::Error::other("disk gone")))
+            }
+        }
+        let sut = Shortener::new(Arc::new(BrokenStore), ScriptedCodes::new(&[]));
+
+        // Act
+        let actual = sut.resolve("favicon.ico");
+
+        // Assert
+        assert!(matches!(actual, Ok(None)));
+    }
+}
+
+#[cfg(test)]
+mod specs_for_router {
+    use std::sync::Arc;
+
+    use axum::body::Body;
+    use axum::http::Method;
+    use axum::http::Request;
+    use axum::http::StatusCode;
+    use axum::http::header::CONTENT_TYPE;
+    use axum::http::header::LOCATION;
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    use super::CodeGenerator;
+    use super::ShortenResponse;
+    use super::Shortener;
+    use super::ShortenerStore;
+    use super::in_memory::InMemoryShortenerStore;
+    use super::router;
+
+    struct FixedCode;
+
+    impl CodeGenerator for FixedCode {
+        fn generate(&self) -> String {
+            "abc".to_string()
+        }
+    }
+
+    #[rstest::fixture]
+    fn store() -> Arc<InMemoryShortenerStore> {
+        let store = InMemoryShortenerStore::new();
+        store.insert("go", "https://go.dev/").unwrap();
+        Arc::new(store)
+    }
+
+    async fn send(
+        store: Arc<InMemoryShortenerStore>,
+        request: Request<Body>,
+    ) -> (StatusCode, Option<String>, String) {
+        let sut = router(Shortener::new(store, Arc::new(FixedCode)));
+        let response = sut.oneshot(request).await.unwrap();
+        let status = response.status();
+        let location = response
+            .headers()
+            .get(LOCATION)
+            .map(|value| value.to_str().unwrap().to_string());
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        (status, location, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    fn shorten(body: &str) -> Request<Body> {
+        Request::builder()
+            .method(Method::POST)
+            .uri("/shorten")
+            .header(CONTENT_TYPE, "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap()
+    }
+
+    #[rstest::rstest]
+    #[tokio::test]
+    async fn sut_shortens_url(store: Arc<InMemoryShortenerStore>) {
+        // Act
+        let request = shorten(r#"{"url": "https://www.rust-lang.org/"}"#);
+        let (status, _, body) = send(store.clone(), request).await;
+
+        // Assert
+        assert_eq!(StatusCode::CREATED, status);
+        let actual = serde_json::from_str::<ShortenResponse>(&body).unwrap();
+        assert_eq!("abc", actual.code);
+        let expected = Some("https://www.rust-lang.org/".to_string());
+        assert_eq!(expected, store.get("abc").unwrap());
+    }
+
+    #[rstest::rstest]
+    #[tokio::test]
+    async fn sut_rejects_invalid_url_with_bad_request(store: Arc<InMemoryShortenerStore>) {
+        // Act
+        let (status, _, body) = send(store, shorten(r#"{"url": "rust-lang.org"}"#)).await;
+
+        // Assert
+        assert_eq!(StatusCode::BAD_REQUEST, status);
+        assert_eq!("'rust-lang.org' is not an absolute http or https URL", body);
+    }
+
+    #[rstest::rstest]
+    #[case(r#"{"link": "https://go.dev/"}"#)]
+    #[case("not json")]
+    #[tokio::test]
+    async fn sut_rejects_malformed_request(store: Arc<InMemoryShortenerStore>, #[case] body: &str) {
+        // Act
+        let (status, _, _) = send(store, shorten(body)).await;
+
+        // Assert
+        assert!(status.is_client_error());
+    }
+
+    #[rstest::rstest]
+    #[tokio::test]
+    async fn sut_redirects_code_to_url(store: Arc<InMemoryShortenerStore>) {
+        // Arrange
+        let request = Request::get("/go").body(Body::empty()).unwrap();
+
+        // Act
+        let (status, location, _) = send(store, request).await;
+
+        // Assert
+        assert_eq!(StatusCode::TEMPORARY_REDIRECT, status);
+        assert_eq!(Some("https://go.dev/"), location.as_deref());
+    }
+
+    #[rstest::rstest]
+    #[case("/missing")]
+    #[case("/favicon.ico")]
+    #[tokio::test]
+    async fn sut_returns_not_found_for_unknown_code(
+        store: Arc<InMemoryShortenerStore>,
+        #[case] uri: &str,
+    ) {
+        // Arrange
+        let request = Request::get(uri).body(Body::empty()).unwrap();
+
+        // Act
+        let (status, _, _) = send(store, request).await;
+
+        // Assert
+        assert_eq!(StatusCode::NOT_FOUND, status);
+    }
+}
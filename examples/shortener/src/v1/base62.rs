@@ -0,0 +1,161 @@
+use random_source::RandomSource;
+use random_source::ThreadRandom;
+
+use super::CodeGenerator;
+
+const ALPHABET: &[u8; 62] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// The length of generated codes. 62^7 is over three trillion codes, so
+/// random codes rarely collide.
+pub const CODE_LENGTH: usize = 7;
+
+/// Writes the number in base 62, most significant digit first.
+pub fn encode(mut n: u64) -> String {
+    let mut digits = vec![];
+    loop {
+        digits.push(ALPHABET[(n % 62) as usize]);
+        n /= 62;
+        if n == 0 {
+            break;
+        }
+    }
+    digits.reverse();
+    String::from_utf8(digits).unwrap()
+}
+
+/// Reads a number written by `encode`. Returns `None` for an empty code, a
+/// character outside the alphabet or a number that overflows `u64`.
+pub fn decode(code: &str) -> Option<u64> {
+    if code.is_empty() {
+        return None;
+    }
+    code.bytes().try_fold(0u64, |n, byte| {
+        let digit = ALPHABET.iter().position(|&c| c == byte)?;
+        n.checked_mul(62)?.checked_add(digit as u64)
+    })
+}
+
+/// Returns whether the code could have been generated, so that requests
+/// for anything else are turned away without asking the store.
+pub fn is_code(code: &str) -> bool {
+    !code.is_empty() && code.bytes().all(|byte| ALPHABET.contains(&byte))
+}
+
+/// `RandomCodes` generates codes of `CODE_LENGTH` digits that are hard to
+/// guess, drawn from a `RandomSource`.
+#[derive(Debug, Default)]
+pub struct RandomCodes<S = ThreadRandom> {
+    source: S,
+}
+
+impl RandomCodes {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<S: RandomSource> RandomCodes<S> {
+    /// Creates the generator drawing from `source`, so that a seeded source
+    /// generates the same codes every run.
+    pub fn with_source(source: S) -> Self {
+        RandomCodes { source }
+    }
+}
+
+impl<S: RandomSource> CodeGenerator for RandomCodes<S> {
+    fn generate(&self) -> String {
+        let n = self.source.next_u64();
+        let code = encode(n % 62u64.pow(CODE_LENGTH as u32));
+        format!("{:0>width$}", code, width = CODE_LENGTH)
+    }
+}
+
+#[cfg(test)]
+mod specs_for_base62 {
+    use super::decode;
+    use super::encode;
+    use super::is_code;
+
+    #[rstest::rstest]
+    #[case(0, "0")]
+    #[case(9, "9")]
+    #[case(10, "A")]
+    #[case(61, "z")]
+    #[case(62, "10")]
+    #[case(3843, "zz")]
+    #[case(u64::MAX, "LygHa16AHYF")]
+    fn sut_encodes_and_decodes_number(#[case] n: u64, #[case] code: &str) {
+        // Act
+        let actual = (encode(n), decode(code));
+
+        // Assert
+        assert_eq!((code.to_string(), Some(n)), actual);
+    }
+
+    #[rstest::rstest]
+    #[case("")]
+    #[case("ab-c")]
+    #[case("LygHa16AHYG")]
+    fn sut_does_not_decode_invalid_code(#[case] code: &str) {
+        // Act
+        let actual = decode(code);
+
+        // Assert
+        assert_eq!(None, actual);
+    }
+
+    #[rstest::rstest]
+    #[case("aZ09", true)]
+    #[case("", false)]
+    #[case("favicon.ico", false)]
+    fn sut_tells_whether_code_could_be_generated(#[case] code: &str, #[case] expected: bool) {
+        // Act
+        let actual = is_code(code);
+
+        // Assert
+        assert_eq!(expected, actual);
+    }
+}
+
+#[cfg(test)]
+mod specs_for_random_codes {
+    use std::collections::HashSet;
+
+    use random_source::SeededRandom;
+
+    use super::CODE_LENGTH;
+    use super::RandomCodes;
+    use super::is_code;
+    use crate::v1::CodeGenerator;
+
+    #[test]
+    fn sut_generates_distinct_codes_of_fixed_length() {
+        // Arrange
+        let sut = RandomCodes::new();
+
+        // Act
+        let actual = (0..1000).map(|_| sut.generate()).collect::<HashSet<_>>();
+
+        // Assert
+        assert_eq!(1000, actual.len());
+        assert!(
+            actual
+                .iter()
+                .all(|code| code.len() == CODE_LENGTH && is_code(code))
+        );
+    }
+
+    #[test]
+    fn sut_generates_same_codes_from_same_seed() {
+        // Arrange
+        let sut = RandomCodes::with_source(SeededRandom::new(42));
+
+        // Act
+        let actual = (0..10).map(|_| sut.generate()).collect::<Vec<_>>();
+
+        // Assert
+        let other = RandomCodes::with_source(SeededRandom::new(42));
+        let expected = (0..10).map(|_| other.generate()).collect::<Vec<_>>();
+        assert_eq!(expected, actual);
+    }
+}
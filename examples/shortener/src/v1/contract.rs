@@ -0,0 +1,76 @@
+//! Specs every `ShortenerStore` has to meet, whatever keeps the links.
+//!
+//! `shortener_store_contract!` expands to a test module running the specs
+//! against the store the expression creates, afresh for every spec.
+
+macro_rules! shortener_store_contract {
+    ($store:expr) => {
+        mod specs_for_shortener_store_contract {
+            use assertions::assert_err_matches;
+
+            use super::*;
+            use crate::v1::ShortenerStore;
+            use crate::v1::StoreError;
+
+            #[test]
+            fn sut_returns_none_for_unknown_code() {
+                // Arrange
+                let sut = $store;
+
+                // Act
+                let actual = sut.get("abc").unwrap();
+
+                // Assert
+                assert_eq!(None, actual);
+            }
+
+            #[test]
+            fn sut_returns_inserted_url() {
+                // Arrange
+                let sut = $store;
+                sut.insert("abc", "https://example.com/a").unwrap();
+                sut.insert("def", "https://example.com/b").unwrap();
+
+                // Act
+                let actual = (sut.get("abc").unwrap(), sut.get("def").unwrap());
+
+                // Assert
+                let expected = (
+                    Some("https://example.com/a".to_string()),
+                    Some("https://example.com/b".to_string()),
+                );
+                assert_eq!(expected, actual);
+            }
+
+            #[test]
+            fn sut_keeps_first_url_if_code_is_taken() {
+                // Arrange
+                let sut = $store;
+                sut.insert("abc", "https://example.com/a").unwrap();
+
+                // Act
+                let actual = sut.insert("abc", "https://example.com/b");
+
+                // Assert
+                assert_err_matches!(actual, StoreError::Collision(code) if code == "abc");
+                let expected = Some("https://example.com/a".to_string());
+                assert_eq!(expected, sut.get("abc").unwrap());
+            }
+
+            #[test]
+            fn sut_tells_codes_apart_by_case() {
+                // Arrange
+                let sut = $store;
+                sut.insert("abc", "https://example.com/a").unwrap();
+
+                // Act
+                let actual = sut.get("ABC").unwrap();
+
+                // Assert
+                assert_eq!(None, actual);
+            }
+        }
+    };
+}
+
+pub(crate) use shortener_store_contract;
@@ -0,0 +1,139 @@
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::fs::OpenOptions;
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+use std::io::Write;
+use std::path::Path;
+use std::sync::Mutex;
+
+use super::ShortenerStore;
+use super::StoreError;
+
+/// `FileSystemShortenerStore` keeps the links as a JSON object in a file,
+/// such as `{"abc1234":"https://example.com/"}`.
+///
+/// The links are read once when the store is created and the whole file is
+/// rewritten after every insert.
+#[derive(Debug)]
+pub struct FileSystemShortenerStore {
+    state: Mutex<(File, BTreeMap<String, String>)>,
+}
+
+impl FileSystemShortenerStore {
+    /// Reads the links from the file. An empty file has no links.
+    pub fn new(mut file: File) -> Result<Self, StoreError> {
+        let mut content = String::new();
+        file.seek(SeekFrom::Start(0))?;
+        file.read_to_string(&mut content)?;
+        let links = if content.trim().is_empty() {
+            BTreeMap::new()
+        } else {
+            serde_json::from_str(&content)?
+        };
+        Ok(FileSystemShortenerStore {
+            state: Mutex::new((file, links)),
+        })
+    }
+
+    /// Opens the file at the path, creating it if it does not exist yet.
+    pub fn from_path(path: &Path) -> Result<Self, StoreError> {
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)?;
+        Self::new(file)
+    }
+}
+
+impl ShortenerStore for FileSystemShortenerStore {
+    fn get(&self, code: &str) -> Result<Option<String>, StoreError> {
+        let (_, links) = &*self.state.lock().unwrap();
+        Ok(links.get(code).cloned())
+    }
+
+    fn insert(&self, code: &str, url: &str) -> Result<(), StoreError> {
+        let (file, links) = &mut *self.state.lock().unwrap();
+        if links.contains_key(code) {
+            return Err(StoreError::Collision(code.to_string()));
+        }
+        links.insert(code.to_string(), url.to_string());
+        let content = serde_json::to_vec(links)?;
+        // Truncating first keeps a shorter file from ending in old content.
+        file.seek(SeekFrom::Start(0))?;
+        file.set_len(0)?;
+        file.write_all(&content)?;
+        file.flush()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod specs_for_file_system_shortener_store {
+    use std::fs;
+
+    use assertions::assert_err_matches;
+    use tempfile::NamedTempFile;
+
+    use super::FileSystemShortenerStore;
+    use crate::v1::ShortenerStore;
+    use crate::v1::StoreError;
+
+    crate::v1::contract::shortener_store_contract!(
+        FileSystemShortenerStore::new(tempfile::tempfile().unwrap()).unwrap()
+    );
+
+    fn database(content: &str) -> NamedTempFile {
+        let file = NamedTempFile::new().unwrap();
+        fs::write(file.path(), content).unwrap();
+        file
+    }
+
+    #[test]
+    fn sut_reads_links_from_file() {
+        // Arrange
+        let file = database(r#"{"go":"https://go.dev/"}"#);
+
+        // Act
+        let sut = FileSystemShortenerStore::from_path(file.path()).unwrap();
+
+        // Assert
+        assert_eq!(Some("https://go.dev/".to_string()), sut.get("go").unwrap());
+    }
+
+    #[test]
+    fn sut_keeps_inserted_link_after_reopening() {
+        // Arrange
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("links.json");
+        let sut = FileSystemShortenerStore::from_path(&path).unwrap();
+
+        // Act
+        sut.insert("rs", "https://www.rust-lang.org/").unwrap();
+
+        // Assert
+        let reopened = FileSystemShortenerStore::from_path(&path).unwrap();
+        let expected = Some("https://www.rust-lang.org/".to_string());
+        assert_eq!(expected, reopened.get("rs").unwrap());
+        let content = fs::read_to_string(&path).unwrap();
+        assert_eq!(r#"{"rs":"https://www.rust-lang.org/"}"#, content);
+    }
+
+    #[rstest::rstest]
+    #[case("not json")]
+    #[case(r#"["https://go.dev/"]"#)]
+    #[case(r#"{"go":1}"#)]
+    fn sut_rejects_corrupt_file(#[case] content: &str) {
+        // Arrange
+        let file = database(content);
+
+        // Act
+        let actual = FileSystemShortenerStore::from_path(file.path());
+
+        // Assert
+        assert_err_matches!(actual, StoreError::Corrupt(_));
+    }
+}
@@ -0,0 +1,42 @@
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::sync::Mutex;
+
+use super::ShortenerStore;
+use super::StoreError;
+
+/// `InMemoryShortenerStore` keeps the links in a map guarded by a lock, so
+/// that two requests cannot take the same code.
+#[derive(Debug, Default)]
+pub struct InMemoryShortenerStore {
+    links: Mutex<HashMap<String, String>>,
+}
+
+impl InMemoryShortenerStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl ShortenerStore for InMemoryShortenerStore {
+    fn get(&self, code: &str) -> Result<Option<String>, StoreError> {
+        Ok(self.links.lock().unwrap().get(code).cloned())
+    }
+
+    fn insert(&self, code: &str, url: &str) -> Result<(), StoreError> {
+        match self.links.lock().unwrap().entry(code.to_string()) {
+            Entry::Occupied(_) => Err(StoreError::Collision(code.to_string())),
+            Entry::Vacant(entry) => {
+                entry.insert(url.to_string());
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod specs_for_in_memory_shortener_store {
+    use super::InMemoryShortenerStore;
+
+    crate::v1::contract::shortener_store_contract!(InMemoryShortenerStore::new());
+}
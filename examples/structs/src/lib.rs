@@ -1,5 +1,13 @@
+//! The shapes only need `core`, so without the default `std` feature the
+//! crate is `no_std`.
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 pub mod v1;
 pub mod v2;
 pub mod v3;
 pub mod v4;
 pub mod v5;
+pub mod v6;
@@ -0,0 +1,72 @@
+//! Generators of shapes for property tests, shared with the crates
+//! depending on this one through the `testing` feature.
+
+use geometry::Point;
+use proptest::prelude::*;
+
+use crate::v6::Circle;
+use crate::v6::Rectangle;
+use crate::v6::Shape;
+use crate::v6::Triangle;
+
+/// The lengths and coordinates generated stay below this, so that areas do
+/// not lose precision.
+pub const MAX_LENGTH: f64 = 1000.0;
+
+/// `ShapeKind` is any of the shapes, so that a property can hold for all of
+/// them at once.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ShapeKind {
+    Rectangle(Rectangle),
+    Circle(Circle),
+    Triangle(Triangle),
+}
+
+impl Shape for ShapeKind {
+    fn area(&self) -> f64 {
+        self.as_shape().area()
+    }
+
+    fn perimeter(&self) -> f64 {
+        self.as_shape().perimeter()
+    }
+}
+
+impl ShapeKind {
+    fn as_shape(&self) -> &dyn Shape {
+        match self {
+            ShapeKind::Rectangle(rectangle) => rectangle,
+            ShapeKind::Circle(circle) => circle,
+            ShapeKind::Triangle(triangle) => triangle,
+        }
+    }
+}
+
+fn length() -> impl Strategy<Value = f64> {
+    0.0..MAX_LENGTH
+}
+
+fn point() -> impl Strategy<Value = Point> {
+    (-MAX_LENGTH..MAX_LENGTH, -MAX_LENGTH..MAX_LENGTH).prop_map(|(x, y)| Point { x, y })
+}
+
+pub fn rectangle() -> impl Strategy<Value = Rectangle> {
+    (length(), length()).prop_map(|(width, height)| Rectangle { width, height })
+}
+
+pub fn circle() -> impl Strategy<Value = Circle> {
+    length().prop_map(|radius| Circle { radius })
+}
+
+pub fn triangle() -> impl Strategy<Value = Triangle> {
+    (point(), point(), point()).prop_map(|(a, b, c)| Triangle { a, b, c })
+}
+
+/// Generates rectangles, circles and triangles alike.
+pub fn shape() -> impl Strategy<Value = ShapeKind> {
+    prop_oneof![
+        rectangle().prop_map(ShapeKind::Rectangle),
+        circle().prop_map(ShapeKind::Circle),
+        triangle().prop_map(ShapeKind::Triangle),
+    ]
+}
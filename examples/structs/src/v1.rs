@@ -4,6 +4,8 @@ pub fn perimeter(width: f64, height: f64) -> f64 {
 
 #[cfg(test)]
 mod specs_for_perimeter {
+    use assertions::assert_approx_eq;
+
     use super::perimeter;
 
     #[test]
@@ -17,6 +19,6 @@ mod specs_for_perimeter {
 
         // Assert
         let expected = 40.0;
-        assert_eq!(expected, actual);
+        assert_approx_eq!(expected, actual);
     }
 }
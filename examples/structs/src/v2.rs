@@ -8,6 +8,8 @@ pub fn area(width: f64, height: f64) -> f64 {
 
 #[cfg(test)]
 mod specs_for_perimeter {
+    use assertions::assert_approx_eq;
+
     use super::perimeter;
 
     #[test]
@@ -21,12 +23,14 @@ mod specs_for_perimeter {
 
         // Assert
         let expected = 40.0;
-        assert_eq!(expected, actual);
+        assert_approx_eq!(expected, actual);
     }
 }
 
 #[cfg(test)]
 mod specs_for_area {
+    use assertions::assert_approx_eq;
+
     use super::area;
 
     #[test]
@@ -40,6 +44,6 @@ mod specs_for_area {
 
         // Assert
         let expected = 72.0;
-        assert_eq!(expected, actual);
+        assert_approx_eq!(expected, actual);
     }
 }
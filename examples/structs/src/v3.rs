@@ -13,6 +13,8 @@ pub fn area(rectangle: Rectangle) -> f64 {
 
 #[cfg(test)]
 mod specs_for_perimeter {
+    use assertions::assert_approx_eq;
+
     use super::Rectangle;
     use super::perimeter;
 
@@ -29,12 +31,14 @@ mod specs_for_perimeter {
 
         // Assert
         let expected = 40.0;
-        assert_eq!(expected, actual);
+        assert_approx_eq!(expected, actual);
     }
 }
 
 #[cfg(test)]
 mod specs_for_area {
+    use assertions::assert_approx_eq;
+
     use super::Rectangle;
     use super::area;
 
@@ -51,6 +55,6 @@ mod specs_for_area {
 
         // Assert
         let expected = 72.0;
-        assert_eq!(expected, actual);
+        assert_approx_eq!(expected, actual);
     }
 }
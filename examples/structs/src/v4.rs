@@ -1,4 +1,4 @@
-use std::f64::consts::PI;
+use core::f64::consts::PI;
 
 pub struct Rectangle {
     pub width: f64,
@@ -31,6 +31,10 @@ impl Circle {
 
 #[cfg(test)]
 mod specs_for_perimeter {
+    use std::f64::consts::PI;
+
+    use assertions::assert_approx_eq;
+
     use super::Circle;
     use super::Rectangle;
 
@@ -47,7 +51,7 @@ mod specs_for_perimeter {
 
         // Assert
         let expected = 40.0;
-        assert_eq!(expected, actual);
+        assert_approx_eq!(expected, actual);
     }
 
     #[test]
@@ -59,13 +63,17 @@ mod specs_for_perimeter {
         let actual = circle.perimeter();
 
         // Assert
-        let expected = 62.83185307179586;
-        assert_eq!(expected, actual);
+        let expected = 20.0 * PI;
+        assert_approx_eq!(expected, actual);
     }
 }
 
 #[cfg(test)]
 mod specs_for_area {
+    use std::f64::consts::PI;
+
+    use assertions::assert_approx_eq;
+
     use super::Circle;
     use super::Rectangle;
 
@@ -82,7 +90,7 @@ mod specs_for_area {
 
         // Assert
         let expected = 72.0;
-        assert_eq!(expected, actual);
+        assert_approx_eq!(expected, actual);
     }
 
     #[test]
@@ -94,7 +102,7 @@ mod specs_for_area {
         let actual = circle.area();
 
         // Assert
-        let expected = 314.1592653589793;
-        assert_eq!(expected, actual);
+        let expected = 100.0 * PI;
+        assert_approx_eq!(expected, actual);
     }
 }
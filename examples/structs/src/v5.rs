@@ -1,4 +1,4 @@
-use std::f64::consts::PI;
+use core::f64::consts::PI;
 
 pub trait Shape {
     fn area(&self) -> f64;
@@ -44,6 +44,10 @@ pub fn sum_areas(shapes: &[&dyn Shape]) -> f64 {
 
 #[cfg(test)]
 mod specs_for_sum_areas {
+    use std::f64::consts::PI;
+
+    use assertions::assert_approx_eq;
+
     use super::Shape;
     use super::sum_areas;
 
@@ -61,13 +65,16 @@ mod specs_for_sum_areas {
         let actual = sum_areas(&shapes);
 
         // Assert
-        let expected = 414.1592653589793;
-        assert_eq!(expected, actual);
+        let expected = 100.0 + 100.0 * PI;
+        assert_approx_eq!(expected, actual);
     }
 }
 
 #[cfg(test)]
 mod specs_for_shape {
+    use std::f64::consts::PI;
+
+    use assertions::assert_approx_eq;
     use rstest::rstest;
 
     use super::Circle;
@@ -76,23 +83,23 @@ mod specs_for_shape {
 
     #[rstest]
     #[case(Rectangle {width: 10.0, height: 10.0}, 40.0)]
-    #[case(Circle {radius: 10.0}, 62.83185307179586)]
+    #[case(Circle {radius: 10.0}, 20.0 * PI)]
     fn sut_returns_perimeter_of_shape_correctly(#[case] shape: impl Shape, #[case] expected: f64) {
         // Act
         let actual = shape.perimeter();
 
         // Assert
-        assert_eq!(expected, actual);
+        assert_approx_eq!(expected, actual);
     }
 
     #[rstest]
     #[case(Rectangle {width: 12.0, height: 6.0}, 72.0)]
-    #[case(Circle {radius: 10.0}, 314.1592653589793)]
+    #[case(Circle {radius: 10.0}, 100.0 * PI)]
     fn sut_returns_area_of_shape_correctly(#[case] shape: impl Shape, #[case] expected: f64) {
         // Act
         let actual = shape.area();
 
         // Assert
-        assert_eq!(expected, actual);
+        assert_approx_eq!(expected, actual);
     }
 }
@@ -0,0 +1,155 @@
+use core::f64::consts::PI;
+
+use geometry::Point;
+
+pub trait Shape {
+    fn area(&self) -> f64;
+    fn perimeter(&self) -> f64;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Rectangle {
+    pub width: f64,
+    pub height: f64,
+}
+
+impl Shape for Rectangle {
+    fn area(&self) -> f64 {
+        self.width * self.height
+    }
+
+    fn perimeter(&self) -> f64 {
+        2.0 * (self.width + self.height)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Circle {
+    pub radius: f64,
+}
+
+impl Shape for Circle {
+    fn area(&self) -> f64 {
+        PI * self.radius * self.radius
+    }
+
+    fn perimeter(&self) -> f64 {
+        2.0 * PI * self.radius
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Triangle {
+    pub a: Point,
+    pub b: Point,
+    pub c: Point,
+}
+
+impl Shape for Triangle {
+    fn area(&self) -> f64 {
+        let Triangle { a, b, c } = self;
+        ((b.x - a.x) * (c.y - a.y) - (c.x - a.x) * (b.y - a.y)).abs() / 2.0
+    }
+
+    fn perimeter(&self) -> f64 {
+        self.a.distance_to(self.b) + self.b.distance_to(self.c) + self.c.distance_to(self.a)
+    }
+}
+
+pub fn sum_areas(shapes: &[&dyn Shape]) -> f64 {
+    let mut total_area = 0.0;
+    for shape in shapes {
+        total_area += shape.area();
+    }
+    total_area
+}
+
+#[cfg(test)]
+mod specs_for_sum_areas {
+    use std::f64::consts::PI;
+
+    use assertions::assert_approx_eq;
+
+    use super::Shape;
+    use super::sum_areas;
+
+    #[test]
+    fn sut_returns_sum_of_areas_if_rectangle_and_circle_are_given() {
+        // Arrange
+        let rectangle = super::Rectangle {
+            width: 10.0,
+            height: 10.0,
+        };
+        let circle = super::Circle { radius: 10.0 };
+        let shapes: Vec<&dyn Shape> = vec![&rectangle, &circle];
+
+        // Act
+        let actual = sum_areas(&shapes);
+
+        // Assert
+        let expected = 100.0 + 100.0 * PI;
+        assert_approx_eq!(expected, actual);
+    }
+}
+
+#[cfg(test)]
+mod specs_for_shape {
+    use std::f64::consts::PI;
+
+    use assertions::assert_approx_eq;
+    use geometry::Point;
+    use rstest::rstest;
+
+    use super::Circle;
+    use super::Rectangle;
+    use super::Shape;
+    use super::Triangle;
+
+    #[rstest]
+    #[case(Rectangle {width: 10.0, height: 10.0}, 40.0)]
+    #[case(Circle {radius: 10.0}, 20.0 * PI)]
+    #[case(Triangle {a: Point::new(0.0, 0.0), b: Point::new(3.0, 0.0), c: Point::new(0.0, 4.0)}, 12.0)]
+    fn sut_returns_perimeter_of_shape_correctly(#[case] shape: impl Shape, #[case] expected: f64) {
+        // Act
+        let actual = shape.perimeter();
+
+        // Assert
+        assert_approx_eq!(expected, actual);
+    }
+
+    #[rstest]
+    #[case(Rectangle {width: 12.0, height: 6.0}, 72.0)]
+    #[case(Circle {radius: 10.0}, 100.0 * PI)]
+    #[case(Triangle {a: Point::new(0.0, 0.0), b: Point::new(12.0, 0.0), c: Point::new(6.0, 6.0)}, 36.0)]
+    fn sut_returns_area_of_shape_correctly(#[case] shape: impl Shape, #[case] expected: f64) {
+        // Act
+        let actual = shape.area();
+
+        // Assert
+        assert_approx_eq!(expected, actual);
+    }
+}
+
+#[cfg(test)]
+mod properties_of_shapes {
+    use proptest::prelude::*;
+
+    use super::Shape;
+    use super::sum_areas;
+    use crate::testing::shape;
+
+    proptest! {
+        #[test]
+        fn have_no_negative_area_or_perimeter(shape in shape()) {
+            prop_assert!(shape.area() >= 0.0);
+            prop_assert!(shape.perimeter() >= 0.0);
+        }
+
+        #[test]
+        fn sum_to_area_of_each_added_up(shapes in prop::collection::vec(shape(), 0..10)) {
+            let shapes = shapes.iter().map(|shape| shape as &dyn Shape).collect::<Vec<_>>();
+            let expected = shapes.iter().map(|shape| shape.area()).sum::<f64>();
+            prop_assert_eq!(expected, sum_areas(&shapes));
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use criterion::Criterion;
+use criterion::black_box;
+use criterion::criterion_group;
+use criterion::criterion_main;
+
+use sudoku::v1::Board;
+use sudoku::v1::solver::solve;
+
+const PUZZLES: [(&str, &str); 2] = [
+    ("easy", include_str!("../fixtures/v1/easy.txt")),
+    ("hard", include_str!("../fixtures/v1/hard.txt")),
+];
+
+/// Times solving a puzzle propagation alone solves against one that needs
+/// guesses.
+pub fn bench_solve(c: &mut Criterion) {
+    let mut group = c.benchmark_group("solve");
+    for (name, puzzle) in PUZZLES {
+        let board = puzzle.parse::<Board>().unwrap();
+        group.bench_function(name, |b| b.iter(|| solve(black_box(&board))));
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_solve);
+criterion_main!(benches);
@@ -0,0 +1,28 @@
+use std::io::Read;
+use std::io::stdin;
+use std::process::ExitCode;
+
+use sudoku::v1::Board;
+use sudoku::v1::solver::solve;
+
+fn main() -> ExitCode {
+    let mut puzzle = String::new();
+    if let Err(error) = stdin().read_to_string(&mut puzzle) {
+        eprintln!("{error}");
+        return ExitCode::FAILURE;
+    }
+    let solution = puzzle
+        .parse::<Board>()
+        .map_err(|error| error.to_string())
+        .and_then(|board| solve(&board).map_err(|error| error.to_string()));
+    match solution {
+        Ok(solution) => {
+            print!("{solution}");
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("{error}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,235 @@
+pub mod solver;
+
+use std::fmt;
+use std::str::FromStr;
+
+/// `Board` is a 9x9 Sudoku grid, with some cells filled with digits from 1
+/// to 9 and the rest empty.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Board {
+    /// The cells row by row, 0 for an empty one.
+    cells: [u8; 81],
+}
+
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum ParseError {
+    #[error("cell {cell}: expected a digit, '.' or '0', found {character:?}")]
+    UnexpectedCharacter { cell: usize, character: char },
+    #[error("expected 81 cells, found {0}")]
+    WrongCellCount(usize),
+}
+
+/// `Unit` is a row, column or 3x3 box, numbered from 1 and left to right,
+/// top to bottom.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Unit {
+    Row(usize),
+    Column(usize),
+    Box(usize),
+}
+
+impl fmt::Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Unit::Row(n) => write!(f, "row {n}"),
+            Unit::Column(n) => write!(f, "column {n}"),
+            Unit::Box(n) => write!(f, "box {n}"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+#[error("{digit} appears more than once in {unit}")]
+pub struct Conflict {
+    pub digit: u8,
+    pub unit: Unit,
+}
+
+impl Board {
+    /// Returns the digit at `row` and `column`, counted from 0, or `None`
+    /// if the cell is empty.
+    pub fn get(&self, row: usize, column: usize) -> Option<u8> {
+        match self.cells[row * 9 + column] {
+            0 => None,
+            digit => Some(digit),
+        }
+    }
+
+    /// Checks that no digit appears twice in a row, column or box. Empty
+    /// cells never conflict, so a valid board may still be unsolvable.
+    pub fn check(&self) -> Result<(), Conflict> {
+        for n in 0..9 {
+            let units = [
+                (Unit::Row(n + 1), row(n)),
+                (Unit::Column(n + 1), column(n)),
+                (Unit::Box(n + 1), box_(n)),
+            ];
+            for (unit, cells) in units {
+                let mut seen = 0u16;
+                for digit in cells
+                    .into_iter()
+                    .map(|i| self.cells[i])
+                    .filter(|&digit| digit != 0)
+                {
+                    if seen & (1 << digit) != 0 {
+                        return Err(Conflict { digit, unit });
+                    }
+                    seen |= 1 << digit;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn row(n: usize) -> [usize; 9] {
+    std::array::from_fn(|i| n * 9 + i)
+}
+
+fn column(n: usize) -> [usize; 9] {
+    std::array::from_fn(|i| i * 9 + n)
+}
+
+fn box_(n: usize) -> [usize; 9] {
+    let (top, left) = (n / 3 * 3, n % 3 * 3);
+    std::array::from_fn(|i| (top + i / 3) * 9 + left + i % 3)
+}
+
+/// Reads a board from 81 cells, where `.` or `0` is an empty cell.
+/// Whitespace is skipped, so the board may sit on a line or on nine:
+///
+/// ```text
+/// 53..7....
+/// 6..195...
+/// ...
+/// ```
+///
+/// Cells in errors are counted from 0.
+impl FromStr for Board {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut cells = [0; 81];
+        let mut count = 0;
+        for character in s.chars().filter(|c| !c.is_whitespace()) {
+            let digit = match character {
+                '.' => 0,
+                '0'..='9' => character as u8 - b'0',
+                _ => {
+                    return Err(ParseError::UnexpectedCharacter {
+                        cell: count,
+                        character,
+                    });
+                }
+            };
+            if count < 81 {
+                cells[count] = digit;
+            }
+            count += 1;
+        }
+        if count != 81 {
+            return Err(ParseError::WrongCellCount(count));
+        }
+        Ok(Board { cells })
+    }
+}
+
+/// Writes the board as nine lines, with `.` for empty cells.
+impl fmt::Display for Board {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.cells.chunks(9) {
+            for &digit in row {
+                match digit {
+                    0 => f.write_str(".")?,
+                    _ => write!(f, "{digit}")?,
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Board {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\n{self}")
+    }
+}
+
+#[cfg(test)]
+mod specs_for_board {
+    use super::Board;
+    use super::Conflict;
+    use super::ParseError;
+    use super::Unit;
+
+    const EASY: &str = include_str!("../fixtures/v1/easy.txt");
+
+    fn board_with(cells: &[(usize, char)]) -> Board {
+        let mut text = vec!['.'; 81];
+        for &(cell, digit) in cells {
+            text[cell] = digit;
+        }
+        text.into_iter().collect::<String>().parse().unwrap()
+    }
+
+    #[test]
+    fn sut_reads_board_on_nine_lines() {
+        // Act
+        let actual = EASY.parse::<Board>().unwrap();
+
+        // Assert
+        assert_eq!(Some(5), actual.get(0, 0));
+        assert_eq!(None, actual.get(0, 2));
+        assert_eq!(Some(9), actual.get(8, 8));
+    }
+
+    #[test]
+    fn sut_reads_board_on_one_line_with_zeros_for_empty_cells() {
+        // Arrange
+        let line = EASY.replace('\n', "").replace('.', "0");
+
+        // Act
+        let actual = line.parse::<Board>();
+
+        // Assert
+        assert_eq!(EASY.parse::<Board>(), actual);
+    }
+
+    #[test]
+    fn sut_writes_board_as_it_reads_it() {
+        assert_eq!(EASY, EASY.parse::<Board>().unwrap().to_string());
+    }
+
+    #[rstest::rstest]
+    #[case::too_few(&".".repeat(80), ParseError::WrongCellCount(80))]
+    #[case::too_many(&".".repeat(82), ParseError::WrongCellCount(82))]
+    #[case::letter("..x", ParseError::UnexpectedCharacter { cell: 2, character: 'x' })]
+    #[case::separator("123|456", ParseError::UnexpectedCharacter { cell: 3, character: '|' })]
+    fn sut_rejects_malformed_board(#[case] text: &str, #[case] expected: ParseError) {
+        assert_eq!(Err(expected), text.parse::<Board>());
+    }
+
+    #[rstest::rstest]
+    #[case::row(&[(9, '4'), (17, '4')], Unit::Row(2))]
+    #[case::column(&[(4, '7'), (76, '7')], Unit::Column(5))]
+    #[case::box_(&[(60, '2'), (80, '2')], Unit::Box(9))]
+    fn sut_finds_digit_twice_in_unit(#[case] cells: &[(usize, char)], #[case] unit: Unit) {
+        // Arrange
+        let sut = board_with(cells);
+
+        // Act
+        let actual = sut.check();
+
+        // Assert
+        let digit = cells[0].1 as u8 - b'0';
+        assert_eq!(Err(Conflict { digit, unit }), actual);
+    }
+
+    #[rstest::rstest]
+    #[case::empty(&[])]
+    #[case::same_digit_in_different_units(&[(0, '1'), (13, '1'), (26, '1')])]
+    fn sut_accepts_board_without_conflicts(#[case] cells: &[(usize, char)]) {
+        assert_eq!(Ok(()), board_with(cells).check());
+    }
+}
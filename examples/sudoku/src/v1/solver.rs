@@ -0,0 +1,171 @@
+use std::sync::LazyLock;
+
+use super::Board;
+use super::Conflict;
+use super::box_;
+use super::column;
+use super::row;
+
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum SolveError {
+    #[error(transparent)]
+    Invalid(#[from] Conflict),
+    #[error("puzzle has no solution")]
+    Unsolvable,
+    #[error("puzzle has more than one solution")]
+    MultipleSolutions,
+}
+
+/// The 20 cells sharing a row, column or box with each cell.
+static PEERS: LazyLock<[Vec<usize>; 81]> = LazyLock::new(|| {
+    std::array::from_fn(|cell| {
+        let (r, c) = (cell / 9, cell % 9);
+        let mut peers = [row(r), column(c), box_(r / 3 * 3 + c / 3)].concat();
+        peers.sort_unstable();
+        peers.dedup();
+        peers.retain(|&peer| peer != cell);
+        peers
+    })
+});
+
+/// Solves the puzzle, which has to have exactly one solution.
+///
+/// The search fills every cell left with a single candidate digit, and
+/// repeats until none is left. Then it guesses the digits of the cell with
+/// the fewest candidates, and backtracks on a guess that leaves a cell
+/// without any.
+pub fn solve(board: &Board) -> Result<Board, SolveError> {
+    board.check()?;
+    let mut solutions = vec![];
+    search(board.cells, &mut solutions);
+    match solutions[..] {
+        [] => Err(SolveError::Unsolvable),
+        [cells] => Ok(Board { cells }),
+        _ => Err(SolveError::MultipleSolutions),
+    }
+}
+
+/// Collects solutions until it finds a second one, which is enough to tell
+/// the puzzle apart from a proper one.
+fn search(mut cells: [u8; 81], solutions: &mut Vec<[u8; 81]>) {
+    if !propagate(&mut cells) {
+        return;
+    }
+    let guess = (0..81)
+        .filter(|&cell| cells[cell] == 0)
+        .min_by_key(|&cell| candidates(&cells, cell).count_ones());
+    let Some(cell) = guess else {
+        solutions.push(cells);
+        return;
+    };
+    let candidates = candidates(&cells, cell);
+    for digit in (1..=9).filter(|digit| candidates & (1 << digit) != 0) {
+        cells[cell] = digit;
+        search(cells, solutions);
+        if solutions.len() > 1 {
+            return;
+        }
+    }
+}
+
+/// Fills the cells left with a single candidate, and returns false as soon
+/// as a cell is left with none.
+fn propagate(cells: &mut [u8; 81]) -> bool {
+    loop {
+        let mut filled = false;
+        for cell in 0..81 {
+            if cells[cell] != 0 {
+                continue;
+            }
+            let candidates = candidates(cells, cell);
+            match candidates.count_ones() {
+                0 => return false,
+                1 => {
+                    cells[cell] = candidates.trailing_zeros() as u8;
+                    filled = true;
+                }
+                _ => {}
+            }
+        }
+        if !filled {
+            return true;
+        }
+    }
+}
+
+/// Returns the digits `cell` could hold as bits 1 to 9.
+fn candidates(cells: &[u8; 81], cell: usize) -> u16 {
+    let taken = PEERS[cell]
+        .iter()
+        .fold(0u16, |taken, &peer| taken | (1 << cells[peer]));
+    !taken & 0b11_1111_1110
+}
+
+#[cfg(test)]
+mod specs_for_solve {
+    use super::SolveError;
+    use super::solve;
+    use crate::v1::Board;
+    use crate::v1::Conflict;
+    use crate::v1::Unit;
+
+    fn board(text: &str) -> Board {
+        text.parse().unwrap()
+    }
+
+    #[rstest::rstest]
+    #[case::easy(
+        include_str!("../../fixtures/v1/easy.txt"),
+        include_str!("../../fixtures/v1/easy.solution.txt"),
+    )]
+    #[case::hard(
+        include_str!("../../fixtures/v1/hard.txt"),
+        include_str!("../../fixtures/v1/hard.solution.txt"),
+    )]
+    fn sut_solves_puzzle(#[case] puzzle: &str, #[case] solution: &str) {
+        // Act
+        let actual = solve(&board(puzzle));
+
+        // Assert
+        assert_eq!(Ok(board(solution)), actual);
+    }
+
+    #[test]
+    fn sut_returns_solved_board_as_it_is() {
+        // Arrange
+        let solved = board(include_str!("../../fixtures/v1/easy.solution.txt"));
+
+        // Act
+        let actual = solve(&solved);
+
+        // Assert
+        assert_eq!(Ok(solved), actual);
+    }
+
+    #[rstest::rstest]
+    #[case::unsolvable(include_str!("../../fixtures/v1/unsolvable.txt"), SolveError::Unsolvable)]
+    #[case::multiple(include_str!("../../fixtures/v1/multiple.txt"), SolveError::MultipleSolutions)]
+    #[case::empty(&".".repeat(81), SolveError::MultipleSolutions)]
+    fn sut_rejects_puzzle_without_single_solution(
+        #[case] puzzle: &str,
+        #[case] expected: SolveError,
+    ) {
+        assert_eq!(Err(expected), solve(&board(puzzle)));
+    }
+
+    #[test]
+    fn sut_rejects_puzzle_with_conflict() {
+        // Arrange
+        let puzzle = format!("11{}", ".".repeat(79));
+
+        // Act
+        let actual = solve(&board(&puzzle));
+
+        // Assert
+        let expected = Conflict {
+            digit: 1,
+            unit: Unit::Row(1),
+        };
+        assert_eq!(Err(SolveError::Invalid(expected)), actual);
+    }
+}
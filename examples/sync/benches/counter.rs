@@ -0,0 +1,55 @@
+use std::sync::Arc;
+use std::thread;
+
+use criterion::BenchmarkId;
+use criterion::Criterion;
+use criterion::criterion_group;
+use criterion::criterion_main;
+
+use sync::v3::AtomicCounter;
+use sync::v3::Counter;
+use sync::v3::ShardedCounter;
+
+const THREADS: [usize; 3] = [1, 4, 16];
+const INCREASES: usize = 10_000;
+
+type NewCounter = fn() -> Arc<dyn Counter>;
+
+fn count_up(counter: Arc<dyn Counter>, threads: usize) -> usize {
+    let handles = (0..threads)
+        .map(|_| {
+            let counter = Arc::clone(&counter);
+            thread::spawn(move || {
+                for _ in 0..INCREASES {
+                    counter.increase();
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    counter.value()
+}
+
+/// Compares counting up from a growing number of threads behind a lock, in
+/// an atomic and in sharded atomics.
+pub fn bench_counters(c: &mut Criterion) {
+    let counters: [(&str, NewCounter); 3] = [
+        ("RwLock", || Arc::new(sync::v2::Counter::new())),
+        ("atomic", || Arc::new(AtomicCounter::new())),
+        ("sharded", || Arc::new(ShardedCounter::new())),
+    ];
+    let mut group = c.benchmark_group("counter increase");
+    for threads in THREADS {
+        for (name, counter) in counters {
+            group.bench_with_input(BenchmarkId::new(name, threads), &threads, |b, &threads| {
+                b.iter(|| count_up(counter(), threads))
+            });
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_counters);
+criterion_main!(benches);
@@ -1,2 +1,5 @@
+mod primitives;
 pub mod v1;
 pub mod v2;
+pub mod v3;
+pub mod v4;
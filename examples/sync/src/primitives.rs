@@ -0,0 +1,28 @@
+//! The synchronization primitives the counters are built on: the ones of
+//! `std`, or of `loom` when built with `--cfg sync_loom`, so that the loom
+//! models explore every interleaving of the very same code.
+
+#[cfg(sync_loom)]
+pub(crate) use loom::sync::Condvar;
+#[cfg(sync_loom)]
+pub(crate) use loom::sync::Mutex;
+#[cfg(sync_loom)]
+pub(crate) use loom::sync::RwLock;
+#[cfg(sync_loom)]
+pub(crate) use loom::sync::atomic::AtomicUsize;
+#[cfg(sync_loom)]
+pub(crate) use loom::sync::atomic::Ordering;
+#[cfg(sync_loom)]
+pub(crate) use loom::thread;
+#[cfg(not(sync_loom))]
+pub(crate) use std::sync::Condvar;
+#[cfg(not(sync_loom))]
+pub(crate) use std::sync::Mutex;
+#[cfg(not(sync_loom))]
+pub(crate) use std::sync::RwLock;
+#[cfg(not(sync_loom))]
+pub(crate) use std::sync::atomic::AtomicUsize;
+#[cfg(not(sync_loom))]
+pub(crate) use std::sync::atomic::Ordering;
+#[cfg(not(sync_loom))]
+pub(crate) use std::thread;
@@ -1,4 +1,4 @@
-use std::sync::RwLock;
+use crate::primitives::RwLock;
 
 #[derive(Default, Debug)]
 pub struct Counter {
@@ -6,20 +6,20 @@ pub struct Counter {
 }
 
 impl Counter {
-    fn new() -> Self {
+    pub fn new() -> Self {
         Self::default()
     }
 
-    fn increase(&self) {
+    pub fn increase(&self) {
         *self.value.write().unwrap() += 1;
     }
 
-    fn value(&self) -> usize {
+    pub fn value(&self) -> usize {
         *self.value.read().unwrap()
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, not(sync_loom)))]
 mod specs_for_counter {
     use std::sync::Arc;
 
@@ -65,3 +65,33 @@ mod specs_for_counter {
         assert_eq!(actual, count);
     }
 }
+
+#[cfg(all(test, sync_loom))]
+mod models_of_counter {
+    use loom::sync::Arc;
+    use loom::thread;
+
+    use super::Counter;
+
+    #[test]
+    fn sut_counts_both_increases_however_threads_interleave() {
+        loom::model(|| {
+            // Arrange
+            let counter = Arc::new(Counter::new());
+
+            // Act
+            let handles = (0..2)
+                .map(|_| {
+                    let counter = Arc::clone(&counter);
+                    thread::spawn(move || counter.increase())
+                })
+                .collect::<Vec<_>>();
+            for handle in handles {
+                handle.join().unwrap();
+            }
+
+            // Assert
+            assert_eq!(2, counter.value());
+        });
+    }
+}
@@ -0,0 +1,208 @@
+use std::hash::BuildHasher;
+use std::hash::Hash;
+
+use crate::primitives::AtomicUsize;
+use crate::primitives::Ordering;
+use crate::primitives::thread;
+
+/// Picks the shard of a thread. Under loom the pick is the same on every
+/// run, as a model must replay the same way each time it is explored.
+#[cfg(not(sync_loom))]
+type ShardHasher = std::hash::RandomState;
+#[cfg(sync_loom)]
+type ShardHasher = std::hash::BuildHasherDefault<std::hash::DefaultHasher>;
+
+/// `Counter` is counted up by many threads at once.
+pub trait Counter: Send + Sync {
+    fn increase(&self);
+    fn value(&self) -> usize;
+}
+
+impl Counter for crate::v2::Counter {
+    fn increase(&self) {
+        crate::v2::Counter::increase(self);
+    }
+
+    fn value(&self) -> usize {
+        crate::v2::Counter::value(self)
+    }
+}
+
+/// `AtomicCounter` counts without a lock, in a single atomic.
+#[derive(Default, Debug)]
+pub struct AtomicCounter {
+    value: AtomicUsize,
+}
+
+impl AtomicCounter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Counter for AtomicCounter {
+    fn increase(&self) {
+        self.value.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn value(&self) -> usize {
+        self.value.load(Ordering::Relaxed)
+    }
+}
+
+/// `Shard` sits on a cache line of its own, so that threads counting on
+/// neighbouring shards do not slow each other down.
+#[derive(Default, Debug)]
+#[repr(align(64))]
+struct Shard(AtomicUsize);
+
+/// `ShardedCounter` spreads the count over shards picked by thread, and
+/// adds them up when read. Counting up is cheap, reading is not.
+#[derive(Debug)]
+pub struct ShardedCounter {
+    shards: Vec<Shard>,
+    hasher: ShardHasher,
+}
+
+impl ShardedCounter {
+    pub fn new() -> Self {
+        ShardedCounter {
+            shards: (0..shard_count()).map(|_| Shard::default()).collect(),
+            hasher: ShardHasher::default(),
+        }
+    }
+}
+
+#[cfg(not(sync_loom))]
+fn shard_count() -> usize {
+    std::thread::available_parallelism().map_or(4, |n| n.get() * 4)
+}
+
+#[cfg(not(sync_loom))]
+fn current_thread() -> impl Hash {
+    thread::current().id()
+}
+
+/// Under loom a thread id hashes differently on every run, while the id it
+/// prints is the same.
+#[cfg(sync_loom)]
+fn current_thread() -> impl Hash {
+    format!("{:?}", thread::current().id())
+}
+
+/// Two shards are enough for the models to have threads counting on the
+/// same shard or on different ones.
+#[cfg(sync_loom)]
+fn shard_count() -> usize {
+    2
+}
+
+impl Default for ShardedCounter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Counter for ShardedCounter {
+    fn increase(&self) {
+        let shard = self.hasher.hash_one(current_thread()) as usize % self.shards.len();
+        self.shards[shard].0.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn value(&self) -> usize {
+        self.shards
+            .iter()
+            .map(|shard| shard.0.load(Ordering::Relaxed))
+            .sum()
+    }
+}
+
+#[cfg(all(test, not(sync_loom)))]
+mod specs_for_counter {
+    use std::sync::Arc;
+    use std::thread;
+
+    use rstest::rstest;
+
+    use super::AtomicCounter;
+    use super::Counter;
+    use super::ShardedCounter;
+
+    #[rstest]
+    #[case::rw_lock(Arc::new(crate::v2::Counter::new()))]
+    #[case::atomic(Arc::new(AtomicCounter::new()))]
+    #[case::sharded(Arc::new(ShardedCounter::new()))]
+    fn sut_counts_every_increase_of_every_thread(#[case] counter: Arc<dyn Counter>) {
+        // Arrange
+        let threads = 8;
+        let increases = 1000;
+
+        // Act
+        let handles = (0..threads)
+            .map(|_| {
+                let counter = Arc::clone(&counter);
+                thread::spawn(move || {
+                    for _ in 0..increases {
+                        counter.increase();
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        // Assert
+        assert_eq!(threads * increases, counter.value());
+    }
+}
+
+#[cfg(all(test, sync_loom))]
+mod models_of_counter {
+    use loom::sync::Arc;
+    use loom::thread;
+
+    use super::AtomicCounter;
+    use super::Counter;
+    use super::ShardedCounter;
+
+    /// Explores every interleaving of two threads counting up once while
+    /// the main thread reads the counter.
+    fn check_increases_and_read<C: Counter + 'static>(new: fn() -> C) {
+        loom::model(move || {
+            // Arrange
+            let counter = Arc::new(new());
+
+            // Act
+            let handles = (0..2)
+                .map(|_| {
+                    let counter = Arc::clone(&counter);
+                    thread::spawn(move || counter.increase())
+                })
+                .collect::<Vec<_>>();
+            let read_meanwhile = counter.value();
+            for handle in handles {
+                handle.join().unwrap();
+            }
+
+            // Assert
+            assert!(read_meanwhile <= 2);
+            assert_eq!(2, counter.value());
+        });
+    }
+
+    #[test]
+    fn sut_with_rw_lock_counts_every_increase() {
+        check_increases_and_read(crate::v2::Counter::new);
+    }
+
+    #[test]
+    fn sut_with_atomic_counts_every_increase() {
+        check_increases_and_read(AtomicCounter::new);
+    }
+
+    #[test]
+    fn sut_with_shards_counts_every_increase() {
+        check_increases_and_read(ShardedCounter::new);
+    }
+}
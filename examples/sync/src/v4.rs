@@ -0,0 +1,131 @@
+use crate::primitives::Condvar;
+use crate::primitives::Mutex;
+
+/// `WaitGroup` lets a thread wait until a number of tasks are done: every
+/// task is added before it starts, and marked done when it finishes.
+#[derive(Default, Debug)]
+pub struct WaitGroup {
+    pending: Mutex<usize>,
+    all_done: Condvar,
+}
+
+impl WaitGroup {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `tasks` to the ones to wait for.
+    pub fn add(&self, tasks: usize) {
+        *self.pending.lock().unwrap() += tasks;
+    }
+
+    /// Marks one task done, waking up the waiting threads if it was the
+    /// last one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if more tasks are marked done than were added.
+    pub fn done(&self) {
+        let mut pending = self.pending.lock().unwrap();
+        *pending = pending
+            .checked_sub(1)
+            .expect("more tasks done than were added");
+        if *pending == 0 {
+            self.all_done.notify_all();
+        }
+    }
+
+    /// Blocks until every task added is done.
+    pub fn wait(&self) {
+        let mut pending = self.pending.lock().unwrap();
+        while *pending > 0 {
+            pending = self.all_done.wait(pending).unwrap();
+        }
+    }
+}
+
+#[cfg(all(test, not(sync_loom)))]
+mod specs_for_wait_group {
+    use std::sync::Arc;
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+    use std::thread;
+
+    use super::WaitGroup;
+
+    #[test]
+    fn sut_waits_until_every_task_is_done() {
+        // Arrange
+        let tasks = 8;
+        let sut = Arc::new(WaitGroup::new());
+        let finished = Arc::new(AtomicUsize::new(0));
+        sut.add(tasks);
+
+        // Act
+        for _ in 0..tasks {
+            let sut = Arc::clone(&sut);
+            let finished = Arc::clone(&finished);
+            thread::spawn(move || {
+                finished.fetch_add(1, Ordering::SeqCst);
+                sut.done();
+            });
+        }
+        sut.wait();
+
+        // Assert
+        assert_eq!(tasks, finished.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn sut_does_not_wait_without_tasks() {
+        // Arrange
+        let sut = WaitGroup::new();
+
+        // Act
+        sut.wait();
+    }
+
+    #[test]
+    #[should_panic(expected = "more tasks done than were added")]
+    fn sut_panics_if_more_tasks_are_done_than_added() {
+        // Arrange
+        let sut = WaitGroup::new();
+
+        // Act
+        sut.done();
+    }
+}
+
+#[cfg(all(test, sync_loom))]
+mod models_of_wait_group {
+    use loom::sync::Arc;
+    use loom::sync::atomic::AtomicUsize;
+    use loom::sync::atomic::Ordering;
+    use loom::thread;
+
+    use super::WaitGroup;
+
+    #[test]
+    fn sut_returns_from_wait_only_after_every_task_is_done() {
+        loom::model(|| {
+            // Arrange
+            let sut = Arc::new(WaitGroup::new());
+            let finished = Arc::new(AtomicUsize::new(0));
+            sut.add(2);
+
+            // Act
+            for _ in 0..2 {
+                let sut = Arc::clone(&sut);
+                let finished = Arc::clone(&finished);
+                thread::spawn(move || {
+                    finished.fetch_add(1, Ordering::Relaxed);
+                    sut.done();
+                });
+            }
+            sut.wait();
+
+            // Assert
+            assert_eq!(2, finished.load(Ordering::Relaxed));
+        });
+    }
+}
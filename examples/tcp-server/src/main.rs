@@ -0,0 +1,17 @@
+use std::error::Error;
+
+use tcp_server::v1::LineServer;
+use tokio::net::TcpListener;
+
+const ADDRESS: &str = "127.0.0.1:7000";
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    let listener = TcpListener::bind(ADDRESS).await?;
+    println!("listening on {}", listener.local_addr()?);
+    let shutdown = async {
+        tokio::signal::ctrl_c().await.unwrap();
+    };
+    LineServer::new().serve(listener, shutdown).await?;
+    Ok(())
+}
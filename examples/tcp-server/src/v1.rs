@@ -0,0 +1,371 @@
+use std::future::Future;
+use std::io;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::io::AsyncBufReadExt;
+use tokio::io::AsyncWrite;
+use tokio::io::AsyncWriteExt;
+use tokio::io::BufReader;
+use tokio::net::TcpListener;
+use tokio::net::TcpStream;
+use tokio::sync::Semaphore;
+use tokio::sync::watch;
+use tokio::task::JoinSet;
+
+pub const DEFAULT_MAX_CONNECTIONS: usize = 64;
+pub const DEFAULT_IDLE_TIMEOUT: Duration = Duration::from_secs(60);
+
+pub const TOO_MANY_CONNECTIONS: &str = "ERR too many connections";
+pub const IDLE: &str = "BYE idle for too long";
+pub const SHUTTING_DOWN: &str = "BYE server is shutting down";
+
+/// `Reply` is what the server answers to a line.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Reply {
+    /// The line to answer with, keeping the connection open.
+    Line(String),
+    /// The last line to answer with before closing the connection.
+    Close(String),
+}
+
+/// Answers a line of the protocol:
+///
+/// - `ECHO <text>` answers `<text>`
+/// - `PING` answers `PONG`
+/// - `QUIT` answers `BYE` and closes the connection
+pub fn respond(line: &str) -> Reply {
+    let (command, argument) = line.split_once(' ').unwrap_or((line, ""));
+    match command {
+        "ECHO" => Reply::Line(argument.to_string()),
+        "PING" => Reply::Line("PONG".to_string()),
+        "QUIT" => Reply::Close("BYE".to_string()),
+        _ => Reply::Line(format!("ERR unknown command: {}", command)),
+    }
+}
+
+/// `LineServer` answers the lines of every client in a task of its own.
+///
+/// A client beyond the maximum number of connections is told so and turned
+/// away, and a client that sends nothing for the idle timeout is
+/// disconnected.
+pub struct LineServer {
+    max_connections: usize,
+    idle_timeout: Duration,
+}
+
+impl LineServer {
+    pub fn new() -> Self {
+        LineServer {
+            max_connections: DEFAULT_MAX_CONNECTIONS,
+            idle_timeout: DEFAULT_IDLE_TIMEOUT,
+        }
+    }
+
+    pub fn with_max_connections(self, max_connections: usize) -> Self {
+        LineServer {
+            max_connections,
+            ..self
+        }
+    }
+
+    pub fn with_idle_timeout(self, idle_timeout: Duration) -> Self {
+        LineServer {
+            idle_timeout,
+            ..self
+        }
+    }
+
+    /// Serves on the listener until `shutdown` completes.
+    ///
+    /// Shutting down stops accepting connections and tells every connected
+    /// client goodbye. A line being answered is answered first. Returns once
+    /// every connection is closed.
+    pub async fn serve(
+        self,
+        listener: TcpListener,
+        shutdown: impl Future<Output = ()>,
+    ) -> io::Result<()> {
+        let permits = Arc::new(Semaphore::new(self.max_connections));
+        let (stop, stopped) = watch::channel(());
+        let mut connections = JoinSet::new();
+        let mut shutdown = std::pin::pin!(shutdown);
+        loop {
+            tokio::select! {
+                () = &mut shutdown => break,
+                accepted = listener.accept() => {
+                    let (mut stream, _) = accepted?;
+                    let Ok(permit) = permits.clone().try_acquire_owned() else {
+                        connections.spawn(async move {
+                            let _ = write_line(&mut stream, TOO_MANY_CONNECTIONS).await;
+                        });
+                        continue;
+                    };
+                    let stopped = stopped.clone();
+                    let idle_timeout = self.idle_timeout;
+                    connections.spawn(async move {
+                        let _ = handle(stream, idle_timeout, stopped).await;
+                        drop(permit);
+                    });
+                }
+                // Reaps closed connections, which would pile up otherwise.
+                Some(_) = connections.join_next(), if !connections.is_empty() => {}
+            }
+        }
+        drop(listener);
+        stop.send_replace(());
+        while connections.join_next().await.is_some() {}
+        Ok(())
+    }
+}
+
+impl Default for LineServer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+async fn handle(
+    stream: TcpStream,
+    idle_timeout: Duration,
+    mut stopped: watch::Receiver<()>,
+) -> io::Result<()> {
+    let (reader, mut writer) = stream.into_split();
+    let mut lines = BufReader::new(reader).lines();
+    loop {
+        // Reading a line is cancel safe, so no half-read line is lost when
+        // the server stops.
+        let line = tokio::select! {
+            line = tokio::time::timeout(idle_timeout, lines.next_line()) => line,
+            _ = stopped.changed() => return write_line(&mut writer, SHUTTING_DOWN).await,
+        };
+        let Ok(line) = line else {
+            return write_line(&mut writer, IDLE).await;
+        };
+        let Some(line) = line? else {
+            return Ok(());
+        };
+        match respond(&line) {
+            Reply::Line(reply) => write_line(&mut writer, &reply).await?,
+            Reply::Close(reply) => return write_line(&mut writer, &reply).await,
+        }
+    }
+}
+
+async fn write_line(writer: &mut (impl AsyncWrite + Unpin), line: &str) -> io::Result<()> {
+    writer.write_all(format!("{}\n", line).as_bytes()).await
+}
+
+#[cfg(test)]
+mod specs_for_respond {
+    use super::Reply;
+    use super::respond;
+
+    #[rstest::rstest]
+    #[case::echo("ECHO hello, world", Reply::Line("hello, world".to_string()))]
+    #[case::echo_nothing("ECHO", Reply::Line("".to_string()))]
+    #[case::ping("PING", Reply::Line("PONG".to_string()))]
+    #[case::quit("QUIT", Reply::Close("BYE".to_string()))]
+    #[case::unknown("JUMP high", Reply::Line("ERR unknown command: JUMP".to_string()))]
+    #[case::lowercase("ping", Reply::Line("ERR unknown command: ping".to_string()))]
+    fn sut_answers_line(#[case] line: &str, #[case] expected: Reply) {
+        // Act
+        let actual = respond(line);
+
+        // Assert
+        assert_eq!(expected, actual);
+    }
+}
+
+#[cfg(test)]
+mod specs_for_line_server {
+    use std::net::SocketAddr;
+    use std::time::Duration;
+
+    use tokio::io::AsyncBufReadExt;
+    use tokio::io::AsyncWriteExt;
+    use tokio::io::BufReader;
+    use tokio::io::Lines;
+    use tokio::net::TcpListener;
+    use tokio::net::TcpStream;
+    use tokio::net::tcp::OwnedReadHalf;
+    use tokio::net::tcp::OwnedWriteHalf;
+    use tokio::sync::oneshot;
+    use tokio::task::JoinHandle;
+
+    use super::IDLE;
+    use super::LineServer;
+    use super::SHUTTING_DOWN;
+    use super::TOO_MANY_CONNECTIONS;
+
+    /// `Client` speaks the protocol over a raw TCP connection.
+    struct Client {
+        lines: Lines<BufReader<OwnedReadHalf>>,
+        writer: OwnedWriteHalf,
+    }
+
+    impl Client {
+        async fn connect(address: SocketAddr) -> Self {
+            let (reader, writer) = TcpStream::connect(address).await.unwrap().into_split();
+            Client {
+                lines: BufReader::new(reader).lines(),
+                writer,
+            }
+        }
+
+        async fn send(&mut self, line: &str) {
+            self.writer
+                .write_all(format!("{}\n", line).as_bytes())
+                .await
+                .unwrap();
+        }
+
+        /// Returns the next line, or `None` once the server closed the
+        /// connection.
+        async fn receive(&mut self) -> Option<String> {
+            self.lines.next_line().await.unwrap()
+        }
+    }
+
+    /// `Running` is a server on a port of its own that shuts down when
+    /// `stop` is sent to.
+    struct Running {
+        address: SocketAddr,
+        stop: oneshot::Sender<()>,
+        server: JoinHandle<std::io::Result<()>>,
+    }
+
+    async fn start(sut: LineServer) -> Running {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let (stop, stopped) = oneshot::channel();
+        let server = tokio::spawn(sut.serve(listener, async {
+            let _ = stopped.await;
+        }));
+        Running {
+            address,
+            stop,
+            server,
+        }
+    }
+
+    #[tokio::test]
+    async fn sut_answers_every_line_of_client() {
+        // Arrange
+        let running = start(LineServer::new()).await;
+        let mut client = Client::connect(running.address).await;
+
+        // Act
+        client.send("ECHO hello").await;
+        client.send("PING").await;
+        client.send("QUIT").await;
+
+        // Assert
+        assert_eq!(Some("hello".to_string()), client.receive().await);
+        assert_eq!(Some("PONG".to_string()), client.receive().await);
+        assert_eq!(Some("BYE".to_string()), client.receive().await);
+        assert_eq!(None, client.receive().await);
+    }
+
+    #[tokio::test]
+    async fn sut_answers_clients_independently() {
+        // Arrange
+        let running = start(LineServer::new()).await;
+        let mut client_1 = Client::connect(running.address).await;
+        let mut client_2 = Client::connect(running.address).await;
+
+        // Act
+        client_1.send("ECHO one").await;
+        client_2.send("ECHO two").await;
+
+        // Assert
+        assert_eq!(Some("two".to_string()), client_2.receive().await);
+        assert_eq!(Some("one".to_string()), client_1.receive().await);
+    }
+
+    #[tokio::test]
+    async fn sut_turns_away_client_beyond_max_connections() {
+        // Arrange
+        let running = start(LineServer::new().with_max_connections(1)).await;
+        let mut client_1 = Client::connect(running.address).await;
+        client_1.send("PING").await;
+        client_1.receive().await;
+
+        // Act
+        let mut client_2 = Client::connect(running.address).await;
+
+        // Assert
+        assert_eq!(
+            Some(TOO_MANY_CONNECTIONS.to_string()),
+            client_2.receive().await
+        );
+        assert_eq!(None, client_2.receive().await);
+    }
+
+    #[tokio::test]
+    async fn sut_accepts_client_once_connection_is_closed() {
+        // Arrange
+        let running = start(LineServer::new().with_max_connections(1)).await;
+        let mut client_1 = Client::connect(running.address).await;
+        client_1.send("QUIT").await;
+        while client_1.receive().await.is_some() {}
+
+        // Act
+        let mut client_2 = Client::connect(running.address).await;
+        client_2.send("PING").await;
+
+        // Assert
+        assert_eq!(Some("PONG".to_string()), client_2.receive().await);
+    }
+
+    #[tokio::test]
+    async fn sut_disconnects_idle_client() {
+        // Arrange
+        let idle_timeout = Duration::from_millis(50);
+        let running = start(LineServer::new().with_idle_timeout(idle_timeout)).await;
+
+        // Act
+        let mut client = Client::connect(running.address).await;
+
+        // Assert
+        assert_eq!(Some(IDLE.to_string()), client.receive().await);
+        assert_eq!(None, client.receive().await);
+    }
+
+    #[tokio::test]
+    async fn sut_keeps_client_that_sends_lines_within_idle_timeout() {
+        // Arrange
+        let idle_timeout = Duration::from_millis(200);
+        let running = start(LineServer::new().with_idle_timeout(idle_timeout)).await;
+        let mut client = Client::connect(running.address).await;
+
+        // Act
+        let mut actual = vec![];
+        for _ in 0..2 {
+            tokio::time::sleep(Duration::from_millis(120)).await;
+            client.send("PING").await;
+            actual.push(client.receive().await);
+        }
+
+        // Assert
+        assert_eq!(vec![Some("PONG".to_string()); 2], actual);
+    }
+
+    #[tokio::test]
+    async fn sut_says_goodbye_to_clients_and_stops_accepting_on_shutdown() {
+        // Arrange
+        let running = start(LineServer::new()).await;
+        let mut client = Client::connect(running.address).await;
+        client.send("PING").await;
+        client.receive().await;
+
+        // Act
+        running.stop.send(()).unwrap();
+
+        // Assert
+        assert_eq!(Some(SHUTTING_DOWN.to_string()), client.receive().await);
+        assert_eq!(None, client.receive().await);
+        running.server.await.unwrap().unwrap();
+        assert!(TcpStream::connect(running.address).await.is_err());
+    }
+}
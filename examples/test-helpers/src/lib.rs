@@ -0,0 +1,21 @@
+//! Test doubles shared by the examples' specs.
+//!
+//! The doubles record their calls into a `CallRecorder`. Doubles sharing a
+//! recorder record into one log, in the order the calls happened, so specs
+//! can assert on how calls to several collaborators interleave.
+//!
+//! With the `tracing` feature, `TraceCapture` captures the spans and events
+//! traced while a spec runs, so it can assert on them.
+
+mod recorder;
+mod spy;
+#[cfg(feature = "tracing")]
+mod trace;
+
+pub use recorder::CallRecorder;
+pub use spy::SpySleeper;
+pub use spy::SpyWriter;
+#[cfg(feature = "tracing")]
+pub use trace::Fields;
+#[cfg(feature = "tracing")]
+pub use trace::TraceCapture;
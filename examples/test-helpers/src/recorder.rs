@@ -0,0 +1,96 @@
+use std::sync::Arc;
+use std::sync::Mutex;
+
+/// `CallRecorder` keeps the calls made to test doubles in the order they
+/// happened. Clones share the same log, so a recorder can be handed to
+/// several doubles, or moved into a closure and still be read afterwards.
+#[derive(Debug)]
+pub struct CallRecorder<C> {
+    calls: Arc<Mutex<Vec<C>>>,
+}
+
+impl<C> CallRecorder<C> {
+    pub fn new() -> Self {
+        CallRecorder {
+            calls: Arc::default(),
+        }
+    }
+
+    pub fn record(&self, call: C) {
+        self.calls.lock().unwrap().push(call);
+    }
+
+    pub fn count(&self) -> usize {
+        self.calls.lock().unwrap().len()
+    }
+}
+
+impl<C: Clone> CallRecorder<C> {
+    /// Returns the calls recorded so far, the earliest first.
+    pub fn calls(&self) -> Vec<C> {
+        self.calls.lock().unwrap().clone()
+    }
+}
+
+impl<C: PartialEq> CallRecorder<C> {
+    /// Returns how often `call` was recorded.
+    pub fn count_of(&self, call: &C) -> usize {
+        self.calls
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|c| *c == call)
+            .count()
+    }
+}
+
+impl<C> Clone for CallRecorder<C> {
+    fn clone(&self) -> Self {
+        CallRecorder {
+            calls: Arc::clone(&self.calls),
+        }
+    }
+}
+
+impl<C> Default for CallRecorder<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod specs_for_call_recorder {
+    use std::thread;
+
+    use super::CallRecorder;
+
+    #[test]
+    fn sut_keeps_calls_in_order() {
+        // Arrange
+        let sut = CallRecorder::new();
+
+        // Act
+        sut.record("write");
+        sut.record("sleep");
+        sut.record("write");
+
+        // Assert
+        assert_eq!(vec!["write", "sleep", "write"], sut.calls());
+        assert_eq!(3, sut.count());
+        assert_eq!(2, sut.count_of(&"write"));
+    }
+
+    #[test]
+    fn sut_shares_calls_among_clones() {
+        // Arrange
+        let sut = CallRecorder::new();
+        let clone = sut.clone();
+
+        // Act
+        thread::spawn(move || clone.record(1)).join().unwrap();
+        sut.record(2);
+
+        // Assert
+        assert_eq!(vec![1, 2], sut.calls());
+    }
+}
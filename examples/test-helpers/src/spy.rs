@@ -0,0 +1,89 @@
+use std::io;
+use std::io::Write;
+
+use super::CallRecorder;
+
+/// `SpyWriter` keeps what is written to it, and records `call` for every
+/// write.
+#[derive(Debug)]
+pub struct SpyWriter<C> {
+    recorder: CallRecorder<C>,
+    call: C,
+    written: Vec<u8>,
+}
+
+impl<C: Clone> SpyWriter<C> {
+    pub fn new(recorder: &CallRecorder<C>, call: C) -> Self {
+        SpyWriter {
+            recorder: recorder.clone(),
+            call,
+            written: vec![],
+        }
+    }
+
+    /// Returns everything written so far, as text.
+    pub fn written(&self) -> String {
+        String::from_utf8_lossy(&self.written).into_owned()
+    }
+}
+
+impl<C: Clone> Write for SpyWriter<C> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.recorder.record(self.call.clone());
+        self.written.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// `SpySleeper` records `call` whenever it is told to sleep, without
+/// sleeping. Every example declares its own `Sleeper` trait, so the
+/// example implements its trait for `SpySleeper` by calling
+/// `SpySleeper::sleep`.
+#[derive(Debug)]
+pub struct SpySleeper<C> {
+    recorder: CallRecorder<C>,
+    call: C,
+}
+
+impl<C: Clone> SpySleeper<C> {
+    pub fn new(recorder: &CallRecorder<C>, call: C) -> Self {
+        SpySleeper {
+            recorder: recorder.clone(),
+            call,
+        }
+    }
+
+    pub fn sleep(&self) {
+        self.recorder.record(self.call.clone());
+    }
+}
+
+#[cfg(test)]
+mod specs_for_spies {
+    use std::io::Write;
+
+    use super::SpySleeper;
+    use super::SpyWriter;
+    use crate::CallRecorder;
+
+    #[test]
+    fn sut_records_writes_and_sleeps_into_shared_recorder_in_order() {
+        // Arrange
+        let recorder = CallRecorder::new();
+        let mut writer = SpyWriter::new(&recorder, "write");
+        let sleeper = SpySleeper::new(&recorder, "sleep");
+
+        // Act
+        write!(writer, "3").unwrap();
+        sleeper.sleep();
+        write!(writer, "Go!").unwrap();
+
+        // Assert
+        assert_eq!(vec!["write", "sleep", "write"], recorder.calls());
+        assert_eq!("3Go!", writer.written());
+    }
+}
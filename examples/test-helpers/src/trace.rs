@@ -0,0 +1,162 @@
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use tracing::Event;
+use tracing::Subscriber;
+use tracing::field::Field;
+use tracing::field::Visit;
+use tracing::span::Attributes;
+use tracing::span::Id;
+use tracing::span::Record;
+use tracing::subscriber::DefaultGuard;
+use tracing_subscriber::Layer;
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::registry::LookupSpan;
+
+/// The fields of a span or an event, by name, as they would be printed.
+pub type Fields = BTreeMap<String, String>;
+
+struct FieldVisitor<'a>(&'a mut Fields);
+
+impl Visit for FieldVisitor<'_> {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name().to_string(), value.to_string());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.0
+            .insert(field.name().to_string(), format!("{:?}", value));
+    }
+}
+
+/// `TraceCapture` keeps the fields of every span once it is closed, and of
+/// every event, by name. Clones share what was captured.
+///
+/// It only captures on the thread it is set as default on, so specs of
+/// async code should run on a current-thread runtime, as `#[tokio::test]`
+/// does unless told otherwise.
+#[derive(Clone, Debug, Default)]
+pub struct TraceCapture {
+    spans: Arc<Mutex<Vec<(String, Fields)>>>,
+    events: Arc<Mutex<Vec<(String, Fields)>>>,
+}
+
+impl TraceCapture {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Captures what is traced on this thread until the guard is dropped.
+    pub fn set_default(&self) -> DefaultGuard {
+        tracing::subscriber::set_default(tracing_subscriber::registry().with(self.clone()))
+    }
+
+    /// Returns the fields of the closed spans of the name, in the order they
+    /// were closed.
+    pub fn spans(&self, name: &str) -> Vec<Fields> {
+        named(&self.spans, name)
+    }
+
+    /// Returns the fields of the events of the name, in the order they
+    /// happened. An event's message is its `message` field.
+    pub fn events(&self, name: &str) -> Vec<Fields> {
+        named(&self.events, name)
+    }
+}
+
+fn named(captured: &Mutex<Vec<(String, Fields)>>, name: &str) -> Vec<Fields> {
+    captured
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|(captured, _)| captured == name)
+        .map(|(_, fields)| fields.clone())
+        .collect()
+}
+
+impl<S> Layer<S> for TraceCapture
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attributes: &Attributes<'_>, id: &Id, context: Context<'_, S>) {
+        let mut fields = Fields::new();
+        attributes.record(&mut FieldVisitor(&mut fields));
+        context.span(id).unwrap().extensions_mut().insert(fields);
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, context: Context<'_, S>) {
+        let span = context.span(id).unwrap();
+        let mut extensions = span.extensions_mut();
+        values.record(&mut FieldVisitor(extensions.get_mut::<Fields>().unwrap()));
+    }
+
+    fn on_event(&self, event: &Event<'_>, _: Context<'_, S>) {
+        let mut fields = Fields::new();
+        event.record(&mut FieldVisitor(&mut fields));
+        let name = event.metadata().name().to_string();
+        self.events.lock().unwrap().push((name, fields));
+    }
+
+    fn on_close(&self, id: Id, context: Context<'_, S>) {
+        let span = context.span(&id).unwrap();
+        let fields = span.extensions_mut().remove::<Fields>().unwrap();
+        let name = span.name().to_string();
+        self.spans.lock().unwrap().push((name, fields));
+    }
+}
+
+#[cfg(test)]
+mod specs_for_trace_capture {
+    use super::TraceCapture;
+
+    #[test]
+    fn sut_captures_fields_of_closed_spans_including_recorded_ones() {
+        // Arrange
+        let sut = TraceCapture::new();
+        let _guard = sut.set_default();
+
+        // Act
+        let span = tracing::info_span!("check", url = "http://a.com", up = tracing::field::Empty);
+        span.record("up", true);
+        drop(span);
+
+        // Assert
+        let actual = sut.spans("check");
+        assert_eq!(1, actual.len());
+        assert_eq!("http://a.com", actual[0]["url"]);
+        assert_eq!("true", actual[0]["up"]);
+    }
+
+    #[test]
+    fn sut_captures_events_by_name() {
+        // Arrange
+        let sut = TraceCapture::new();
+        let _guard = sut.set_default();
+
+        // Act
+        tracing::info!(name: "race.winner", url = "http://a.com", "won");
+        tracing::info!("unnamed");
+
+        // Assert
+        let actual = sut.events("race.winner");
+        assert_eq!(1, actual.len());
+        assert_eq!("http://a.com", actual[0]["url"]);
+        assert_eq!("won", actual[0]["message"]);
+    }
+
+    #[test]
+    fn sut_does_not_capture_after_guard_is_dropped() {
+        // Arrange
+        let sut = TraceCapture::new();
+        drop(sut.set_default());
+
+        // Act
+        tracing::info!(name: "late", "missed");
+
+        // Assert
+        assert!(sut.events("late").is_empty());
+    }
+}
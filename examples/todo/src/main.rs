@@ -0,0 +1,29 @@
+use std::env;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::Parser;
+use todo::v1::Cli;
+use todo::v1::execute;
+use todo::v1::file_system::FileSystemTodoRepository;
+
+const FILE_NAME: &str = ".todo.json";
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let Some(home) = env::var_os("HOME") else {
+        eprintln!("HOME is not set");
+        return ExitCode::FAILURE;
+    };
+    let repository = FileSystemTodoRepository::new(PathBuf::from(home).join(FILE_NAME));
+    match execute(cli.command, &repository) {
+        Ok(output) => {
+            println!("{output}");
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("{error}");
+            ExitCode::FAILURE
+        }
+    }
+}
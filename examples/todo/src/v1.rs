@@ -0,0 +1,245 @@
+use std::fmt::Write;
+
+use clap::Parser;
+use clap::Subcommand;
+use serde::Deserialize;
+use serde::Serialize;
+
+#[cfg(test)]
+mod contract;
+pub mod file_system;
+pub mod in_memory;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Todo {
+    pub id: u32,
+    pub title: String,
+    pub done: bool,
+}
+
+/// `TodoRepository` keeps the todos between runs of the CLI.
+pub trait TodoRepository {
+    /// Returns every todo, in the order they were added.
+    fn load(&self) -> Result<Vec<Todo>, RepositoryError>;
+    /// Replaces every todo with `todos`.
+    fn save(&self, todos: &[Todo]) -> Result<(), RepositoryError>;
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RepositoryError {
+    #[error("failed to access todos: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("todos are corrupt: {0}")]
+    Corrupt(#[from] serde_json::Error),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum TodoError {
+    #[error("no todo with id {0}")]
+    NotFound(u32),
+
+    #[error("title must not be empty")]
+    EmptyTitle,
+
+    #[error(transparent)]
+    Repository(#[from] RepositoryError),
+}
+
+/// Keeps track of things to do.
+#[derive(Debug, Parser)]
+#[command(name = "todo")]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Subcommand)]
+pub enum Command {
+    /// Adds a todo.
+    Add { title: String },
+    /// Lists every todo.
+    List,
+    /// Marks a todo as done.
+    Done { id: u32 },
+    /// Removes a todo.
+    Remove { id: u32 },
+}
+
+/// Runs `command` against the todos in `repository`, returning what to
+/// print.
+pub fn execute(command: Command, repository: &impl TodoRepository) -> Result<String, TodoError> {
+    let mut todos = repository.load()?;
+    match command {
+        Command::Add { title } => {
+            let title = title.trim();
+            if title.is_empty() {
+                return Err(TodoError::EmptyTitle);
+            }
+            let id = todos.iter().map(|todo| todo.id).max().unwrap_or(0) + 1;
+            todos.push(Todo {
+                id,
+                title: title.to_string(),
+                done: false,
+            });
+            repository.save(&todos)?;
+            Ok(format!("added {id}: {title}"))
+        }
+        Command::List => {
+            if todos.is_empty() {
+                return Ok("nothing to do".to_string());
+            }
+            let mut output = String::new();
+            for todo in &todos {
+                let mark = if todo.done { "x" } else { " " };
+                writeln!(output, "[{mark}] {} {}", todo.id, todo.title).unwrap();
+            }
+            output.pop();
+            Ok(output)
+        }
+        Command::Done { id } => {
+            let todo = todos
+                .iter_mut()
+                .find(|todo| todo.id == id)
+                .ok_or(TodoError::NotFound(id))?;
+            todo.done = true;
+            let output = format!("done {id}: {}", todo.title);
+            repository.save(&todos)?;
+            Ok(output)
+        }
+        Command::Remove { id } => {
+            let index = todos
+                .iter()
+                .position(|todo| todo.id == id)
+                .ok_or(TodoError::NotFound(id))?;
+            let todo = todos.remove(index);
+            repository.save(&todos)?;
+            Ok(format!("removed {id}: {}", todo.title))
+        }
+    }
+}
+
+#[cfg(test)]
+mod specs_for_execute {
+    use assertions::assert_err_matches;
+
+    use super::Command;
+    use super::TodoError;
+    use super::TodoRepository;
+    use super::execute;
+    use super::in_memory::InMemoryTodoRepository;
+
+    fn add(repository: &InMemoryTodoRepository, titles: &[&str]) {
+        for title in titles {
+            let command = Command::Add {
+                title: title.to_string(),
+            };
+            execute(command, repository).unwrap();
+        }
+    }
+
+    #[test]
+    fn sut_adds_todo_with_next_id() {
+        // Arrange
+        let repository = InMemoryTodoRepository::new();
+        add(&repository, &["Buy milk"]);
+
+        // Act
+        let command = Command::Add {
+            title: "  Write tests ".to_string(),
+        };
+        let actual = execute(command, &repository).unwrap();
+
+        // Assert
+        assert_eq!("added 2: Write tests", actual);
+        let titles = repository
+            .load()
+            .unwrap()
+            .into_iter()
+            .map(|todo| todo.title)
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["Buy milk", "Write tests"], titles);
+    }
+
+    #[test]
+    fn sut_gives_next_id_after_highest_one() {
+        // Arrange
+        let repository = InMemoryTodoRepository::new();
+        add(&repository, &["a", "b", "c"]);
+        execute(Command::Remove { id: 1 }, &repository).unwrap();
+
+        // Act
+        let command = Command::Add {
+            title: "d".to_string(),
+        };
+        let actual = execute(command, &repository).unwrap();
+
+        // Assert
+        assert_eq!("added 4: d", actual);
+    }
+
+    #[test]
+    fn sut_lists_todos_marking_done_ones() {
+        // Arrange
+        let repository = InMemoryTodoRepository::new();
+        add(&repository, &["Buy milk", "Write tests"]);
+        execute(Command::Done { id: 1 }, &repository).unwrap();
+
+        // Act
+        let actual = execute(Command::List, &repository).unwrap();
+
+        // Assert
+        assert_eq!("[x] 1 Buy milk\n[ ] 2 Write tests", actual);
+    }
+
+    #[test]
+    fn sut_lists_nothing_to_do_without_todos() {
+        // Act
+        let actual = execute(Command::List, &InMemoryTodoRepository::new()).unwrap();
+
+        // Assert
+        assert_eq!("nothing to do", actual);
+    }
+
+    #[rstest::rstest]
+    #[case::done(Command::Done { id: 1 }, "done 1: Buy milk")]
+    #[case::remove(Command::Remove { id: 1 }, "removed 1: Buy milk")]
+    fn sut_reports_changed_todo(#[case] command: Command, #[case] expected: &str) {
+        // Arrange
+        let repository = InMemoryTodoRepository::new();
+        add(&repository, &["Buy milk"]);
+
+        // Act
+        let actual = execute(command, &repository).unwrap();
+
+        // Assert
+        assert_eq!(expected, actual);
+    }
+
+    #[rstest::rstest]
+    #[case::done(Command::Done { id: 7 })]
+    #[case::remove(Command::Remove { id: 7 })]
+    fn sut_returns_error_for_unknown_id(#[case] command: Command) {
+        // Act
+        let actual = execute(command, &InMemoryTodoRepository::new());
+
+        // Assert
+        assert_err_matches!(actual, TodoError::NotFound(7));
+    }
+
+    #[test]
+    fn sut_refuses_empty_title() {
+        // Arrange
+        let repository = InMemoryTodoRepository::new();
+
+        // Act
+        let command = Command::Add {
+            title: " ".to_string(),
+        };
+        let actual = execute(command, &repository);
+
+        // Assert
+        assert_err_matches!(actual, TodoError::EmptyTitle);
+        assert!(repository.load().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,64 @@
+//! Specs every `TodoRepository` has to meet, wherever it keeps the todos.
+//!
+//! `todo_repository_contract!` expands to a test module running the specs
+//! against the repository the expression creates, afresh for every spec.
+
+macro_rules! todo_repository_contract {
+    ($repository:expr) => {
+        mod specs_for_todo_repository_contract {
+            #[allow(unused_imports)]
+            use super::*;
+            use crate::v1::Todo;
+            use crate::v1::TodoRepository;
+
+            fn todo(id: u32, title: &str, done: bool) -> Todo {
+                Todo {
+                    id,
+                    title: title.to_string(),
+                    done,
+                }
+            }
+
+            #[test]
+            fn sut_has_no_todos_at_first() {
+                // Arrange
+                let sut = $repository;
+
+                // Act
+                let actual = sut.load().unwrap();
+
+                // Assert
+                assert_eq!(Vec::<Todo>::new(), actual);
+            }
+
+            #[test]
+            fn sut_returns_saved_todos_in_order() {
+                // Arrange
+                let sut = $repository;
+                let todos = vec![todo(2, "Write tests", false), todo(1, "Buy milk", true)];
+
+                // Act
+                sut.save(&todos).unwrap();
+
+                // Assert
+                assert_eq!(todos, sut.load().unwrap());
+            }
+
+            #[test]
+            fn sut_replaces_todos_on_save() {
+                // Arrange
+                let sut = $repository;
+                sut.save(&[todo(1, "Buy milk", false), todo(2, "Write tests", false)])
+                    .unwrap();
+
+                // Act
+                sut.save(&[todo(2, "Write tests", true)]).unwrap();
+
+                // Assert
+                assert_eq!(vec![todo(2, "Write tests", true)], sut.load().unwrap());
+            }
+        }
+    };
+}
+
+pub(crate) use todo_repository_contract;
@@ -0,0 +1,115 @@
+use std::fs;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+
+use super::RepositoryError;
+use super::Todo;
+use super::TodoRepository;
+
+/// `FileSystemTodoRepository` keeps the todos as a JSON array in a file,
+/// such as `[{"id":1,"title":"Buy milk","done":false}]`. A file that does
+/// not exist yet has no todos.
+#[derive(Debug)]
+pub struct FileSystemTodoRepository {
+    path: PathBuf,
+}
+
+impl FileSystemTodoRepository {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        FileSystemTodoRepository { path: path.into() }
+    }
+}
+
+impl TodoRepository for FileSystemTodoRepository {
+    fn load(&self) -> Result<Vec<Todo>, RepositoryError> {
+        let content = match fs::read_to_string(&self.path) {
+            Ok(content) => content,
+            Err(error) if error.kind() == ErrorKind::NotFound => return Ok(vec![]),
+            Err(error) => return Err(error.into()),
+        };
+        if content.trim().is_empty() {
+            return Ok(vec![]);
+        }
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    fn save(&self, todos: &[Todo]) -> Result<(), RepositoryError> {
+        fs::write(&self.path, serde_json::to_vec_pretty(todos)?)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod specs_for_file_system_todo_repository {
+    use std::fs;
+
+    use assertions::assert_err_matches;
+    use tempfile::TempDir;
+
+    use super::FileSystemTodoRepository;
+    use crate::v1::RepositoryError;
+    use crate::v1::Todo;
+    use crate::v1::TodoRepository;
+
+    /// `TempRepository` keeps its file in a directory removed once it is
+    /// dropped.
+    struct TempRepository {
+        repository: FileSystemTodoRepository,
+        _directory: TempDir,
+    }
+
+    impl TempRepository {
+        fn new() -> Self {
+            let directory = tempfile::tempdir().unwrap();
+            TempRepository {
+                repository: FileSystemTodoRepository::new(directory.path().join("todos.json")),
+                _directory: directory,
+            }
+        }
+    }
+
+    impl TodoRepository for TempRepository {
+        fn load(&self) -> Result<Vec<Todo>, RepositoryError> {
+            self.repository.load()
+        }
+
+        fn save(&self, todos: &[Todo]) -> Result<(), RepositoryError> {
+            self.repository.save(todos)
+        }
+    }
+
+    crate::v1::contract::todo_repository_contract!(TempRepository::new());
+
+    #[test]
+    fn sut_keeps_saved_todos_after_reopening() {
+        // Arrange
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("todos.json");
+        let todos = vec![Todo {
+            id: 1,
+            title: "Buy milk".to_string(),
+            done: true,
+        }];
+
+        // Act
+        FileSystemTodoRepository::new(&path).save(&todos).unwrap();
+
+        // Assert
+        let actual = FileSystemTodoRepository::new(&path).load().unwrap();
+        assert_eq!(todos, actual);
+    }
+
+    #[test]
+    fn sut_returns_error_for_corrupt_file() {
+        // Arrange
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("todos.json");
+        fs::write(&path, "not json").unwrap();
+
+        // Act
+        let actual = FileSystemTodoRepository::new(&path).load();
+
+        // Assert
+        assert_err_matches!(actual, RepositoryError::Corrupt(_));
+    }
+}
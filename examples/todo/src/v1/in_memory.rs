@@ -0,0 +1,36 @@
+use std::sync::Mutex;
+
+use super::RepositoryError;
+use super::Todo;
+use super::TodoRepository;
+
+/// `InMemoryTodoRepository` keeps the todos for as long as it lives, which
+/// is all the command specs need.
+#[derive(Debug, Default)]
+pub struct InMemoryTodoRepository {
+    todos: Mutex<Vec<Todo>>,
+}
+
+impl InMemoryTodoRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl TodoRepository for InMemoryTodoRepository {
+    fn load(&self) -> Result<Vec<Todo>, RepositoryError> {
+        Ok(self.todos.lock().unwrap().clone())
+    }
+
+    fn save(&self, todos: &[Todo]) -> Result<(), RepositoryError> {
+        *self.todos.lock().unwrap() = todos.to_vec();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod specs_for_in_memory_todo_repository {
+    use super::InMemoryTodoRepository;
+
+    crate::v1::contract::todo_repository_contract!(InMemoryTodoRepository::new());
+}
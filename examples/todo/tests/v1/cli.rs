@@ -0,0 +1,82 @@
+use std::fs;
+
+use assert_cmd::Command;
+use predicates::str::contains;
+use tempfile::TempDir;
+
+/// Returns the CLI with a temporary directory as its home, so that every
+/// spec starts without todos.
+fn todo(home: &TempDir) -> Command {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_todo"));
+    command.env("HOME", home.path());
+    command
+}
+
+#[rstest::fixture]
+fn home() -> TempDir {
+    tempfile::tempdir().unwrap()
+}
+
+#[rstest::rstest]
+fn sut_keeps_todos_between_runs(home: TempDir) {
+    // Arrange
+    todo(&home).args(["add", "Buy milk"]).assert().success();
+    todo(&home).args(["add", "Write tests"]).assert().success();
+    todo(&home).args(["done", "1"]).assert().success();
+
+    // Act
+    let actual = todo(&home).arg("list").assert();
+
+    // Assert
+    actual
+        .success()
+        .stdout("[x] 1 Buy milk\n[ ] 2 Write tests\n");
+}
+
+#[rstest::rstest]
+fn sut_writes_todos_to_file_in_home(home: TempDir) {
+    // Act
+    todo(&home)
+        .args(["add", "Buy milk"])
+        .assert()
+        .success()
+        .stdout("added 1: Buy milk\n");
+
+    // Assert
+    let content = fs::read_to_string(home.path().join(".todo.json")).unwrap();
+    assert!(content.contains("\"title\": \"Buy milk\""));
+}
+
+#[rstest::rstest]
+fn sut_removes_todo(home: TempDir) {
+    // Arrange
+    todo(&home).args(["add", "Buy milk"]).assert().success();
+
+    // Act
+    todo(&home).args(["remove", "1"]).assert().success();
+
+    // Assert
+    todo(&home)
+        .arg("list")
+        .assert()
+        .success()
+        .stdout("nothing to do\n");
+}
+
+#[rstest::rstest]
+fn sut_fails_for_unknown_id(home: TempDir) {
+    // Act
+    let actual = todo(&home).args(["done", "7"]).assert();
+
+    // Assert
+    actual.failure().stderr("no todo with id 7\n");
+}
+
+#[rstest::rstest]
+fn sut_shows_usage_for_unknown_command(home: TempDir) {
+    // Act
+    let actual = todo(&home).arg("archive").assert();
+
+    // Assert
+    actual.failure().stderr(contains("Usage: todo <COMMAND>"));
+}
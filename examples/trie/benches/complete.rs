@@ -0,0 +1,71 @@
+use criterion::BenchmarkId;
+use criterion::Criterion;
+use criterion::black_box;
+use criterion::criterion_group;
+use criterion::criterion_main;
+
+use trie::v1::Autocomplete;
+use trie::v1::sorted_vec::SortedVec;
+use trie::v1::trie::Trie;
+
+const SYLLABLES: [&str; 10] = ["ka", "ri", "to", "me", "su", "na", "lo", "pe", "di", "gu"];
+
+const SIZES: [usize; 3] = [100, 1_000, 10_000];
+
+/// Returns `n` distinct words made of syllables, the same on every run.
+fn words(n: usize) -> Vec<String> {
+    (0..n)
+        .map(|mut i| {
+            let mut word = String::new();
+            loop {
+                word.push_str(SYLLABLES[i % SYLLABLES.len()]);
+                i /= SYLLABLES.len();
+                if i == 0 {
+                    break word;
+                }
+            }
+        })
+        .collect()
+}
+
+fn filled<A: Autocomplete>(mut autocomplete: A, words: &[String]) -> A {
+    for word in words {
+        autocomplete.insert(word);
+    }
+    autocomplete
+}
+
+/// Compares building the autocomplete from scratch for growing word counts.
+pub fn bench_insert(c: &mut Criterion) {
+    let mut group = c.benchmark_group("insert");
+    for size in SIZES {
+        let words = words(size);
+        group.bench_with_input(BenchmarkId::new("trie", size), &words, |b, words| {
+            b.iter(|| filled(Trie::new(), black_box(words)))
+        });
+        group.bench_with_input(BenchmarkId::new("sorted vec", size), &words, |b, words| {
+            b.iter(|| filled(SortedVec::new(), black_box(words)))
+        });
+    }
+    group.finish();
+}
+
+/// Compares completing a short prefix for growing word counts.
+pub fn bench_complete(c: &mut Criterion) {
+    let mut group = c.benchmark_group("complete");
+    for size in SIZES {
+        let words = words(size);
+        let trie = filled(Trie::new(), &words);
+        let sorted_vec = filled(SortedVec::new(), &words);
+        group.bench_with_input(BenchmarkId::new("trie", size), &size, |b, _| {
+            b.iter(|| trie.complete(black_box("kari"), 10))
+        });
+        group.bench_with_input(BenchmarkId::new("sorted vec", size), &size, |b, _| {
+            b.iter(|| sorted_vec.complete(black_box("kari"), 10))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_insert, bench_complete);
+criterion_main!(benches);
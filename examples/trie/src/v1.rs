@@ -0,0 +1,31 @@
+#[cfg(test)]
+mod contract;
+pub mod sorted_vec;
+pub mod trie;
+
+/// `Autocomplete` keeps words with how often each was inserted, and
+/// completes prefixes with the most frequent ones.
+pub trait Autocomplete {
+    /// Inserts `word`, or counts it once more if it is already there.
+    fn insert(&mut self, word: &str);
+
+    fn contains(&self, word: &str) -> bool;
+
+    /// Returns up to `limit` words starting with `prefix`, the most
+    /// frequent first and alphabetically among equally frequent ones.
+    fn complete(&self, prefix: &str, limit: usize) -> Vec<String>;
+
+    /// Removes `word` however often it was inserted, and tells whether it
+    /// was there.
+    fn remove(&mut self, word: &str) -> bool;
+}
+
+/// Orders `(word, frequency)` candidates as `complete` returns them.
+fn rank(mut candidates: Vec<(String, usize)>, limit: usize) -> Vec<String> {
+    candidates.sort_by(|(a, a_count), (b, b_count)| b_count.cmp(a_count).then_with(|| a.cmp(b)));
+    candidates
+        .into_iter()
+        .take(limit)
+        .map(|(word, _)| word)
+        .collect()
+}
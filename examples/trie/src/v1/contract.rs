@@ -0,0 +1,128 @@
+//! Specs every `Autocomplete` has to meet, however it keeps the words.
+//!
+//! `autocomplete_contract!` expands to a test module running the specs
+//! against the autocomplete the expression creates, afresh for every spec.
+
+macro_rules! autocomplete_contract {
+    ($autocomplete:expr) => {
+        mod specs_for_autocomplete_contract {
+            #[allow(unused_imports)]
+            use super::*;
+            use crate::v1::Autocomplete;
+
+            fn autocomplete(words: &[&str]) -> impl Autocomplete {
+                let mut autocomplete = $autocomplete;
+                for word in words {
+                    autocomplete.insert(word);
+                }
+                autocomplete
+            }
+
+            #[rstest::rstest]
+            #[case::inserted("car", true)]
+            #[case::prefix_of_inserted("ca", false)]
+            #[case::longer_than_inserted("cars", false)]
+            #[case::unrelated("dog", false)]
+            fn sut_contains_only_inserted_words(#[case] word: &str, #[case] expected: bool) {
+                // Arrange
+                let sut = autocomplete(&["car", "cart"]);
+
+                // Act
+                let actual = sut.contains(word);
+
+                // Assert
+                assert_eq!(expected, actual);
+            }
+
+            #[rstest::rstest]
+            #[case::shared_prefix("car", vec!["car", "card", "care", "cart"])]
+            #[case::whole_word("card", vec!["card"])]
+            #[case::empty_prefix("", vec!["car", "card", "care", "cart", "dog"])]
+            #[case::unknown_prefix("x", vec![])]
+            fn sut_completes_prefix_alphabetically_when_equally_frequent(
+                #[case] prefix: &str,
+                #[case] expected: Vec<&str>,
+            ) {
+                // Arrange
+                let sut = autocomplete(&["cart", "dog", "car", "care", "card"]);
+
+                // Act
+                let actual = sut.complete(prefix, 10);
+
+                // Assert
+                assert_eq!(expected, actual);
+            }
+
+            #[test]
+            fn sut_completes_most_frequent_words_first() {
+                // Arrange
+                let sut = autocomplete(&["car", "cart", "care", "care", "cart", "care"]);
+
+                // Act
+                let actual = sut.complete("ca", 10);
+
+                // Assert
+                assert_eq!(vec!["care", "cart", "car"], actual);
+            }
+
+            #[rstest::rstest]
+            #[case::none(0, vec![])]
+            #[case::fewer_than_candidates(2, vec!["care", "car"])]
+            #[case::more_than_candidates(5, vec!["care", "car", "cart"])]
+            fn sut_completes_up_to_limit(#[case] limit: usize, #[case] expected: Vec<&str>) {
+                // Arrange
+                let sut = autocomplete(&["car", "cart", "care", "care"]);
+
+                // Act
+                let actual = sut.complete("c", limit);
+
+                // Assert
+                assert_eq!(expected, actual);
+            }
+
+            #[test]
+            fn sut_forgets_removed_word_however_often_inserted() {
+                // Arrange
+                let mut sut = autocomplete(&["car", "car", "cart"]);
+
+                // Act
+                let removed = sut.remove("car");
+
+                // Assert
+                assert!(removed);
+                assert!(!sut.contains("car"));
+                assert_eq!(vec!["cart"], sut.complete("car", 10));
+            }
+
+            #[rstest::rstest]
+            #[case::unknown("dog")]
+            #[case::prefix_of_inserted("ca")]
+            fn sut_tells_when_removed_word_was_not_there(#[case] word: &str) {
+                // Arrange
+                let mut sut = autocomplete(&["car"]);
+
+                // Act
+                let removed = sut.remove(word);
+
+                // Assert
+                assert!(!removed);
+                assert!(sut.contains("car"));
+            }
+
+            #[test]
+            fn sut_counts_word_from_scratch_when_inserted_after_removal() {
+                // Arrange
+                let mut sut = autocomplete(&["car", "car", "car", "cart", "cart"]);
+                sut.remove("car");
+
+                // Act
+                sut.insert("car");
+
+                // Assert
+                assert_eq!(vec!["cart", "car"], sut.complete("car", 10));
+            }
+        }
+    };
+}
+
+pub(crate) use autocomplete_contract;
@@ -0,0 +1,54 @@
+use super::Autocomplete;
+use super::rank;
+
+/// `SortedVec` keeps the words in alphabetical order, so that the words
+/// sharing a prefix sit next to each other. It is the baseline the trie is
+/// benchmarked against.
+#[derive(Debug, Default)]
+pub struct SortedVec {
+    words: Vec<(String, usize)>,
+}
+
+impl SortedVec {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn search(&self, word: &str) -> Result<usize, usize> {
+        self.words.binary_search_by(|(w, _)| w.as_str().cmp(word))
+    }
+}
+
+impl Autocomplete for SortedVec {
+    fn insert(&mut self, word: &str) {
+        match self.search(word) {
+            Ok(i) => self.words[i].1 += 1,
+            Err(i) => self.words.insert(i, (word.to_string(), 1)),
+        }
+    }
+
+    fn contains(&self, word: &str) -> bool {
+        self.search(word).is_ok()
+    }
+
+    fn complete(&self, prefix: &str, limit: usize) -> Vec<String> {
+        let start = self.words.partition_point(|(w, _)| w.as_str() < prefix);
+        let candidates = self.words[start..]
+            .iter()
+            .take_while(|(w, _)| w.starts_with(prefix))
+            .cloned()
+            .collect();
+        rank(candidates, limit)
+    }
+
+    fn remove(&mut self, word: &str) -> bool {
+        self.search(word).map(|i| self.words.remove(i)).is_ok()
+    }
+}
+
+#[cfg(test)]
+mod specs_for_sorted_vec {
+    use super::SortedVec;
+
+    crate::v1::contract::autocomplete_contract!(SortedVec::new());
+}
@@ -0,0 +1,125 @@
+use std::collections::BTreeMap;
+
+use super::Autocomplete;
+use super::rank;
+
+/// `Trie` keeps a node per character, so that the words sharing a prefix
+/// share the path down to it. Completing a prefix only visits the words
+/// under its node.
+#[derive(Debug, Default)]
+pub struct Trie {
+    root: Node,
+}
+
+#[derive(Debug, Default)]
+struct Node {
+    children: BTreeMap<char, Node>,
+    /// How often the word ending here was inserted, 0 if none does.
+    count: usize,
+}
+
+impl Trie {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn find(&self, prefix: &str) -> Option<&Node> {
+        prefix
+            .chars()
+            .try_fold(&self.root, |node, c| node.children.get(&c))
+    }
+}
+
+impl Node {
+    fn collect(&self, word: &mut String, candidates: &mut Vec<(String, usize)>) {
+        if self.count > 0 {
+            candidates.push((word.clone(), self.count));
+        }
+        for (&c, child) in &self.children {
+            word.push(c);
+            child.collect(word, candidates);
+            word.pop();
+        }
+    }
+
+    /// Removes the rest of the word from this node, and tells whether it
+    /// was there.
+    fn remove(&mut self, mut rest: std::str::Chars) -> bool {
+        let Some(c) = rest.next() else {
+            let found = self.count > 0;
+            self.count = 0;
+            return found;
+        };
+        let Some(child) = self.children.get_mut(&c) else {
+            return false;
+        };
+        let found = child.remove(rest);
+        if child.count == 0 && child.children.is_empty() {
+            self.children.remove(&c);
+        }
+        found
+    }
+}
+
+impl Autocomplete for Trie {
+    fn insert(&mut self, word: &str) {
+        let node = word.chars().fold(&mut self.root, |node, c| {
+            node.children.entry(c).or_default()
+        });
+        node.count += 1;
+    }
+
+    fn contains(&self, word: &str) -> bool {
+        self.find(word).is_some_and(|node| node.count > 0)
+    }
+
+    fn complete(&self, prefix: &str, limit: usize) -> Vec<String> {
+        let Some(node) = self.find(prefix) else {
+            return vec![];
+        };
+        let mut candidates = vec![];
+        node.collect(&mut prefix.to_string(), &mut candidates);
+        rank(candidates, limit)
+    }
+
+    fn remove(&mut self, word: &str) -> bool {
+        self.root.remove(word.chars())
+    }
+}
+
+#[cfg(test)]
+mod specs_for_trie {
+    use super::Trie;
+    use crate::v1::Autocomplete;
+
+    crate::v1::contract::autocomplete_contract!(Trie::new());
+
+    #[test]
+    fn sut_prunes_nodes_left_without_words() {
+        // Arrange
+        let mut sut = Trie::new();
+        sut.insert("car");
+        sut.insert("cart");
+
+        // Act
+        sut.remove("cart");
+        sut.remove("car");
+
+        // Assert
+        assert!(sut.root.children.is_empty());
+    }
+
+    #[test]
+    fn sut_keeps_nodes_of_longer_words_on_removal() {
+        // Arrange
+        let mut sut = Trie::new();
+        sut.insert("car");
+        sut.insert("cart");
+
+        // Act
+        sut.remove("car");
+
+        // Assert
+        assert_eq!(vec!["cart"], sut.complete("c", 10));
+    }
+}
@@ -0,0 +1,193 @@
+use std::fmt;
+
+/// `State` is where an order is in its lifecycle. An order moves from
+/// `Draft` to `Submitted` to `Paid` to `Shipped`, and never back.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum State {
+    Draft,
+    Submitted,
+    Paid { amount: u64 },
+    Shipped { amount: u64, tracking: String },
+}
+
+impl fmt::Display for State {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = match self {
+            State::Draft => "draft",
+            State::Submitted => "submitted",
+            State::Paid { .. } => "paid",
+            State::Shipped { .. } => "shipped",
+        };
+        f.write_str(state)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum OrderError {
+    #[error("cannot {action} an order that is {state}")]
+    InvalidTransition { action: &'static str, state: String },
+
+    #[error("cannot submit an order without items")]
+    Empty,
+}
+
+/// `Order` checks at runtime that every step is taken in the right state.
+/// Nothing stops a caller from trying to ship a draft; it only fails when
+/// it runs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Order {
+    items: Vec<String>,
+    state: State,
+}
+
+impl Order {
+    pub fn new() -> Self {
+        Order {
+            items: vec![],
+            state: State::Draft,
+        }
+    }
+
+    pub fn state(&self) -> &State {
+        &self.state
+    }
+
+    pub fn items(&self) -> &[String] {
+        &self.items
+    }
+
+    pub fn add_item(&mut self, item: &str) -> Result<(), OrderError> {
+        self.expect(matches!(self.state, State::Draft), "add an item to")?;
+        self.items.push(item.to_string());
+        Ok(())
+    }
+
+    pub fn submit(&mut self) -> Result<(), OrderError> {
+        self.expect(matches!(self.state, State::Draft), "submit")?;
+        if self.items.is_empty() {
+            return Err(OrderError::Empty);
+        }
+        self.state = State::Submitted;
+        Ok(())
+    }
+
+    pub fn pay(&mut self, amount: u64) -> Result<(), OrderError> {
+        self.expect(matches!(self.state, State::Submitted), "pay")?;
+        self.state = State::Paid { amount };
+        Ok(())
+    }
+
+    pub fn ship(&mut self, tracking: &str) -> Result<(), OrderError> {
+        let State::Paid { amount } = self.state else {
+            return Err(self.invalid_transition("ship"));
+        };
+        self.state = State::Shipped {
+            amount,
+            tracking: tracking.to_string(),
+        };
+        Ok(())
+    }
+
+    fn expect(&self, allowed: bool, action: &'static str) -> Result<(), OrderError> {
+        if allowed {
+            Ok(())
+        } else {
+            Err(self.invalid_transition(action))
+        }
+    }
+
+    fn invalid_transition(&self, action: &'static str) -> OrderError {
+        OrderError::InvalidTransition {
+            action,
+            state: self.state.to_string(),
+        }
+    }
+}
+
+impl Default for Order {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod specs_for_order {
+    use super::Order;
+    use super::OrderError;
+    use super::State;
+
+    fn submitted() -> Order {
+        let mut order = Order::new();
+        order.add_item("Learn Rust with Tests").unwrap();
+        order.submit().unwrap();
+        order
+    }
+
+    #[test]
+    fn sut_moves_from_draft_to_shipped() {
+        // Arrange
+        let mut sut = submitted();
+
+        // Act
+        sut.pay(2500).unwrap();
+        sut.ship("TRACK-1").unwrap();
+
+        // Assert
+        let expected = State::Shipped {
+            amount: 2500,
+            tracking: "TRACK-1".to_string(),
+        };
+        assert_eq!(&expected, sut.state());
+        assert_eq!(["Learn Rust with Tests"], sut.items());
+    }
+
+    #[test]
+    fn sut_refuses_to_submit_without_items() {
+        // Arrange
+        let mut sut = Order::new();
+
+        // Act
+        let actual = sut.submit();
+
+        // Assert
+        assert_eq!(Err(OrderError::Empty), actual);
+        assert_eq!(&State::Draft, sut.state());
+    }
+
+    #[rstest::rstest]
+    #[case::ship_draft(Order::new(), |order: &mut Order| order.ship("TRACK-1"), "ship", "draft")]
+    #[case::pay_draft(Order::new(), |order: &mut Order| order.pay(1), "pay", "draft")]
+    #[case::add_item_to_submitted(
+        submitted(),
+        |order: &mut Order| order.add_item("Another book"),
+        "add an item to",
+        "submitted",
+    )]
+    #[case::submit_twice(submitted(), |order: &mut Order| order.submit(), "submit", "submitted")]
+    #[case::ship_unpaid(submitted(), |order: &mut Order| order.ship("TRACK-1"), "ship", "submitted")]
+    fn sut_refuses_transition_from_wrong_state(
+        #[case] mut sut: Order,
+        #[case] transition: fn(&mut Order) -> Result<(), OrderError>,
+        #[case] action: &'static str,
+        #[case] state: &str,
+    ) {
+        // Act
+        let actual = transition(&mut sut);
+
+        // Assert
+        let expected = OrderError::InvalidTransition {
+            action,
+            state: state.to_string(),
+        };
+        assert_eq!(Err(expected), actual);
+    }
+
+    #[test]
+    fn sut_describes_invalid_transition() {
+        // Act
+        let actual = Order::new().ship("TRACK-1").unwrap_err().to_string();
+
+        // Assert
+        assert_eq!("cannot ship an order that is draft", actual);
+    }
+}
@@ -0,0 +1,144 @@
+use std::marker::PhantomData;
+
+/// The states of an order. They are types rather than values, so that an
+/// `Order<Draft>` and an `Order<Paid>` are different types with different
+/// methods.
+#[derive(Debug)]
+pub enum Draft {}
+#[derive(Debug)]
+pub enum Submitted {}
+#[derive(Debug)]
+pub enum Paid {}
+#[derive(Debug)]
+pub enum Shipped {}
+
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+#[error("cannot submit an order without items")]
+pub struct EmptyOrder;
+
+/// `Order` checks at compile time that every step is taken in the right
+/// state: each transition consumes the order and returns it in the next
+/// state, and only offers the methods that state allows. Shipping a draft
+/// is not an error at runtime; it does not compile.
+///
+/// Whether an order has items is only known at runtime, so `submit` still
+/// returns a `Result`.
+#[derive(Debug)]
+pub struct Order<S> {
+    items: Vec<String>,
+    amount: u64,
+    tracking: String,
+    state: PhantomData<S>,
+}
+
+impl<S> Order<S> {
+    pub fn items(&self) -> &[String] {
+        &self.items
+    }
+
+    fn into_state<T>(self) -> Order<T> {
+        Order {
+            items: self.items,
+            amount: self.amount,
+            tracking: self.tracking,
+            state: PhantomData,
+        }
+    }
+}
+
+impl Order<Draft> {
+    pub fn new() -> Self {
+        Order {
+            items: vec![],
+            amount: 0,
+            tracking: String::new(),
+            state: PhantomData,
+        }
+    }
+
+    pub fn add_item(mut self, item: &str) -> Self {
+        self.items.push(item.to_string());
+        self
+    }
+
+    /// Returns the order back as it was if it has no items, so that items
+    /// can still be added to it.
+    pub fn submit(self) -> Result<Order<Submitted>, (Self, EmptyOrder)> {
+        if self.items.is_empty() {
+            return Err((self, EmptyOrder));
+        }
+        Ok(self.into_state())
+    }
+}
+
+impl Default for Order<Draft> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Order<Submitted> {
+    pub fn pay(self, amount: u64) -> Order<Paid> {
+        Order {
+            amount,
+            ..self.into_state()
+        }
+    }
+}
+
+impl Order<Paid> {
+    pub fn amount(&self) -> u64 {
+        self.amount
+    }
+
+    pub fn ship(self, tracking: &str) -> Order<Shipped> {
+        Order {
+            tracking: tracking.to_string(),
+            ..self.into_state()
+        }
+    }
+}
+
+impl Order<Shipped> {
+    pub fn amount(&self) -> u64 {
+        self.amount
+    }
+
+    pub fn tracking(&self) -> &str {
+        &self.tracking
+    }
+}
+
+#[cfg(test)]
+mod specs_for_order {
+    use super::EmptyOrder;
+    use super::Order;
+
+    #[test]
+    fn sut_moves_from_draft_to_shipped() {
+        // Arrange
+        let sut = Order::new().add_item("Learn Rust with Tests");
+
+        // Act
+        let actual = sut.submit().unwrap().pay(2500).ship("TRACK-1");
+
+        // Assert
+        assert_eq!(["Learn Rust with Tests"], actual.items());
+        assert_eq!(2500, actual.amount());
+        assert_eq!("TRACK-1", actual.tracking());
+    }
+
+    #[test]
+    fn sut_gives_back_draft_it_refused_to_submit() {
+        // Arrange
+        let sut = Order::new();
+
+        // Act
+        let (draft, error) = sut.submit().unwrap_err();
+
+        // Assert
+        assert_eq!(EmptyOrder, error);
+        let actual = draft.add_item("Learn Rust with Tests").submit();
+        assert!(actual.is_ok());
+    }
+}
@@ -0,0 +1,6 @@
+use typestate::v2::Order;
+
+fn main() {
+    let submitted = Order::new().add_item("Learn Rust with Tests").submit().unwrap();
+    submitted.pay(2500).pay(2500);
+}
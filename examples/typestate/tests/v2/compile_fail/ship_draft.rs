@@ -0,0 +1,5 @@
+use typestate::v2::Order;
+
+fn main() {
+    Order::new().add_item("Learn Rust with Tests").ship("TRACK-1");
+}
@@ -0,0 +1,7 @@
+use typestate::v2::Order;
+
+fn main() {
+    let draft = Order::new().add_item("Learn Rust with Tests");
+    let _submitted = draft.submit().unwrap();
+    draft.add_item("Another book");
+}
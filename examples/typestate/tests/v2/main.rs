@@ -0,0 +1,7 @@
+/// Each case tries a transition the state of the order does not allow, and
+/// has to fail to compile with the error recorded next to it.
+#[test]
+fn sut_does_not_compile_invalid_transitions() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/v2/compile_fail/*.rs");
+}
@@ -0,0 +1,61 @@
+use std::pin::pin;
+use std::sync::Arc;
+use std::task::Context;
+use std::task::Poll;
+use std::task::Wake;
+use std::thread;
+use std::thread::Thread;
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Runs `future` on the current thread, parking it while the future waits.
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let waker = Arc::new(ThreadWaker(thread::current())).into();
+    let mut context = Context::from_waker(&waker);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+        thread::park();
+    }
+}
+
+#[cfg(test)]
+mod specs_for_block_on {
+    use std::thread;
+    use std::time::Duration;
+
+    use super::block_on;
+    use crate::Clock;
+    use crate::TestClock;
+
+    #[test]
+    fn sut_returns_output_of_ready_future() {
+        assert_eq!(42, block_on(async { 42 }));
+    }
+
+    #[test]
+    fn sut_waits_until_future_is_woken() {
+        // Arrange
+        let clock = TestClock::new();
+        let sleep = clock.sleep(Duration::from_secs(1));
+        let advancing = clock.clone();
+        let handle = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(10));
+            advancing.advance(Duration::from_secs(1));
+        });
+
+        // Act
+        block_on(sleep);
+
+        // Assert
+        handle.join().unwrap();
+    }
+}
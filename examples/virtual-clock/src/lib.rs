@@ -0,0 +1,30 @@
+//! A clock the examples can be handed instead of reading the time
+//! themselves, so that specs decide when time passes.
+//!
+//! - `SystemClock` follows the system clock and sleeps on a thread.
+//! - `TokioClock` follows the tokio timer, which tests can pause.
+//! - `TestClock` only moves when told to, or jumps to the end of every
+//!   sleep when auto-advancing.
+//!
+//! `block_on` waits for a sleep outside of any runtime, for code that
+//! sleeps on threads of its own.
+
+use std::time::Duration;
+use std::time::Instant;
+
+mod block_on;
+mod system;
+mod test_clock;
+
+pub use block_on::block_on;
+pub use system::SystemClock;
+pub use system::TokioClock;
+pub use test_clock::TestClock;
+
+/// `Clock` tells the time and waits for it to pass.
+pub trait Clock: Send + Sync {
+    fn now(&self) -> Instant;
+
+    /// Completes once `duration` has passed on this clock.
+    fn sleep(&self, duration: Duration) -> impl Future<Output = ()> + Send + 'static;
+}
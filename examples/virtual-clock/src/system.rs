@@ -0,0 +1,83 @@
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::task::Poll;
+use std::task::Waker;
+use std::thread;
+use std::time::Duration;
+use std::time::Instant;
+
+use super::Clock;
+
+#[derive(Default)]
+struct SleepState {
+    done: bool,
+    waker: Option<Waker>,
+}
+
+/// `SystemClock` follows the system clock. Every sleep waits on a thread of
+/// its own, so it needs no runtime.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn sleep(&self, duration: Duration) -> impl Future<Output = ()> + Send + 'static {
+        let state: Arc<Mutex<SleepState>> = Arc::default();
+        let waking = Arc::clone(&state);
+        thread::spawn(move || {
+            thread::sleep(duration);
+            let mut state = waking.lock().unwrap();
+            state.done = true;
+            if let Some(waker) = state.waker.take() {
+                waker.wake();
+            }
+        });
+        std::future::poll_fn(move |cx| {
+            let mut state = state.lock().unwrap();
+            if state.done {
+                return Poll::Ready(());
+            }
+            state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        })
+    }
+}
+
+/// `TokioClock` is the clock of the tokio timer, which follows the system
+/// clock unless a test pauses it. Its sleeps need a tokio runtime.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TokioClock;
+
+impl Clock for TokioClock {
+    fn now(&self) -> Instant {
+        tokio::time::Instant::now().into_std()
+    }
+
+    fn sleep(&self, duration: Duration) -> impl Future<Output = ()> + Send + 'static {
+        tokio::time::sleep(duration)
+    }
+}
+
+#[cfg(test)]
+mod specs_for_tokio_clock {
+    use std::time::Duration;
+
+    use super::TokioClock;
+    use crate::Clock;
+
+    #[tokio::test(start_paused = true)]
+    async fn sut_sleeps_on_tokio_timer() {
+        // Arrange
+        let sut = TokioClock;
+        let start = sut.now();
+
+        // Act
+        sut.sleep(Duration::from_secs(60)).await;
+
+        // Assert
+        assert_eq!(Duration::from_secs(60), sut.now() - start);
+    }
+}
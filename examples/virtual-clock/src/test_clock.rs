@@ -0,0 +1,193 @@
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::task::Poll;
+use std::task::Waker;
+use std::time::Duration;
+use std::time::Instant;
+
+use super::Clock;
+
+#[derive(Debug)]
+struct State {
+    now: Instant,
+    auto_advance: bool,
+    sleepers: Vec<(Instant, Waker)>,
+}
+
+impl State {
+    fn move_to(&mut self, now: Instant) {
+        self.now = self.now.max(now);
+        let now = self.now;
+        self.sleepers.retain(|(deadline, waker)| {
+            let due = *deadline <= now;
+            if due {
+                waker.wake_by_ref();
+            }
+            !due
+        });
+    }
+}
+
+/// `TestClock` only moves when told to. Clones share the same time, so a
+/// spec can keep one and hand the others out.
+///
+/// An auto-advancing clock also jumps to the end of every sleep as soon as
+/// it is awaited, so code that sleeps runs through without waiting.
+#[derive(Clone, Debug)]
+pub struct TestClock {
+    state: Arc<Mutex<State>>,
+}
+
+impl TestClock {
+    pub fn new() -> Self {
+        TestClock {
+            state: Arc::new(Mutex::new(State {
+                now: Instant::now(),
+                auto_advance: false,
+                sleepers: vec![],
+            })),
+        }
+    }
+
+    pub fn auto_advancing() -> Self {
+        let clock = Self::new();
+        clock.state.lock().unwrap().auto_advance = true;
+        clock
+    }
+
+    /// Moves the clock forward by `duration`, waking the sleeps that are
+    /// over by then.
+    pub fn advance(&self, duration: Duration) {
+        let mut state = self.state.lock().unwrap();
+        let now = state.now + duration;
+        state.move_to(now);
+    }
+
+    /// Returns how many sleeps are waiting for the clock to move.
+    pub fn sleepers(&self) -> usize {
+        self.state.lock().unwrap().sleepers.len()
+    }
+}
+
+impl Default for TestClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for TestClock {
+    fn now(&self) -> Instant {
+        self.state.lock().unwrap().now
+    }
+
+    fn sleep(&self, duration: Duration) -> impl Future<Output = ()> + Send + 'static {
+        let deadline = self.now() + duration;
+        let state = Arc::clone(&self.state);
+        std::future::poll_fn(move |cx| {
+            let mut state = state.lock().unwrap();
+            if state.now >= deadline {
+                return Poll::Ready(());
+            }
+            if state.auto_advance {
+                state.move_to(deadline);
+                return Poll::Ready(());
+            }
+            state
+                .sleepers
+                .retain(|(due, waker)| *due != deadline || !waker.will_wake(cx.waker()));
+            state.sleepers.push((deadline, cx.waker().clone()));
+            Poll::Pending
+        })
+    }
+}
+
+#[cfg(test)]
+mod specs_for_test_clock {
+    use std::pin::pin;
+    use std::task::Context;
+    use std::task::Poll;
+    use std::task::Waker;
+    use std::time::Duration;
+
+    use super::TestClock;
+    use crate::Clock;
+
+    fn poll(future: &mut (impl Future<Output = ()> + Unpin)) -> Poll<()> {
+        std::pin::Pin::new(future).poll(&mut Context::from_waker(Waker::noop()))
+    }
+
+    #[test]
+    fn sut_moves_only_when_advanced() {
+        // Arrange
+        let sut = TestClock::new();
+        let start = sut.now();
+
+        // Act
+        sut.advance(Duration::from_secs(5));
+
+        // Assert
+        assert_eq!(Duration::from_secs(5), sut.now() - start);
+    }
+
+    #[test]
+    fn sut_shares_time_with_clones() {
+        // Arrange
+        let sut = TestClock::new();
+        let clone = sut.clone();
+
+        // Act
+        clone.advance(Duration::from_secs(5));
+
+        // Assert
+        assert_eq!(clone.now(), sut.now());
+    }
+
+    #[test]
+    fn sut_finishes_sleep_once_advanced_past_it() {
+        // Arrange
+        let sut = TestClock::new();
+        let mut sleep = pin!(sut.sleep(Duration::from_secs(10)));
+        assert_eq!(Poll::Pending, poll(&mut sleep));
+
+        // Act
+        sut.advance(Duration::from_secs(9));
+        let before = poll(&mut sleep);
+        sut.advance(Duration::from_secs(1));
+        let after = poll(&mut sleep);
+
+        // Assert
+        assert_eq!(Poll::Pending, before);
+        assert_eq!(Poll::Ready(()), after);
+        assert_eq!(0, sut.sleepers());
+    }
+
+    #[test]
+    fn sut_jumps_to_end_of_sleep_if_auto_advancing() {
+        // Arrange
+        let sut = TestClock::auto_advancing();
+        let start = sut.now();
+        let mut sleep = pin!(sut.sleep(Duration::from_secs(60)));
+
+        // Act
+        let actual = poll(&mut sleep);
+
+        // Assert
+        assert_eq!(Poll::Ready(()), actual);
+        assert_eq!(Duration::from_secs(60), sut.now() - start);
+    }
+
+    #[tokio::test]
+    async fn sut_runs_sleeping_code_through_if_auto_advancing() {
+        // Arrange
+        let sut = TestClock::auto_advancing();
+        let start = sut.now();
+
+        // Act
+        for _ in 0..3 {
+            sut.sleep(Duration::from_secs(3600)).await;
+        }
+
+        // Assert
+        assert_eq!(Duration::from_secs(3 * 3600), sut.now() - start);
+    }
+}
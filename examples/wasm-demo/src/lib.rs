@@ -0,0 +1,87 @@
+//! Exposes the clock and the Game of Life to JavaScript through
+//! wasm-bindgen, for the demo page in `www/`.
+//!
+//! Build it for the page with
+//! `wasm-pack build --target web --out-dir www/pkg`.
+
+use chrono::NaiveTime;
+use clock::v2::HandMode;
+use clock::v2::svg_writer;
+use life::v1::Edges;
+use life::v1::Grid;
+use life::v1::pattern::Pattern;
+use life::v1::render::Renderer;
+use life::v1::render::SvgRenderer;
+use wasm_bindgen::prelude::*;
+
+/// Returns the SVG of the clock face at the given time. With `sweep`, the
+/// second hand glides between seconds instead of ticking.
+#[wasm_bindgen(js_name = clockSvg)]
+pub fn clock_svg(
+    hour: u32,
+    minute: u32,
+    second: u32,
+    millisecond: u32,
+    sweep: bool,
+) -> Result<String, JsError> {
+    let time = NaiveTime::from_hms_milli_opt(hour, minute, second, millisecond)
+        .ok_or_else(|| JsError::new("invalid time"))?;
+    let mode = if sweep {
+        HandMode::Sweep
+    } else {
+        HandMode::Tick
+    };
+    let mut buffer = Vec::new();
+    svg_writer(&mut buffer, time, mode)?;
+    Ok(String::from_utf8(buffer)?)
+}
+
+/// `Life` is a Game of Life whose edges wrap around, stepped from
+/// JavaScript one generation at a time.
+#[wasm_bindgen]
+pub struct Life {
+    grid: Grid,
+}
+
+#[wasm_bindgen]
+impl Life {
+    /// Creates a grid with the plaintext `pattern` placed in its top left
+    /// corner.
+    #[wasm_bindgen(constructor)]
+    pub fn new(width: usize, height: usize, pattern: &str) -> Result<Life, JsError> {
+        if width == 0 || height == 0 {
+            return Err(JsError::new("grid must not be empty"));
+        }
+        let mut grid = Grid::new(width, height, Edges::Toroidal);
+        grid.place(&Pattern::from_plaintext(pattern)?, (0, 0));
+        Ok(Life { grid })
+    }
+
+    pub fn step(&mut self) {
+        self.grid = self.grid.step();
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn width(&self) -> usize {
+        self.grid.width()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn height(&self) -> usize {
+        self.grid.height()
+    }
+
+    #[wasm_bindgen(js_name = isAlive)]
+    pub fn is_alive(&self, x: usize, y: usize) -> bool {
+        self.grid.is_alive(x, y)
+    }
+
+    /// Returns the SVG of the generation, with a square of `cell_size` for
+    /// every live cell.
+    #[wasm_bindgen(js_name = toSvg)]
+    pub fn to_svg(&self, cell_size: usize) -> Result<String, JsError> {
+        let mut buffer = Vec::new();
+        SvgRenderer { cell_size }.render(&mut buffer, &self.grid)?;
+        Ok(String::from_utf8(buffer)?)
+    }
+}
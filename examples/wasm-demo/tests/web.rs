@@ -0,0 +1,84 @@
+//! Run with `wasm-pack test --headless --firefox`.
+
+#![cfg(target_arch = "wasm32")]
+
+use wasm_bindgen_test::wasm_bindgen_test;
+use wasm_bindgen_test::wasm_bindgen_test_configure;
+use wasm_demo::Life;
+use wasm_demo::clock_svg;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+const BLINKER: &str = ".O.\n.O.\n.O.";
+
+#[wasm_bindgen_test]
+fn sut_draws_clock_face_as_svg() {
+    // Act
+    let actual = clock_svg(0, 0, 0, 0, false).unwrap();
+
+    // Assert
+    assert!(actual.starts_with("<?xml"));
+    assert!(actual.contains(r#"<line x1="150" y1="150" x2="150.000" y2="60.000""#));
+    assert!(actual.ends_with("</svg>"));
+}
+
+#[wasm_bindgen_test]
+fn sut_moves_second_hand_between_seconds_in_sweep_mode() {
+    // Act
+    let tick = clock_svg(0, 0, 0, 500, false).unwrap();
+    let sweep = clock_svg(0, 0, 0, 500, true).unwrap();
+
+    // Assert
+    assert_ne!(tick, sweep);
+}
+
+#[wasm_bindgen_test]
+fn sut_rejects_invalid_time() {
+    // Act
+    let actual = clock_svg(24, 0, 0, 0, false);
+
+    // Assert
+    assert!(actual.is_err());
+}
+
+#[wasm_bindgen_test]
+fn sut_places_pattern_in_top_left_corner() {
+    // Act
+    let sut = Life::new(5, 5, BLINKER).unwrap();
+
+    // Assert
+    assert_eq!((5, 5), (sut.width(), sut.height()));
+    assert!(sut.is_alive(1, 0) && sut.is_alive(1, 1) && sut.is_alive(1, 2));
+    assert!(!sut.is_alive(0, 1) && !sut.is_alive(2, 1));
+}
+
+#[wasm_bindgen_test]
+fn sut_steps_blinker_to_its_other_phase() {
+    // Arrange
+    let mut sut = Life::new(5, 5, BLINKER).unwrap();
+
+    // Act
+    sut.step();
+
+    // Assert
+    assert!(sut.is_alive(0, 1) && sut.is_alive(1, 1) && sut.is_alive(2, 1));
+    assert!(!sut.is_alive(1, 0) && !sut.is_alive(1, 2));
+}
+
+#[wasm_bindgen_test]
+fn sut_draws_a_square_per_live_cell_as_svg() {
+    // Arrange
+    let sut = Life::new(5, 5, BLINKER).unwrap();
+
+    // Act
+    let actual = sut.to_svg(10).unwrap();
+
+    // Assert
+    assert_eq!(3, actual.matches(r##"fill="#000""##).count());
+}
+
+#[wasm_bindgen_test]
+fn sut_rejects_empty_grid_and_malformed_pattern() {
+    assert!(Life::new(0, 5, BLINKER).is_err());
+    assert!(Life::new(5, 5, "O?O").is_err());
+}
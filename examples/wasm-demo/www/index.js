@@ -0,0 +1,43 @@
+import init, { Life, clockSvg } from "./pkg/wasm_demo.js";
+
+const GLIDER_GUN = `........................O...........
+......................O.O...........
+............OO......OO............OO
+...........O...O....OO............OO
+OO........O.....O...OO..............
+OO........O...O.OO....O.O...........
+..........O.....O.......O...........
+...........O...O....................
+............OO......................`;
+
+await init();
+
+const clock = document.getElementById("clock");
+const sweep = document.getElementById("sweep");
+
+function drawClock() {
+  const now = new Date();
+  clock.innerHTML = clockSvg(
+    now.getHours(),
+    now.getMinutes(),
+    now.getSeconds(),
+    now.getMilliseconds(),
+    sweep.checked,
+  );
+  requestAnimationFrame(drawClock);
+}
+
+const board = document.getElementById("life");
+let life = new Life(60, 40, GLIDER_GUN);
+
+document.getElementById("restart").addEventListener("click", () => {
+  life.free();
+  life = new Life(60, 40, GLIDER_GUN);
+});
+
+setInterval(() => {
+  board.innerHTML = life.toSvg(8);
+  life.step();
+}, 100);
+
+drawClock();
@@ -0,0 +1,591 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use cancellation::Scope;
+use executor::JoinHandle;
+use executor::Spawner;
+use executor::TokioSpawner;
+use futures::future::join_all;
+use tokio::sync::Mutex;
+use tokio::sync::mpsc;
+
+pub const DEFAULT_WORKERS: usize = 4;
+pub const DEFAULT_MAX_ATTEMPTS: u32 = 3;
+pub const DEFAULT_BACKOFF: Backoff = Backoff {
+    initial: Duration::from_millis(100),
+    max: Duration::from_secs(10),
+};
+
+/// `JobError` is why an attempt at a job failed.
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+#[error("{0}")]
+pub struct JobError(pub String);
+
+/// `Stopped` is returned to a job enqueued when no worker is left to run it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+#[error("queue has stopped")]
+pub struct Stopped;
+
+/// `Job` is a unit of work. It may be run again after it fails, so it takes
+/// `&self`.
+pub trait Job: Send + Sync + 'static {
+    fn run(&self) -> impl Future<Output = Result<(), JobError>> + Send;
+}
+
+/// `DeadLetterSink` receives the jobs that failed every attempt, so that
+/// they can be looked into rather than lost.
+pub trait DeadLetterSink<J>: Send + Sync {
+    /// Takes the job along with the error of its last attempt.
+    fn bury(&self, job: J, error: JobError);
+}
+
+/// `Backoff` is how long a job waits before it is attempted again. The wait
+/// doubles after every failed attempt, up to `max`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Backoff {
+    pub initial: Duration,
+    pub max: Duration,
+}
+
+impl Backoff {
+    /// Returns the wait after the failed attempt, counting attempts from 1.
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.initial.saturating_mul(factor).min(self.max)
+    }
+}
+
+/// `WorkerPool` tells how the jobs of a queue are run: by how many workers,
+/// and how many times a failing job is attempted.
+#[derive(Clone, Copy, Debug)]
+pub struct WorkerPool {
+    workers: usize,
+    max_attempts: u32,
+    backoff: Backoff,
+}
+
+impl WorkerPool {
+    pub fn new() -> Self {
+        WorkerPool {
+            workers: DEFAULT_WORKERS,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            backoff: DEFAULT_BACKOFF,
+        }
+    }
+
+    pub fn with_workers(self, workers: usize) -> Self {
+        WorkerPool { workers, ..self }
+    }
+
+    pub fn with_max_attempts(self, max_attempts: u32) -> Self {
+        WorkerPool {
+            max_attempts,
+            ..self
+        }
+    }
+
+    pub fn with_backoff(self, backoff: Backoff) -> Self {
+        WorkerPool { backoff, ..self }
+    }
+
+    /// Spawns the workers on the current tokio runtime, returning the queue
+    /// they take jobs from.
+    pub fn start<J: Job>(self, dead_letters: Arc<dyn DeadLetterSink<J>>) -> Queue<J> {
+        self.start_on(dead_letters, &TokioSpawner)
+    }
+
+    /// Spawns the workers with `spawner`, returning the queue they take jobs
+    /// from. The backoff between attempts is waited out on a tokio timer, so
+    /// a job can only be retried within a tokio runtime.
+    pub fn start_on<J: Job>(
+        self,
+        dead_letters: Arc<dyn DeadLetterSink<J>>,
+        spawner: &impl Spawner,
+    ) -> Queue<J> {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let jobs = Arc::new(Mutex::new(receiver));
+        let scope = Scope::new();
+        let workers = (0..self.workers)
+            .map(|_| {
+                spawner.spawn(work(
+                    jobs.clone(),
+                    self,
+                    dead_letters.clone(),
+                    scope.clone(),
+                ))
+            })
+            .collect();
+        Queue {
+            sender,
+            workers,
+            scope,
+        }
+    }
+}
+
+impl Default for WorkerPool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// `Queue` hands the jobs enqueued to the first free worker. Dropping it
+/// lets the workers finish the jobs enqueued, like `shutdown` without the
+/// wait.
+pub struct Queue<J> {
+    sender: mpsc::UnboundedSender<J>,
+    workers: Vec<JoinHandle<()>>,
+    scope: Scope,
+}
+
+impl<J: Job> Queue<J> {
+    pub fn enqueue(&self, job: J) -> Result<(), Stopped> {
+        self.sender.send(job).map_err(|_| Stopped)
+    }
+
+    /// Stops taking jobs and waits for the workers to finish every job
+    /// already enqueued, retries included.
+    pub async fn shutdown(self) {
+        drop(self.sender);
+        join_all(self.workers).await;
+    }
+
+    /// Stops the workers without finishing the jobs left. The jobs being
+    /// attempted or waiting out a backoff are dropped, and so are the jobs
+    /// still enqueued; none of them reaches the dead letters.
+    pub async fn abort(self) {
+        self.scope.cancel();
+        join_all(self.workers).await;
+    }
+}
+
+async fn work<J: Job>(
+    jobs: Arc<Mutex<mpsc::UnboundedReceiver<J>>>,
+    pool: WorkerPool,
+    dead_letters: Arc<dyn DeadLetterSink<J>>,
+    scope: Scope,
+) {
+    loop {
+        // The lock is released at the end of the statement, so that it is
+        // only held while waiting for a job, not while running it.
+        let job = scope.run(async { jobs.lock().await.recv().await }).await;
+        let Ok(Some(job)) = job else {
+            return;
+        };
+        if scope
+            .run(attempt(job, pool, dead_letters.as_ref()))
+            .await
+            .is_err()
+        {
+            return;
+        }
+    }
+}
+
+/// Runs the job until it succeeds or runs out of attempts. The worker waits
+/// out the backoff itself, so a retried job keeps its worker busy.
+///
+/// With the `tracing` feature, every job runs in a `job` span recording its
+/// `attempts`, with a `job.retry` event before each retry and a `job.dead`
+/// event when it is buried.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(name = "job", skip_all, fields(attempts = tracing::field::Empty))
+)]
+async fn attempt<J: Job>(job: J, pool: WorkerPool, dead_letters: &dyn DeadLetterSink<J>) {
+    let mut attempt = 1;
+    loop {
+        let result = job.run().await;
+        #[cfg(feature = "tracing")]
+        tracing::Span::current().record("attempts", attempt);
+        match result {
+            Ok(()) => return,
+            Err(error) if attempt >= pool.max_attempts => {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(name: "job.dead", attempts = attempt, %error, "job is buried");
+                return dead_letters.bury(job, error);
+            }
+            Err(_error) => {
+                #[cfg(feature = "tracing")]
+                tracing::info!(name: "job.retry", attempt, error = %_error, "job is retried");
+                tokio::time::sleep(pool.backoff.delay(attempt)).await;
+                attempt += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod specs_for_backoff {
+    use std::time::Duration;
+
+    use super::Backoff;
+
+    #[rstest::rstest]
+    #[case(1, 100)]
+    #[case(2, 200)]
+    #[case(3, 400)]
+    #[case(5, 1000)]
+    #[case(u32::MAX, 1000)]
+    fn sut_doubles_delay_up_to_max(#[case] attempt: u32, #[case] expected: u64) {
+        // Arrange
+        let sut = Backoff {
+            initial: Duration::from_millis(100),
+            max: Duration::from_secs(1),
+        };
+
+        // Act
+        let actual = sut.delay(attempt);
+
+        // Assert
+        assert_eq!(Duration::from_millis(expected), actual);
+    }
+}
+
+#[cfg(test)]
+mod specs_for_queue {
+    use std::sync::Arc;
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    use executor::TestExecutor;
+    use tokio::sync::Barrier;
+    use tokio::time::Instant;
+
+    use super::Backoff;
+    use super::DeadLetterSink;
+    use super::Job;
+    use super::JobError;
+    use super::WorkerPool;
+
+    const BACKOFF: Backoff = Backoff {
+        initial: Duration::from_millis(100),
+        max: Duration::from_secs(1),
+    };
+
+    /// `Attempts` records when each job was attempted.
+    type Attempts = Arc<Mutex<Vec<(u32, Instant)>>>;
+
+    /// `FlakyJob` fails its first `failures` attempts.
+    struct FlakyJob {
+        id: u32,
+        failures: usize,
+        attempts: Attempts,
+    }
+
+    impl FlakyJob {
+        fn new(id: u32, failures: usize, attempts: &Attempts) -> Self {
+            FlakyJob {
+                id,
+                failures,
+                attempts: attempts.clone(),
+            }
+        }
+    }
+
+    impl Job for FlakyJob {
+        async fn run(&self) -> Result<(), JobError> {
+            let mut attempts = self.attempts.lock().unwrap();
+            let attempted = attempts.iter().filter(|(id, _)| *id == self.id).count();
+            attempts.push((self.id, Instant::now()));
+            if attempted < self.failures {
+                return Err(JobError(format!("attempt {} failed", attempted + 1)));
+            }
+            Ok(())
+        }
+    }
+
+    /// `BarrierJob` only finishes once as many jobs as the barrier waits for
+    /// run at the same time.
+    struct BarrierJob(Arc<Barrier>);
+
+    impl Job for BarrierJob {
+        async fn run(&self) -> Result<(), JobError> {
+            self.0.wait().await;
+            Ok(())
+        }
+    }
+
+    #[derive(Default)]
+    struct SpyDeadLetterSink {
+        buried: Mutex<Vec<(u32, JobError)>>,
+    }
+
+    impl DeadLetterSink<FlakyJob> for SpyDeadLetterSink {
+        fn bury(&self, job: FlakyJob, error: JobError) {
+            self.buried.lock().unwrap().push((job.id, error));
+        }
+    }
+
+    impl DeadLetterSink<BarrierJob> for SpyDeadLetterSink {
+        fn bury(&self, _: BarrierJob, error: JobError) {
+            self.buried.lock().unwrap().push((0, error));
+        }
+    }
+
+    fn ids(attempts: &Attempts) -> Vec<u32> {
+        let mut ids = attempts
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(id, _)| *id)
+            .collect::<Vec<_>>();
+        ids.sort();
+        ids
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn sut_runs_every_enqueued_job_before_shutting_down() {
+        // Arrange
+        let attempts = Attempts::default();
+        let sut = WorkerPool::new()
+            .with_workers(2)
+            .start(Arc::new(SpyDeadLetterSink::default()));
+
+        // Act
+        for id in 1..=5 {
+            sut.enqueue(FlakyJob::new(id, 0, &attempts)).unwrap();
+        }
+        sut.shutdown().await;
+
+        // Assert
+        assert_eq!(vec![1, 2, 3, 4, 5], ids(&attempts));
+    }
+
+    #[test]
+    fn sut_hands_jobs_to_workers_in_order_enqueued() {
+        // Arrange
+        let attempts = Attempts::default();
+        let executor = TestExecutor::new();
+        let sut = WorkerPool::new()
+            .with_workers(2)
+            .start_on(Arc::new(SpyDeadLetterSink::default()), &executor.spawner());
+
+        // Act
+        for id in 1..=5 {
+            sut.enqueue(FlakyJob::new(id, 0, &attempts)).unwrap();
+        }
+        executor.block_on(sut.shutdown());
+
+        // Assert
+        let actual = attempts
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(id, _)| *id)
+            .collect::<Vec<_>>();
+        assert_eq!(vec![1, 2, 3, 4, 5], actual);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn sut_retries_failed_job_after_backoff() {
+        // Arrange
+        let attempts = Attempts::default();
+        let dead_letters = Arc::new(SpyDeadLetterSink::default());
+        let sut = WorkerPool::new()
+            .with_backoff(BACKOFF)
+            .start(dead_letters.clone());
+
+        // Act
+        sut.enqueue(FlakyJob::new(1, 2, &attempts)).unwrap();
+        sut.shutdown().await;
+
+        // Assert
+        let attempts = attempts.lock().unwrap();
+        let waits = attempts
+            .windows(2)
+            .map(|pair| pair[1].1 - pair[0].1)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![Duration::from_millis(100), Duration::from_millis(200)],
+            waits
+        );
+        assert!(dead_letters.buried.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn sut_sends_job_failing_every_attempt_to_dead_letters() {
+        // Arrange
+        let attempts = Attempts::default();
+        let dead_letters = Arc::new(SpyDeadLetterSink::default());
+        let sut = WorkerPool::new()
+            .with_max_attempts(3)
+            .with_backoff(BACKOFF)
+            .start(dead_letters.clone());
+
+        // Act
+        sut.enqueue(FlakyJob::new(1, usize::MAX, &attempts))
+            .unwrap();
+        sut.shutdown().await;
+
+        // Assert
+        assert_eq!(vec![1, 1, 1], ids(&attempts));
+        assert_eq!(
+            vec![(1, JobError("attempt 3 failed".to_string()))],
+            *dead_letters.buried.lock().unwrap()
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn sut_runs_jobs_on_every_worker_at_once() {
+        // Arrange
+        let workers = 3;
+        let barrier = Arc::new(Barrier::new(workers));
+        let sut = WorkerPool::new()
+            .with_workers(workers)
+            .start(Arc::new(SpyDeadLetterSink::default()));
+
+        // Act
+        for _ in 0..workers {
+            sut.enqueue(BarrierJob(barrier.clone())).unwrap();
+        }
+        let actual = tokio::time::timeout(Duration::from_secs(1), sut.shutdown()).await;
+
+        // Assert
+        assert!(actual.is_ok());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn sut_keeps_other_workers_busy_while_job_waits_out_backoff() {
+        // Arrange
+        let attempts = Attempts::default();
+        let sut = WorkerPool::new()
+            .with_workers(2)
+            .with_backoff(BACKOFF)
+            .start(Arc::new(SpyDeadLetterSink::default()));
+        let started = Instant::now();
+
+        // Act
+        sut.enqueue(FlakyJob::new(1, 1, &attempts)).unwrap();
+        sut.enqueue(FlakyJob::new(2, 0, &attempts)).unwrap();
+        sut.shutdown().await;
+
+        // Assert
+        let attempts = attempts.lock().unwrap();
+        let second = attempts.iter().find(|(id, _)| *id == 2).unwrap();
+        assert_eq!(started, second.1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn sut_drops_jobs_left_once_aborted() {
+        // Arrange
+        let attempts = Attempts::default();
+        let dead_letters = Arc::new(SpyDeadLetterSink::default());
+        let sut = WorkerPool::new()
+            .with_workers(1)
+            .with_backoff(BACKOFF)
+            .start(dead_letters.clone());
+        sut.enqueue(FlakyJob::new(1, usize::MAX, &attempts))
+            .unwrap();
+        sut.enqueue(FlakyJob::new(2, 0, &attempts)).unwrap();
+        tokio::task::yield_now().await;
+
+        // Act
+        let actual = tokio::time::timeout(Duration::from_millis(50), sut.abort()).await;
+
+        // Assert
+        assert!(actual.is_ok());
+        assert_eq!(vec![1], ids(&attempts));
+        assert!(dead_letters.buried.lock().unwrap().is_empty());
+    }
+}
+
+#[cfg(all(test, feature = "tracing"))]
+mod specs_for_queue_tracing {
+    use std::sync::Arc;
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    use test_helpers::TraceCapture;
+
+    use super::Backoff;
+    use super::DeadLetterSink;
+    use super::Job;
+    use super::JobError;
+    use super::WorkerPool;
+
+    /// `FailingJob` fails its first `failures` attempts.
+    struct FailingJob {
+        failures: u32,
+        attempted: Mutex<u32>,
+    }
+
+    impl FailingJob {
+        fn new(failures: u32) -> Self {
+            FailingJob {
+                failures,
+                attempted: Mutex::new(0),
+            }
+        }
+    }
+
+    impl Job for FailingJob {
+        async fn run(&self) -> Result<(), JobError> {
+            let mut attempted = self.attempted.lock().unwrap();
+            *attempted += 1;
+            if *attempted <= self.failures {
+                return Err(JobError(format!("attempt {attempted} failed")));
+            }
+            Ok(())
+        }
+    }
+
+    struct NoDeadLetterSink;
+
+    impl DeadLetterSink<FailingJob> for NoDeadLetterSink {
+        fn bury(&self, _: FailingJob, _: JobError) {}
+    }
+
+    async fn run(jobs: Vec<FailingJob>) {
+        let sut = WorkerPool::new()
+            .with_workers(1)
+            .with_max_attempts(3)
+            .with_backoff(Backoff {
+                initial: Duration::from_millis(100),
+                max: Duration::from_secs(1),
+            })
+            .start(Arc::new(NoDeadLetterSink));
+        for job in jobs {
+            sut.enqueue(job).unwrap();
+        }
+        sut.shutdown().await;
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn sut_records_attempts_of_every_job_in_job_span() {
+        // Arrange
+        let capture = TraceCapture::new();
+        let _guard = capture.set_default();
+
+        // Act
+        run(vec![FailingJob::new(0), FailingJob::new(1)]).await;
+
+        // Assert
+        let attempts = capture
+            .spans("job")
+            .iter()
+            .map(|job| job["attempts"].clone())
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["1", "2"], attempts);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn sut_tells_retries_and_burial_of_job_in_events() {
+        // Arrange
+        let capture = TraceCapture::new();
+        let _guard = capture.set_default();
+
+        // Act
+        run(vec![FailingJob::new(u32::MAX)]).await;
+
+        // Assert
+        let retries = capture.events("job.retry");
+        assert_eq!(2, retries.len());
+        assert_eq!("1", retries[0]["attempt"]);
+        assert_eq!("attempt 1 failed", retries[0]["error"]);
+        let dead = capture.events("job.dead");
+        assert_eq!(1, dead.len());
+        assert_eq!("3", dead[0]["attempts"]);
+        assert_eq!("attempt 3 failed", dead[0]["error"]);
+    }
+}